@@ -0,0 +1,66 @@
+//! Benchmarks of `Update` deserialization.
+//!
+//! `get_updates_batch` measures the throughput of deserializing a large batch
+//! of updates, as returned by [`GetUpdates`] with the maximum `limit`.
+//!
+//! [`GetUpdates`]: teloxide_core::payloads::GetUpdates
+//!
+//! Run with `cargo bench --features nightly --bench update`.
+#![feature(test)]
+
+extern crate test;
+
+use teloxide_core::types::Update;
+use test::{black_box, Bencher};
+
+const UPDATES: &[&str] = &[
+    include_str!("../test_data/types/update/private_text_message.json"),
+    include_str!("../test_data/types/update/command_with_entities.json"),
+    include_str!("../test_data/types/update/callback_query.json"),
+    include_str!("../test_data/types/update/my_chat_member_kicked.json"),
+];
+
+#[bench]
+fn from_str(b: &mut Bencher) {
+    b.iter(|| {
+        for json in UPDATES {
+            let input = String::from_utf8(black_box(json.as_bytes()).to_vec()).unwrap();
+            black_box(serde_json::from_str::<Update>(&input).unwrap());
+        }
+    });
+}
+
+#[bench]
+fn from_json_bytes(b: &mut Bencher) {
+    b.iter(|| {
+        for json in UPDATES {
+            black_box(Update::from_json_bytes(black_box(json.as_bytes())).unwrap());
+        }
+    });
+}
+
+/// The maximum number of updates returned by a single `getUpdates` call.
+const BATCH_SIZE: usize = 100;
+
+fn get_updates_response() -> String {
+    let updates: Vec<_> = UPDATES.iter().cycle().take(BATCH_SIZE).copied().collect();
+    format!(r#"{{"ok":true,"result":[{}]}}"#, updates.join(","))
+}
+
+#[derive(serde::Deserialize)]
+struct Response {
+    #[allow(dead_code)]
+    ok: bool,
+    result: Vec<Update>,
+}
+
+#[bench]
+fn get_updates_batch(b: &mut Bencher) {
+    let json = get_updates_response();
+    b.bytes = json.len() as u64;
+    b.iter(|| {
+        let response: Response = serde_json::from_str(black_box(&json)).unwrap();
+        assert_eq!(response.result.len(), BATCH_SIZE);
+        black_box(response);
+    });
+}
@@ -33,15 +33,33 @@ pub mod erased;
 #[cfg(feature = "throttle")]
 pub mod throttle;
 
+/// [`FileIdCache`] bot adaptor which reuses file ids of uploaded files.
+///
+/// [`FileIdCache`]: file_id_cache::FileIdCache
+#[cfg(feature = "file_id_cache")]
+pub mod file_id_cache;
+
+/// [`MockBot`] bot adaptor which records requests instead of sending them.
+///
+/// [`MockBot`]: mock::MockBot
+#[cfg(feature = "mock_bot")]
+pub mod mock;
+
 mod parse_mode;
+mod protect_content;
 
 #[cfg(feature = "cache_me")]
 pub use cache_me::CacheMe;
 #[cfg(feature = "erased")]
-pub use erased::ErasedRequester;
+pub use erased::{DynRequester, ErasedRequester};
+#[cfg(feature = "file_id_cache")]
+pub use file_id_cache::FileIdCache;
+#[cfg(feature = "mock_bot")]
+pub use mock::MockBot;
 #[cfg(feature = "throttle")]
 pub use throttle::Throttle;
 #[cfg(feature = "trace_adaptor")]
 pub use trace::Trace;
 
 pub use parse_mode::DefaultParseMode;
+pub use protect_content::DefaultProtectContent;
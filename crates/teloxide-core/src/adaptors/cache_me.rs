@@ -105,10 +105,8 @@ where
         send_video_note,
         send_media_group,
         send_location,
-        edit_message_live_location,
-        edit_message_live_location_inline,
-        stop_message_live_location,
-        stop_message_live_location_inline,
+        edit_message_live_location_target,
+        stop_message_live_location_target,
         send_venue,
         send_contact,
         send_poll,
@@ -166,14 +164,10 @@ where
         delete_my_commands,
         answer_inline_query,
         answer_web_app_query,
-        edit_message_text,
-        edit_message_text_inline,
-        edit_message_caption,
-        edit_message_caption_inline,
-        edit_message_media,
-        edit_message_media_inline,
-        edit_message_reply_markup,
-        edit_message_reply_markup_inline,
+        edit_message_text_target,
+        edit_message_caption_target,
+        edit_message_media_target,
+        edit_message_reply_markup_target,
         stop_poll,
         delete_message,
         send_sticker,
@@ -191,8 +185,7 @@ where
         answer_pre_checkout_query,
         set_passport_data_errors,
         send_game,
-        set_game_score,
-        set_game_score_inline,
+        set_game_score_target,
         get_game_high_scores,
         approve_chat_join_request,
         decline_chat_join_request
@@ -11,7 +11,7 @@ use crate::{
 
 /// [`Requester`] with erased type.
 pub struct ErasedRequester<'a, E> {
-    inner: Arc<dyn ErasableRequester<'a, Err = E> + 'a>,
+    inner: Arc<dyn DynRequester<'a, Err = E> + 'a>,
 }
 
 impl<'a, E> ErasedRequester<'a, E> {
@@ -26,6 +26,11 @@ impl<'a, E> ErasedRequester<'a, E> {
     {
         Self { inner: Arc::new(requester) }
     }
+
+    /// Creates a [`Requester`] from a [`DynRequester`] trait object.
+    pub fn from_dyn(requester: Arc<dyn DynRequester<'a, Err = E> + 'a>) -> Self {
+        Self { inner: requester }
+    }
 }
 
 impl<E> std::fmt::Debug for ErasedRequester<'_, E> {
@@ -194,10 +199,8 @@ where
         send_video_note,
         send_media_group,
         send_location,
-        edit_message_live_location,
-        edit_message_live_location_inline,
-        stop_message_live_location,
-        stop_message_live_location_inline,
+        edit_message_live_location_target,
+        stop_message_live_location_target,
         send_venue,
         send_contact,
         send_poll,
@@ -255,14 +258,10 @@ where
         delete_my_commands,
         answer_inline_query,
         answer_web_app_query,
-        edit_message_text,
-        edit_message_text_inline,
-        edit_message_caption,
-        edit_message_caption_inline,
-        edit_message_media,
-        edit_message_media_inline,
-        edit_message_reply_markup,
-        edit_message_reply_markup_inline,
+        edit_message_text_target,
+        edit_message_caption_target,
+        edit_message_media_target,
+        edit_message_reply_markup_target,
         stop_poll,
         delete_message,
         send_sticker,
@@ -280,8 +279,7 @@ where
         answer_pre_checkout_query,
         set_passport_data_errors,
         send_game,
-        set_game_score,
-        set_game_score_inline,
+        set_game_score_target,
         get_game_high_scores,
         approve_chat_join_request,
         decline_chat_join_request
@@ -290,7 +288,41 @@ where
 }
 
 /// Object safe version of [`Requester`].
-trait ErasableRequester<'a> {
+///
+/// Every [`Requester`] implements this trait, so it can be used to accept any
+/// requester as a trait object, e.g. in components which get their
+/// dependencies at runtime. Requests returned by the methods of this trait are
+/// [`ErasedRequest`]s, which send boxed futures.
+///
+/// ## Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use teloxide_core::{
+///     adaptors::{erased::DynRequester, ErasedRequester},
+///     types::ChatId,
+///     Bot, RequestError,
+/// };
+///
+/// struct Plugin {
+///     bot: Arc<dyn DynRequester<'static, Err = RequestError> + Send + Sync>,
+/// }
+///
+/// impl Plugin {
+///     async fn greet(&self, chat_id: ChatId) -> Result<(), RequestError> {
+///         self.bot.send_message(chat_id.into(), "Hi!".to_owned()).await?;
+///         Ok(())
+///     }
+/// }
+///
+/// let plugin = Plugin { bot: Arc::new(Bot::new("TOKEN")) };
+///
+/// // A trait object can also be turned back into a `Requester`
+/// let bot = ErasedRequester::from_dyn(plugin.bot.clone());
+/// # let _ = (plugin.greet(ChatId(0)), bot);
+/// ```
+pub trait DynRequester<'a> {
     /// Error type returned by all requests.
     type Err: std::error::Error + Send;
 
@@ -383,31 +415,17 @@ trait ErasableRequester<'a> {
         longitude: f64,
     ) -> ErasedRequest<'a, SendLocation, Self::Err>;
 
-    fn edit_message_live_location(
+    fn edit_message_live_location_target(
         &self,
-        chat_id: Recipient,
-        message_id: MessageId,
-        latitude: f64,
-        longitude: f64,
-    ) -> ErasedRequest<'a, EditMessageLiveLocation, Self::Err>;
-
-    fn edit_message_live_location_inline(
-        &self,
-        inline_message_id: String,
+        target: TargetMessage,
         latitude: f64,
         longitude: f64,
-    ) -> ErasedRequest<'a, EditMessageLiveLocationInline, Self::Err>;
-
-    fn stop_message_live_location(
-        &self,
-        chat_id: Recipient,
-        message_id: MessageId,
-    ) -> ErasedRequest<'a, StopMessageLiveLocation, Self::Err>;
+    ) -> ErasedRequest<'a, EditMessageLiveLocationTarget, Self::Err>;
 
-    fn stop_message_live_location_inline(
+    fn stop_message_live_location_target(
         &self,
-        inline_message_id: String,
-    ) -> ErasedRequest<'a, StopMessageLiveLocationInline, Self::Err>;
+        target: TargetMessage,
+    ) -> ErasedRequest<'a, StopMessageLiveLocationTarget, Self::Err>;
 
     fn send_venue(
         &self,
@@ -445,7 +463,7 @@ trait ErasableRequester<'a> {
         user_id: UserId,
     ) -> ErasedRequest<'a, GetUserProfilePhotos, Self::Err>;
 
-    fn get_file(&self, file_id: String) -> ErasedRequest<'a, GetFile, Self::Err>;
+    fn get_file(&self, file_id: FileId) -> ErasedRequest<'a, GetFile, Self::Err>;
 
     fn ban_chat_member(
         &self,
@@ -719,53 +737,27 @@ trait ErasableRequester<'a> {
         result: InlineQueryResult,
     ) -> ErasedRequest<'a, AnswerWebAppQuery, Self::Err>;
 
-    fn edit_message_text(
+    fn edit_message_text_target(
         &self,
-        chat_id: Recipient,
-        message_id: MessageId,
-        text: String,
-    ) -> ErasedRequest<'a, EditMessageText, Self::Err>;
-
-    fn edit_message_text_inline(
-        &self,
-        inline_message_id: String,
+        target: TargetMessage,
         text: String,
-    ) -> ErasedRequest<'a, EditMessageTextInline, Self::Err>;
-
-    fn edit_message_caption(
-        &self,
-        chat_id: Recipient,
-        message_id: MessageId,
-    ) -> ErasedRequest<'a, EditMessageCaption, Self::Err>;
-
-    fn edit_message_caption_inline(
-        &self,
-        inline_message_id: String,
-    ) -> ErasedRequest<'a, EditMessageCaptionInline, Self::Err>;
+    ) -> ErasedRequest<'a, EditMessageTextTarget, Self::Err>;
 
-    fn edit_message_media(
+    fn edit_message_caption_target(
         &self,
-        chat_id: Recipient,
-        message_id: MessageId,
-        media: InputMedia,
-    ) -> ErasedRequest<'a, EditMessageMedia, Self::Err>;
+        target: TargetMessage,
+    ) -> ErasedRequest<'a, EditMessageCaptionTarget, Self::Err>;
 
-    fn edit_message_media_inline(
+    fn edit_message_media_target(
         &self,
-        inline_message_id: String,
+        target: TargetMessage,
         media: InputMedia,
-    ) -> ErasedRequest<'a, EditMessageMediaInline, Self::Err>;
-
-    fn edit_message_reply_markup(
-        &self,
-        chat_id: Recipient,
-        message_id: MessageId,
-    ) -> ErasedRequest<'a, EditMessageReplyMarkup, Self::Err>;
+    ) -> ErasedRequest<'a, EditMessageMediaTarget, Self::Err>;
 
-    fn edit_message_reply_markup_inline(
+    fn edit_message_reply_markup_target(
         &self,
-        inline_message_id: String,
-    ) -> ErasedRequest<'a, EditMessageReplyMarkupInline, Self::Err>;
+        target: TargetMessage,
+    ) -> ErasedRequest<'a, EditMessageReplyMarkupTarget, Self::Err>;
 
     fn stop_poll(
         &self,
@@ -876,24 +868,16 @@ trait ErasableRequester<'a> {
 
     fn send_game(
         &self,
-        chat_id: u32,
+        chat_id: ChatId,
         game_short_name: String,
     ) -> ErasedRequest<'a, SendGame, Self::Err>;
 
-    fn set_game_score(
-        &self,
-        user_id: UserId,
-        score: u64,
-        chat_id: u32,
-        message_id: MessageId,
-    ) -> ErasedRequest<'a, SetGameScore, Self::Err>;
-
-    fn set_game_score_inline(
+    fn set_game_score_target(
         &self,
         user_id: UserId,
         score: u64,
-        inline_message_id: String,
-    ) -> ErasedRequest<'a, SetGameScoreInline, Self::Err>;
+        target: TargetMessage,
+    ) -> ErasedRequest<'a, SetGameScoreTarget, Self::Err>;
 
     fn get_game_high_scores(
         &self,
@@ -902,7 +886,7 @@ trait ErasableRequester<'a> {
     ) -> ErasedRequest<'a, GetGameHighScores, Self::Err>;
 }
 
-impl<'a, B> ErasableRequester<'a> for B
+impl<'a, B> DynRequester<'a> for B
 where
     B: Requester + 'a,
 {
@@ -1035,40 +1019,20 @@ where
         Requester::send_location(self, chat_id, latitude, longitude).erase()
     }
 
-    fn edit_message_live_location(
-        &self,
-        chat_id: Recipient,
-        message_id: MessageId,
-        latitude: f64,
-        longitude: f64,
-    ) -> ErasedRequest<'a, EditMessageLiveLocation, Self::Err> {
-        Requester::edit_message_live_location(self, chat_id, message_id, latitude, longitude)
-            .erase()
-    }
-
-    fn edit_message_live_location_inline(
+    fn edit_message_live_location_target(
         &self,
-        inline_message_id: String,
+        target: TargetMessage,
         latitude: f64,
         longitude: f64,
-    ) -> ErasedRequest<'a, EditMessageLiveLocationInline, Self::Err> {
-        Requester::edit_message_live_location_inline(self, inline_message_id, latitude, longitude)
-            .erase()
-    }
-
-    fn stop_message_live_location(
-        &self,
-        chat_id: Recipient,
-        message_id: MessageId,
-    ) -> ErasedRequest<'a, StopMessageLiveLocation, Self::Err> {
-        Requester::stop_message_live_location(self, chat_id, message_id).erase()
+    ) -> ErasedRequest<'a, EditMessageLiveLocationTarget, Self::Err> {
+        Requester::edit_message_live_location_target(self, target, latitude, longitude).erase()
     }
 
-    fn stop_message_live_location_inline(
+    fn stop_message_live_location_target(
         &self,
-        inline_message_id: String,
-    ) -> ErasedRequest<'a, StopMessageLiveLocationInline, Self::Err> {
-        Requester::stop_message_live_location_inline(self, inline_message_id).erase()
+        target: TargetMessage,
+    ) -> ErasedRequest<'a, StopMessageLiveLocationTarget, Self::Err> {
+        Requester::stop_message_live_location_target(self, target).erase()
     }
 
     fn send_venue(
@@ -1119,7 +1083,7 @@ where
         Requester::get_user_profile_photos(self, user_id).erase()
     }
 
-    fn get_file(&self, file_id: String) -> ErasedRequest<'a, GetFile, Self::Err> {
+    fn get_file(&self, file_id: FileId) -> ErasedRequest<'a, GetFile, Self::Err> {
         Requester::get_file(self, file_id).erase()
     }
 
@@ -1499,68 +1463,34 @@ where
         Requester::answer_web_app_query(self, web_app_query_id, result).erase()
     }
 
-    fn edit_message_text(
-        &self,
-        chat_id: Recipient,
-        message_id: MessageId,
-        text: String,
-    ) -> ErasedRequest<'a, EditMessageText, Self::Err> {
-        Requester::edit_message_text(self, chat_id, message_id, text).erase()
-    }
-
-    fn edit_message_text_inline(
+    fn edit_message_text_target(
         &self,
-        inline_message_id: String,
+        target: TargetMessage,
         text: String,
-    ) -> ErasedRequest<'a, EditMessageTextInline, Self::Err> {
-        Requester::edit_message_text_inline(self, inline_message_id, text).erase()
-    }
-
-    fn edit_message_caption(
-        &self,
-        chat_id: Recipient,
-        message_id: MessageId,
-    ) -> ErasedRequest<'a, EditMessageCaption, Self::Err> {
-        Requester::edit_message_caption(self, chat_id, message_id).erase()
-    }
-
-    fn edit_message_caption_inline(
-        &self,
-        inline_message_id: String,
-    ) -> ErasedRequest<'a, EditMessageCaptionInline, Self::Err> {
-        Requester::edit_message_caption_inline(self, inline_message_id).erase()
+    ) -> ErasedRequest<'a, EditMessageTextTarget, Self::Err> {
+        Requester::edit_message_text_target(self, target, text).erase()
     }
 
-    fn edit_message_media(
+    fn edit_message_caption_target(
         &self,
-        chat_id: Recipient,
-        message_id: MessageId,
-        media: InputMedia,
-    ) -> ErasedRequest<'a, EditMessageMedia, Self::Err> {
-        Requester::edit_message_media(self, chat_id, message_id, media).erase()
+        target: TargetMessage,
+    ) -> ErasedRequest<'a, EditMessageCaptionTarget, Self::Err> {
+        Requester::edit_message_caption_target(self, target).erase()
     }
 
-    fn edit_message_media_inline(
+    fn edit_message_media_target(
         &self,
-        inline_message_id: String,
+        target: TargetMessage,
         media: InputMedia,
-    ) -> ErasedRequest<'a, EditMessageMediaInline, Self::Err> {
-        Requester::edit_message_media_inline(self, inline_message_id, media).erase()
-    }
-
-    fn edit_message_reply_markup(
-        &self,
-        chat_id: Recipient,
-        message_id: MessageId,
-    ) -> ErasedRequest<'a, EditMessageReplyMarkup, Self::Err> {
-        Requester::edit_message_reply_markup(self, chat_id, message_id).erase()
+    ) -> ErasedRequest<'a, EditMessageMediaTarget, Self::Err> {
+        Requester::edit_message_media_target(self, target, media).erase()
     }
 
-    fn edit_message_reply_markup_inline(
+    fn edit_message_reply_markup_target(
         &self,
-        inline_message_id: String,
-    ) -> ErasedRequest<'a, EditMessageReplyMarkupInline, Self::Err> {
-        Requester::edit_message_reply_markup_inline(self, inline_message_id).erase()
+        target: TargetMessage,
+    ) -> ErasedRequest<'a, EditMessageReplyMarkupTarget, Self::Err> {
+        Requester::edit_message_reply_markup_target(self, target).erase()
     }
 
     fn stop_poll(
@@ -1721,29 +1651,19 @@ where
 
     fn send_game(
         &self,
-        chat_id: u32,
+        chat_id: ChatId,
         game_short_name: String,
     ) -> ErasedRequest<'a, SendGame, Self::Err> {
         Requester::send_game(self, chat_id, game_short_name).erase()
     }
 
-    fn set_game_score(
-        &self,
-        user_id: UserId,
-        score: u64,
-        chat_id: u32,
-        message_id: MessageId,
-    ) -> ErasedRequest<'a, SetGameScore, Self::Err> {
-        Requester::set_game_score(self, user_id, score, chat_id, message_id).erase()
-    }
-
-    fn set_game_score_inline(
+    fn set_game_score_target(
         &self,
         user_id: UserId,
         score: u64,
-        inline_message_id: String,
-    ) -> ErasedRequest<'a, SetGameScoreInline, Self::Err> {
-        Requester::set_game_score_inline(self, user_id, score, inline_message_id).erase()
+        target: TargetMessage,
+    ) -> ErasedRequest<'a, SetGameScoreTarget, Self::Err> {
+        Requester::set_game_score_target(self, user_id, score, target).erase()
     }
 
     fn get_game_high_scores(
@@ -0,0 +1,541 @@
+use std::{
+    collections::HashMap,
+    future::IntoFuture,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures::{future::BoxFuture, Future};
+use sha2::{Digest, Sha256};
+use tokio::io::AsyncReadExt;
+use url::Url;
+
+use crate::{
+    errors::{ApiError, RequestError},
+    payloads::{
+        SendAnimation, SendAudio, SendDocument, SendPhoto, SendSticker, SendVideo, SendVideoNote,
+        SendVoice,
+    },
+    requests::{HasPayload, Output, Payload, Request, Requester},
+    types::*,
+};
+
+/// Cache of file ids of uploaded files, keyed by the hash of their contents.
+///
+/// Telegram returns a file id for every uploaded file, which can be used to
+/// send the same file again without uploading it. This adaptor hashes the
+/// contents of files sent via [`send_photo`], [`send_audio`],
+/// [`send_document`], [`send_video`], [`send_animation`], [`send_voice`],
+/// [`send_video_note`] and [`send_sticker`] and when a file with the same
+/// contents is sent again via the same method, replaces it with an
+/// [`InputFile::file_id`].
+///
+/// Notes:
+/// - Only files created via [`InputFile::memory`] and [`InputFile::file`] are
+///   cached, other files are sent as is.
+/// - Files on disk are read twice when they are uploaded: to compute the hash
+///   and to upload them.
+/// - If Telegram rejects a cached file id (e.g. with
+///   [`ApiError::WrongFileId`]), the file id is forgotten and the request is
+///   retried with the file itself. Other errors are returned as is.
+/// - The cache is shared between clones of the adaptor and is never cleared
+///   automatically, see [`FileIdCache::clear`].
+///
+/// [`ApiError::WrongFileId`]: crate::errors::ApiError::WrongFileId
+/// [`send_photo`]: crate::requests::Requester::send_photo
+/// [`send_audio`]: crate::requests::Requester::send_audio
+/// [`send_document`]: crate::requests::Requester::send_document
+/// [`send_video`]: crate::requests::Requester::send_video
+/// [`send_animation`]: crate::requests::Requester::send_animation
+/// [`send_voice`]: crate::requests::Requester::send_voice
+/// [`send_video_note`]: crate::requests::Requester::send_video_note
+/// [`send_sticker`]: crate::requests::Requester::send_sticker
+#[derive(Clone, Debug)]
+pub struct FileIdCache<B> {
+    bot: B,
+    cache: Cache,
+}
+
+/// File ids by the name of the method and the hash of the file contents.
+///
+/// The name of the method is a part of the key, since file ids can't be used
+/// to send a file as a different type (e.g. a photo as a document).
+type Cache = Arc<Mutex<HashMap<(&'static str, [u8; 32]), FileId>>>;
+
+impl<B> FileIdCache<B> {
+    /// Creates new cache.
+    ///
+    /// Note: it's recommended to use [`RequesterExt::cache_file_ids`] instead.
+    ///
+    /// [`RequesterExt::cache_file_ids`]: crate::requests::RequesterExt::cache_file_ids
+    pub fn new(bot: B) -> Self {
+        Self { bot, cache: Arc::default() }
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+
+    /// Returns the number of cached file ids.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cache.lock().unwrap().len()
+    }
+
+    /// Returns `true` if no file ids are cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cache.lock().unwrap().is_empty()
+    }
+
+    /// Forgets all cached file ids, including ones cached by clones of `self`.
+    pub fn clear(&self) {
+        self.cache.lock().unwrap().clear()
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        FileIdCacheRequest {
+            req: $this.inner().$m($($arg),*),
+            cache: Arc::clone(&$this.cache),
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        FileIdCacheRequest<B::$T>
+    };
+}
+
+macro_rules! fid {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        $this.inner().$m($($arg),*)
+    };
+}
+
+macro_rules! ftyid {
+    ($T:ident) => {
+        B::$T
+    };
+}
+
+impl<B> Requester for FileIdCache<B>
+where
+    B: Requester,
+    B::Err: Send + 'static,
+    B::SendPhoto: Clone + Send + 'static,
+    <B::SendPhoto as Request>::Send: Send,
+    B::SendAudio: Clone + Send + 'static,
+    <B::SendAudio as Request>::Send: Send,
+    B::SendDocument: Clone + Send + 'static,
+    <B::SendDocument as Request>::Send: Send,
+    B::SendVideo: Clone + Send + 'static,
+    <B::SendVideo as Request>::Send: Send,
+    B::SendAnimation: Clone + Send + 'static,
+    <B::SendAnimation as Request>::Send: Send,
+    B::SendVoice: Clone + Send + 'static,
+    <B::SendVoice as Request>::Send: Send,
+    B::SendVideoNote: Clone + Send + 'static,
+    <B::SendVideoNote as Request>::Send: Send,
+    B::SendSticker: Clone + Send + 'static,
+    <B::SendSticker as Request>::Send: Send,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        send_photo,
+        send_audio,
+        send_document,
+        send_video,
+        send_animation,
+        send_voice,
+        send_video_note,
+        send_sticker
+        => f, fty
+    }
+
+    requester_forward! {
+        get_me,
+        log_out,
+        close,
+        get_updates,
+        set_webhook,
+        delete_webhook,
+        get_webhook_info,
+        forward_message,
+        copy_message,
+        send_message,
+        send_media_group,
+        send_location,
+        edit_message_live_location_target,
+        stop_message_live_location_target,
+        send_venue,
+        send_contact,
+        send_poll,
+        send_dice,
+        send_chat_action,
+        get_user_profile_photos,
+        get_file,
+        kick_chat_member,
+        ban_chat_member,
+        unban_chat_member,
+        restrict_chat_member,
+        promote_chat_member,
+        set_chat_administrator_custom_title,
+        ban_chat_sender_chat,
+        unban_chat_sender_chat,
+        set_chat_permissions,
+        export_chat_invite_link,
+        create_chat_invite_link,
+        edit_chat_invite_link,
+        revoke_chat_invite_link,
+        set_chat_photo,
+        delete_chat_photo,
+        set_chat_title,
+        set_chat_description,
+        pin_chat_message,
+        unpin_chat_message,
+        unpin_all_chat_messages,
+        leave_chat,
+        get_chat,
+        get_chat_administrators,
+        get_chat_members_count,
+        get_chat_member_count,
+        get_chat_member,
+        set_chat_sticker_set,
+        delete_chat_sticker_set,
+        get_forum_topic_icon_stickers,
+        create_forum_topic,
+        edit_forum_topic,
+        close_forum_topic,
+        reopen_forum_topic,
+        delete_forum_topic,
+        unpin_all_forum_topic_messages,
+        edit_general_forum_topic,
+        close_general_forum_topic,
+        reopen_general_forum_topic,
+        hide_general_forum_topic,
+        unhide_general_forum_topic,
+        answer_callback_query,
+        set_my_commands,
+        get_my_commands,
+        set_chat_menu_button,
+        get_chat_menu_button,
+        set_my_default_administrator_rights,
+        get_my_default_administrator_rights,
+        delete_my_commands,
+        answer_inline_query,
+        answer_web_app_query,
+        edit_message_text_target,
+        edit_message_caption_target,
+        edit_message_media_target,
+        edit_message_reply_markup_target,
+        stop_poll,
+        delete_message,
+        get_sticker_set,
+        get_custom_emoji_stickers,
+        upload_sticker_file,
+        create_new_sticker_set,
+        add_sticker_to_set,
+        set_sticker_position_in_set,
+        delete_sticker_from_set,
+        set_sticker_set_thumb,
+        send_invoice,
+        create_invoice_link,
+        answer_shipping_query,
+        answer_pre_checkout_query,
+        set_passport_data_errors,
+        send_game,
+        set_game_score_target,
+        get_game_high_scores,
+        approve_chat_join_request,
+        decline_chat_join_request
+        => fid, ftyid
+    }
+}
+
+download_forward! {
+    B
+    FileIdCache<B>
+    { this => this.inner() }
+}
+
+/// Request returned by [`FileIdCache`] methods which send files.
+#[must_use = "Requests are lazy and do nothing unless sent"]
+#[derive(Clone)]
+pub struct FileIdCacheRequest<R> {
+    req: R,
+    cache: Cache,
+}
+
+impl<R> FileIdCacheRequest<R>
+where
+    R: Request + Clone,
+    R::Payload: CachedFile,
+    R::Err: 'static,
+{
+    async fn send_cached(self) -> Result<Message, R::Err> {
+        let Self { req, cache } = self;
+        let Some(hash) = hash(req.payload_ref().file().clone()).await else {
+            return req.send().await;
+        };
+        let key = (<R::Payload as Payload>::NAME, hash);
+
+        let cached = cache.lock().unwrap().get(&key).cloned();
+        if let Some(file_id) = cached {
+            let mut cached_req = req.clone();
+            *cached_req.payload_mut().file_mut() = InputFile::file_id(file_id);
+            match cached_req.send().await {
+                Ok(message) => return Ok(message),
+                // The file id may have become invalid, so the file is uploaded again
+                Err(err) if is_file_id_error(&err) => {
+                    cache.lock().unwrap().remove(&key);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        let message = req.send().await?;
+        if let Some(file_id) = R::Payload::sent_file_id(&message) {
+            cache.lock().unwrap().insert(key, file_id.clone());
+        }
+
+        Ok(message)
+    }
+}
+
+impl<R> Request for FileIdCacheRequest<R>
+where
+    R: Request + Clone + Send + 'static,
+    R::Payload: CachedFile,
+    R::Err: Send + 'static,
+    R::Send: Send,
+{
+    type Err = R::Err;
+    type Send = FileIdCacheSend<R>;
+    type SendRef = FileIdCacheSend<R>;
+
+    fn send(self) -> Self::Send {
+        FileIdCacheSend(Box::pin(self.send_cached()))
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        // The payload may need to be changed, so the request is cloned
+        self.clone().send()
+    }
+}
+
+impl<R> IntoFuture for FileIdCacheRequest<R>
+where
+    Self: Request,
+{
+    type Output = Result<Output<Self>, <Self as Request>::Err>;
+    type IntoFuture = <Self as Request>::Send;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.send()
+    }
+}
+
+impl<R> HasPayload for FileIdCacheRequest<R>
+where
+    R: Request,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.req.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.req.payload_ref()
+    }
+}
+
+/// Future returned by [`FileIdCacheRequest`].
+#[must_use = "Futures are lazy and do nothing unless polled or .awaited"]
+pub struct FileIdCacheSend<R: Request>(BoxFuture<'static, Result<Output<R>, R::Err>>);
+
+impl<R: Request> Future for FileIdCacheSend<R> {
+    type Output = Result<Output<R>, R::Err>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+/// A payload which sends a single file.
+pub trait CachedFile: Payload<Output = Message> {
+    /// Returns the sent file.
+    fn file(&self) -> &InputFile;
+
+    /// Returns a mutable reference to the sent file.
+    fn file_mut(&mut self) -> &mut InputFile;
+
+    /// Returns the file id of the file in a sent message.
+    fn sent_file_id(message: &Message) -> Option<&FileId>;
+}
+
+macro_rules! impl_cached_file {
+    ($($T:ident { $field:ident, |$message:ident| $file_id:expr }),* $(,)?) => {
+        $(
+            impl CachedFile for $T {
+                fn file(&self) -> &InputFile {
+                    &self.$field
+                }
+
+                fn file_mut(&mut self) -> &mut InputFile {
+                    &mut self.$field
+                }
+
+                fn sent_file_id($message: &Message) -> Option<&FileId> {
+                    $file_id
+                }
+            }
+        )*
+    };
+}
+
+impl_cached_file! {
+    // The last photo size is the original photo
+    SendPhoto { photo, |message| Some(&message.photo()?.last()?.file.id) },
+    SendAudio { audio, |message| Some(&message.audio()?.file.id) },
+    SendDocument { document, |message| Some(&message.document()?.file.id) },
+    SendVideo { video, |message| Some(&message.video()?.file.id) },
+    SendAnimation { animation, |message| Some(&message.animation()?.file.id) },
+    SendVoice { voice, |message| Some(&message.voice()?.file.id) },
+    SendVideoNote { video_note, |message| Some(&message.video_note()?.file.id) },
+    SendSticker { sticker, |message| Some(&message.sticker()?.file.id) },
+}
+
+/// Returns the hash of the contents of `file`, or `None` if it can't be
+/// computed.
+async fn hash(file: InputFile) -> Option<[u8; 32]> {
+    let mut hasher = Sha256::new();
+
+    if let Some(bytes) = file.as_bytes() {
+        hasher.update(bytes);
+    } else if let Some(path) = file.as_path() {
+        let mut file = tokio::fs::File::open(path).await.ok()?;
+        let mut buf = vec![0; 8 * 1024];
+        loop {
+            match file.read(&mut buf).await.ok()? {
+                0 => break,
+                n => hasher.update(&buf[..n]),
+            }
+        }
+    } else {
+        return None;
+    }
+
+    Some(hasher.finalize().into())
+}
+
+/// Returns `true` if `err` means that Telegram rejected a file id, other errors
+/// are unrelated to the cache and must not trigger a reupload.
+fn is_file_id_error(err: &(dyn std::error::Error + 'static)) -> bool {
+    let api = match err.downcast_ref::<RequestError>() {
+        Some(RequestError::Api(api)) => Some(api),
+        _ => err.downcast_ref::<ApiError>(),
+    };
+
+    match api {
+        Some(ApiError::WrongFileId | ApiError::WrongFileIdOrUrl | ApiError::FileIdInvalid) => true,
+        Some(ApiError::Unknown(text)) => text.contains("wrong file identifier"),
+        _ => false,
+    }
+}
+
+#[cfg(all(test, feature = "mock_bot"))]
+mod tests {
+    use super::*;
+    use crate::{adaptors::MockBot, requests::RequesterExt};
+
+    fn document_message(file_id: &str) -> Message {
+        serde_json::from_value(serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": { "id": 1, "type": "private" },
+            "document": { "file_id": file_id, "file_unique_id": file_id },
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn reuses_file_ids() {
+        let mock = MockBot::new();
+        let bot = mock.clone().cache_file_ids();
+
+        mock.respond::<SendDocument>(document_message("a"));
+        bot.send_document(ChatId(1), InputFile::memory("a")).await.unwrap();
+        assert_eq!(bot.len(), 1);
+
+        mock.respond::<SendDocument>(document_message("a"));
+        bot.send_document(ChatId(1), InputFile::memory("a")).await.unwrap();
+
+        // Different contents are uploaded
+        mock.respond::<SendDocument>(document_message("b"));
+        bot.send_document(ChatId(1), InputFile::memory("b")).await.unwrap();
+
+        let attached: Vec<_> = mock
+            .requests_of::<SendDocument>()
+            .iter()
+            .map(|req| req.document.needs_attach())
+            .collect();
+        assert_eq!(attached, [true, false, true]);
+        assert_eq!(bot.len(), 2);
+
+        // A rejected file id is forgotten and the file is uploaded again
+        mock.clear_requests();
+        mock.respond_err::<SendDocument>(RequestError::Api(ApiError::WrongFileId));
+        mock.respond::<SendDocument>(document_message("c"));
+        bot.send_document(ChatId(1), InputFile::memory("a")).await.unwrap();
+
+        let attached: Vec<_> = mock
+            .requests_of::<SendDocument>()
+            .iter()
+            .map(|req| req.document.needs_attach())
+            .collect();
+        assert_eq!(attached, [false, true]);
+        assert_eq!(bot.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn returns_unrelated_errors() {
+        let mock = MockBot::new();
+        let bot = mock.clone().cache_file_ids();
+
+        mock.respond::<SendDocument>(document_message("a"));
+        bot.send_document(ChatId(1), InputFile::memory("a")).await.unwrap();
+
+        mock.clear_requests();
+        mock.respond_err::<SendDocument>(RequestError::Api(ApiError::BotBlocked));
+        let err = bot.send_document(ChatId(1), InputFile::memory("a")).await.unwrap_err();
+        assert!(matches!(err, RequestError::Api(ApiError::BotBlocked)));
+
+        // The file isn't uploaded again and its id is kept
+        assert_eq!(mock.requests_of::<SendDocument>().len(), 1);
+        assert_eq!(bot.len(), 1);
+    }
+
+    #[test]
+    fn file_id_errors() {
+        let unknown = |text: &str| RequestError::Api(ApiError::Unknown(text.to_owned()));
+
+        assert!(is_file_id_error(&RequestError::Api(ApiError::WrongFileId)));
+        assert!(is_file_id_error(&RequestError::Api(ApiError::WrongFileIdOrUrl)));
+        assert!(is_file_id_error(&ApiError::FileIdInvalid));
+        assert!(is_file_id_error(&unknown("Bad Request: wrong file identifier")));
+        assert!(!is_file_id_error(&unknown("Bad Request: chat not found")));
+        assert!(!is_file_id_error(&RequestError::RetryAfter(Seconds::from_seconds(1))));
+    }
+}
@@ -0,0 +1,405 @@
+use std::{
+    any::Any,
+    collections::{HashMap, VecDeque},
+    fmt,
+    future::{self, IntoFuture},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use url::Url;
+
+use crate::{
+    errors::{ApiError, RequestError},
+    net::Download,
+    requests::{HasPayload, Output, Payload, Request, Requester},
+    types::*,
+    Bot,
+};
+
+/// A bot which doesn't send requests to Telegram, but records them and
+/// responds with scripted responses.
+///
+/// This is useful for testing handlers without network access. Responses are
+/// scripted per method via [`MockBot::respond`] and [`MockBot::respond_err`],
+/// and are returned in the order they were added. A request without a scripted
+/// response fails with [`ApiError::Unknown`].
+///
+/// Clones of a `MockBot` share recorded requests and scripted responses.
+///
+/// Files are downloaded as with a [`Bot`], so only local files (see
+/// [`File::is_local`]) can be downloaded without network access.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{
+///     adaptors::MockBot,
+///     payloads::{GetChat, SendMessage},
+///     prelude::*,
+///     types::{Chat, ChatId},
+/// };
+///
+/// # async {
+/// let bot = MockBot::new();
+/// let chat: Chat = serde_json::from_str(r#"{"id": 1, "type": "private"}"#).unwrap();
+/// bot.respond::<GetChat>(chat.clone());
+///
+/// assert_eq!(bot.get_chat(ChatId(1)).await.unwrap(), chat);
+/// assert!(bot.send_message(ChatId(1), "hi").await.is_err());
+///
+/// assert_eq!(bot.request_names(), ["GetChat", "SendMessage"]);
+/// assert_eq!(bot.requests_of::<SendMessage>()[0].text, "hi");
+/// # };
+/// ```
+#[derive(Clone)]
+pub struct MockBot {
+    bot: Bot,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    requests: Vec<(&'static str, Box<dyn Any + Send>)>,
+    responses: HashMap<&'static str, VecDeque<Box<dyn Any + Send>>>,
+}
+
+impl MockBot {
+    /// Creates a mock bot without recorded requests and scripted responses.
+    #[must_use]
+    pub fn new() -> Self {
+        // The API url of a local server, so that local files can be downloaded
+        let bot =
+            Bot::new("0:mock").set_api_url(reqwest::Url::parse("http://localhost:8081").unwrap());
+        Self { bot, state: Arc::default() }
+    }
+
+    /// Adds a successful response to a request with payload `P`.
+    pub fn respond<P>(&self, output: P::Output)
+    where
+        P: Payload,
+        P::Output: Send + 'static,
+    {
+        self.push_response::<P>(Ok(output));
+    }
+
+    /// Adds an error response to a request with payload `P`.
+    pub fn respond_err<P>(&self, error: RequestError)
+    where
+        P: Payload,
+        P::Output: Send + 'static,
+    {
+        self.push_response::<P>(Err(error));
+    }
+
+    /// Returns names of all recorded requests (e.g. `"SendMessage"`), in the
+    /// order they were sent.
+    #[must_use]
+    pub fn request_names(&self) -> Vec<&'static str> {
+        self.state().requests.iter().map(|(name, _)| *name).collect()
+    }
+
+    /// Returns payloads of all recorded requests of type `P`, in the order
+    /// they were sent.
+    #[must_use]
+    pub fn requests_of<P>(&self) -> Vec<P>
+    where
+        P: Payload + Clone + 'static,
+    {
+        self.state()
+            .requests
+            .iter()
+            .filter_map(|(_, payload)| payload.downcast_ref().cloned())
+            .collect()
+    }
+
+    /// Forgets all recorded requests.
+    pub fn clear_requests(&self) {
+        self.state().requests.clear();
+    }
+
+    fn push_response<P>(&self, response: Result<P::Output, RequestError>)
+    where
+        P: Payload,
+        P::Output: Send + 'static,
+    {
+        self.state().responses.entry(P::NAME).or_default().push_back(Box::new(response));
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        lock(&self.state)
+    }
+}
+
+impl Default for MockBot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for MockBot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockBot").field("requests", &self.request_names()).finish_non_exhaustive()
+    }
+}
+
+impl Download for MockBot {
+    type Err<'dst> = <Bot as Download>::Err<'dst>;
+
+    type Fut<'dst> = <Bot as Download>::Fut<'dst>;
+
+    fn download_file<'dst>(
+        &self,
+        path: &str,
+        destination: &'dst mut (dyn tokio::io::AsyncWrite + Unpin + Send),
+    ) -> Self::Fut<'dst> {
+        self.bot.download_file(path, destination)
+    }
+
+    type StreamErr = <Bot as Download>::StreamErr;
+
+    type Stream = <Bot as Download>::Stream;
+
+    fn download_file_stream(&self, path: &str) -> Self::Stream {
+        self.bot.download_file_stream(path)
+    }
+}
+
+fn lock(state: &Mutex<State>) -> MutexGuard<'_, State> {
+    // A panic in a test must not poison other tests sharing the bot
+    state.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        MockRequest<<Bot as Requester>::$T>
+    };
+}
+
+macro_rules! fwd_inner {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        MockRequest {
+            inner: $this.bot.$m($($arg),*),
+            state: Arc::clone(&$this.state),
+        }
+    };
+}
+
+impl Requester for MockBot {
+    type Err = RequestError;
+
+    requester_forward! {
+        get_me,
+        log_out,
+        close,
+        get_updates,
+        set_webhook,
+        delete_webhook,
+        get_webhook_info,
+        forward_message,
+        copy_message,
+        send_message,
+        send_photo,
+        send_audio,
+        send_document,
+        send_video,
+        send_animation,
+        send_voice,
+        send_video_note,
+        send_media_group,
+        send_location,
+        edit_message_live_location_target,
+        stop_message_live_location_target,
+        send_venue,
+        send_contact,
+        send_poll,
+        send_dice,
+        send_chat_action,
+        get_user_profile_photos,
+        get_file,
+        kick_chat_member,
+        ban_chat_member,
+        unban_chat_member,
+        restrict_chat_member,
+        promote_chat_member,
+        set_chat_administrator_custom_title,
+        ban_chat_sender_chat,
+        unban_chat_sender_chat,
+        set_chat_permissions,
+        export_chat_invite_link,
+        create_chat_invite_link,
+        edit_chat_invite_link,
+        revoke_chat_invite_link,
+        set_chat_photo,
+        delete_chat_photo,
+        set_chat_title,
+        set_chat_description,
+        pin_chat_message,
+        unpin_chat_message,
+        unpin_all_chat_messages,
+        leave_chat,
+        get_chat,
+        get_chat_administrators,
+        get_chat_members_count,
+        get_chat_member_count,
+        get_chat_member,
+        set_chat_sticker_set,
+        delete_chat_sticker_set,
+        get_forum_topic_icon_stickers,
+        create_forum_topic,
+        edit_forum_topic,
+        close_forum_topic,
+        reopen_forum_topic,
+        delete_forum_topic,
+        unpin_all_forum_topic_messages,
+        edit_general_forum_topic,
+        close_general_forum_topic,
+        reopen_general_forum_topic,
+        hide_general_forum_topic,
+        unhide_general_forum_topic,
+        answer_callback_query,
+        set_my_commands,
+        get_my_commands,
+        set_chat_menu_button,
+        get_chat_menu_button,
+        set_my_default_administrator_rights,
+        get_my_default_administrator_rights,
+        delete_my_commands,
+        answer_inline_query,
+        answer_web_app_query,
+        edit_message_text_target,
+        edit_message_caption_target,
+        edit_message_media_target,
+        edit_message_reply_markup_target,
+        stop_poll,
+        delete_message,
+        send_sticker,
+        get_sticker_set,
+        get_custom_emoji_stickers,
+        upload_sticker_file,
+        create_new_sticker_set,
+        add_sticker_to_set,
+        set_sticker_position_in_set,
+        delete_sticker_from_set,
+        set_sticker_set_thumb,
+        send_invoice,
+        create_invoice_link,
+        answer_shipping_query,
+        answer_pre_checkout_query,
+        set_passport_data_errors,
+        send_game,
+        set_game_score_target,
+        get_game_high_scores,
+        approve_chat_join_request,
+        decline_chat_join_request
+        => fwd_inner, fty
+    }
+}
+
+/// A request of [`MockBot`].
+#[must_use = "Requests are lazy and do nothing unless sent"]
+#[derive(Clone)]
+pub struct MockRequest<R> {
+    inner: R,
+    state: Arc<Mutex<State>>,
+}
+
+impl<R> MockRequest<R>
+where
+    R: HasPayload,
+    R::Payload: Clone + Send + 'static,
+    Output<R>: Send + 'static,
+{
+    fn respond(&self) -> Result<Output<R>, RequestError> {
+        let name = <R::Payload as Payload>::NAME;
+        let mut state = lock(&self.state);
+        state.requests.push((name, Box::new(self.inner.payload_ref().clone())));
+
+        match state.responses.get_mut(name).and_then(VecDeque::pop_front) {
+            Some(response) => *response
+                .downcast()
+                .expect("responses are stored under the name of their payload type"),
+            None => Err(RequestError::Api(ApiError::Unknown(format!(
+                "MockBot has no response to `{name}` request"
+            )))),
+        }
+    }
+}
+
+impl<R> HasPayload for MockRequest<R>
+where
+    R: HasPayload,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.inner.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.inner.payload_ref()
+    }
+}
+
+impl<R> Request for MockRequest<R>
+where
+    R: HasPayload,
+    R::Payload: Clone + Send + 'static,
+    Output<R>: Send + 'static,
+{
+    type Err = RequestError;
+
+    type Send = future::Ready<Result<Output<Self>, Self::Err>>;
+
+    type SendRef = future::Ready<Result<Output<Self>, Self::Err>>;
+
+    fn send(self) -> Self::Send {
+        future::ready(self.respond())
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        future::ready(self.respond())
+    }
+}
+
+impl<R> IntoFuture for MockRequest<R>
+where
+    R: HasPayload,
+    R::Payload: Clone + Send + 'static,
+    Output<R>: Send + 'static,
+{
+    type Output = Result<Output<Self>, RequestError>;
+    type IntoFuture = <Self as Request>::Send;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.send()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payloads::{GetMe, SendMessage};
+
+    #[tokio::test]
+    async fn records_and_responds() {
+        let bot = MockBot::new();
+        let me: Me = serde_json::from_str(
+            r#"{"id":1,"is_bot":true,"first_name":"Bot","username":"bot","can_join_groups":true,"can_read_all_group_messages":false,"supports_inline_queries":false}"#,
+        )
+        .unwrap();
+
+        bot.respond::<GetMe>(me.clone());
+        bot.respond_err::<GetMe>(RequestError::Api(ApiError::BotBlocked));
+
+        assert_eq!(bot.get_me().await.unwrap(), me);
+        assert!(matches!(bot.get_me().await, Err(RequestError::Api(ApiError::BotBlocked))));
+        assert!(matches!(bot.get_me().await, Err(RequestError::Api(ApiError::Unknown(_)))));
+
+        bot.clone().send_message(ChatId(1), "hi").await.unwrap_err();
+        assert_eq!(bot.request_names(), ["GetMe", "GetMe", "GetMe", "SendMessage"]);
+        assert_eq!(bot.requests_of::<SendMessage>(), [SendMessage::new(ChatId(1), "hi")]);
+
+        bot.clear_requests();
+        assert!(bot.request_names().is_empty());
+    }
+}
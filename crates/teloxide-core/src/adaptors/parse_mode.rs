@@ -4,10 +4,9 @@ use url::Url;
 
 use crate::{
     payloads::{
-        AnswerInlineQuery, AnswerWebAppQuery, CopyMessage, EditMessageCaption,
-        EditMessageCaptionInline, EditMessageMedia, EditMessageMediaInline, EditMessageText,
-        EditMessageTextInline, SendAnimation, SendAudio, SendDocument, SendMediaGroup, SendMessage,
-        SendPhoto, SendPoll, SendVideo, SendVoice,
+        AnswerInlineQuery, AnswerWebAppQuery, CopyMessage, EditMessageCaptionTarget,
+        EditMessageMediaTarget, EditMessageTextTarget, SendAnimation, SendAudio, SendDocument,
+        SendMediaGroup, SendMessage, SendPhoto, SendPoll, SendVideo, SendVoice,
     },
     prelude::Requester,
     requests::{HasPayload, Output, Request},
@@ -140,16 +139,13 @@ where
     B::SendDocument: Clone,
     B::SendAnimation: Clone,
     B::SendVoice: Clone,
-    B::EditMessageText: Clone,
-    B::EditMessageTextInline: Clone,
-    B::EditMessageCaption: Clone,
-    B::EditMessageCaptionInline: Clone,
+    B::EditMessageTextTarget: Clone,
+    B::EditMessageCaptionTarget: Clone,
     B::SendPoll: Clone,
     B::CopyMessage: Clone,
     B::AnswerInlineQuery: Clone,
     B::AnswerWebAppQuery: Clone,
-    B::EditMessageMedia: Clone,
-    B::EditMessageMediaInline: Clone,
+    B::EditMessageMediaTarget: Clone,
     B::SendMediaGroup: Clone,
 {
     type Err = B::Err;
@@ -163,16 +159,13 @@ where
         send_animation,
         send_voice,
         send_poll,
-        edit_message_text,
-        edit_message_text_inline,
-        edit_message_caption,
-        edit_message_caption_inline,
+        edit_message_text_target,
+        edit_message_caption_target,
         copy_message,
         answer_inline_query,
         answer_web_app_query,
         send_media_group,
-        edit_message_media,
-        edit_message_media_inline,
+        edit_message_media_target,
         => f, fty
     }
 
@@ -187,10 +180,8 @@ where
         forward_message,
         send_video_note,
         send_location,
-        edit_message_live_location,
-        edit_message_live_location_inline,
-        stop_message_live_location,
-        stop_message_live_location_inline,
+        edit_message_live_location_target,
+        stop_message_live_location_target,
         send_venue,
         send_contact,
         send_dice,
@@ -245,8 +236,7 @@ where
         set_my_default_administrator_rights,
         get_my_default_administrator_rights,
         delete_my_commands,
-        edit_message_reply_markup,
-        edit_message_reply_markup_inline,
+        edit_message_reply_markup_target,
         stop_poll,
         delete_message,
         send_sticker,
@@ -264,8 +254,7 @@ where
         answer_pre_checkout_query,
         set_passport_data_errors,
         send_game,
-        set_game_score,
-        set_game_score_inline,
+        set_game_score_target,
         get_game_high_scores,
         approve_chat_join_request,
         decline_chat_join_request
@@ -314,10 +303,8 @@ impl_visit_parse_modes! {
     SendDocument => [parse_mode],
     SendAnimation => [parse_mode],
     SendVoice => [parse_mode],
-    EditMessageText => [parse_mode],
-    EditMessageTextInline => [parse_mode],
-    EditMessageCaption => [parse_mode],
-    EditMessageCaptionInline => [parse_mode],
+    EditMessageTextTarget => [parse_mode],
+    EditMessageCaptionTarget => [parse_mode],
     // FIXME: check if `parse_mode` changes anything if `.caption` is not set
     //        (and if it does, maybe not call visitor if `self.caption.is_none()`)
     CopyMessage => [parse_mode],
@@ -346,13 +333,7 @@ impl VisitParseModes for SendMediaGroup {
     }
 }
 
-impl VisitParseModes for EditMessageMedia {
-    fn visit_parse_modes(&mut self, mut visitor: impl FnMut(&mut Option<ParseMode>)) {
-        visit_parse_modes_in_input_media(&mut self.media, &mut visitor);
-    }
-}
-
-impl VisitParseModes for EditMessageMediaInline {
+impl VisitParseModes for EditMessageMediaTarget {
     fn visit_parse_modes(&mut self, mut visitor: impl FnMut(&mut Option<ParseMode>)) {
         visit_parse_modes_in_input_media(&mut self.media, &mut visitor);
     }
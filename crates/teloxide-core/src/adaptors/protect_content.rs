@@ -0,0 +1,331 @@
+use std::future::IntoFuture;
+
+use url::Url;
+
+use crate::{
+    payloads::{
+        CopyMessage, ForwardMessage, SendAnimation, SendAudio, SendContact, SendDice, SendDocument,
+        SendGame, SendInvoice, SendLocation, SendMediaGroup, SendMessage, SendPhoto, SendPoll,
+        SendSticker, SendVenue, SendVideo, SendVideoNote, SendVoice,
+    },
+    prelude::Requester,
+    requests::{HasPayload, Output, Request},
+    types::*,
+};
+
+/// Default `protect_content` adaptor, see
+/// [`RequesterExt::protect_content`](crate::requests::RequesterExt::protect_content).
+#[derive(Clone, Debug)]
+pub struct DefaultProtectContent<B> {
+    bot: B,
+    protect_content: bool,
+}
+
+/// Request returned by [`DefaultProtectContent`] methods.
+#[derive(Clone)]
+pub struct DefaultProtectContentRequest<R> {
+    req: R,
+    protect_content: bool,
+}
+
+impl<B> DefaultProtectContent<B> {
+    /// Creates new [`DefaultProtectContent`].
+    ///
+    /// Note: it's recommended to use [`RequesterExt::protect_content`] instead.
+    ///
+    /// [`RequesterExt::protect_content`]: crate::requests::RequesterExt::protect_content
+    pub fn new(bot: B, protect_content: bool) -> Self {
+        Self { bot, protect_content }
+    }
+
+    /// Allows to access the inner bot.
+    pub fn inner(&self) -> &B {
+        &self.bot
+    }
+
+    /// Unwraps the inner bot.
+    pub fn into_inner(self) -> B {
+        self.bot
+    }
+
+    /// Returns currently used default value of `protect_content`.
+    pub fn protect_content(&self) -> bool {
+        self.protect_content
+    }
+}
+
+impl<R> Request for DefaultProtectContentRequest<R>
+where
+    R: Request + Clone,
+    R::Payload: HasProtectContent,
+{
+    type Err = R::Err;
+    type Send = R::Send;
+    type SendRef = R::Send;
+
+    fn send(mut self) -> Self::Send {
+        self.req.payload_mut().protect_content_mut().get_or_insert(self.protect_content);
+        self.req.send()
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        // There is no other way to change the payload, given a `&self` :(
+        self.clone().send()
+    }
+}
+
+impl<R> IntoFuture for DefaultProtectContentRequest<R>
+where
+    Self: Request,
+{
+    type Output = Result<Output<Self>, <Self as Request>::Err>;
+    type IntoFuture = <Self as Request>::Send;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.send()
+    }
+}
+
+impl<R> HasPayload for DefaultProtectContentRequest<R>
+where
+    R: Request,
+{
+    type Payload = R::Payload;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        self.req.payload_mut()
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        self.req.payload_ref()
+    }
+}
+
+macro_rules! f {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        {
+            let req = $this.inner().$m($($arg),*);
+            DefaultProtectContentRequest { req, protect_content: $this.protect_content }
+        }
+    };
+}
+
+macro_rules! fty {
+    ($T:ident) => {
+        DefaultProtectContentRequest<B::$T>
+    };
+}
+
+macro_rules! ftyid {
+    ($T:ident) => {
+        B::$T
+    };
+}
+
+macro_rules! fid {
+    ($m:ident $this:ident ($($arg:ident : $T:ty),*)) => {
+        $this.inner().$m($($arg),*)
+    };
+}
+
+impl<B> Requester for DefaultProtectContent<B>
+where
+    B: Requester,
+    B::SendMessage: Clone,
+    B::ForwardMessage: Clone,
+    B::CopyMessage: Clone,
+    B::SendPhoto: Clone,
+    B::SendAudio: Clone,
+    B::SendDocument: Clone,
+    B::SendVideo: Clone,
+    B::SendAnimation: Clone,
+    B::SendVoice: Clone,
+    B::SendVideoNote: Clone,
+    B::SendMediaGroup: Clone,
+    B::SendLocation: Clone,
+    B::SendVenue: Clone,
+    B::SendContact: Clone,
+    B::SendPoll: Clone,
+    B::SendDice: Clone,
+    B::SendSticker: Clone,
+    B::SendInvoice: Clone,
+    B::SendGame: Clone,
+{
+    type Err = B::Err;
+
+    requester_forward! {
+        send_message,
+        forward_message,
+        copy_message,
+        send_photo,
+        send_audio,
+        send_document,
+        send_video,
+        send_animation,
+        send_voice,
+        send_video_note,
+        send_media_group,
+        send_location,
+        send_venue,
+        send_contact,
+        send_poll,
+        send_dice,
+        send_sticker,
+        send_invoice,
+        send_game,
+        => f, fty
+    }
+
+    requester_forward! {
+        edit_message_text_target,
+        edit_message_caption_target,
+        answer_inline_query,
+        answer_web_app_query,
+        edit_message_media_target,
+        get_me,
+        log_out,
+        close,
+        get_updates,
+        set_webhook,
+        delete_webhook,
+        get_webhook_info,
+        edit_message_live_location_target,
+        stop_message_live_location_target,
+        send_chat_action,
+        get_user_profile_photos,
+        get_file,
+        kick_chat_member,
+        ban_chat_member,
+        unban_chat_member,
+        restrict_chat_member,
+        promote_chat_member,
+        set_chat_administrator_custom_title,
+        ban_chat_sender_chat,
+        unban_chat_sender_chat,
+        set_chat_permissions,
+        export_chat_invite_link,
+        create_chat_invite_link,
+        edit_chat_invite_link,
+        revoke_chat_invite_link,
+        set_chat_photo,
+        delete_chat_photo,
+        set_chat_title,
+        set_chat_description,
+        pin_chat_message,
+        unpin_chat_message,
+        unpin_all_chat_messages,
+        leave_chat,
+        get_chat,
+        get_chat_administrators,
+        get_chat_members_count,
+        get_chat_member_count,
+        get_chat_member,
+        set_chat_sticker_set,
+        delete_chat_sticker_set,
+        get_forum_topic_icon_stickers,
+        create_forum_topic,
+        edit_forum_topic,
+        close_forum_topic,
+        reopen_forum_topic,
+        delete_forum_topic,
+        edit_general_forum_topic,
+        close_general_forum_topic,
+        reopen_general_forum_topic,
+        hide_general_forum_topic,
+        unhide_general_forum_topic,
+        unpin_all_forum_topic_messages,
+        answer_callback_query,
+        set_my_commands,
+        get_my_commands,
+        set_chat_menu_button,
+        get_chat_menu_button,
+        set_my_default_administrator_rights,
+        get_my_default_administrator_rights,
+        delete_my_commands,
+        edit_message_reply_markup_target,
+        stop_poll,
+        delete_message,
+        get_sticker_set,
+        get_custom_emoji_stickers,
+        upload_sticker_file,
+        create_new_sticker_set,
+        add_sticker_to_set,
+        set_sticker_position_in_set,
+        delete_sticker_from_set,
+        set_sticker_set_thumb,
+        create_invoice_link,
+        answer_shipping_query,
+        answer_pre_checkout_query,
+        set_passport_data_errors,
+        set_game_score_target,
+        get_game_high_scores,
+        approve_chat_join_request,
+        decline_chat_join_request
+        => fid, ftyid
+    }
+}
+
+download_forward! {
+    B
+    DefaultProtectContent<B>
+    { this => this.inner() }
+}
+
+trait HasProtectContent {
+    fn protect_content_mut(&mut self) -> &mut Option<bool>;
+}
+
+macro_rules! impl_has_protect_content {
+    ($($T:ty),* $(,)?) => {
+        $(
+            impl HasProtectContent for $T {
+                fn protect_content_mut(&mut self) -> &mut Option<bool> {
+                    &mut self.protect_content
+                }
+            }
+        )*
+    }
+}
+
+impl_has_protect_content! {
+    SendMessage,
+    ForwardMessage,
+    CopyMessage,
+    SendPhoto,
+    SendAudio,
+    SendDocument,
+    SendVideo,
+    SendAnimation,
+    SendVoice,
+    SendVideoNote,
+    SendMediaGroup,
+    SendLocation,
+    SendVenue,
+    SendContact,
+    SendPoll,
+    SendDice,
+    SendSticker,
+    SendInvoice,
+    SendGame,
+}
+
+#[cfg(all(test, feature = "mock_bot"))]
+mod tests {
+    use super::*;
+    use crate::{adaptors::MockBot, payloads::SendMessageSetters, requests::RequesterExt};
+
+    #[tokio::test]
+    async fn sets_default() {
+        let mock = MockBot::new();
+        let bot = mock.clone().protect_content(true);
+
+        bot.send_message(ChatId(1), "unset").await.unwrap_err();
+        bot.send_message(ChatId(1), "explicit").protect_content(false).await.unwrap_err();
+        bot.forward_message(ChatId(1), ChatId(2), MessageId(3)).await.unwrap_err();
+
+        let sent: Vec<_> =
+            mock.requests_of::<SendMessage>().into_iter().map(|req| req.protect_content).collect();
+        assert_eq!(sent, [Some(true), Some(false)]);
+        assert_eq!(mock.requests_of::<ForwardMessage>()[0].protect_content, Some(true));
+    }
+}
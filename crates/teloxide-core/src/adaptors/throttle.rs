@@ -27,7 +27,7 @@ use self::{
 };
 
 pub use request::{ThrottlingRequest, ThrottlingSend};
-pub use settings::{Limits, Settings};
+pub use settings::{Fairness, InvalidLimits, Limits, Settings};
 
 /// Automatic request limits respecting mechanism.
 ///
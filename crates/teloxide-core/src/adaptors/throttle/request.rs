@@ -22,7 +22,9 @@ use crate::{
 pub struct ThrottlingRequest<R: HasPayload> {
     pub(super) request: Arc<R>,
     pub(super) chat_id: fn(&R::Payload) -> ChatIdHash,
+    pub(super) message_count: fn(&R::Payload) -> u32,
     pub(super) worker: mpsc::Sender<(ChatIdHash, RequestLock)>,
+    pub(super) bypass: bool,
 }
 
 /// Future returned by [`ThrottlingRequest`]s.
@@ -35,6 +37,20 @@ enum ShareableRequest<R> {
     Owned(Option<R>),
 }
 
+impl<R: HasPayload> ThrottlingRequest<R> {
+    /// Makes this request bypass throttling.
+    ///
+    /// The request is sent right away, without waiting in the queue, which is
+    /// useful for urgent requests, e.g. answers to callback queries. It's
+    /// still counted towards the limits, delaying the following requests.
+    ///
+    /// Note that the request is not retried, even if
+    /// [`Settings::retry`](crate::adaptors::throttle::Settings::retry) is set.
+    pub fn bypass(self) -> Self {
+        Self { bypass: true, ..self }
+    }
+}
+
 impl<R: HasPayload + Clone> HasPayload for ThrottlingRequest<R> {
     type Payload = R::Payload;
 
@@ -61,19 +77,21 @@ where
 
     fn send(self) -> Self::Send {
         let chat = (self.chat_id)(self.payload_ref());
+        let message_count = (self.message_count)(self.payload_ref());
         let request = match Arc::try_unwrap(self.request) {
             Ok(owned) => ShareableRequest::Owned(Some(owned)),
             Err(shared) => ShareableRequest::Shared(shared),
         };
-        let fut = send(request, chat, self.worker);
+        let fut = send(request, chat, message_count, self.worker, self.bypass);
 
         ThrottlingSend(Box::pin(fut))
     }
 
     fn send_ref(&self) -> Self::SendRef {
         let chat = (self.chat_id)(self.payload_ref());
+        let message_count = (self.message_count)(self.payload_ref());
         let request = ShareableRequest::Shared(Arc::clone(&self.request));
-        let fut = send(request, chat, self.worker.clone());
+        let fut = send(request, chat, message_count, self.worker.clone(), self.bypass);
 
         ThrottlingSend(Box::pin(fut))
     }
@@ -155,7 +173,9 @@ where
 async fn send<R>(
     mut request: ShareableRequest<R>,
     chat: ChatIdHash,
+    message_count: u32,
     worker: mpsc::Sender<(ChatIdHash, RequestLock)>,
+    bypass: bool,
 ) -> Result<Output<R>, R::Err>
 where
     R: Request + Send + Sync + 'static,
@@ -167,8 +187,21 @@ where
     // All unwraps down below will succeed because we always return immediately
     // after taking.
 
+    if bypass {
+        // Don't wait for the worker, but let it know about the request, so it
+        // is counted towards the limits
+        tokio::spawn(async move {
+            let _ = worker.send((chat, RequestLock::bypassed(message_count))).await;
+        });
+
+        return match request {
+            ShareableRequest::Shared(shared) => shared.send_ref().await,
+            ShareableRequest::Owned(owned) => owned.unwrap().await,
+        };
+    }
+
     loop {
-        let (lock, wait) = channel();
+        let (lock, wait) = channel(message_count);
 
         // The worker is unlikely to drop queue before sending all requests,
         // but just in case it has dropped the queue, we want to just send the
@@ -216,7 +249,10 @@ where
         }
 
         match res {
-            Err(_) if retry && retry_after.is_some() => continue,
+            Err(_) if retry && retry_after.is_some() => {
+                crate::net::record_retry();
+                continue;
+            }
             res => break res,
         };
     }
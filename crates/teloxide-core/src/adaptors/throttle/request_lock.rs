@@ -11,23 +11,47 @@ use tokio::sync::{
 
 use crate::adaptors::throttle::FreezeUntil;
 
-pub(super) fn channel() -> (RequestLock, RequestWaiter) {
+/// Creates a lock of a request which sends `message_count` messages.
+pub(super) fn channel(message_count: u32) -> (RequestLock, RequestWaiter) {
     let (tx, rx) = oneshot::channel();
-    let tx = RequestLock(tx);
+    let tx = RequestLock { tx: Some(tx), message_count };
     let rx = RequestWaiter(rx);
     (tx, rx)
 }
 
 #[must_use]
-pub(super) struct RequestLock(Sender<(bool, mpsc::Sender<FreezeUntil>)>);
+pub(super) struct RequestLock {
+    // `None` for requests which bypass throttling
+    tx: Option<Sender<(bool, mpsc::Sender<FreezeUntil>)>>,
+    message_count: u32,
+}
 
 #[must_use]
 #[pin_project::pin_project]
 pub(super) struct RequestWaiter(#[pin] Receiver<(bool, mpsc::Sender<FreezeUntil>)>);
 
 impl RequestLock {
+    /// Creates a lock of a request which bypasses throttling and was sent
+    /// without waiting. Such locks are only used to record the request.
+    pub(super) fn bypassed(message_count: u32) -> Self {
+        Self { tx: None, message_count }
+    }
+
+    pub(super) fn is_bypassed(&self) -> bool {
+        self.tx.is_none()
+    }
+
+    /// Returns the number of messages the request sends, each of them is
+    /// counted towards the limits.
+    pub(super) fn message_count(&self) -> u32 {
+        self.message_count
+    }
+
     pub(super) fn unlock(self, retry: bool, freeze: mpsc::Sender<FreezeUntil>) -> Result<(), ()> {
-        self.0.send((retry, freeze)).map_err(drop)
+        match self.tx {
+            Some(tx) => tx.send((retry, freeze)).map_err(drop),
+            None => Ok(()),
+        }
     }
 }
 
@@ -1,10 +1,11 @@
-use std::sync::Arc;
+use std::{any::Any, sync::Arc};
 
 use url::Url;
 
 use crate::{
     adaptors::{throttle::ThrottlingRequest, Throttle},
     errors::AsResponseParameters,
+    payloads::SendMediaGroup,
     requests::{HasPayload, Requester},
     types::*,
 };
@@ -14,11 +15,22 @@ macro_rules! f {
         ThrottlingRequest {
             request: Arc::new($this.inner().$m($($arg),*)),
             chat_id: |p| (&p.payload_ref().chat_id).into(),
+            message_count: |p| message_count(p.payload_ref()),
             worker: $this.queue.clone(),
+            bypass: false,
         }
     };
 }
 
+/// Returns the number of messages sent by a request with `payload`, Telegram
+/// counts each of them towards the limits.
+fn message_count<P: 'static>(payload: &P) -> u32 {
+    match (payload as &dyn Any).downcast_ref::<SendMediaGroup>() {
+        Some(group) => group.media.len().max(1) as u32,
+        None => 1,
+    }
+}
+
 macro_rules! fty {
     ($T:ident) => {
         ThrottlingRequest<B::$T>
@@ -92,10 +104,8 @@ where
         set_webhook,
         delete_webhook,
         get_webhook_info,
-        edit_message_live_location,
-        edit_message_live_location_inline,
-        stop_message_live_location,
-        stop_message_live_location_inline,
+        edit_message_live_location_target,
+        stop_message_live_location_target,
         send_chat_action,
         get_user_profile_photos,
         get_file,
@@ -149,14 +159,10 @@ where
         delete_my_commands,
         answer_inline_query,
         answer_web_app_query,
-        edit_message_text,
-        edit_message_text_inline,
-        edit_message_caption,
-        edit_message_caption_inline,
-        edit_message_media,
-        edit_message_media_inline,
-        edit_message_reply_markup,
-        edit_message_reply_markup_inline,
+        edit_message_text_target,
+        edit_message_caption_target,
+        edit_message_media_target,
+        edit_message_reply_markup_target,
         stop_poll,
         delete_message,
         get_sticker_set,
@@ -172,8 +178,7 @@ where
         answer_pre_checkout_query,
         set_passport_data_errors,
         send_game,
-        set_game_score,
-        set_game_score_inline,
+        set_game_score_target,
         approve_chat_join_request,
         decline_chat_join_request,
         get_game_high_scores
@@ -1,6 +1,7 @@
 use std::pin::Pin;
 
 use futures::{future::ready, Future};
+use thiserror::Error;
 
 // Required to not trigger `clippy::type-complexity` lint
 type BoxedFnMut<I, O> = Box<dyn FnMut(I) -> O + Send>;
@@ -28,6 +29,7 @@ pub struct Settings {
     pub on_queue_full: BoxedFnMut<usize, BoxedFuture>,
     pub retry: bool,
     pub check_slow_mode: bool,
+    pub fairness: Fairness,
 }
 
 /// Telegram request limits.
@@ -37,8 +39,15 @@ pub struct Settings {
 /// Note that you may ask telegram [@BotSupport] to increase limits for your
 /// particular bot if it has a lot of users (but they may or may not do that).
 ///
+/// Besides the [`Default`] limits, which follow the Telegram documentation,
+/// there are [`conservative`] and [`aggressive`] presets. Use [`custom`] to
+/// make sure your own limits make sense.
+///
 /// [@BotSupport]: https://t.me/botsupport
 /// [`Throttle`]: crate::adaptors::throttle::Throttle
+/// [`conservative`]: Limits::conservative
+/// [`aggressive`]: Limits::aggressive
+/// [`custom`]: Limits::custom
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Limits {
     /// Allowed messages in one chat per second.
@@ -54,6 +63,132 @@ pub struct Limits {
     pub messages_per_sec_overall: u32,
 }
 
+/// An error returned by [`Limits::custom`] when the limits don't make sense.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidLimits {
+    /// One of the limits is zero, so no requests could ever be sent.
+    #[error("Limits must be positive")]
+    Zero,
+
+    /// Allowed messages in one chat per second exceed the allowed messages
+    /// per minute.
+    #[error("Messages per second in a chat exceed messages per minute")]
+    PerSecExceedsPerMin,
+
+    /// Allowed messages in one chat per second exceed the allowed messages
+    /// per second overall.
+    #[error("Messages per second in a chat exceed messages per second overall")]
+    PerChatExceedsOverall,
+}
+
+/// The policy [`Throttle`] uses to choose which of the queued requests to send,
+/// when not all of them can be sent right away.
+///
+/// Requests to the same chat are always sent in the order they were made.
+///
+/// [`Throttle`]: crate::adaptors::throttle::Throttle
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Fairness {
+    /// Requests are sent in the order they were made.
+    ///
+    /// Requests to a chat with a lot of pending requests (e.g. a broadcast)
+    /// can fill the queue and delay requests to all other chats until they
+    /// are sent.
+    #[default]
+    Fifo,
+
+    /// Chats take turns: one request is sent to every chat with pending
+    /// requests before a second one is sent to any of them, and chats which
+    /// were sent to least recently go first.
+    ///
+    /// This bounds the wait of every chat by the number of chats with pending
+    /// requests. To see requests behind the ones to a busy chat, the queue
+    /// holds up to 8 times [`Limits::messages_per_sec_overall`] requests in
+    /// this mode, so requests further behind still wait for their turn.
+    /// [`Settings::on_queue_full`] is called when the queue holds more than
+    /// [`Limits::messages_per_sec_overall`] requests.
+    RoundRobin,
+}
+
+impl Limits {
+    /// Limits which leave a margin below the [`Default`] ones, for bots that
+    /// can't afford to hit a `RetryAfter` error.
+    pub fn conservative() -> Self {
+        Self {
+            messages_per_sec_chat: 1,
+            messages_per_sec_overall: 20,
+            messages_per_min_chat: 15,
+            messages_per_min_channel: 8,
+        }
+    }
+
+    /// Limits which allow short bursts above the [`Default`] ones.
+    ///
+    /// Telegram tolerates bursts to some extent, but this preset will hit
+    /// `RetryAfter` errors more often, so it's best used with retries on (see
+    /// [`Settings::no_retry`]).
+    pub fn aggressive() -> Self {
+        Self {
+            messages_per_sec_chat: 2,
+            messages_per_sec_overall: 30,
+            messages_per_min_chat: 30,
+            messages_per_min_channel: 20,
+        }
+    }
+
+    /// Creates custom limits, checking that they make sense.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if any of the limits is zero, or if the allowed
+    /// messages in one chat per second exceed the allowed messages per minute
+    /// or per second overall.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::adaptors::throttle::{InvalidLimits, Limits};
+    ///
+    /// assert!(Limits::custom(1, 20, 10, 30).is_ok());
+    /// assert_eq!(Limits::custom(1, 20, 10, 0), Err(InvalidLimits::Zero));
+    /// assert_eq!(Limits::custom(30, 20, 10, 30), Err(InvalidLimits::PerSecExceedsPerMin));
+    /// ```
+    pub fn custom(
+        messages_per_sec_chat: u32,
+        messages_per_min_chat: u32,
+        messages_per_min_channel: u32,
+        messages_per_sec_overall: u32,
+    ) -> Result<Self, InvalidLimits> {
+        if [
+            messages_per_sec_chat,
+            messages_per_min_chat,
+            messages_per_min_channel,
+            messages_per_sec_overall,
+        ]
+        .contains(&0)
+        {
+            return Err(InvalidLimits::Zero);
+        }
+
+        if messages_per_sec_chat > messages_per_min_chat
+            || messages_per_sec_chat > messages_per_min_channel
+        {
+            return Err(InvalidLimits::PerSecExceedsPerMin);
+        }
+
+        if messages_per_sec_chat > messages_per_sec_overall {
+            return Err(InvalidLimits::PerChatExceedsOverall);
+        }
+
+        Ok(Self {
+            messages_per_sec_chat,
+            messages_per_min_chat,
+            messages_per_min_channel,
+            messages_per_sec_overall,
+        })
+    }
+}
+
 impl Settings {
     pub fn limits(mut self, val: Limits) -> Self {
         self.limits = val;
@@ -78,6 +213,11 @@ impl Settings {
         self.check_slow_mode = true;
         self
     }
+
+    pub fn fairness(mut self, val: Fairness) -> Self {
+        self.fairness = val;
+        self
+    }
 }
 
 impl Default for Settings {
@@ -90,6 +230,7 @@ impl Default for Settings {
             }),
             retry: true,
             check_slow_mode: false,
+            fairness: Fairness::Fifo,
         }
     }
 }
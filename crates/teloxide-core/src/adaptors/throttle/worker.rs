@@ -10,7 +10,7 @@ use tokio::sync::{mpsc, mpsc::error::TryRecvError, oneshot::Sender};
 use vecrem::VecExt;
 
 use crate::{
-    adaptors::throttle::{request_lock::RequestLock, ChatIdHash, Limits, Settings},
+    adaptors::throttle::{request_lock::RequestLock, ChatIdHash, Fairness, Limits, Settings},
     errors::AsResponseParameters,
     requests::Requester,
 };
@@ -27,6 +27,11 @@ const DELAY: Duration = Duration::from_millis(250);
 /// Minimal time between calls to queue_full function
 const QUEUE_FULL_DELAY: Duration = Duration::from_secs(4);
 
+/// How many times the queue capacity is read from the channel with
+/// `Fairness::RoundRobin`, so that requests to other chats can be seen behind
+/// the ones to a busy chat
+const ROUND_ROBIN_WINDOW: usize = 8;
+
 #[derive(Debug)]
 pub(super) enum InfoMessage {
     GetLimits { response: Sender<Limits> },
@@ -88,12 +93,16 @@ pub(super) struct FreezeUntil {
 // `Map<ChatId, Count>`). (Note: the same map, but for last minute also exists,
 // but it's updated, instead of recreation.)
 //
-// 8. While `allowed >= 0` search for requests which chat haven't exceed the
+// 8. With `Fairness::RoundRobin`, reorder the queue, so that the first requests
+// of every chat go before the second ones and so on, with chats sent to least
+// recently going first.
+//
+// 9. While `allowed >= 0` search for requests which chat haven't exceed the
 // limits (i.e.: map[chat] < limit), if one is found, decrease `allowed`, notify
 // the request that it can be now executed, increase counts, add record to the
 // history.
 pub(super) async fn worker<B>(
-    Settings { mut limits, mut on_queue_full, retry, check_slow_mode }: Settings,
+    Settings { mut limits, mut on_queue_full, retry, check_slow_mode, fairness }: Settings,
     mut rx: mpsc::Receiver<(ChatIdHash, RequestLock)>,
     mut info_rx: mpsc::Receiver<InfoMessage>,
     bot: B,
@@ -106,6 +115,14 @@ pub(super) async fn worker<B>(
     //                stayed), amortized O(1) push (vec+vecrem).
     let mut queue: Vec<(ChatIdHash, RequestLock)> =
         Vec::with_capacity(limits.messages_per_sec_overall as usize);
+    let queue_limit = queue.capacity();
+
+    // Round-robin needs to see requests to other chats, so it reads further,
+    // but still a bounded number of requests to limit DOS possibility
+    let read_limit = match fairness {
+        Fairness::Fifo => queue_limit,
+        Fairness::RoundRobin => queue_limit * ROUND_ROBIN_WINDOW,
+    };
 
     let mut history: VecDeque<(ChatIdHash, Instant)> = VecDeque::new();
     let mut requests_sent = RequestsSentToChats::default();
@@ -113,6 +130,9 @@ pub(super) async fn worker<B>(
     let mut slow_mode: Option<HashMap<ChatIdHash, (Duration, Instant)>> =
         check_slow_mode.then(HashMap::new);
 
+    let mut last_sent: Option<HashMap<ChatIdHash, Instant>> =
+        (fairness == Fairness::RoundRobin).then(HashMap::new);
+
     let mut rx_is_closed = false;
 
     let mut last_queue_full =
@@ -134,7 +154,7 @@ pub(super) async fn worker<B>(
         loop {
             let res = future::select(
                 pin!(freeze_rx.recv()),
-                pin!(read_from_rx(&mut rx, &mut queue, &mut rx_is_closed)),
+                pin!(read_from_rx(&mut rx, &mut queue, read_limit, &mut rx_is_closed)),
             )
             .map(either)
             .await
@@ -149,7 +169,25 @@ pub(super) async fn worker<B>(
         }
         //debug_assert_eq!(queue.capacity(), limits.messages_per_sec_overall as usize);
 
-        if queue.len() == queue.capacity() && last_queue_full.elapsed() > QUEUE_FULL_DELAY {
+        // Requests which bypass throttling are already sent, so we only record them
+        queue.retain(|(chat, lock)| {
+            if !lock.is_bypassed() {
+                return true;
+            }
+
+            let count = lock.message_count();
+            let now = Instant::now();
+            *requests_sent.per_min.entry(*chat).or_insert(0) += count;
+            history.extend(std::iter::repeat((*chat, now)).take(count as usize));
+
+            if let Some(last_sent) = &mut last_sent {
+                last_sent.insert(*chat, now);
+            }
+
+            false
+        });
+
+        if queue.len() >= queue_limit && last_queue_full.elapsed() > QUEUE_FULL_DELAY {
             last_queue_full = Instant::now();
             tokio::spawn(on_queue_full(queue.len()));
         }
@@ -226,6 +264,12 @@ pub(super) async fn worker<B>(
             *requests_sent.per_sec.entry(*chat).or_insert(0) += 1;
         }
 
+        if let Some(last_sent) = &mut last_sent {
+            // Chats not sent to for a minute are as good as never sent to
+            last_sent.retain(|_, time| *time >= min_back);
+            order_round_robin(&mut queue, last_sent);
+        }
+
         let mut queue_removing = queue.removing();
 
         while let Some(entry) = queue_removing.next() {
@@ -256,19 +300,28 @@ pub(super) async fn worker<B>(
 
                 let chat = *chat;
                 let (_, lock) = entry.remove();
+                // Requests sending several messages (i.e. media groups) are allowed if at
+                // least one message can be sent, but reserve slots for all of them, delaying
+                // the following requests
+                let count = lock.message_count();
 
                 // Only count request as sent if the request wasn't dropped before unlocked
                 if lock.unlock(retry, freeze_tx.clone()).is_ok() {
-                    *requests_sent.per_sec.entry(chat).or_insert(0) += 1;
-                    *requests_sent.per_min.entry(chat).or_insert(0) += 1;
-                    history.push_back((chat, Instant::now()));
+                    *requests_sent.per_sec.entry(chat).or_insert(0) += count;
+                    *requests_sent.per_min.entry(chat).or_insert(0) += count;
+                    let now = Instant::now();
+                    history.extend(std::iter::repeat((chat, now)).take(count as usize));
 
                     if let Some((_, last)) = slow_mode {
                         *last = Instant::now();
                     }
 
-                    // We have "sent" one request, so now we can send one less.
-                    allowed -= 1;
+                    if let Some(last_sent) = &mut last_sent {
+                        last_sent.insert(chat, Instant::now());
+                    }
+
+                    // We have "sent" `count` messages, so now we can send less.
+                    allowed = allowed.saturating_sub(count);
                     if allowed == 0 {
                         break;
                     }
@@ -355,7 +408,37 @@ async fn freeze(
     }
 }
 
-async fn read_from_rx<T>(rx: &mut mpsc::Receiver<T>, queue: &mut Vec<T>, rx_is_closed: &mut bool) {
+/// Reorders `queue` so that the first requests of every chat go first, then the
+/// second ones and so on. Chats of the same turn are ordered by the time of
+/// the last request sent to them, chats never sent to go first.
+///
+/// The order of requests to the same chat is preserved. The queue is bounded by
+/// `ROUND_ROBIN_WINDOW` times its capacity, so sorting it is cheap.
+fn order_round_robin<T>(
+    queue: &mut Vec<(ChatIdHash, T)>,
+    last_sent: &HashMap<ChatIdHash, Instant>,
+) {
+    let mut turns = HashMap::<ChatIdHash, usize>::new();
+    let mut keyed: Vec<_> = queue
+        .drain(..)
+        .map(|entry| {
+            let turn = turns.entry(entry.0).or_insert(0);
+            *turn += 1;
+            ((*turn, last_sent.get(&entry.0).copied()), entry)
+        })
+        .collect();
+
+    // The sort is stable, so requests to the same chat keep their order
+    keyed.sort_by_key(|(key, _)| *key);
+    queue.extend(keyed.into_iter().map(|(_, entry)| entry));
+}
+
+async fn read_from_rx<T>(
+    rx: &mut mpsc::Receiver<T>,
+    queue: &mut Vec<T>,
+    limit: usize,
+    rx_is_closed: &mut bool,
+) {
     if queue.is_empty() {
         log::debug!("blocking on queue");
 
@@ -365,8 +448,8 @@ async fn read_from_rx<T>(rx: &mut mpsc::Receiver<T>, queue: &mut Vec<T>, rx_is_c
         }
     }
 
-    // Don't grow queue bigger than the capacity to limit DOS possibility
-    while queue.len() < queue.capacity() {
+    // Don't grow queue bigger than the limit to limit DOS possibility
+    while queue.len() < limit {
         match rx.try_recv() {
             Ok(req) => queue.push(req),
             Err(TryRecvError::Disconnected) => {
@@ -388,6 +471,128 @@ fn either<L, R>(x: future::Either<L, R>) -> Either<L, R> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
+    use futures::FutureExt as _;
+    use tokio::sync::mpsc;
+
+    use crate::{
+        adaptors::throttle::{
+            request_lock::{channel, RequestLock, RequestWaiter},
+            ChatIdHash, Fairness, Limits, Settings,
+        },
+        types::ChatId,
+        Bot,
+    };
+
+    const A: ChatIdHash = ChatIdHash::Id(ChatId(1));
+    const B: ChatIdHash = ChatIdHash::Id(ChatId(2));
+
+    const LIMITS: Limits = Limits {
+        messages_per_sec_chat: 10,
+        messages_per_min_chat: 1000,
+        messages_per_min_channel: 1000,
+        messages_per_sec_overall: 10,
+    };
+
+    /// Spawns a worker, returning a function that queues a request to `chat`
+    /// which sends `message_count` messages.
+    fn spawn_worker(settings: Settings) -> impl Fn(ChatIdHash, u32) -> RequestWaiter {
+        let (tx, rx) = mpsc::channel(100);
+        let (_, info_rx) = mpsc::channel(1);
+        tokio::spawn(super::worker(settings, rx, info_rx, Bot::new("TOKEN")));
+
+        move |chat, message_count| {
+            let (lock, waiter) = channel(message_count);
+            tx.try_send((chat, lock)).ok().unwrap();
+            waiter
+        }
+    }
+
+    /// Queues `busy` requests to chat `A` and then one to chat `B`, returning
+    /// whether the request to `B` was unlocked in time and how many requests
+    /// to `A` were unlocked by then.
+    async fn busy_chat_and_other_chat(fairness: Fairness, busy: usize) -> (bool, usize) {
+        let send = spawn_worker(Settings::default().limits(LIMITS).fairness(fairness));
+
+        let mut busy: Vec<RequestWaiter> = (0..busy).map(|_| send(A, 1)).collect();
+        let other = send(B, 1);
+
+        let unlocked = tokio::time::timeout(super::SECOND, other).await.is_ok();
+        let busy_unlocked = busy.iter_mut().filter_map(|w| w.now_or_never()).count();
+        (unlocked, busy_unlocked)
+    }
+
+    #[tokio::test]
+    async fn media_groups_reserve_slots() {
+        let send = spawn_worker(Settings::default().limits(LIMITS));
+
+        let group = send(A, 10);
+        let other = send(B, 1);
+
+        tokio::time::timeout(super::SECOND, group).await.unwrap();
+        // All slots of this second are taken by the media group
+        assert!(tokio::time::timeout(super::SECOND / 2, other).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn bypassed_requests_are_recorded() {
+        let (tx, rx) = mpsc::channel(100);
+        let (_, info_rx) = mpsc::channel(1);
+        tokio::spawn(super::worker(
+            Settings::default().limits(LIMITS),
+            rx,
+            info_rx,
+            Bot::new("TOKEN"),
+        ));
+
+        tx.send((A, RequestLock::bypassed(10))).await.ok().unwrap();
+        let (lock, mut other) = channel(1);
+        tx.send((B, lock)).await.ok().unwrap();
+
+        // All slots of this second are taken by the bypassed request
+        assert!(tokio::time::timeout(super::SECOND / 2, &mut other).await.is_err());
+        tokio::time::timeout(super::SECOND, other).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn fifo_delays_other_chats() {
+        let (unlocked, _) = busy_chat_and_other_chat(Fairness::Fifo, 50).await;
+        assert!(!unlocked);
+    }
+
+    #[tokio::test]
+    async fn round_robin_bounds_wait() {
+        let (unlocked, busy_unlocked) = busy_chat_and_other_chat(Fairness::RoundRobin, 50).await;
+        assert!(unlocked);
+        assert!(busy_unlocked < 50);
+    }
+
+    #[tokio::test]
+    async fn round_robin_window_is_bounded() {
+        // The request to `B` is behind `ROUND_ROBIN_WINDOW` times the capacity
+        let (unlocked, _) = busy_chat_and_other_chat(Fairness::RoundRobin, 95).await;
+        assert!(!unlocked);
+    }
+
+    #[test]
+    fn round_robin_order() {
+        let queue = vec![(A, 1), (A, 2), (A, 3), (B, 1), (ChatIdHash::ChannelUsernameHash(0), 1)];
+
+        let mut never_sent = queue.clone();
+        super::order_round_robin(&mut never_sent, &HashMap::new());
+        assert_eq!(
+            never_sent,
+            [(A, 1), (B, 1), (ChatIdHash::ChannelUsernameHash(0), 1), (A, 2), (A, 3)]
+        );
+
+        // Chats sent to recently go last in their turn
+        let mut sent = queue;
+        let last_sent = HashMap::from([(A, std::time::Instant::now())]);
+        super::order_round_robin(&mut sent, &last_sent);
+        assert_eq!(sent[..3], [(B, 1), (ChatIdHash::ChannelUsernameHash(0), 1), (A, 1)]);
+    }
+
     #[tokio::test]
     async fn issue_535() {
         let (tx, mut rx) = tokio::sync::mpsc::channel(1);
@@ -396,6 +601,6 @@ mod tests {
         drop(tx);
 
         // Previously this caused an infinite loop
-        super::read_from_rx::<()>(&mut rx, &mut Vec::new(), &mut false).await;
+        super::read_from_rx::<()>(&mut rx, &mut Vec::new(), 0, &mut false).await;
     }
 }
@@ -1,15 +1,21 @@
-use std::{future::Future, sync::Arc};
+use std::{borrow::Cow, future::Future, sync::Arc, time::Duration};
 
 use reqwest::Client;
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
+    errors::TokenError,
     net,
-    requests::{MultipartPayload, Payload, ResponseResult},
+    requests::{MultipartPayload, Payload, Requester, ResponseResult},
     serde_multipart,
+    types::{Me, UserId},
+    RequestError,
 };
 
+pub use self::builder::BotBuilder;
+
 mod api;
+mod builder;
 mod download;
 
 const TELOXIDE_TOKEN: &str = "TELOXIDE_TOKEN";
@@ -57,6 +63,8 @@ pub struct Bot {
     token: Arc<str>,
     api_url: Arc<reqwest::Url>,
     client: Client,
+    request_logging: net::RequestLogging,
+    me: Option<Arc<Me>>,
 }
 
 /// Constructors
@@ -96,7 +104,16 @@ impl Bot {
                 .expect("Failed to parse default Telegram bot API url"),
         );
 
-        Self { token, api_url, client }
+        Self { token, api_url, client, request_logging: net::RequestLogging::new(), me: None }
+    }
+
+    /// Creates a [`BotBuilder`], which allows to tune the [`reqwest::Client`]
+    /// used by the bot, e.g. its connection pool.
+    pub fn builder<S>(token: S) -> BotBuilder
+    where
+        S: Into<String>,
+    {
+        BotBuilder::new(token.into())
     }
 
     /// Creates a new `Bot` with the `TELOXIDE_TOKEN` & `TELOXIDE_PROXY`
@@ -133,6 +150,58 @@ impl Bot {
         Self::with_client(get_env(TELOXIDE_TOKEN), client)
     }
 
+    /// Creates a new `Bot` like [`Bot::from_env`], but returns an error
+    /// instead of panicking if the `TELOXIDE_TOKEN` environmental variable is
+    /// not set, and checks the format of the token.
+    ///
+    /// See also [`Bot::validate_token`].
+    ///
+    /// # Panics
+    /// If it cannot create [`reqwest::Client`].
+    ///
+    /// [`reqwest::Client`]: https://docs.rs/reqwest/0.10.1/reqwest/struct.Client.html
+    pub fn from_env_checked() -> Result<Self, TokenError> {
+        let token =
+            std::env::var(TELOXIDE_TOKEN).map_err(|_| TokenError::MissingEnv(TELOXIDE_TOKEN))?;
+        let bot = Self::with_client(token, crate::net::client_from_env());
+        bot.validate_token()?;
+
+        Ok(bot)
+    }
+
+    /// Checks that the token looks like a valid bot token, without sending
+    /// any requests.
+    ///
+    /// Note that this doesn't guarantee that the token is accepted by
+    /// Telegram, use [`Bot::with_me`] to check that.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::{Bot, TokenError};
+    ///
+    /// let bot = Bot::new("123456789:ABC-DEF1234ghIkl-zyx57W2v1u123ew11");
+    /// assert_eq!(bot.validate_token(), Ok(()));
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// assert_eq!(bot.validate_token(), Err(TokenError::InvalidFormat));
+    /// ```
+    pub fn validate_token(&self) -> Result<(), TokenError> {
+        parse_token(&self.token).map(drop)
+    }
+
+    /// Fetches information about the bot with [`GetMe`], so that it can be
+    /// later accessed with [`Bot::me`] and [`Bot::username`].
+    ///
+    /// This also checks that the token is accepted by Telegram.
+    ///
+    /// [`GetMe`]: crate::payloads::GetMe
+    pub async fn with_me(mut self) -> Result<Self, RequestError> {
+        let me = self.get_me().await?;
+        self.me = Some(Arc::new(me));
+        Ok(self)
+    }
+
     /// Sets a custom API URL.
     ///
     /// For example, you can run your own [Telegram bot API server][tbas] and
@@ -176,6 +245,17 @@ impl Bot {
         self.api_url = Arc::new(url);
         self
     }
+
+    /// Sets the settings of logging raw requests and responses.
+    ///
+    /// By default, logging is enabled with the default redaction settings, see
+    /// [`RequestLogging::new`].
+    ///
+    /// [`RequestLogging::new`]: net::RequestLogging::new
+    pub fn set_request_logging(mut self, logging: net::RequestLogging) -> Self {
+        self.request_logging = logging;
+        self
+    }
 }
 
 /// Getters
@@ -197,6 +277,113 @@ impl Bot {
     pub fn api_url(&self) -> reqwest::Url {
         reqwest::Url::clone(&*self.api_url)
     }
+
+    /// Returns the id of the bot, which is a part of its token.
+    ///
+    /// Returns `None` if the token has an invalid format.
+    #[must_use]
+    pub fn id(&self) -> Option<UserId> {
+        parse_token(&self.token).ok()
+    }
+
+    /// Returns information about the bot, if it was fetched with
+    /// [`Bot::with_me`].
+    #[must_use]
+    pub fn me(&self) -> Option<&Me> {
+        self.me.as_deref()
+    }
+
+    /// Returns the username of the bot, if information about the bot was
+    /// fetched with [`Bot::with_me`].
+    #[must_use]
+    pub fn username(&self) -> Option<&str> {
+        self.me().map(Me::username)
+    }
+}
+
+/// Raw requests
+impl Bot {
+    /// Sends a request to the method `method_name` with `params` and returns
+    /// the result deserialized as `T`.
+    ///
+    /// This is an escape hatch for calling methods which are not supported by
+    /// this library yet. Requests are sent and errors are handled in the same
+    /// way as for [`Requester`] methods. If `params` can't be serialized,
+    /// [`RequestError::Io`] is returned.
+    ///
+    /// Like [`Bot::send_json`], this method is only available on `Bot`, call it
+    /// on the inner bot when using adaptors (e.g. `bot.inner().raw(...)`).
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use serde::Serialize;
+    /// use teloxide_core::{prelude::*, types::ChatId};
+    ///
+    /// #[derive(Serialize)]
+    /// struct GetChatMemberCount {
+    ///     chat_id: ChatId,
+    /// }
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN");
+    ///
+    /// let count: u32 =
+    ///     bot.raw("getChatMemberCount", &GetChatMemberCount { chat_id: ChatId(-1) }).await?;
+    /// # Ok::<_, teloxide_core::RequestError>(()) };
+    /// ```
+    pub fn raw<T>(
+        &self,
+        method_name: impl Into<String>,
+        params: &impl Serialize,
+    ) -> impl Future<Output = ResponseResult<T>> + 'static
+    where
+        T: DeserializeOwned + 'static,
+    {
+        let request = serde_json::to_vec(params)
+            .map(|params| self.execute_json_raw(Cow::Owned(method_name.into()), params, None))
+            .map_err(|err| RequestError::Io(err.into()));
+
+        async move { request?.await }
+    }
+
+    /// Sends a request to the method `method_name` with JSON `params` and
+    /// returns the JSON result.
+    ///
+    /// This is the same as [`Bot::raw`], but is more convenient for sending
+    /// payloads stored as JSON (all payloads without files to upload implement
+    /// [`Deserialize`]).
+    ///
+    /// Unlike [`Requester`] methods, this method is only available on `Bot`:
+    /// requester methods return requests with payloads known at compile time,
+    /// which adaptors rely on (e.g. [`Throttle`] finds the chat of a request in
+    /// its payload). To send a request with a bot wrapped in adaptors, call
+    /// this method on the inner bot (e.g. `bot.inner().send_json(...)`), note
+    /// that such requests bypass the adaptors.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{payloads::SendMessage, prelude::*, requests::Payload};
+    ///
+    /// # async {
+    /// let bot = Bot::new("TOKEN");
+    ///
+    /// // A payload restored from a queue
+    /// let payload: SendMessage = serde_json::from_str(r#"{"chat_id":1,"text":"Hi!"}"#)?;
+    /// let message = bot.send_json(SendMessage::NAME, serde_json::to_value(&payload)?).await?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(()) };
+    /// ```
+    ///
+    /// [`Deserialize`]: serde::Deserialize
+    /// [`Throttle`]: crate::adaptors::Throttle
+    pub fn send_json(
+        &self,
+        method_name: impl Into<String>,
+        params: serde_json::Value,
+    ) -> impl Future<Output = ResponseResult<serde_json::Value>> + 'static {
+        self.raw(method_name, &params)
+    }
 }
 
 impl Bot {
@@ -208,24 +395,38 @@ impl Bot {
         P: Payload + Serialize,
         P::Output: DeserializeOwned + 'static,
     {
-        let client = self.client.clone();
-        let token = Arc::clone(&self.token);
-        let api_url = Arc::clone(&self.api_url);
-
         let timeout_hint = payload.timeout_hint();
         let params = serde_json::to_vec(payload)
             // this `expect` should be ok since we don't write request those may trigger error here
             .expect("serialization of request to be infallible");
 
+        self.execute_json_raw(Cow::Borrowed(P::NAME), params, timeout_hint)
+    }
+
+    fn execute_json_raw<T>(
+        &self,
+        method_name: Cow<'static, str>,
+        params: Vec<u8>,
+        timeout_hint: Option<Duration>,
+    ) -> impl Future<Output = ResponseResult<T>> + 'static
+    where
+        T: DeserializeOwned + 'static,
+    {
+        let client = self.client.clone();
+        let token = Arc::clone(&self.token);
+        let api_url = Arc::clone(&self.api_url);
+        let request_logging = self.request_logging.clone();
+
         // async move to capture client&token&api_url&params
         async move {
             net::request_json(
                 &client,
                 token.as_ref(),
                 reqwest::Url::clone(&*api_url),
-                P::NAME,
+                &method_name,
                 params,
                 timeout_hint,
+                &request_logging,
             )
             .await
         }
@@ -242,9 +443,10 @@ impl Bot {
         let client = self.client.clone();
         let token = Arc::clone(&self.token);
         let api_url = Arc::clone(&self.api_url);
+        let request_logging = self.request_logging.clone();
 
         let timeout_hint = payload.timeout_hint();
-        let params = serde_multipart::to_form(payload);
+        let params = serde_multipart::to_form(payload, client.clone());
 
         // async move to capture client&token&api_url&params
         async move {
@@ -256,6 +458,7 @@ impl Bot {
                 P::NAME,
                 params,
                 timeout_hint,
+                &request_logging,
             )
             .await
         }
@@ -272,9 +475,10 @@ impl Bot {
         let client = self.client.clone();
         let token = Arc::clone(&self.token);
         let api_url = self.api_url.clone();
+        let request_logging = self.request_logging.clone();
 
         let timeout_hint = payload.timeout_hint();
-        let params = serde_multipart::to_form_ref(payload);
+        let params = serde_multipart::to_form_ref(payload, client.clone());
 
         // async move to capture client&token&api_url&params
         async move {
@@ -286,12 +490,41 @@ impl Bot {
                 P::NAME,
                 params,
                 timeout_hint,
+                &request_logging,
             )
             .await
         }
     }
 }
 
+/// Parses a token of the form `<bot id>:<secret>`, returning the bot id.
+fn parse_token(token: &str) -> Result<UserId, TokenError> {
+    let (id, secret) = token.split_once(':').ok_or(TokenError::InvalidFormat)?;
+
+    let valid_secret = secret.len() >= 30
+        && secret.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-');
+    if !valid_secret || !id.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(TokenError::InvalidFormat);
+    }
+
+    id.parse().map(UserId).map_err(|_| TokenError::InvalidFormat)
+}
+
 fn get_env(env: &'static str) -> String {
     std::env::var(env).unwrap_or_else(|_| panic!("Cannot get the {env} env variable"))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{types::True, Bot, RequestError};
+
+    #[tokio::test]
+    async fn raw_invalid_params() {
+        // JSON objects can't have non-string keys
+        let params = HashMap::from([((1, 2), 3)]);
+        let res = Bot::new("TOKEN").raw::<True>("method", &params).await;
+        assert!(matches!(res, Err(RequestError::Io(_))));
+    }
+}
@@ -5,8 +5,8 @@ use crate::{
     prelude::Requester,
     requests::{JsonRequest, MultipartRequest},
     types::{
-        BotCommand, ChatId, ChatPermissions, InlineQueryResult, InputFile, InputMedia,
-        InputSticker, LabeledPrice, MessageId, Recipient, ThreadId, UserId,
+        BotCommand, ChatId, ChatPermissions, FileId, InlineQueryResult, InputFile, InputMedia,
+        InputSticker, LabeledPrice, MessageId, Recipient, TargetMessage, ThreadId, UserId,
     },
     Bot,
 };
@@ -157,69 +157,32 @@ impl Requester for Bot {
         )
     }
 
-    type EditMessageLiveLocation = JsonRequest<payloads::EditMessageLiveLocation>;
+    type EditMessageLiveLocationTarget = JsonRequest<payloads::EditMessageLiveLocationTarget>;
 
-    fn edit_message_live_location<C>(
+    fn edit_message_live_location_target<T>(
         &self,
-        chat_id: C,
-        message_id: MessageId,
-        latitude: f64,
-        longitude: f64,
-    ) -> Self::EditMessageLiveLocation
-    where
-        C: Into<Recipient>,
-    {
-        Self::EditMessageLiveLocation::new(
-            self.clone(),
-            payloads::EditMessageLiveLocation::new(chat_id, message_id, latitude, longitude),
-        )
-    }
-
-    type EditMessageLiveLocationInline = JsonRequest<payloads::EditMessageLiveLocationInline>;
-
-    fn edit_message_live_location_inline<I>(
-        &self,
-        inline_message_id: I,
+        target: T,
         latitude: f64,
         longitude: f64,
-    ) -> Self::EditMessageLiveLocationInline
-    where
-        I: Into<String>,
-    {
-        Self::EditMessageLiveLocationInline::new(
-            self.clone(),
-            payloads::EditMessageLiveLocationInline::new(inline_message_id, latitude, longitude),
-        )
-    }
-
-    type StopMessageLiveLocation = JsonRequest<payloads::StopMessageLiveLocation>;
-
-    fn stop_message_live_location<C>(
-        &self,
-        chat_id: C,
-        message_id: MessageId,
-    ) -> Self::StopMessageLiveLocation
+    ) -> Self::EditMessageLiveLocationTarget
     where
-        C: Into<Recipient>,
+        T: Into<TargetMessage>,
     {
-        Self::StopMessageLiveLocation::new(
+        Self::EditMessageLiveLocationTarget::new(
             self.clone(),
-            payloads::StopMessageLiveLocation::new(chat_id, message_id),
+            payloads::EditMessageLiveLocationTarget::new(target, latitude, longitude),
         )
     }
 
-    type StopMessageLiveLocationInline = JsonRequest<payloads::StopMessageLiveLocationInline>;
+    type StopMessageLiveLocationTarget = JsonRequest<payloads::StopMessageLiveLocationTarget>;
 
-    fn stop_message_live_location_inline<I>(
-        &self,
-        inline_message_id: I,
-    ) -> Self::StopMessageLiveLocationInline
+    fn stop_message_live_location_target<T>(&self, target: T) -> Self::StopMessageLiveLocationTarget
     where
-        I: Into<String>,
+        T: Into<TargetMessage>,
     {
-        Self::StopMessageLiveLocationInline::new(
+        Self::StopMessageLiveLocationTarget::new(
             self.clone(),
-            payloads::StopMessageLiveLocationInline::new(inline_message_id),
+            payloads::StopMessageLiveLocationTarget::new(target),
         )
     }
 
@@ -301,7 +264,7 @@ impl Requester for Bot {
 
     fn get_file<F>(&self, file_id: F) -> Self::GetFile
     where
-        F: Into<String>,
+        F: Into<FileId>,
     {
         Self::GetFile::new(self.clone(), payloads::GetFile::new(file_id))
     }
@@ -894,126 +857,56 @@ impl Requester for Bot {
         )
     }
 
-    type EditMessageText = JsonRequest<payloads::EditMessageText>;
-
-    fn edit_message_text<C, T>(
-        &self,
-        chat_id: C,
-        message_id: MessageId,
-        text: T,
-    ) -> Self::EditMessageText
-    where
-        C: Into<Recipient>,
-        T: Into<String>,
-    {
-        Self::EditMessageText::new(
-            self.clone(),
-            payloads::EditMessageText::new(chat_id, message_id, text),
-        )
-    }
-
-    type EditMessageTextInline = JsonRequest<payloads::EditMessageTextInline>;
+    type EditMessageTextTarget = JsonRequest<payloads::EditMessageTextTarget>;
 
-    fn edit_message_text_inline<I, T>(
-        &self,
-        inline_message_id: I,
-        text: T,
-    ) -> Self::EditMessageTextInline
+    fn edit_message_text_target<Ta, T>(&self, target: Ta, text: T) -> Self::EditMessageTextTarget
     where
-        I: Into<String>,
+        Ta: Into<TargetMessage>,
         T: Into<String>,
     {
-        Self::EditMessageTextInline::new(
+        Self::EditMessageTextTarget::new(
             self.clone(),
-            payloads::EditMessageTextInline::new(inline_message_id, text),
+            payloads::EditMessageTextTarget::new(target, text),
         )
     }
 
-    type EditMessageCaption = JsonRequest<payloads::EditMessageCaption>;
+    type EditMessageCaptionTarget = JsonRequest<payloads::EditMessageCaptionTarget>;
 
-    fn edit_message_caption<C>(&self, chat_id: C, message_id: MessageId) -> Self::EditMessageCaption
+    fn edit_message_caption_target<T>(&self, target: T) -> Self::EditMessageCaptionTarget
     where
-        C: Into<Recipient>,
+        T: Into<TargetMessage>,
     {
-        Self::EditMessageCaption::new(
+        Self::EditMessageCaptionTarget::new(
             self.clone(),
-            payloads::EditMessageCaption::new(chat_id, message_id),
+            payloads::EditMessageCaptionTarget::new(target),
         )
     }
 
-    type EditMessageCaptionInline = JsonRequest<payloads::EditMessageCaptionInline>;
+    type EditMessageMediaTarget = MultipartRequest<payloads::EditMessageMediaTarget>;
 
-    fn edit_message_caption_inline<I>(&self, inline_message_id: I) -> Self::EditMessageCaptionInline
-    where
-        I: Into<String>,
-    {
-        Self::EditMessageCaptionInline::new(
-            self.clone(),
-            payloads::EditMessageCaptionInline::new(inline_message_id),
-        )
-    }
-
-    type EditMessageMedia = MultipartRequest<payloads::EditMessageMedia>;
-
-    fn edit_message_media<C>(
+    fn edit_message_media_target<T>(
         &self,
-        chat_id: C,
-        message_id: MessageId,
+        target: T,
         media: InputMedia,
-    ) -> Self::EditMessageMedia
+    ) -> Self::EditMessageMediaTarget
     where
-        C: Into<Recipient>,
+        T: Into<TargetMessage>,
     {
-        Self::EditMessageMedia::new(
+        Self::EditMessageMediaTarget::new(
             self.clone(),
-            payloads::EditMessageMedia::new(chat_id, message_id, media),
+            payloads::EditMessageMediaTarget::new(target, media),
         )
     }
 
-    type EditMessageMediaInline = MultipartRequest<payloads::EditMessageMediaInline>;
+    type EditMessageReplyMarkupTarget = JsonRequest<payloads::EditMessageReplyMarkupTarget>;
 
-    fn edit_message_media_inline<I>(
-        &self,
-        inline_message_id: I,
-        media: InputMedia,
-    ) -> Self::EditMessageMediaInline
+    fn edit_message_reply_markup_target<T>(&self, target: T) -> Self::EditMessageReplyMarkupTarget
     where
-        I: Into<String>,
+        T: Into<TargetMessage>,
     {
-        Self::EditMessageMediaInline::new(
+        Self::EditMessageReplyMarkupTarget::new(
             self.clone(),
-            payloads::EditMessageMediaInline::new(inline_message_id, media),
-        )
-    }
-
-    type EditMessageReplyMarkup = JsonRequest<payloads::EditMessageReplyMarkup>;
-
-    fn edit_message_reply_markup<C>(
-        &self,
-        chat_id: C,
-        message_id: MessageId,
-    ) -> Self::EditMessageReplyMarkup
-    where
-        C: Into<Recipient>,
-    {
-        Self::EditMessageReplyMarkup::new(
-            self.clone(),
-            payloads::EditMessageReplyMarkup::new(chat_id, message_id),
-        )
-    }
-
-    type EditMessageReplyMarkupInline = JsonRequest<payloads::EditMessageReplyMarkupInline>;
-
-    fn edit_message_reply_markup_inline<I>(
-        &self,
-        inline_message_id: I,
-    ) -> Self::EditMessageReplyMarkupInline
-    where
-        I: Into<String>,
-    {
-        Self::EditMessageReplyMarkupInline::new(
-            self.clone(),
-            payloads::EditMessageReplyMarkupInline::new(inline_message_id),
+            payloads::EditMessageReplyMarkupTarget::new(target),
         )
     }
 
@@ -1264,42 +1157,28 @@ impl Requester for Bot {
 
     type SendGame = JsonRequest<payloads::SendGame>;
 
-    fn send_game<G>(&self, chat_id: u32, game_short_name: G) -> Self::SendGame
+    fn send_game<C, G>(&self, chat_id: C, game_short_name: G) -> Self::SendGame
     where
+        C: Into<ChatId>,
         G: Into<String>,
     {
         Self::SendGame::new(self.clone(), payloads::SendGame::new(chat_id, game_short_name))
     }
 
-    type SetGameScore = JsonRequest<payloads::SetGameScore>;
+    type SetGameScoreTarget = JsonRequest<payloads::SetGameScoreTarget>;
 
-    fn set_game_score(
+    fn set_game_score_target<T>(
         &self,
         user_id: UserId,
         score: u64,
-        chat_id: u32,
-        message_id: MessageId,
-    ) -> Self::SetGameScore {
-        Self::SetGameScore::new(
-            self.clone(),
-            payloads::SetGameScore::new(user_id, score, chat_id, message_id),
-        )
-    }
-
-    type SetGameScoreInline = JsonRequest<payloads::SetGameScoreInline>;
-
-    fn set_game_score_inline<I>(
-        &self,
-        user_id: UserId,
-        score: u64,
-        inline_message_id: I,
-    ) -> Self::SetGameScoreInline
+        target: T,
+    ) -> Self::SetGameScoreTarget
     where
-        I: Into<String>,
+        T: Into<TargetMessage>,
     {
-        Self::SetGameScoreInline::new(
+        Self::SetGameScoreTarget::new(
             self.clone(),
-            payloads::SetGameScoreInline::new(user_id, score, inline_message_id),
+            payloads::SetGameScoreTarget::new(user_id, score, target),
         )
     }
 
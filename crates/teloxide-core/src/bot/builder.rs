@@ -0,0 +1,234 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use reqwest::{dns::Resolve, ClientBuilder};
+
+use crate::{net, Bot};
+
+/// A builder of [`Bot`]s which allows to tune the underlying HTTP client.
+///
+/// The builder starts with the [default settings] of the client, which can be
+/// further adjusted with the builder methods. Long-polling bots, in particular,
+/// may benefit from keeping more connections alive and warming them up in
+/// advance, since establishing a new connection noticeably adds to the latency
+/// of a request.
+///
+/// ## Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use teloxide_core::Bot;
+///
+/// # #[tokio::main] async fn main() {
+/// let bot = Bot::builder("TOKEN")
+///     .pool_max_idle_per_host(4)
+///     .pool_idle_timeout(Duration::from_secs(300))
+///     .tcp_keepalive(Duration::from_secs(60))
+///     .warm_up(true)
+///     .build();
+/// # }
+/// ```
+///
+/// [default settings]: crate::net::default_reqwest_settings
+#[must_use]
+#[derive(Debug)]
+pub struct BotBuilder {
+    token: String,
+    client: ClientBuilder,
+    api_url: Option<reqwest::Url>,
+    resolve_to: Vec<IpAddr>,
+    warm_up: bool,
+}
+
+impl BotBuilder {
+    pub(super) fn new(token: String) -> Self {
+        Self {
+            token,
+            client: net::default_reqwest_settings(),
+            api_url: None,
+            resolve_to: Vec::new(),
+            warm_up: false,
+        }
+    }
+
+    /// Sets the maximum number of idle connections kept alive per host.
+    ///
+    /// There is no limit by default.
+    pub fn pool_max_idle_per_host(self, max: usize) -> Self {
+        self.map_client(|c| c.pool_max_idle_per_host(max))
+    }
+
+    /// Sets how long idle connections are kept alive.
+    ///
+    /// `None` keeps them alive forever. The default is 90 seconds.
+    pub fn pool_idle_timeout(self, timeout: impl Into<Option<Duration>>) -> Self {
+        let timeout = timeout.into();
+        self.map_client(|c| c.pool_idle_timeout(timeout))
+    }
+
+    /// Sets the interval of TCP keepalive probes.
+    ///
+    /// `None` disables TCP keepalive, which is the default.
+    pub fn tcp_keepalive(self, interval: impl Into<Option<Duration>>) -> Self {
+        let interval = interval.into();
+        self.map_client(|c| c.tcp_keepalive(interval))
+    }
+
+    /// Enables or disables the adaptive flow control of HTTP/2.
+    ///
+    /// Disabled by default.
+    pub fn http2_adaptive_window(self, enabled: bool) -> Self {
+        self.map_client(|c| c.http2_adaptive_window(enabled))
+    }
+
+    /// Sets the interval of HTTP/2 keep-alive pings.
+    ///
+    /// `None` disables the pings, which is the default.
+    pub fn http2_keep_alive_interval(self, interval: impl Into<Option<Duration>>) -> Self {
+        let interval = interval.into();
+        self.map_client(|c| c.http2_keep_alive_interval(interval))
+    }
+
+    /// Sets a custom API URL, see [`Bot::set_api_url`].
+    pub fn api_url(self, url: reqwest::Url) -> Self {
+        Self { api_url: Some(url), ..self }
+    }
+
+    /// Makes the bot connect to the API server using the given IP addresses,
+    /// instead of resolving the host of the API URL with DNS.
+    ///
+    /// This is useful when some of the addresses returned by DNS are
+    /// unreachable. If connecting to an address fails, the next one is tried,
+    /// with the [connection timeout] divided evenly between the addresses.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::net::{IpAddr, Ipv4Addr};
+    ///
+    /// use teloxide_core::Bot;
+    ///
+    /// let bot = Bot::builder("TOKEN")
+    ///     .resolve_to([
+    ///         IpAddr::V4(Ipv4Addr::new(149, 154, 167, 220)),
+    ///         IpAddr::V4(Ipv4Addr::new(149, 154, 167, 221)),
+    ///     ])
+    ///     .build();
+    /// ```
+    ///
+    /// [connection timeout]: crate::net::default_reqwest_settings
+    pub fn resolve_to(self, addrs: impl IntoIterator<Item = IpAddr>) -> Self {
+        Self { resolve_to: addrs.into_iter().collect(), ..self }
+    }
+
+    /// Sets a custom DNS resolver.
+    ///
+    /// The resolver is not used for the host set with
+    /// [`resolve_to`](BotBuilder::resolve_to).
+    pub fn dns_resolver<R>(self, resolver: Arc<R>) -> Self
+    where
+        R: Resolve + 'static,
+    {
+        self.map_client(|c| c.dns_resolver(resolver))
+    }
+
+    /// Makes [`build`] open a connection to the API server in advance, so that
+    /// the first request doesn't have to wait for it.
+    ///
+    /// The connection is opened in the background with a `HEAD` request to
+    /// the API URL. If [`build`] is called outside of a tokio runtime, this
+    /// setting is ignored.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`build`]: BotBuilder::build
+    pub fn warm_up(self, enabled: bool) -> Self {
+        Self { warm_up: enabled, ..self }
+    }
+
+    /// Builds the bot.
+    ///
+    /// # Panics
+    ///
+    /// If it cannot create [`reqwest::Client`].
+    pub fn build(self) -> Bot {
+        let api_url = match self.api_url {
+            Some(url) => url,
+            None => reqwest::Url::parse(net::TELEGRAM_API_URL)
+                .expect("Failed to parse default Telegram bot API url"),
+        };
+
+        let mut client = self.client;
+        match api_url.host_str() {
+            Some(host) if !self.resolve_to.is_empty() => {
+                // Ports of the overrides are ignored, the port of the URL is used instead
+                let addrs: Vec<_> =
+                    self.resolve_to.iter().map(|&ip| SocketAddr::new(ip, 0)).collect();
+                client = client.resolve_to_addrs(host, &addrs);
+            }
+            _ => {}
+        }
+
+        let client = client.build().expect("Client creation failed");
+        let mut bot = Bot::with_client(self.token, client);
+        bot.api_url = Arc::new(api_url);
+
+        if self.warm_up {
+            match tokio::runtime::Handle::try_current() {
+                Ok(runtime) => {
+                    runtime.spawn(warm_up(bot.client.clone(), bot.api_url()));
+                }
+                Err(_) => log::warn!("Can't warm up the connection outside of a tokio runtime"),
+            }
+        }
+
+        bot
+    }
+
+    fn map_client(self, f: impl FnOnce(ClientBuilder) -> ClientBuilder) -> Self {
+        Self { client: f(self.client), ..self }
+    }
+}
+
+async fn warm_up(client: reqwest::Client, api_url: reqwest::Url) {
+    match client.head(api_url).send().await {
+        Ok(_) => log::debug!("Warmed up the connection to the API server"),
+        Err(err) => log::warn!("Couldn't warm up the connection to the API server: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn warms_up() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap()).parse().unwrap();
+
+        let bot = Bot::builder("TOKEN").api_url(url).warm_up(true).build();
+        assert_eq!(bot.token(), "TOKEN");
+
+        tokio::time::timeout(Duration::from_secs(5), listener.accept()).await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn resolves_to_pinned_addrs() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let url = format!("http://api.telegram.invalid:{port}").parse().unwrap();
+
+        // Nothing listens on the first address, so the bot should fall back to the
+        // second one
+        let addrs = ["::1".parse().unwrap(), "127.0.0.1".parse().unwrap()];
+        let _bot = Bot::builder("TOKEN").api_url(url).resolve_to(addrs).warm_up(true).build();
+
+        tokio::time::timeout(Duration::from_secs(5), listener.accept()).await.unwrap().unwrap();
+    }
+}
@@ -1,10 +1,14 @@
 use bytes::Bytes;
-use futures::{future::BoxFuture, stream::BoxStream, FutureExt, StreamExt};
+use futures::{
+    future::BoxFuture, stream::BoxStream, FutureExt, StreamExt, TryFutureExt, TryStreamExt,
+};
 use tokio::io::AsyncWrite;
+use tokio_util::io::ReaderStream;
 
 use crate::{
     bot::Bot,
     net::{self, Download},
+    types::is_local_path,
     DownloadError,
 };
 
@@ -20,6 +24,16 @@ impl Download for Bot {
         path: &str,
         destination: &'dst mut (dyn AsyncWrite + Unpin + Send),
     ) -> Self::Fut<'dst> {
+        if let Some(path) = self.local_file_path(path) {
+            let path = path.to_owned();
+            return async move {
+                let mut file = tokio::fs::File::open(path).await?;
+                tokio::io::copy(&mut file, destination).await?;
+                Ok(())
+            }
+            .boxed();
+        }
+
         net::download_file(
             &self.client,
             reqwest::Url::clone(&*self.api_url),
@@ -30,18 +44,79 @@ impl Download for Bot {
         .boxed()
     }
 
-    type StreamErr = reqwest::Error;
+    type StreamErr = DownloadError;
 
     type Stream = BoxStream<'static, Result<Bytes, Self::StreamErr>>;
 
     fn download_file_stream(&self, path: &str) -> Self::Stream {
+        if let Some(path) = self.local_file_path(path) {
+            return tokio::fs::File::open(path.to_owned())
+                .map_ok(ReaderStream::new)
+                .try_flatten_stream()
+                .map_err(DownloadError::Io)
+                .boxed();
+        }
+
         net::download_file_stream(
             &self.client,
             reqwest::Url::clone(&*self.api_url),
             &self.token,
             path,
         )
-        .map(|res| res.map_err(crate::errors::hide_token))
+        .map_err(DownloadError::from)
         .boxed()
     }
 }
+
+impl Bot {
+    /// Returns `path` if it should be read from the file system, i.e. if it's
+    /// [local] and the bot uses a local Bot API server.
+    ///
+    /// The official server never returns local paths, so paths which only look
+    /// local are downloaded from it as usual.
+    ///
+    /// [local]: crate::types::File::is_local
+    fn local_file_path<'a>(&self, path: &'a str) -> Option<&'a str> {
+        let official_server = self.api_url.as_str().trim_end_matches('/') == net::TELEGRAM_API_URL;
+
+        (!official_server && is_local_path(path)).then_some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use crate::{net::Download, Bot};
+
+    #[tokio::test]
+    async fn download_local_file() {
+        let path = std::env::temp_dir().join("teloxide_core_download_local_file.txt");
+        tokio::fs::write(&path, "local file").await.unwrap();
+        let path = path.to_str().unwrap();
+
+        let bot = Bot::new("TOKEN").set_api_url("http://localhost:8081".parse().unwrap());
+
+        let mut dst = Vec::new();
+        bot.download_file(path, &mut dst).await.unwrap();
+        assert_eq!(dst, b"local file");
+
+        let chunks: Vec<_> = bot.download_file_stream(path).try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), b"local file");
+
+        tokio::fs::remove_file(path).await.unwrap();
+    }
+
+    #[test]
+    fn official_server_never_reads_files() {
+        let path = std::env::temp_dir().join("teloxide_core_official_server.txt");
+        let path = path.to_str().unwrap();
+
+        let official = Bot::new("TOKEN");
+        assert_eq!(official.local_file_path(path), None);
+
+        let local = official.set_api_url("http://localhost:8081".parse().unwrap());
+        assert_eq!(local.local_file_path(path), Some(path));
+        assert_eq!(local.local_file_path("photos/file_0.jpg"), None);
+    }
+}
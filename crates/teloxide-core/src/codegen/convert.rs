@@ -21,9 +21,11 @@ pub fn convert_for(ty: &Type) -> Convert {
         Type::Option(inner) => convert_for(inner),
         Type::ArrayOf(ty) => Convert::Collect((**ty).clone()),
         Type::RawTy(s) => match s.as_str() {
-            raw @ "Recipient" | raw @ "ChatId" | raw @ "TargetMessage" | raw @ "ReplyMarkup" => {
-                Convert::Into(Type::RawTy(raw.to_owned()))
-            }
+            raw @ "Recipient"
+            | raw @ "ChatId"
+            | raw @ "TargetMessage"
+            | raw @ "ReplyMarkup"
+            | raw @ "FileId" => Convert::Into(Type::RawTy(raw.to_owned())),
             raw => Convert::Id(Type::RawTy(raw.to_owned())),
         },
         ty @ Type::Url => Convert::Id(ty.clone()),
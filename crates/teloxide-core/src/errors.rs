@@ -60,6 +60,20 @@ pub enum DownloadError {
     Io(#[from] std::io::Error),
 }
 
+/// An error caused by an invalid bot token.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum TokenError {
+    /// The environmental variable with a token doesn't exist or isn't valid
+    /// unicode.
+    #[error("The `{0}` environmental variable is not set")]
+    MissingEnv(&'static str),
+
+    /// The token doesn't look like
+    /// `123456789:ABC-DEF1234ghIkl-zyx57W2v1u123ew11`.
+    #[error("The token has an invalid format")]
+    InvalidFormat,
+}
+
 pub trait AsResponseParameters {
     fn response_parameters(&self) -> Option<ResponseParameters>;
 
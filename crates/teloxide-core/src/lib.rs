@@ -36,23 +36,40 @@
 //!
 //! ## Cargo features
 //!
-//! - `native-tls` = use [`native-tls`] tls implementation (**enabled by
-//!   default**)
-//! - `rustls` — use [`rustls`] tls implementation
+//! - `rustls-tls` — use [`rustls`] tls implementation (**enabled by
+//!   default**), e.g. for static (musl) builds
+//! - `native-tls` — use [`native-tls`] tls implementation, e.g. to use the
+//!   system CA store. It's used instead of `rustls` if both are enabled
+//! - `rustls` — an alias of `rustls-tls`
+//! - `types` — enables the [`types`] module, use `default-features = false,
+//!   features = ["types"]` to depend only on the types (e.g. in a webhook
+//!   receiver), without `reqwest` and `tokio`
+//! - `network` — enables [`Bot`], [`payloads`], [`requests`] and everything
+//!   else needed to send requests (**enabled by default**, via the tls
+//!   features)
 //! - `trace_adaptor` — enables [`Trace`] bot adaptor
 //! - `erased` — enables [`ErasedRequester`] bot adaptor
 //! - `throttle` — enables [`Throttle`] bot adaptor
 //! - `cache_me` — enables [`CacheMe`] bot adaptor
-//! - `full` — enables all features except `nightly` and tls-related
+//! - `file_id_cache` — enables [`FileIdCache`] bot adaptor
+//! - `mock_bot` — enables [`MockBot`] bot adaptor for testing
+//! - `test_fixtures` — enables constructors of types for testing, such as
+//!   [`Message::text_fixture`]
+//! - `passport_decrypt` — enables [`passport`] module for decrypting Telegram
+//!   Passport data
+//! - `full` — enables all features except `nightly`, tls-related, `mock_bot`
+//!   and `test_fixtures`
 //! - `nightly` — enables nightly-only features, currently:
 //!   - Removes some future boxing using `#![feature(type_alias_impl_trait)]`
 //!   - Used to built docs (`#![feature(doc_cfg, doc_notable_trait)]`)
 //!
-//! [`AutoSend`]: adaptors::AutoSend
 //! [`Trace`]: adaptors::Trace
 //! [`ErasedRequester`]: adaptors::ErasedRequester
 //! [`Throttle`]: adaptors::Throttle
 //! [`CacheMe`]: adaptors::CacheMe
+//! [`FileIdCache`]: adaptors::FileIdCache
+//! [`MockBot`]: adaptors::MockBot
+//! [`Message::text_fixture`]: types::Message::text_fixture
 //! [`native-tls`]: https://docs.rs/native-tls
 //! [`rustls`]: https://docs.rs/rustls
 
@@ -108,28 +125,42 @@
 )]
 
 // The internal helper macros.
+#[cfg(feature = "network")]
 #[macro_use]
 mod local_macros;
 
+#[cfg(feature = "network")]
 pub use self::{
-    bot::Bot,
-    errors::{ApiError, DownloadError, RequestError},
+    bot::{Bot, BotBuilder},
+    errors::{ApiError, DownloadError, RequestError, TokenError},
 };
 
+#[cfg(feature = "network")]
 pub mod adaptors;
+#[cfg(feature = "network")]
 pub mod errors;
+#[cfg(feature = "network")]
 pub mod net;
+#[cfg(feature = "network")]
 pub mod payloads;
+#[cfg(feature = "passport_decrypt")]
+pub mod passport;
+#[cfg(feature = "network")]
 pub mod prelude;
+#[cfg(feature = "network")]
 pub mod requests;
+#[cfg(feature = "types")]
 pub mod types;
 
 // reexported
+#[cfg(feature = "network")]
 mod bot;
 
 // implementation details
+#[cfg(feature = "network")]
 mod serde_multipart;
+#[cfg(feature = "types")]
 mod util;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "network"))]
 mod codegen;
@@ -95,6 +95,9 @@ macro_rules! impl_payload {
         $(
             @[timeout_secs = $timeout_secs:ident]
         )?
+        $(
+            @[deprecated_setter $alias:ident = $aliased:ident : $AliasTy:ty, since = $since:literal, note = $note:literal]
+        )*
         $(
             #[ $($method_meta:tt)* ]
         )*
@@ -202,6 +205,22 @@ macro_rules! impl_payload {
                         impl_payload! { @setter_opt $Method $opt_fields : $OptFTy $([$opt_conv])? }
                     )*
                 )?
+                $(
+                    calculated_doc! {
+                        #[doc = concat!(
+                            "Deprecated alias of [`",
+                            stringify!($aliased),
+                            "`](Self::",
+                            stringify!($aliased),
+                            ")."
+                        )]
+                        #[deprecated(since = $since, note = $note)]
+                        #[must_use = "Payloads and requests do nothing unless sent"]
+                        fn $alias(self, value: $AliasTy) -> Self {
+                            self.$aliased(value)
+                        }
+                    }
+                )*
             }
         }
 
@@ -569,36 +588,20 @@ macro_rules! requester_forward {
             $body!(send_location this (chat_id: C, latitude: f64, longitude: f64))
         }
     };
-    (@method edit_message_live_location $body:ident $ty:ident) => {
-        type EditMessageLiveLocation = $ty![EditMessageLiveLocation];
-
-        fn edit_message_live_location<C>(&self, chat_id: C, message_id: MessageId, latitude: f64, longitude: f64) -> Self::EditMessageLiveLocation where C: Into<Recipient> {
-            let this = self;
-            $body!(edit_message_live_location this (chat_id: C, message_id: MessageId, latitude: f64, longitude: f64))
-        }
-    };
-    (@method edit_message_live_location_inline $body:ident $ty:ident) => {
-        type EditMessageLiveLocationInline = $ty![EditMessageLiveLocationInline];
+    (@method edit_message_live_location_target $body:ident $ty:ident) => {
+        type EditMessageLiveLocationTarget = $ty![EditMessageLiveLocationTarget];
 
-        fn edit_message_live_location_inline<I>(&self, inline_message_id: I, latitude: f64, longitude: f64) -> Self::EditMessageLiveLocationInline where I: Into<String> {
+        fn edit_message_live_location_target<T>(&self, target: T, latitude: f64, longitude: f64) -> Self::EditMessageLiveLocationTarget where T: Into<TargetMessage> {
             let this = self;
-            $body!(edit_message_live_location_inline this (inline_message_id: I, latitude: f64, longitude: f64))
+            $body!(edit_message_live_location_target this (target: T, latitude: f64, longitude: f64))
         }
     };
-    (@method stop_message_live_location $body:ident $ty:ident) => {
-        type StopMessageLiveLocation = $ty![StopMessageLiveLocation];
+    (@method stop_message_live_location_target $body:ident $ty:ident) => {
+        type StopMessageLiveLocationTarget = $ty![StopMessageLiveLocationTarget];
 
-        fn stop_message_live_location<C>(&self, chat_id: C, message_id: MessageId) -> Self::StopMessageLiveLocation where C: Into<Recipient> {
+        fn stop_message_live_location_target<T>(&self, target: T) -> Self::StopMessageLiveLocationTarget where T: Into<TargetMessage> {
             let this = self;
-            $body!(stop_message_live_location this (chat_id: C, message_id: MessageId))
-        }
-    };
-    (@method stop_message_live_location_inline $body:ident $ty:ident) => {
-        type StopMessageLiveLocationInline = $ty![StopMessageLiveLocationInline];
-
-        fn stop_message_live_location_inline<I>(&self, inline_message_id: I) -> Self::StopMessageLiveLocationInline where I: Into<String> {
-            let this = self;
-            $body!(stop_message_live_location_inline this (inline_message_id: I))
+            $body!(stop_message_live_location_target this (target: T))
         }
     };
     (@method send_venue $body:ident $ty:ident) => {
@@ -658,7 +661,7 @@ macro_rules! requester_forward {
     (@method get_file $body:ident $ty:ident) => {
         type GetFile = $ty![GetFile];
 
-        fn get_file<F>(&self, file_id: F) -> Self::GetFile where F: Into<String> {
+        fn get_file<F>(&self, file_id: F) -> Self::GetFile where F: Into<FileId> {
             let this = self;
             $body!(get_file this (file_id: F))
         }
@@ -1090,70 +1093,37 @@ macro_rules! requester_forward {
             $body!(answer_web_app_query this (web_app_query_id: W, result: InlineQueryResult))
         }
     };
-    (@method edit_message_text $body:ident $ty:ident) => {
-        type EditMessageText = $ty![EditMessageText];
-
-        fn edit_message_text<C, T>(&self, chat_id: C, message_id: MessageId, text: T) -> Self::EditMessageText where C: Into<Recipient>,
-        T: Into<String> {
-            let this = self;
-            $body!(edit_message_text this (chat_id: C, message_id: MessageId, text: T))
-        }
-    };
-    (@method edit_message_text_inline $body:ident $ty:ident) => {
-        type EditMessageTextInline = $ty![EditMessageTextInline];
+    (@method edit_message_text_target $body:ident $ty:ident) => {
+        type EditMessageTextTarget = $ty![EditMessageTextTarget];
 
-        fn edit_message_text_inline<I, T>(&self, inline_message_id: I, text: T) -> Self::EditMessageTextInline where I: Into<String>,
+        fn edit_message_text_target<Ta, T>(&self, target: Ta, text: T) -> Self::EditMessageTextTarget where Ta: Into<TargetMessage>,
         T: Into<String> {
             let this = self;
-            $body!(edit_message_text_inline this (inline_message_id: I, text: T))
-        }
-    };
-    (@method edit_message_caption $body:ident $ty:ident) => {
-        type EditMessageCaption = $ty![EditMessageCaption];
-
-        fn edit_message_caption<C>(&self, chat_id: C, message_id: MessageId) -> Self::EditMessageCaption where C: Into<Recipient> {
-            let this = self;
-            $body!(edit_message_caption this (chat_id: C, message_id: MessageId))
-        }
-    };
-    (@method edit_message_caption_inline $body:ident $ty:ident) => {
-        type EditMessageCaptionInline = $ty![EditMessageCaptionInline];
-
-        fn edit_message_caption_inline<I>(&self, inline_message_id: I) -> Self::EditMessageCaptionInline where I: Into<String> {
-            let this = self;
-            $body!(edit_message_caption_inline this (inline_message_id: I))
+            $body!(edit_message_text_target this (target: Ta, text: T))
         }
     };
-    (@method edit_message_media $body:ident $ty:ident) => {
-        type EditMessageMedia = $ty![EditMessageMedia];
+    (@method edit_message_caption_target $body:ident $ty:ident) => {
+        type EditMessageCaptionTarget = $ty![EditMessageCaptionTarget];
 
-        fn edit_message_media<C>(&self, chat_id: C, message_id: MessageId, media: InputMedia) -> Self::EditMessageMedia where C: Into<Recipient> {
+        fn edit_message_caption_target<T>(&self, target: T) -> Self::EditMessageCaptionTarget where T: Into<TargetMessage> {
             let this = self;
-            $body!(edit_message_media this (chat_id: C, message_id: MessageId, media: InputMedia))
+            $body!(edit_message_caption_target this (target: T))
         }
     };
-    (@method edit_message_media_inline $body:ident $ty:ident) => {
-        type EditMessageMediaInline = $ty![EditMessageMediaInline];
+    (@method edit_message_media_target $body:ident $ty:ident) => {
+        type EditMessageMediaTarget = $ty![EditMessageMediaTarget];
 
-        fn edit_message_media_inline<I>(&self, inline_message_id: I, media: InputMedia) -> Self::EditMessageMediaInline where I: Into<String> {
+        fn edit_message_media_target<T>(&self, target: T, media: InputMedia) -> Self::EditMessageMediaTarget where T: Into<TargetMessage> {
             let this = self;
-            $body!(edit_message_media_inline this (inline_message_id: I, media: InputMedia))
+            $body!(edit_message_media_target this (target: T, media: InputMedia))
         }
     };
-    (@method edit_message_reply_markup $body:ident $ty:ident) => {
-        type EditMessageReplyMarkup = $ty![EditMessageReplyMarkup];
+    (@method edit_message_reply_markup_target $body:ident $ty:ident) => {
+        type EditMessageReplyMarkupTarget = $ty![EditMessageReplyMarkupTarget];
 
-        fn edit_message_reply_markup<C>(&self, chat_id: C, message_id: MessageId) -> Self::EditMessageReplyMarkup where C: Into<Recipient> {
+        fn edit_message_reply_markup_target<T>(&self, target: T) -> Self::EditMessageReplyMarkupTarget where T: Into<TargetMessage> {
             let this = self;
-            $body!(edit_message_reply_markup this (chat_id: C, message_id: MessageId))
-        }
-    };
-    (@method edit_message_reply_markup_inline $body:ident $ty:ident) => {
-        type EditMessageReplyMarkupInline = $ty![EditMessageReplyMarkupInline];
-
-        fn edit_message_reply_markup_inline<I>(&self, inline_message_id: I) -> Self::EditMessageReplyMarkupInline where I: Into<String> {
-            let this = self;
-            $body!(edit_message_reply_markup_inline this (inline_message_id: I))
+            $body!(edit_message_reply_markup_target this (target: T))
         }
     };
     (@method stop_poll $body:ident $ty:ident) => {
@@ -1301,25 +1271,18 @@ macro_rules! requester_forward {
     (@method send_game $body:ident $ty:ident) => {
         type SendGame = $ty![SendGame];
 
-        fn send_game<G>(&self, chat_id: u32, game_short_name: G) -> Self::SendGame where G: Into<String> {
-            let this = self;
-            $body!(send_game this (chat_id: u32, game_short_name: G))
-        }
-    };
-    (@method set_game_score $body:ident $ty:ident) => {
-        type SetGameScore = $ty![SetGameScore];
-
-        fn set_game_score(&self, user_id: UserId, score: u64, chat_id: u32, message_id: MessageId) -> Self::SetGameScore {
+        fn send_game<C, G>(&self, chat_id: C, game_short_name: G) -> Self::SendGame where C: Into<ChatId>,
+        G: Into<String> {
             let this = self;
-            $body!(set_game_score this (user_id: UserId, score: u64, chat_id: u32, message_id: MessageId))
+            $body!(send_game this (chat_id: C, game_short_name: G))
         }
     };
-    (@method set_game_score_inline $body:ident $ty:ident) => {
-        type SetGameScoreInline = $ty![SetGameScoreInline];
+    (@method set_game_score_target $body:ident $ty:ident) => {
+        type SetGameScoreTarget = $ty![SetGameScoreTarget];
 
-        fn set_game_score_inline<I>(&self, user_id: UserId, score: u64, inline_message_id: I) -> Self::SetGameScoreInline where I: Into<String> {
+        fn set_game_score_target<T>(&self, user_id: UserId, score: u64, target: T) -> Self::SetGameScoreTarget where T: Into<TargetMessage> {
             let this = self;
-            $body!(set_game_score_inline this (user_id: UserId, score: u64, inline_message_id: I))
+            $body!(set_game_score_target this (user_id: UserId, score: u64, target: T))
         }
     };
     (@method get_game_high_scores $body:ident $ty:ident) => {
@@ -1352,6 +1315,8 @@ fn codegen_requester_forward() {
     let contents = schema
         .methods
         .iter()
+        // Methods with a sibling are provided by `Requester` itself
+        .filter(|m| m.sibling.is_none())
         .map(|m| {
             let mut convert_params = m
                 .params
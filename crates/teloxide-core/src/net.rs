@@ -2,16 +2,26 @@
 
 use std::time::Duration;
 
-pub use self::download::{download_file, download_file_stream, Download};
+pub use self::{
+    download::{download_file, download_file_stream, Download},
+    logging::{LogSink, RequestLogging},
+    meta::{ResponseMeta, SendWithMeta},
+    transform::{DownloadStream, MediaTransform},
+};
 
+#[cfg(feature = "throttle")]
+pub(crate) use self::meta::record_retry;
 pub(crate) use self::{
     request::{request_json, request_multipart},
     telegram_response::TelegramResponse,
 };
 
 mod download;
+mod logging;
+mod meta;
 mod request;
 mod telegram_response;
+mod transform;
 
 /// The default Telegram API URL.
 pub const TELEGRAM_API_URL: &str = "https://api.telegram.org";
@@ -59,6 +69,8 @@ pub fn client_from_env() -> reqwest::Client {
 ///  - A connection timeout of 5 seconds.
 ///  - A timeout of 17 seconds.
 ///  - `tcp_nodelay` is on.
+///  - The TLS implementation selected by the `native-tls` or `rustls-tls`
+///    feature is used.
 ///
 /// ## Notes
 ///
@@ -70,10 +82,27 @@ pub fn client_from_env() -> reqwest::Client {
 ///
 /// [issue 223]: https://github.com/teloxide/teloxide/issues/223
 pub fn default_reqwest_settings() -> reqwest::ClientBuilder {
-    reqwest::Client::builder()
+    let builder = reqwest::Client::builder()
         .connect_timeout(Duration::from_secs(5))
         .timeout(Duration::from_secs(17))
-        .tcp_nodelay(true)
+        .tcp_nodelay(true);
+
+    with_tls_backend(builder)
+}
+
+/// Selects the TLS implementation enabled by the `native-tls` or `rustls-tls`
+/// feature.
+///
+/// `native-tls` is preferred if both are enabled, since it's usually enabled
+/// explicitly (e.g. to use the system CA store) while `rustls-tls` is enabled
+/// by default.
+pub(crate) fn with_tls_backend(builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+    #[cfg(feature = "native-tls")]
+    let builder = builder.use_native_tls();
+    #[cfg(all(feature = "rustls-tls", not(feature = "native-tls")))]
+    let builder = builder.use_rustls_tls();
+
+    builder
 }
 
 /// Creates URL for making HTTPS requests. See the [Telegram documentation].
@@ -4,12 +4,15 @@ use bytes::Bytes;
 use futures::{
     future::{ready, Either},
     stream::{once, unfold},
-    FutureExt, Stream, StreamExt,
+    FutureExt, Stream, StreamExt, TryStreamExt,
 };
 use reqwest::{Client, Response, Url};
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 
-use crate::{errors::DownloadError, net::file_url};
+use crate::{
+    errors::DownloadError,
+    net::{file_url, DownloadStream, MediaTransform},
+};
 
 /// A trait for downloading files from Telegram.
 pub trait Download {
@@ -26,7 +29,9 @@ pub trait Download {
 
     /// Download a file from Telegram into `destination`.
     ///
-    /// `path` can be obtained from [`GetFile`].
+    /// `path` can be obtained from [`GetFile`]. The implementation for
+    /// [`Bot`] reads [local] paths, returned by local Bot API servers, directly
+    /// from the file system, unless the bot uses the default [API url].
     ///
     /// To download as a stream of chunks, see [`download_file_stream`].
     ///
@@ -52,6 +57,9 @@ pub trait Download {
     ///
     /// [`GetFile`]: crate::payloads::GetFile
     /// [`download_file_stream`]: Self::download_file_stream
+    /// [`Bot`]: crate::Bot
+    /// [local]: crate::types::File::is_local
+    /// [API url]: crate::Bot::api_url
     fn download_file<'dst>(
         &self,
         path: &str,
@@ -79,6 +87,22 @@ pub trait Download {
     /// [`tokio::fs::File`]: tokio::fs::File
     /// [`download_file`]: Self::download_file
     fn download_file_stream(&self, path: &str) -> Self::Stream;
+
+    /// Download a file from Telegram as [`Stream`], post-processed with
+    /// `transform`.
+    ///
+    /// This is the same as [`download_file_stream`], but the stream is passed
+    /// through a [`MediaTransform`], e.g. to transcode a voice message.
+    ///
+    /// [`download_file_stream`]: Self::download_file_stream
+    fn download_file_transformed<T>(&self, path: &str, transform: &T) -> DownloadStream
+    where
+        T: MediaTransform + ?Sized,
+        Self::StreamErr: Into<DownloadError> + 'static,
+        Self::Stream: 'static,
+    {
+        transform.transform(self.download_file_stream(path).map_err(Into::into).boxed())
+    }
 }
 
 /// Download a file from Telegram into `dst`.
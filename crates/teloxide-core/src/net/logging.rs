@@ -0,0 +1,234 @@
+use std::{collections::HashSet, fmt, sync::Arc};
+
+use serde_json::Value;
+
+/// The log target of raw requests and responses.
+const TARGET: &str = "teloxide_core::net::raw";
+
+/// Placeholder for redacted values.
+const REDACTED: &str = "<redacted>";
+
+/// Fields redacted by default, see [`RequestLogging::new`].
+const DEFAULT_REDACTED_FIELDS: &[&str] =
+    &["provider_token", "secret_token", "phone_number", "email", "shipping_address"];
+
+/// Settings of logging raw requests and responses.
+///
+/// When enabled, JSON sent to and received from Telegram is logged at the
+/// `TRACE` level, with the `teloxide_core::net::raw` target. The bot token is
+/// never logged, and values of sensitive fields are replaced with
+/// `<redacted>`, wherever the fields occur in the JSON.
+///
+/// Bodies of multipart requests (e.g. requests uploading files) are not
+/// logged, only their method names are.
+///
+/// Redacted requests and responses can also be passed to a [`LogSink`], e.g.
+/// to save them to a file, see [`RequestLogging::sink`].
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{net::RequestLogging, Bot};
+///
+/// let bot = Bot::new("TOKEN").set_request_logging(RequestLogging::new().redact("first_name"));
+/// let quiet = bot.clone().set_request_logging(RequestLogging::disabled());
+/// ```
+#[derive(Clone)]
+#[must_use]
+pub struct RequestLogging {
+    enabled: bool,
+    redacted_fields: Arc<HashSet<String>>,
+    sink: Option<Arc<dyn LogSink>>,
+}
+
+/// A receiver of raw requests and responses, see [`RequestLogging::sink`].
+///
+/// Sensitive values are redacted the same way as in logs.
+pub trait LogSink: Send + Sync {
+    /// Receives a request to the `method_name` method, `body` is `None` for
+    /// multipart requests.
+    fn request(&self, method_name: &str, body: Option<&str>);
+
+    /// Receives a response to a request to the `method_name` method.
+    fn response(&self, method_name: &str, body: &str);
+}
+
+impl RequestLogging {
+    /// Enables logging, redacting the `provider_token`, `secret_token`,
+    /// `phone_number`, `email` and `shipping_address` fields.
+    pub fn new() -> Self {
+        let redacted_fields =
+            DEFAULT_REDACTED_FIELDS.iter().map(|&field| field.to_owned()).collect();
+        Self { enabled: true, redacted_fields: Arc::new(redacted_fields), sink: None }
+    }
+
+    /// Disables logging.
+    pub fn disabled() -> Self {
+        Self { enabled: false, ..Self::new() }
+    }
+
+    /// Redacts values of fields named `field`, in addition to already redacted
+    /// ones.
+    pub fn redact<S>(mut self, field: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Arc::make_mut(&mut self.redacted_fields).insert(field.into());
+        self
+    }
+
+    /// Doesn't redact values of fields named `field`.
+    pub fn unredact(mut self, field: &str) -> Self {
+        Arc::make_mut(&mut self.redacted_fields).remove(field);
+        self
+    }
+
+    /// Passes requests and responses to `sink`.
+    ///
+    /// Unlike logs, `sink` receives them even if logging is [disabled] or the
+    /// `TRACE` level is not enabled.
+    ///
+    /// [disabled]: RequestLogging::disabled
+    pub fn sink<S>(self, sink: S) -> Self
+    where
+        S: LogSink + 'static,
+    {
+        Self { sink: Some(Arc::new(sink)), ..self }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled && log::log_enabled!(target: TARGET, log::Level::Trace)
+    }
+
+    pub(crate) fn log_json_request(&self, method_name: &str, body: &[u8]) {
+        let log = self.is_enabled();
+        if !log && self.sink.is_none() {
+            return;
+        }
+
+        let body = self.redact_json(body);
+        if log {
+            log::trace!(target: TARGET, "-> {method_name} {body}");
+        }
+        if let Some(sink) = &self.sink {
+            sink.request(method_name, Some(&body));
+        }
+    }
+
+    pub(crate) fn log_multipart_request(&self, method_name: &str) {
+        if self.is_enabled() {
+            log::trace!(target: TARGET, "-> {method_name} <multipart>");
+        }
+        if let Some(sink) = &self.sink {
+            sink.request(method_name, None);
+        }
+    }
+
+    pub(crate) fn log_response(&self, method_name: &str, body: &str) {
+        let log = self.is_enabled();
+        if !log && self.sink.is_none() {
+            return;
+        }
+
+        let body = self.redact_json(body.as_bytes());
+        if log {
+            log::trace!(target: TARGET, "<- {method_name} {body}");
+        }
+        if let Some(sink) = &self.sink {
+            sink.response(method_name, &body);
+        }
+    }
+
+    /// Returns `json` with sensitive values redacted.
+    ///
+    /// Invalid JSON is not logged verbatim, since it can't be redacted.
+    fn redact_json(&self, json: &[u8]) -> String {
+        match serde_json::from_slice(json) {
+            Ok(mut value) => {
+                self.redact_value(&mut value);
+                value.to_string()
+            }
+            Err(_) => format!("<invalid JSON, {} bytes>", json.len()),
+        }
+    }
+
+    fn redact_value(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    if self.redacted_fields.contains(key) {
+                        *value = Value::String(REDACTED.to_owned());
+                    } else {
+                        self.redact_value(value);
+                    }
+                }
+            }
+            Value::Array(values) => values.iter_mut().for_each(|value| self.redact_value(value)),
+            _ => {}
+        }
+    }
+}
+
+impl Default for RequestLogging {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for RequestLogging {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestLogging")
+            .field("enabled", &self.enabled)
+            .field("redacted_fields", &self.redacted_fields)
+            .field("sink", &self.sink.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_nested_fields() {
+        let logging = RequestLogging::new().redact("text").unredact("email");
+        let json = br#"{"ok":true,"result":[{"text":"hi","contact":{"phone_number":"+1","email":"a@b.c"}}]}"#;
+
+        assert_eq!(
+            logging.redact_json(json),
+            r#"{"ok":true,"result":[{"contact":{"email":"a@b.c","phone_number":"<redacted>"},"text":"<redacted>"}]}"#
+        );
+        assert_eq!(logging.redact_json(b"not json"), "<invalid JSON, 8 bytes>");
+    }
+
+    #[test]
+    fn sink() {
+        #[derive(Default)]
+        struct Sink(std::sync::Mutex<Vec<String>>);
+
+        impl LogSink for Arc<Sink> {
+            fn request(&self, method_name: &str, body: Option<&str>) {
+                self.0.lock().unwrap().push(format!("-> {method_name} {body:?}"));
+            }
+
+            fn response(&self, method_name: &str, body: &str) {
+                self.0.lock().unwrap().push(format!("<- {method_name} {body}"));
+            }
+        }
+
+        let sink = Arc::new(Sink::default());
+        let logging = RequestLogging::disabled().sink(Arc::clone(&sink));
+        logging.log_json_request("sendMessage", br#"{"chat_id":1,"email":"a@b.c"}"#);
+        logging.log_multipart_request("sendPhoto");
+        logging.log_response("sendMessage", r#"{"ok":true}"#);
+
+        assert_eq!(
+            *sink.0.lock().unwrap(),
+            [
+                r#"-> sendMessage Some("{\"chat_id\":1,\"email\":\"<redacted>\"}")"#,
+                "-> sendPhoto None",
+                r#"<- sendMessage {"ok":true}"#,
+            ]
+        );
+    }
+}
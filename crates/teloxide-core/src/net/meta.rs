@@ -0,0 +1,130 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{self, Poll},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use pin_project::pin_project;
+use reqwest::{header::DATE, Response, StatusCode};
+
+tokio::task_local! {
+    static COLLECTOR: Arc<Mutex<ResponseMeta>>;
+}
+
+/// Diagnostic information about a sent request.
+///
+/// Returned alongside the result of a request by
+/// [`Request::send_with_meta`].
+///
+/// Fields describe the last HTTP exchange made while sending the request. If
+/// no exchange was made (e.g. because of a network error or because the
+/// request was handled by [`MockBot`]), `status` and `date` are `None` and
+/// `latency` is zero.
+///
+/// [`Request::send_with_meta`]: crate::requests::Request::send_with_meta
+/// [`MockBot`]: crate::adaptors::MockBot
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ResponseMeta {
+    /// HTTP status of the response.
+    pub status: Option<StatusCode>,
+
+    /// Time between sending the request and receiving the response headers.
+    pub latency: Duration,
+
+    /// Number of times the request was retried by adaptors (e.g. by
+    /// [`Throttle`] after a [`RetryAfter`] error).
+    ///
+    /// [`Throttle`]: crate::adaptors::Throttle
+    /// [`RetryAfter`]: crate::RequestError::RetryAfter
+    pub retries: u32,
+
+    /// Value of the `Date` header of the response, i.e. the time of the
+    /// Telegram server.
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// A future returned by [`Request::send_with_meta`].
+///
+/// [`Request::send_with_meta`]: crate::requests::Request::send_with_meta
+#[pin_project]
+#[must_use = "Futures are lazy and do nothing unless polled or awaited"]
+pub struct SendWithMeta<F> {
+    #[pin]
+    inner: F,
+    meta: Arc<Mutex<ResponseMeta>>,
+}
+
+impl<F> SendWithMeta<F> {
+    pub(crate) fn new(inner: F) -> Self {
+        Self { inner, meta: Arc::default() }
+    }
+}
+
+impl<F: Future> Future for SendWithMeta<F> {
+    type Output = (F::Output, ResponseMeta);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let inner = this.inner;
+
+        let output = COLLECTOR.sync_scope(Arc::clone(this.meta), || inner.poll(cx));
+        output.map(|output| (output, this.meta.lock().unwrap().clone()))
+    }
+}
+
+/// Records the status, latency and date of `response`, if the current request
+/// is sent with [`SendWithMeta`].
+pub(crate) fn record_response(response: &Response, latency: Duration) {
+    let date = response
+        .headers()
+        .get(DATE)
+        .and_then(|date| date.to_str().ok())
+        .and_then(|date| DateTime::parse_from_rfc2822(date).ok())
+        .map(|date| date.with_timezone(&Utc));
+
+    with_meta(|meta| {
+        meta.status = Some(response.status());
+        meta.latency = latency;
+        meta.date = date;
+    });
+}
+
+/// Records that the current request is retried, if it's sent with
+/// [`SendWithMeta`].
+#[cfg_attr(not(feature = "throttle"), allow(dead_code))]
+pub(crate) fn record_retry() {
+    with_meta(|meta| meta.retries += 1);
+}
+
+fn with_meta(f: impl FnOnce(&mut ResponseMeta)) {
+    let _ = COLLECTOR.try_with(|meta| f(&mut meta.lock().unwrap()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn collects_retries() {
+        let (output, meta) = SendWithMeta::new(async {
+            record_retry();
+            tokio::task::yield_now().await;
+            record_retry();
+            17
+        })
+        .await;
+
+        assert_eq!(output, 17);
+        assert_eq!(meta.retries, 2);
+        assert_eq!(meta.status, None);
+    }
+
+    #[test]
+    fn outside_of_scope() {
+        record_retry();
+    }
+}
@@ -1,4 +1,7 @@
-use std::{any::TypeId, time::Duration};
+use std::{
+    any::TypeId,
+    time::{Duration, Instant},
+};
 
 use reqwest::{
     header::{HeaderValue, CONTENT_TYPE},
@@ -6,7 +9,11 @@ use reqwest::{
 };
 use serde::de::DeserializeOwned;
 
-use crate::{net::TelegramResponse, requests::ResponseResult, RequestError};
+use crate::{
+    net::{meta, RequestLogging, TelegramResponse},
+    requests::ResponseResult,
+    RequestError,
+};
 
 const DELAY_ON_SERVER_ERROR: Duration = Duration::from_secs(10);
 
@@ -17,6 +24,7 @@ pub async fn request_multipart<T>(
     method_name: &str,
     params: reqwest::multipart::Form,
     _timeout_hint: Option<Duration>,
+    logging: &RequestLogging,
 ) -> ResponseResult<T>
 where
     T: DeserializeOwned + 'static,
@@ -26,13 +34,15 @@ where
     // Telegram has some methods that return either `Message` or `True` depending on
     // the used arguments we model this as `...` and `..._inline` pairs of methods.
     //
-    // Currently inline versions have wrong Payload::NAME (ie with the "Inline"
-    // suffix). This removes the suffix allowing to call the right telegram method.
-    // Note that currently there are no normal telegram methods ending in "Inline",
-    // so this is fine.
+    // Currently inline and target versions have wrong Payload::NAME (ie with the
+    // "Inline" or "Target" suffix). This removes the suffix allowing to call the
+    // right telegram method. Note that currently there are no normal telegram
+    // methods ending in "Inline" or "Target", so this is fine.
     //
     // [#460]: https://github.com/teloxide/teloxide/issues/460
-    let method_name = method_name.trim_end_matches("Inline");
+    let method_name = method_name.trim_end_matches("Inline").trim_end_matches("Target");
+
+    logging.log_multipart_request(method_name);
 
     let request = client
         .post(crate::net::method_url(api_url, token, method_name))
@@ -44,9 +54,11 @@ where
     //     *request.timeout_mut().get_or_insert(Duration::ZERO) += timeout;
     // }
 
+    let started = Instant::now();
     let response = client.execute(request).await?;
+    meta::record_response(&response, started.elapsed());
 
-    process_response(response).await
+    process_response(response, method_name, logging).await
 }
 
 pub async fn request_json<T>(
@@ -56,6 +68,7 @@ pub async fn request_json<T>(
     method_name: &str,
     params: Vec<u8>,
     _timeout_hint: Option<Duration>,
+    logging: &RequestLogging,
 ) -> ResponseResult<T>
 where
     T: DeserializeOwned + 'static,
@@ -65,13 +78,15 @@ where
     // Telegram has some methods that return either `Message` or `True` depending on
     // the used arguments we model this as `...` and `..._inline` pairs of methods.
     //
-    // Currently inline versions have wrong Payload::NAME (ie with the "Inline"
-    // suffix). This removes the suffix allowing to call the right telegram method.
-    // Note that currently there are no normal telegram methods ending in "Inline",
-    // so this is fine.
+    // Currently inline and target versions have wrong Payload::NAME (ie with the
+    // "Inline" or "Target" suffix). This removes the suffix allowing to call the
+    // right telegram method. Note that currently there are no normal telegram
+    // methods ending in "Inline" or "Target", so this is fine.
     //
     // [#460]: https://github.com/teloxide/teloxide/issues/460
-    let method_name = method_name.trim_end_matches("Inline");
+    let method_name = method_name.trim_end_matches("Inline").trim_end_matches("Target");
+
+    logging.log_json_request(method_name, &params);
 
     let request = client
         .post(crate::net::method_url(api_url, token, method_name))
@@ -84,12 +99,18 @@ where
     //     *request.timeout_mut().get_or_insert(Duration::ZERO) += timeout;
     // }
 
+    let started = Instant::now();
     let response = client.execute(request).await?;
+    meta::record_response(&response, started.elapsed());
 
-    process_response(response).await
+    process_response(response, method_name, logging).await
 }
 
-async fn process_response<T>(response: Response) -> ResponseResult<T>
+async fn process_response<T>(
+    response: Response,
+    method_name: &str,
+    logging: &RequestLogging,
+) -> ResponseResult<T>
 where
     T: DeserializeOwned + 'static,
 {
@@ -98,6 +119,7 @@ where
     }
 
     let text = response.text().await?;
+    logging.log_response(method_name, &text);
 
     deserialize_response(text)
 }
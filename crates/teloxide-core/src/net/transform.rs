@@ -0,0 +1,86 @@
+use bytes::Bytes;
+use futures::stream::BoxStream;
+
+use crate::DownloadError;
+
+/// A stream of a downloaded file, see [`MediaTransform`].
+pub type DownloadStream = BoxStream<'static, Result<Bytes, DownloadError>>;
+
+/// A post-processing step for downloaded files, e.g. transcoding of voice
+/// messages for speech recognition.
+///
+/// Transforms are applied to a file as it's being downloaded, with
+/// [`Download::download_file_transformed`]. A transform which can't process
+/// its input should return an [`DownloadError::Io`] error, e.g. with
+/// [`std::io::ErrorKind::InvalidData`].
+///
+/// Any `Fn(DownloadStream) -> DownloadStream` is a transform.
+///
+/// ## Examples
+///
+/// ```
+/// use futures::{FutureExt, StreamExt, TryFutureExt, TryStreamExt};
+/// use teloxide_core::{
+///     net::{Download, DownloadStream, MediaTransform},
+///     Bot,
+/// };
+///
+/// /// Counts the downloaded bytes.
+/// struct Length;
+///
+/// impl MediaTransform for Length {
+///     fn transform(&self, input: DownloadStream) -> DownloadStream {
+///         input
+///             .try_fold(0, |len, chunk| async move { Ok(len + chunk.len()) })
+///             .map_ok(|len| len.to_string().into())
+///             .into_stream()
+///             .boxed()
+///     }
+/// }
+///
+/// # async fn run(bot: Bot, path: &str) -> Result<(), teloxide_core::DownloadError> {
+/// let len: Vec<_> = bot.download_file_transformed(path, &Length).try_collect().await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`Download::download_file_transformed`]: crate::net::Download::download_file_transformed
+pub trait MediaTransform {
+    /// Transforms a stream of a downloaded file.
+    fn transform(&self, input: DownloadStream) -> DownloadStream;
+}
+
+impl<F> MediaTransform for F
+where
+    F: Fn(DownloadStream) -> DownloadStream,
+{
+    fn transform(&self, input: DownloadStream) -> DownloadStream {
+        self(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{StreamExt, TryStreamExt};
+
+    use crate::{
+        net::{Download, DownloadStream},
+        Bot,
+    };
+
+    #[tokio::test]
+    async fn download_file_transformed() {
+        let path = std::env::temp_dir().join("teloxide_core_download_file_transformed.txt");
+        tokio::fs::write(&path, "local file").await.unwrap();
+        let path = path.to_str().unwrap();
+
+        let bot = Bot::new("TOKEN").set_api_url("http://localhost:8081".parse().unwrap());
+        let uppercase =
+            |input: DownloadStream| input.map_ok(|chunk| chunk.to_ascii_uppercase().into()).boxed();
+
+        let chunks: Vec<_> =
+            bot.download_file_transformed(path, &uppercase).try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), b"LOCAL FILE");
+
+        tokio::fs::remove_file(path).await.unwrap();
+    }
+}
@@ -0,0 +1,368 @@
+//! Decryption of [Telegram Passport] data.
+//!
+//! Data shared with a bot via Telegram Passport is encrypted in two layers.
+//! First, [`EncryptedCredentials`] are decrypted with the bot's private RSA key
+//! into [`Credentials`]. Then, the secrets and hashes from [`Credentials`] are
+//! used to decrypt and authenticate the [`data`] of each element and the
+//! [files] that were downloaded from Telegram.
+//!
+//! ## Examples
+//!
+//! ```no_run
+//! use teloxide_core::{
+//!     passport::{Credentials, PersonalDetails, RsaPrivateKey},
+//!     types::{EncryptedPassportElementKind, PassportData},
+//! };
+//!
+//! # fn f(passport_data: PassportData, private_key: RsaPrivateKey) -> Result<(), Box<dyn std::error::Error>> {
+//! let credentials = Credentials::decrypt(&passport_data.credentials, &private_key)?;
+//!
+//! for element in &passport_data.data {
+//!     if let EncryptedPassportElementKind::PersonalDetails(details) = &element.kind {
+//!         let value = credentials.secure_data.personal_details.as_ref().unwrap();
+//!         let details: PersonalDetails = value.data.as_ref().unwrap().decrypt_json(&details.data)?;
+//!         println!("{} {}", details.first_name, details.last_name);
+//!     }
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! [Telegram Passport]: https://core.telegram.org/passport
+//! [`EncryptedCredentials`]: crate::types::EncryptedCredentials
+//! [`data`]: crate::types::EncryptedPassportElementPersonalDetails::data
+//! [files]: crate::types::PassportFile
+
+use aes::{
+    cipher::{BlockDecryptMut, KeyIvInit},
+    Aes256,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rsa::Oaep;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+use thiserror::Error;
+
+use crate::types::EncryptedCredentials;
+
+pub use rsa::RsaPrivateKey;
+
+/// An error that occurred while decrypting Telegram Passport data.
+#[derive(Debug, Error)]
+pub enum PassportDecryptError {
+    /// A value is not valid base64.
+    #[error("A value is not valid base64: {0}")]
+    Base64(#[from] base64::DecodeError),
+
+    /// Failed to decrypt the credentials secret with the private key.
+    #[error("Failed to decrypt the credentials secret: {0}")]
+    Rsa(#[from] rsa::Error),
+
+    /// The encrypted data has an invalid length or padding.
+    #[error("The encrypted data is malformed")]
+    Malformed,
+
+    /// The hash of the decrypted data doesn't match the expected one, i.e. the
+    /// data was tampered with or a wrong secret was used.
+    #[error("The hash of the decrypted data doesn't match")]
+    HashMismatch,
+
+    /// The decrypted data is not a valid JSON of the expected type.
+    #[error("The decrypted data is not a valid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Decrypted [`EncryptedCredentials`], containing secrets required to decrypt
+/// Telegram Passport elements.
+///
+/// [The official docs](https://core.telegram.org/passport#credentials).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Credentials {
+    /// Credentials for encrypted data.
+    pub secure_data: SecureData,
+
+    /// Bot-specified nonce.
+    ///
+    /// **Make sure that the nonce is the same as was passed in the request.**
+    pub nonce: String,
+}
+
+/// Credentials for encrypted Telegram Passport elements.
+///
+/// [The official docs](https://core.telegram.org/passport#securedata).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecureData {
+    pub personal_details: Option<SecureValue>,
+    pub passport: Option<SecureValue>,
+    pub internal_passport: Option<SecureValue>,
+    pub driver_license: Option<SecureValue>,
+    pub identity_card: Option<SecureValue>,
+    pub address: Option<SecureValue>,
+    pub utility_bill: Option<SecureValue>,
+    pub bank_statement: Option<SecureValue>,
+    pub rental_agreement: Option<SecureValue>,
+    pub passport_registration: Option<SecureValue>,
+    pub temporary_registration: Option<SecureValue>,
+}
+
+/// Credentials for a single Telegram Passport element.
+///
+/// [The official docs](https://core.telegram.org/passport#securevalue).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecureValue {
+    /// Credentials for the encrypted element data.
+    pub data: Option<DataCredentials>,
+
+    /// Credentials for the encrypted front side of the document.
+    pub front_side: Option<FileCredentials>,
+
+    /// Credentials for the encrypted reverse side of the document.
+    pub reverse_side: Option<FileCredentials>,
+
+    /// Credentials for the encrypted selfie of the user with the document.
+    pub selfie: Option<FileCredentials>,
+
+    /// Credentials for the encrypted translations of the document, in the
+    /// same order as [`translation`] files of the element.
+    ///
+    /// [`translation`]: crate::types::EncryptedPassportElementPassport::translation
+    pub translation: Option<Vec<FileCredentials>>,
+
+    /// Credentials for the encrypted files, in the same order as `files` of
+    /// the element.
+    pub files: Option<Vec<FileCredentials>>,
+}
+
+/// Credentials required to decrypt the data of a Telegram Passport element.
+///
+/// [The official docs](https://core.telegram.org/passport#datacredentials).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataCredentials {
+    /// Base64-encoded checksum of the encrypted data.
+    pub data_hash: String,
+
+    /// Base64-encoded secret of the encrypted data.
+    pub secret: String,
+}
+
+/// Credentials required to decrypt a file of a Telegram Passport element.
+///
+/// [The official docs](https://core.telegram.org/passport#filecredentials).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileCredentials {
+    /// Base64-encoded checksum of the encrypted file.
+    pub file_hash: String,
+
+    /// Base64-encoded secret of the encrypted file.
+    pub secret: String,
+}
+
+/// Decrypted data of the `personal_details` element.
+///
+/// [The official docs](https://core.telegram.org/passport#personaldetails).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonalDetails {
+    pub first_name: String,
+    pub last_name: String,
+    pub middle_name: Option<String>,
+    /// Date of birth in the `DD.MM.YYYY` format.
+    pub birth_date: String,
+    /// Gender, `male` or `female`.
+    pub gender: String,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country_code: String,
+    /// ISO 3166-1 alpha-2 country code.
+    pub residence_country_code: String,
+    pub first_name_native: Option<String>,
+    pub last_name_native: Option<String>,
+    pub middle_name_native: Option<String>,
+}
+
+/// Decrypted data of the `passport`, `internal_passport`, `driver_license` and
+/// `identity_card` elements.
+///
+/// [The official docs](https://core.telegram.org/passport#iddocumentdata).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdDocumentData {
+    pub document_no: String,
+    /// Date of expiry in the `DD.MM.YYYY` format.
+    pub expiry_date: Option<String>,
+}
+
+/// Decrypted data of the `address` element.
+///
+/// [The official docs](https://core.telegram.org/passport#residentialaddress).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResidentialAddress {
+    pub street_line1: String,
+    pub street_line2: Option<String>,
+    pub city: String,
+    pub state: Option<String>,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country_code: String,
+    pub post_code: String,
+}
+
+impl Credentials {
+    /// Decrypts and authenticates `encrypted` credentials with the bot's
+    /// private key.
+    pub fn decrypt(
+        encrypted: &EncryptedCredentials,
+        private_key: &RsaPrivateKey,
+    ) -> Result<Self, PassportDecryptError> {
+        let secret =
+            private_key.decrypt(Oaep::new::<Sha1>(), &STANDARD.decode(&encrypted.secret)?)?;
+        let hash = STANDARD.decode(&encrypted.hash)?;
+        let data = decrypt(&STANDARD.decode(&encrypted.data)?, &secret, &hash)?;
+
+        Ok(serde_json::from_slice(&data)?)
+    }
+}
+
+impl DataCredentials {
+    /// Decrypts and authenticates base64-encoded `data` of a Telegram Passport
+    /// element.
+    pub fn decrypt(&self, data: &str) -> Result<Vec<u8>, PassportDecryptError> {
+        let secret = STANDARD.decode(&self.secret)?;
+        let hash = STANDARD.decode(&self.data_hash)?;
+        decrypt(&STANDARD.decode(data)?, &secret, &hash)
+    }
+
+    /// Decrypts and authenticates base64-encoded `data` of a Telegram Passport
+    /// element, deserializing it as `T` (e.g. [`PersonalDetails`]).
+    pub fn decrypt_json<T>(&self, data: &str) -> Result<T, PassportDecryptError>
+    where
+        T: DeserializeOwned,
+    {
+        Ok(serde_json::from_slice(&self.decrypt(data)?)?)
+    }
+}
+
+impl FileCredentials {
+    /// Decrypts and authenticates the contents of a downloaded
+    /// [`PassportFile`].
+    ///
+    /// [`PassportFile`]: crate::types::PassportFile
+    pub fn decrypt(&self, file: &[u8]) -> Result<Vec<u8>, PassportDecryptError> {
+        let secret = STANDARD.decode(&self.secret)?;
+        let hash = STANDARD.decode(&self.file_hash)?;
+        decrypt(file, &secret, &hash)
+    }
+}
+
+/// Decrypts `data` as described in the [official docs], checking that its
+/// SHA-256 is equal to `hash` and removing the padding.
+///
+/// [official docs]: https://core.telegram.org/passport#decrypting-data
+fn decrypt(data: &[u8], secret: &[u8], hash: &[u8]) -> Result<Vec<u8>, PassportDecryptError> {
+    if data.is_empty() || data.len() % 16 != 0 {
+        return Err(PassportDecryptError::Malformed);
+    }
+
+    let secret_hash = Sha512::new().chain_update(secret).chain_update(hash).finalize();
+    let (key, iv) = secret_hash.split_at(32);
+
+    let mut buf = data.to_vec();
+    cbc::Decryptor::<Aes256>::new_from_slices(key, &iv[..16])
+        .map_err(|_| PassportDecryptError::Malformed)?
+        .decrypt_padded_mut::<aes::cipher::block_padding::NoPadding>(&mut buf)
+        .map_err(|_| PassportDecryptError::Malformed)?;
+
+    if Sha256::digest(&buf).as_slice() != hash {
+        return Err(PassportDecryptError::HashMismatch);
+    }
+
+    let padding = usize::from(buf[0]);
+    if !(32..=255).contains(&padding) || padding > buf.len() {
+        return Err(PassportDecryptError::Malformed);
+    }
+
+    buf.drain(..padding);
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use aes::cipher::{block_padding::NoPadding, BlockEncryptMut};
+    use rsa::RsaPublicKey;
+
+    use super::*;
+
+    /// Encrypts `data` the same way Telegram does.
+    fn encrypt(data: &[u8], secret: &[u8]) -> (Vec<u8>, Vec<u8>) {
+        let padding = 32 + (16 - data.len() % 16) % 16;
+        let mut buf = vec![0; padding];
+        buf[0] = padding as u8;
+        buf.extend_from_slice(data);
+
+        let hash = Sha256::digest(&buf).to_vec();
+        let secret_hash = Sha512::new().chain_update(secret).chain_update(&hash).finalize();
+        let (key, iv) = secret_hash.split_at(32);
+
+        let len = buf.len();
+        cbc::Encryptor::<Aes256>::new_from_slices(key, &iv[..16])
+            .unwrap()
+            .encrypt_padded_mut::<NoPadding>(&mut buf, len)
+            .unwrap();
+
+        (buf, hash)
+    }
+
+    #[test]
+    fn decrypt_credentials_and_data() {
+        let mut rng = rand::thread_rng();
+        let private_key = RsaPrivateKey::new(&mut rng, 1024).unwrap();
+        let public_key = RsaPublicKey::from(&private_key);
+
+        let details = br#"{"first_name":"John","last_name":"Doe","birth_date":"01.01.1970","gender":"male","country_code":"US","residence_country_code":"US"}"#;
+        let data_secret = [7; 32];
+        let (encrypted_details, details_hash) = encrypt(details, &data_secret);
+
+        let scan = b"\xff\xd8 jpeg";
+        let file_secret = [9; 32];
+        let (encrypted_scan, scan_hash) = encrypt(scan, &file_secret);
+
+        let credentials = serde_json::json!({
+            "secure_data": {
+                "personal_details": {
+                    "data": { "data_hash": STANDARD.encode(details_hash), "secret": STANDARD.encode(data_secret) }
+                },
+                "utility_bill": {
+                    "files": [{ "file_hash": STANDARD.encode(scan_hash), "secret": STANDARD.encode(file_secret) }]
+                }
+            },
+            "nonce": "nonce"
+        });
+        let credentials_secret = [1; 32];
+        let (data, hash) = encrypt(credentials.to_string().as_bytes(), &credentials_secret);
+        let secret =
+            public_key.encrypt(&mut rng, Oaep::new::<Sha1>(), &credentials_secret).unwrap();
+
+        let encrypted = EncryptedCredentials {
+            data: STANDARD.encode(data),
+            hash: STANDARD.encode(hash),
+            secret: STANDARD.encode(secret),
+        };
+
+        let credentials = Credentials::decrypt(&encrypted, &private_key).unwrap();
+        assert_eq!(credentials.nonce, "nonce");
+
+        let data = credentials.secure_data.personal_details.unwrap().data.unwrap();
+        let details: PersonalDetails =
+            data.decrypt_json(&STANDARD.encode(encrypted_details)).unwrap();
+        assert_eq!(details.first_name, "John");
+        assert_eq!(details.middle_name, None);
+
+        let files = credentials.secure_data.utility_bill.unwrap().files.unwrap();
+        assert_eq!(files[0].decrypt(&encrypted_scan).unwrap(), scan);
+
+        let mut tampered = encrypted_scan;
+        tampered[0] ^= 1;
+        assert!(matches!(files[0].decrypt(&tampered), Err(PassportDecryptError::HashMismatch)));
+    }
+}
@@ -45,14 +45,19 @@ mod edit_forum_topic;
 mod edit_general_forum_topic;
 mod edit_message_caption;
 mod edit_message_caption_inline;
+mod edit_message_caption_target;
 mod edit_message_live_location;
 mod edit_message_live_location_inline;
+mod edit_message_live_location_target;
 mod edit_message_media;
 mod edit_message_media_inline;
+mod edit_message_media_target;
 mod edit_message_reply_markup;
 mod edit_message_reply_markup_inline;
+mod edit_message_reply_markup_target;
 mod edit_message_text;
 mod edit_message_text_inline;
+mod edit_message_text_target;
 mod export_chat_invite_link;
 mod forward_message;
 mod get_chat;
@@ -109,6 +114,7 @@ mod set_chat_sticker_set;
 mod set_chat_title;
 mod set_game_score;
 mod set_game_score_inline;
+mod set_game_score_target;
 mod set_my_commands;
 mod set_my_default_administrator_rights;
 mod set_passport_data_errors;
@@ -117,6 +123,7 @@ mod set_sticker_set_thumb;
 mod set_webhook;
 mod stop_message_live_location;
 mod stop_message_live_location_inline;
+mod stop_message_live_location_target;
 mod stop_poll;
 mod unban_chat_member;
 mod unban_chat_sender_chat;
@@ -156,18 +163,27 @@ pub use edit_forum_topic::{EditForumTopic, EditForumTopicSetters};
 pub use edit_general_forum_topic::{EditGeneralForumTopic, EditGeneralForumTopicSetters};
 pub use edit_message_caption::{EditMessageCaption, EditMessageCaptionSetters};
 pub use edit_message_caption_inline::{EditMessageCaptionInline, EditMessageCaptionInlineSetters};
+pub use edit_message_caption_target::{EditMessageCaptionTarget, EditMessageCaptionTargetSetters};
 pub use edit_message_live_location::{EditMessageLiveLocation, EditMessageLiveLocationSetters};
 pub use edit_message_live_location_inline::{
     EditMessageLiveLocationInline, EditMessageLiveLocationInlineSetters,
 };
+pub use edit_message_live_location_target::{
+    EditMessageLiveLocationTarget, EditMessageLiveLocationTargetSetters,
+};
 pub use edit_message_media::{EditMessageMedia, EditMessageMediaSetters};
 pub use edit_message_media_inline::{EditMessageMediaInline, EditMessageMediaInlineSetters};
+pub use edit_message_media_target::{EditMessageMediaTarget, EditMessageMediaTargetSetters};
 pub use edit_message_reply_markup::{EditMessageReplyMarkup, EditMessageReplyMarkupSetters};
 pub use edit_message_reply_markup_inline::{
     EditMessageReplyMarkupInline, EditMessageReplyMarkupInlineSetters,
 };
+pub use edit_message_reply_markup_target::{
+    EditMessageReplyMarkupTarget, EditMessageReplyMarkupTargetSetters,
+};
 pub use edit_message_text::{EditMessageText, EditMessageTextSetters};
 pub use edit_message_text_inline::{EditMessageTextInline, EditMessageTextInlineSetters};
+pub use edit_message_text_target::{EditMessageTextTarget, EditMessageTextTargetSetters};
 pub use export_chat_invite_link::{ExportChatInviteLink, ExportChatInviteLinkSetters};
 pub use forward_message::{ForwardMessage, ForwardMessageSetters};
 pub use get_chat::{GetChat, GetChatSetters};
@@ -230,6 +246,7 @@ pub use set_chat_sticker_set::{SetChatStickerSet, SetChatStickerSetSetters};
 pub use set_chat_title::{SetChatTitle, SetChatTitleSetters};
 pub use set_game_score::{SetGameScore, SetGameScoreSetters};
 pub use set_game_score_inline::{SetGameScoreInline, SetGameScoreInlineSetters};
+pub use set_game_score_target::{SetGameScoreTarget, SetGameScoreTargetSetters};
 pub use set_my_commands::{SetMyCommands, SetMyCommandsSetters};
 pub use set_my_default_administrator_rights::{
     SetMyDefaultAdministratorRights, SetMyDefaultAdministratorRightsSetters,
@@ -242,6 +259,9 @@ pub use stop_message_live_location::{StopMessageLiveLocation, StopMessageLiveLoc
 pub use stop_message_live_location_inline::{
     StopMessageLiveLocationInline, StopMessageLiveLocationInlineSetters,
 };
+pub use stop_message_live_location_target::{
+    StopMessageLiveLocationTarget, StopMessageLiveLocationTargetSetters,
+};
 pub use stop_poll::{StopPoll, StopPollSetters};
 pub use unban_chat_member::{UnbanChatMember, UnbanChatMemberSetters};
 pub use unban_chat_sender_chat::{UnbanChatSenderChat, UnbanChatSenderChatSetters};
@@ -254,6 +274,10 @@ pub use unpin_chat_message::{UnpinChatMessage, UnpinChatMessageSetters};
 pub use upload_sticker_file::{UploadStickerFile, UploadStickerFileSetters};
 // END BLOCK payload_modules
 
+mod send_poll_ext;
+
+pub use send_poll_ext::InvalidPoll;
+
 /// Generates `mod`s and `pub use`s above.
 #[test]
 fn codegen_payload_mods_and_reexports() {
@@ -310,3 +334,50 @@ fn codegen_setters_reexports() {
 
 #[cfg(test)]
 mod codegen;
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use crate::{
+        payloads::{
+            BanChatMember, BanChatMemberSetters, ForwardMessage, SendMessage, SendMessageSetters,
+            UnpinAllChatMessages, UnpinChatMessage, UnpinChatMessageSetters,
+        },
+        types::{ChatId, InlineKeyboardButton, InlineKeyboardMarkup, MessageId, UserId},
+    };
+
+    #[test]
+    fn unpin_chat_message() {
+        let payload = UnpinChatMessage::new(ChatId(1));
+        assert_eq!(serde_json::to_string(&payload).unwrap(), r#"{"chat_id":1}"#);
+
+        let payload = payload.message_id(MessageId(2));
+        assert_eq!(serde_json::to_string(&payload).unwrap(), r#"{"chat_id":1,"message_id":2}"#);
+    }
+
+    #[test]
+    fn unpin_all_chat_messages() {
+        let payload = UnpinAllChatMessages::new(ChatId(1));
+        assert_eq!(serde_json::to_string(&payload).unwrap(), r#"{"chat_id":1}"#);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let payload = SendMessage::new(ChatId(1), "hi")
+            .reply_to_message_id(MessageId(2))
+            .reply_markup(InlineKeyboardMarkup::new([[InlineKeyboardButton::callback("a", "b")]]));
+        let json = serde_json::to_string(&payload).unwrap();
+        assert_eq!(serde_json::from_str::<SendMessage>(&json).unwrap(), payload);
+
+        let payload = BanChatMember::new(ChatId(1), UserId(2))
+            .until_date(DateTime::from_timestamp(100, 0).unwrap());
+        let json = serde_json::to_string(&payload).unwrap();
+        assert_eq!(serde_json::from_str::<BanChatMember>(&json).unwrap(), payload);
+
+        let payload = ForwardMessage::new(ChatId(1), ChatId(2), MessageId(3));
+        let json = serde_json::to_string(&payload).unwrap();
+        assert_eq!(json, r#"{"chat_id":1,"from_chat_id":2,"message_id":3}"#);
+        assert_eq!(serde_json::from_str::<ForwardMessage>(&json).unwrap(), payload);
+    }
+}
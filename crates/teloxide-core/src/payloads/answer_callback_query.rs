@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::types::True;
@@ -12,7 +12,7 @@ impl_payload! {
     ///
     /// [inline keyboards]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
     /// [@Botfather]: https://t.me/botfather
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub AnswerCallbackQuery (AnswerCallbackQuerySetters) => True {
         required {
             /// Unique identifier for the query to be answered
@@ -1,12 +1,12 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{InlineQueryResult, True};
 
 impl_payload! {
     /// Use this method to send answers to an inline query. On success, _True_ is returned. No more than **50** results per query are allowed.
-    #[derive(Debug, PartialEq, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
     pub AnswerInlineQuery (AnswerInlineQuerySetters) => True {
         required {
             /// Unique identifier for the answered query
@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::True;
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// Once the user has confirmed their payment and shipping details, the Bot API sends the final confirmation in the form of an [`Update`] with the field pre\_checkout\_query. Use this method to respond to such pre-checkout queries. On success, True is returned. **Note:** The Bot API must receive an answer within 10 seconds after the pre-checkout query was sent.
     ///
     /// [`Update`]: crate::types::Update
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub AnswerPreCheckoutQuery (AnswerPreCheckoutQuerySetters) => True {
         required {
             /// Unique identifier for the query to be answered
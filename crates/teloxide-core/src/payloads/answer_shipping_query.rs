@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{ShippingOption, True};
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// If you sent an invoice requesting a shipping address and the parameter _is\_flexible_ was specified, the Bot API will send an [`Update`] with a shipping_query field to the bot. Use this method to reply to shipping queries. On success, True is returned.
     ///
     /// [`Update`]: crate::types::Update
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub AnswerShippingQuery (AnswerShippingQuerySetters) => True {
         required {
             /// Unique identifier for the query to be answered
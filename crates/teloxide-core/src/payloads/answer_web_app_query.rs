@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{InlineQueryResult, SentWebAppMessage};
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// Use this method to set the result of an interaction with a [Web App] and send a corresponding message on behalf of the user to the chat from which the query originated.
     ///
     /// [Web App]: https://core.telegram.org/bots/webapps
-    #[derive(Debug, PartialEq, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
     pub AnswerWebAppQuery (AnswerWebAppQuerySetters) => SentWebAppMessage {
         required {
             /// Unique identifier for the query to be answered
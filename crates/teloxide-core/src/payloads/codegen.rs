@@ -24,7 +24,8 @@ fn codegen_payloads() {
         let file_name = format!("{}.rs", method.names.2);
         let path = base_path.join(&*file_name);
 
-        let uses = uses(&method);
+        let deserializable = deserializable(&method);
+        let uses = uses(&method, deserializable);
 
         let method_doc = render_doc(&method.doc, method.sibling.as_deref());
         let eq_hash_derive = eq_hash_suitable(&method).then(|| " Eq, Hash,").unwrap_or("");
@@ -56,14 +57,13 @@ fn codegen_payloads() {
             .map(|field| format!("    @[multipart = {}]\n", field.join(", ")))
             .unwrap_or_default();
 
-        let derive = if !multipart.is_empty()
-            || matches!(
-                &*method.names.1,
-                "SendMediaGroup" | "EditMessageMedia" | "EditMessageMediaInline"
-            ) {
+        let derive = if !deserializable {
             "#[derive(Debug, Clone, Serialize)]".to_owned()
         } else {
-            format!("#[derive(Debug, PartialEq,{eq_hash_derive}{default_derive} Clone, Serialize)]")
+            format!(
+                "#[derive(Debug, PartialEq,{eq_hash_derive}{default_derive} Clone, Serialize, \
+                 Deserialize)]"
+            )
         };
 
         let timeout_secs = match &*method.names.2 {
@@ -71,12 +71,21 @@ fn codegen_payloads() {
             _ => "",
         };
 
+        let deprecated_setters = match &*method.names.2 {
+            "send_animation" | "send_audio" | "send_document" | "send_video"
+            | "send_video_note" => {
+                "    @[deprecated_setter thumb = thumbnail: InputFile, since = \"0.9.2\", note = \
+                 \"use `thumbnail` instead\"]\n"
+            }
+            _ => "",
+        };
+
         let contents = format!(
             "\
 {uses}
 
 impl_payload! {{
-{multipart}{timeout_secs}{method_doc}
+{multipart}{timeout_secs}{deprecated_setters}{method_doc}
     {derive}
     pub {Method} ({Method}Setters) => {return_ty} {{
 {required}{optional}
@@ -92,7 +101,7 @@ impl_payload! {{
     ensure_files_contents(files.iter().map(|(p, c)| (&**p, &**c)))
 }
 
-fn uses(method: &Method) -> String {
+fn uses(method: &Method, deserializable: bool) -> String {
     enum Use {
         Prelude,
         Crate(String),
@@ -121,7 +130,11 @@ fn uses(method: &Method) -> String {
     let mut crate_uses = HashSet::new();
     let mut external_uses = HashSet::new();
 
-    external_uses.insert(String::from("use serde::Serialize;"));
+    external_uses.insert(String::from(if deserializable {
+        "use serde::{Deserialize, Serialize};"
+    } else {
+        "use serde::Serialize;"
+    }));
 
     core::iter::once(&method.return_ty)
         .chain(method.params.iter().map(|p| &p.ty))
@@ -195,6 +208,19 @@ fn eq_hash_suitable(method: &Method) -> bool {
     method.params.iter().all(|p| ty_eq_hash_suitable(&p.ty))
 }
 
+/// Returns `true` if the payload of `method` can be deserialized, i.e. if it
+/// doesn't contain files to upload.
+fn deserializable(method: &Method) -> bool {
+    multipart_input_file_fields(method).is_none()
+        && !matches!(
+            &*method.names.1,
+            "SendMediaGroup"
+                | "EditMessageMedia"
+                | "EditMessageMediaInline"
+                | "EditMessageMediaTarget"
+        )
+}
+
 fn default_needed(method: &Method) -> bool {
     method.params.iter().all(|p| matches!(p.ty, Type::Option(_)))
 }
@@ -208,8 +234,7 @@ fn params(params: impl Iterator<Item = impl Borrow<Param>>) -> String {
             let ty = &param.ty;
             let flatten = match ty {
                 Type::RawTy(s) if s == "MessageId" && field == "reply_to_message_id" => {
-                    "\n            #[serde(serialize_with = \
-                     \"crate::types::serialize_reply_to_message_id\")]"
+                    "\n            #[serde(default, with = \"crate::types::option_msg_id_as_int\")]"
                 }
                 Type::RawTy(s)
                     if s == "MessageId"
@@ -223,7 +248,7 @@ fn params(params: impl Iterator<Item = impl Borrow<Param>>) -> String {
             };
             let with = match ty {
                 Type::DateTime => {
-                    "\n            #[serde(with = \
+                    "\n            #[serde(default, with = \
                      \"crate::types::serde_opt_date_from_unix_timestamp\")]"
                 }
                 _ => "",
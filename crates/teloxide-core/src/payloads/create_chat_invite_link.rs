@@ -1,7 +1,7 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{ChatInviteLink, Recipient};
 
@@ -10,7 +10,7 @@ impl_payload! {
     ///
     /// [`ChatInviteLink`]: crate::types::ChatInviteLink
     /// [`RevokeChatInviteLink`]: crate::payloads::RevokeChatInviteLink
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub CreateChatInviteLink (CreateChatInviteLinkSetters) => ChatInviteLink {
         required {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
@@ -20,7 +20,7 @@ impl_payload! {
             /// Invite link name; 0-32 characters
             pub name: String [into],
             /// Point in time (Unix timestamp) when the link will expire
-            #[serde(with = "crate::types::serde_opt_date_from_unix_timestamp")]
+            #[serde(default, with = "crate::types::serde_opt_date_from_unix_timestamp")]
             pub expire_date: DateTime<Utc> [into],
             /// Maximum number of users that can be members of the chat simultaneously after joining the chat via this invite link; 1-99999
             pub member_limit: u32,
@@ -1,12 +1,12 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::LabeledPrice;
 
 impl_payload! {
     /// Use this method to create a link for an invoice. Returns the created invoice link as String on success.
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub CreateInvoiceLink (CreateInvoiceLinkSetters) => String {
         required {
             /// Product name, 1-32 characters
@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{BotCommandScope, True};
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// Use this method to delete the list of the bot's commands for the given scope and user language. After deletion, [higher level commands] will be shown to affected users. Returns _True_ on success.
     ///
     /// [higher level commands]: https://core.telegram.org/bots/api#determining-list-of-commands
-    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize, Deserialize)]
     pub DeleteMyCommands (DeleteMyCommandsSetters) => True {
         optional {
             /// A JSON-serialized object, describing scope of users for which the commands are relevant. Defaults to BotCommandScopeDefault.
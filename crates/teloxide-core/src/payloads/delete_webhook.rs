@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::True;
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// Use this method to remove webhook integration if you decide to switch back to [`GetUpdates`]. Returns True on success. Requires no parameters.
     ///
     /// [`GetUpdates`]: crate::payloads::GetUpdates
-    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize, Deserialize)]
     pub DeleteWebhook (DeleteWebhookSetters) => True {
         optional {
             /// Pass _True_ to drop all pending updates
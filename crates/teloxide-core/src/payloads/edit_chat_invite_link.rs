@@ -1,7 +1,7 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::Recipient;
 
@@ -9,7 +9,7 @@ impl_payload! {
     /// Use this method to edit a non-primary invite link created by the bot. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns the edited invite link as a [`ChatInviteLink`] object.
     ///
     /// [`ChatInviteLink`]: crate::types::ChatInviteLink
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub EditChatInviteLink (EditChatInviteLinkSetters) => String {
         required {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
@@ -21,7 +21,7 @@ impl_payload! {
             /// Invite link name; 0-32 characters
             pub name: String [into],
             /// Point in time (Unix timestamp) when the link will expire
-            #[serde(with = "crate::types::serde_opt_date_from_unix_timestamp")]
+            #[serde(default, with = "crate::types::serde_opt_date_from_unix_timestamp")]
             pub expire_date: DateTime<Utc> [into],
             /// Maximum number of users that can be members of the chat simultaneously after joining the chat via this invite link; 1-99999
             pub member_limit: u32,
@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{InlineKeyboardMarkup, Message, MessageEntity, MessageId, ParseMode, Recipient};
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// Use this method to edit captions of messages. On success, the edited Message is returned.
     ///
     /// See also: [`EditMessageCaptionInline`](crate::payloads::EditMessageCaptionInline)
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub EditMessageCaption (EditMessageCaptionSetters) => Message {
         required {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`).
@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{InlineKeyboardMarkup, MessageEntity, ParseMode, True};
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// Use this method to edit captions of messages. On success, _True_ is returned.
     ///
     /// See also: [`EditMessageCaption`](crate::payloads::EditMessageCaption)
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub EditMessageCaptionInline (EditMessageCaptionInlineSetters) => True {
         required {
             /// Identifier of the inline message
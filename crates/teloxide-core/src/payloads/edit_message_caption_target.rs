@@ -0,0 +1,31 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{EditedMessage, InlineKeyboardMarkup, MessageEntity, ParseMode, TargetMessage};
+
+impl_payload! {
+    /// Use this method to edit captions of messages. On success, if the edited message is not an inline message, the edited Message is returned, otherwise _True_ is returned.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+    pub EditMessageCaptionTarget (EditMessageCaptionTargetSetters) => EditedMessage {
+        required {
+            /// Message to edit
+            #[serde(flatten)]
+            pub target: TargetMessage [into],
+        }
+        optional {
+            /// New caption of the message, 0-1024 characters after entities parsing
+            pub caption: String [into],
+            /// Mode for parsing entities in the message text. See [formatting options] for more details.
+            ///
+            /// [formatting options]: https://core.telegram.org/bots/api#formatting-options
+            pub parse_mode: ParseMode,
+            /// List of special entities that appear in the caption, which can be specified instead of _parse\_mode_
+            pub caption_entities: Vec<MessageEntity> [collect],
+            /// A JSON-serialized object for an [inline keyboard].
+            ///
+            /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+            pub reply_markup: InlineKeyboardMarkup,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{Message, MessageId, Recipient, ReplyMarkup};
 
@@ -10,7 +10,7 @@ impl_payload! {
     /// See also: [`EditMessageLiveLocationInline`](crate::payloads::EditMessageLiveLocationInline)
     ///
     /// [`StopMessageLiveLocation`]: crate::payloads::StopMessageLiveLocation
-    #[derive(Debug, PartialEq, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
     pub EditMessageLiveLocation (EditMessageLiveLocationSetters) => Message {
         required {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
@@ -0,0 +1,36 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{EditedMessage, ReplyMarkup, TargetMessage};
+
+impl_payload! {
+    /// Use this method to edit live location messages. A location can be edited until its live_period expires or editing is explicitly disabled by a call to [`StopMessageLiveLocation`]. On success, if the edited message is not an inline message, the edited Message is returned, otherwise _True_ is returned.
+    ///
+    /// [`StopMessageLiveLocation`]: crate::payloads::StopMessageLiveLocation
+    #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+    pub EditMessageLiveLocationTarget (EditMessageLiveLocationTargetSetters) => EditedMessage {
+        required {
+            /// Message to edit
+            #[serde(flatten)]
+            pub target: TargetMessage [into],
+            /// Latitude of new location
+            pub latitude: f64,
+            /// Longitude of new location
+            pub longitude: f64,
+        }
+        optional {
+            /// The radius of uncertainty for the location, measured in meters; 0-1500
+            pub horizontal_accuracy: f64,
+            /// For live locations, a direction in which the user is moving, in degrees. Must be between 1 and 360 if specified.
+            pub heading: u16,
+            /// For live locations, a maximum distance for proximity alerts about approaching another chat member, in meters. Must be between 1 and 100000 if specified.
+            pub proximity_alert_radius: u32,
+            /// Additional interface options. A JSON-serialized object for an [inline keyboard], [custom reply keyboard], instructions to remove reply keyboard or to force a reply from the user.
+            ///
+            /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+            /// [custom reply keyboard]: https://core.telegram.org/bots#keyboards
+            pub reply_markup: ReplyMarkup [into],
+        }
+    }
+}
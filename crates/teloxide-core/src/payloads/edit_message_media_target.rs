@@ -0,0 +1,25 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::Serialize;
+
+use crate::types::{EditedMessage, InlineKeyboardMarkup, InputMedia, TargetMessage};
+
+impl_payload! {
+    /// Use this method to edit animation, audio, document, photo, or video messages. If a message is a part of a message album, then it can be edited only to a photo or a video. Otherwise, message type can be changed arbitrarily. When inline message is edited, new file can't be uploaded. Use previously uploaded file via its file_id or specify a URL. On success, if the edited message is not an inline message, the edited Message is returned, otherwise _True_ is returned.
+    #[derive(Debug, Clone, Serialize)]
+    pub EditMessageMediaTarget (EditMessageMediaTargetSetters) => EditedMessage {
+        required {
+            /// Message to edit
+            #[serde(flatten)]
+            pub target: TargetMessage [into],
+            /// A JSON-serialized object for a new media content of the message
+            pub media: InputMedia,
+        }
+        optional {
+            /// A JSON-serialized object for an [inline keyboard].
+            ///
+            /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+            pub reply_markup: InlineKeyboardMarkup,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{InlineKeyboardMarkup, Message, MessageId, Recipient};
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// Use this method to edit only the reply markup of messages. On success, the edited Message is returned.
     ///
     /// See also: [`EditMessageMediaInline`](crate::payloads::EditMessageMediaInline)
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub EditMessageReplyMarkup (EditMessageReplyMarkupSetters) => Message {
         required {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`).
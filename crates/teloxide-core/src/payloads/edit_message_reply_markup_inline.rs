@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{InlineKeyboardMarkup, True};
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// Use this method to edit only the reply markup of messages. On success, _True_ is returned.
     ///
     /// See also: [`EditMessageReplyMarkup`](crate::payloads::EditMessageReplyMarkup)
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub EditMessageReplyMarkupInline (EditMessageReplyMarkupInlineSetters) => True {
         required {
             /// Identifier of the inline message
@@ -0,0 +1,23 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{EditedMessage, InlineKeyboardMarkup, TargetMessage};
+
+impl_payload! {
+    /// Use this method to edit only the reply markup of messages. On success, if the edited message is not an inline message, the edited Message is returned, otherwise _True_ is returned.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+    pub EditMessageReplyMarkupTarget (EditMessageReplyMarkupTargetSetters) => EditedMessage {
+        required {
+            /// Message to edit
+            #[serde(flatten)]
+            pub target: TargetMessage [into],
+        }
+        optional {
+            /// A JSON-serialized object for an [inline keyboard].
+            ///
+            /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+            pub reply_markup: InlineKeyboardMarkup,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{EditedMessage, InlineKeyboardMarkup, MessageEntity, ParseMode, TargetMessage};
+
+impl_payload! {
+    /// Use this method to edit text and [games] messages. On success, if the edited message is not an inline message, the edited Message is returned, otherwise _True_ is returned.
+    ///
+    /// [games]: https://core.telegram.org/bots/api#games
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+    pub EditMessageTextTarget (EditMessageTextTargetSetters) => EditedMessage {
+        required {
+            /// Message to edit
+            #[serde(flatten)]
+            pub target: TargetMessage [into],
+            /// New text of the message, 1-4096 characters after entities parsing
+            pub text: String [into],
+        }
+        optional {
+            /// Mode for parsing entities in the message text. See [formatting options] for more details.
+            ///
+            /// [formatting options]: https://core.telegram.org/bots/api#formatting-options
+            pub parse_mode: ParseMode,
+            /// List of special entities that appear in message text, which can be specified instead of _parse\_mode_
+            pub entities: Vec<MessageEntity> [collect],
+            /// Disables link previews for links in this message
+            pub disable_web_page_preview: bool,
+            /// A JSON-serialized object for an [inline keyboard].
+            ///
+            /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+            pub reply_markup: InlineKeyboardMarkup,
+        }
+    }
+}
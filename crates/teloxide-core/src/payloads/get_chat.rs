@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{Chat, Recipient};
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// Use this method to get up to date information about the chat (current name of the user for one-on-one conversations, current username of a user, group or channel, etc.). Returns a [`Chat`] object on success.
     ///
     /// [`Chat`]: crate::types::Chat
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub GetChat (GetChatSetters) => Chat {
         required {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{ChatMember, Recipient};
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// Use this method to get a list of administrators in a chat. On success, returns an Array of [`ChatMember`] objects that contains information about all chat administrators except other bots. If the chat is a group or a supergroup and no administrators were appointed, only the creator will be returned.
     ///
     /// [`ChatMember`]: crate::types::ChatMember
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub GetChatAdministrators (GetChatAdministratorsSetters) => Vec<ChatMember> {
         required {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
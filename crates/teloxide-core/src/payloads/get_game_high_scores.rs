@@ -1,8 +1,8 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use crate::types::{TargetMessage, True, UserId};
+use crate::types::{GameHighScore, TargetMessage, UserId};
 
 impl_payload! {
     /// Use this method to get data for high score tables. Will return the score of the specified user and several of their neighbors in a game. On success, returns an Array of [`GameHighScore`] objects.
@@ -10,8 +10,8 @@ impl_payload! {
     /// > This method will currently return scores for the target user, plus two of their closest neighbors on each side. Will also return the top three users if the user and his neighbors are not among them. Please note that this behavior is subject to change.
     ///
     /// [`GameHighScore`]: crate::types::GameHighScore
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
-    pub GetGameHighScores (GetGameHighScoresSetters) => True {
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+    pub GetGameHighScores (GetGameHighScoresSetters) => Vec<GameHighScore> {
         required {
             /// User identifier
             pub user_id: UserId,
@@ -1,12 +1,12 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::StickerSet;
 
 impl_payload! {
     /// Use this method to get a sticker set. On success, a StickerSet object is returned.
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub GetStickerSet (GetStickerSetSetters) => StickerSet {
         required {
             /// Name of the sticker set
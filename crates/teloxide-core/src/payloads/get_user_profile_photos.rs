@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{UserId, UserProfilePhotos};
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// Use this method to get a list of profile pictures for a user. Returns a [`UserProfilePhotos`] object.
     ///
     /// [`UserProfilePhotos`]: crate::types::UserProfilePhotos
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub GetUserProfilePhotos (GetUserProfilePhotosSetters) => UserProfilePhotos {
         required {
             /// Unique identifier of the target user
@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::WebhookInfo;
 
@@ -9,7 +9,7 @@ impl_payload! {
     ///
     /// [`WebhookInfo`]: crate::types::WebhookInfo
     /// [`GetUpdates`]: crate::payloads::GetUpdates
-    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize, Deserialize)]
     pub GetWebhookInfo (GetWebhookInfoSetters) => WebhookInfo {
 
     }
@@ -7,7 +7,8 @@ use crate::types::{
 };
 
 impl_payload! {
-    @[multipart = animation, thumb]
+    @[multipart = animation, thumbnail]
+    @[deprecated_setter thumb = thumbnail: InputFile, since = "0.9.2", note = "use `thumbnail` instead"]
     /// Use this method to send animation files (GIF or H.264/MPEG-4 AVC video without sound). On success, the sent [`Message`] is returned. Bots can currently send animation files of up to 50 MB in size, this limit may be changed in the future.
     ///
     /// [`Message`]: crate::types::Message
@@ -33,7 +34,7 @@ impl_payload! {
             /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. [More info on Sending Files »]
             ///
             /// [More info on Sending Files »]: crate::types::InputFile
-            pub thumb: InputFile,
+            pub thumbnail: InputFile,
             /// Animation caption (may also be used when resending videos by _file\_id_), 0-1024 characters after entities parsing
             pub caption: String [into],
             /// Mode for parsing entities in the animation caption. See [formatting options] for more details.
@@ -51,7 +52,7 @@ impl_payload! {
             /// Protects the contents of sent messages from forwarding and saving
             pub protect_content: bool,
             /// If the message is a reply, ID of the original message
-            #[serde(serialize_with = "crate::types::serialize_reply_to_message_id")]
+            #[serde(default, with = "crate::types::option_msg_id_as_int")]
             pub reply_to_message_id: MessageId,
             /// Pass _True_, if the message should be sent even if the specified replied-to message is not found
             pub allow_sending_without_reply: bool,
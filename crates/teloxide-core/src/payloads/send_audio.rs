@@ -7,7 +7,8 @@ use crate::types::{
 };
 
 impl_payload! {
-    @[multipart = audio, thumb]
+    @[multipart = audio, thumbnail]
+    @[deprecated_setter thumb = thumbnail: InputFile, since = "0.9.2", note = "use `thumbnail` instead"]
     /// Use this method to send audio files, if you want Telegram clients to display them in the music player. Your audio must be in the .MP3 or .M4A format. On success, the sent [`Message`] is returned. Bots can currently send audio files of up to 50 MB in size, this limit may be changed in the future.
     ///
     /// For sending voice messages, use the [`SendVoice`] method instead.
@@ -44,7 +45,7 @@ impl_payload! {
             /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail's width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can't be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. [More info on Sending Files »]
             ///
             /// [More info on Sending Files »]: crate::types::InputFile
-            pub thumb: InputFile,
+            pub thumbnail: InputFile,
             /// Sends the message [silently]. Users will receive a notification with no sound.
             ///
             /// [silently]: https://telegram.org/blog/channels-2-0#silent-messages
@@ -52,7 +53,7 @@ impl_payload! {
             /// Protects the contents of sent messages from forwarding and saving
             pub protect_content: bool,
             /// If the message is a reply, ID of the original message
-            #[serde(serialize_with = "crate::types::serialize_reply_to_message_id")]
+            #[serde(default, with = "crate::types::option_msg_id_as_int")]
             pub reply_to_message_id: MessageId,
             /// Pass _True_, if the message should be sent even if the specified replied-to message is not found
             pub allow_sending_without_reply: bool,
@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{ChatAction, Recipient, ThreadId, True};
 
@@ -12,7 +12,7 @@ impl_payload! {
     /// We only recommend using this method when a response from the bot will take a **noticeable** amount of time to arrive.
     ///
     /// [ImageBot]: https://t.me/imagebot
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub SendChatAction (SendChatActionSetters) => True {
         required {
             /// Unique identifier for the target chat or username of the target channel (in the format `@channelusername`)
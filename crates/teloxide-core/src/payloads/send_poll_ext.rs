@@ -0,0 +1,80 @@
+use thiserror::Error;
+
+use crate::{
+    payloads::SendPoll,
+    types::{PollType, Recipient},
+};
+
+impl SendPoll {
+    /// Creates a payload of a quiz, i.e. a poll with one correct option.
+    ///
+    /// `correct_option_id` is the 0-based index of the correct option in
+    /// `options`.
+    pub fn quiz(
+        chat_id: impl Into<Recipient>,
+        question: impl Into<String>,
+        options: impl IntoIterator<Item = String>,
+        correct_option_id: u8,
+    ) -> Self {
+        Self {
+            type_: Some(PollType::Quiz),
+            correct_option_id: Some(correct_option_id),
+            ..Self::new(chat_id, question, options)
+        }
+    }
+
+    /// Checks the parameters which Telegram would reject.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::{
+    ///     payloads::{InvalidPoll, SendPoll, SendPollSetters},
+    ///     types::ChatId,
+    /// };
+    ///
+    /// let options = ["4", "5"].map(String::from);
+    /// let quiz = SendPoll::quiz(ChatId(1), "2 + 2 = ?", options.clone(), 0).open_period(60);
+    /// assert_eq!(quiz.validate(), Ok(()));
+    ///
+    /// let quiz = SendPoll::quiz(ChatId(1), "2 + 2 = ?", options, 2);
+    /// assert_eq!(quiz.validate(), Err(InvalidPoll::CorrectOptionOutOfRange));
+    /// ```
+    pub fn validate(&self) -> Result<(), InvalidPoll> {
+        if !(2..=10).contains(&self.options.len()) {
+            return Err(InvalidPoll::OptionCount);
+        }
+
+        if self.open_period.is_some() && self.close_date.is_some() {
+            return Err(InvalidPoll::OpenPeriodAndCloseDate);
+        }
+
+        match (&self.type_, self.correct_option_id) {
+            (Some(PollType::Quiz), None) => Err(InvalidPoll::NoCorrectOption),
+            (_, Some(id)) if usize::from(id) >= self.options.len() => {
+                Err(InvalidPoll::CorrectOptionOutOfRange)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// An error returned by [`SendPoll::validate`].
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidPoll {
+    /// A poll must have 2-10 options.
+    #[error("A poll must have 2-10 options")]
+    OptionCount,
+
+    /// Only one of `open_period` and `close_date` can be set.
+    #[error("`open_period` can't be used together with `close_date`")]
+    OpenPeriodAndCloseDate,
+
+    /// A quiz doesn't have `correct_option_id`.
+    #[error("A quiz must have a correct option")]
+    NoCorrectOption,
+
+    /// `correct_option_id` is not an index of an option.
+    #[error("The correct option is out of range")]
+    CorrectOptionOutOfRange,
+}
@@ -1,12 +1,12 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{ChatId, MenuButton, True};
 
 impl_payload! {
     /// Use this method to change the bot's menu button in a private chat, or the default menu button.
-    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize, Deserialize)]
     pub SetChatMenuButton (SetChatMenuButtonSetters) => True {
         optional {
             /// Unique identifier for the target private chat. If not specified, default bot's menu button will be changed.
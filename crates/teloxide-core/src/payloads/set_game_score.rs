@@ -1,8 +1,8 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use crate::types::{Message, MessageId, UserId};
+use crate::types::{ChatId, Message, MessageId, UserId};
 
 impl_payload! {
     /// Use this method to set the score of the specified user in a game. On success, returns the edited [`Message`]. Returns an error, if the new score is not greater than the user's current score in the chat and force is False.
@@ -10,7 +10,7 @@ impl_payload! {
     /// See also: [`SetGameScoreInline`](crate::payloads::SetGameScoreInline)
     ///
     /// [`Message`]: crate::types::Message
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub SetGameScore (SetGameScoreSetters) => Message {
         required {
             /// User identifier
@@ -18,7 +18,7 @@ impl_payload! {
             /// New score
             pub score: u64,
             /// Unique identifier for the target chat
-            pub chat_id: u32,
+            pub chat_id: ChatId [into],
             /// Identifier of the message to edit
             #[serde(flatten)]
             pub message_id: MessageId,
@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{True, UserId};
 
@@ -8,7 +8,7 @@ impl_payload! {
     /// Use this method to set the score of the specified user in a game. On success, returns _True_. Returns an error, if the new score is not greater than the user's current score in the chat and force is False.
     ///
     /// See also: [`SetGameScore`](crate::payloads::SetGameScore)
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub SetGameScoreInline (SetGameScoreInlineSetters) => True {
         required {
             /// User identifier
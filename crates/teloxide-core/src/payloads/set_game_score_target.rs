@@ -0,0 +1,27 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{EditedMessage, TargetMessage, UserId};
+
+impl_payload! {
+    /// Use this method to set the score of the specified user in a game. On success, if the message is not an inline message, the edited Message is returned, otherwise _True_ is returned. Returns an error, if the new score is not greater than the user's current score in the chat and force is False.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+    pub SetGameScoreTarget (SetGameScoreTargetSetters) => EditedMessage {
+        required {
+            /// User identifier
+            pub user_id: UserId,
+            /// New score
+            pub score: u64,
+            /// Message to edit
+            #[serde(flatten)]
+            pub target: TargetMessage [into],
+        }
+        optional {
+            /// Pass True, if the high score is allowed to decrease. This can be useful when fixing mistakes or banning cheaters
+            pub force: bool,
+            /// Pass True, if the game message should not be automatically edited to include the current scoreboard
+            pub disable_edit_message: bool,
+        }
+    }
+}
@@ -1,12 +1,12 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{BotCommand, BotCommandScope, True};
 
 impl_payload! {
     /// Use this method to change the list of the bot's commands. Returns _True_ on success.
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub SetMyCommands (SetMyCommandsSetters) => True {
         required {
             /// A JSON-serialized list of bot commands to be set as the list of the bot's commands. At most 100 commands can be specified.
@@ -1,12 +1,12 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{ChatAdministratorRights, True};
 
 impl_payload! {
     /// Use this method to change the default administrator rights requested by the bot when it's added as an administrator to groups or channels. These rights will be suggested to users, but they are are free to modify the list before adding the bot.
-    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Serialize, Deserialize)]
     pub SetMyDefaultAdministratorRights (SetMyDefaultAdministratorRightsSetters) => True {
         optional {
             /// A JSON-serialized object describing new default administrator rights. If not specified, the default administrator rights will be cleared.
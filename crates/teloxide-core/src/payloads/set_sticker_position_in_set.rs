@@ -1,12 +1,12 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::True;
 
 impl_payload! {
     /// Use this method to move a sticker in a set created by the bot to a specific position. Returns _True_ on success.
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub SetStickerPositionInSet (SetStickerPositionInSetSetters) => True {
         required {
             /// File identifier of the sticker
@@ -13,35 +13,38 @@ pub use crate::payloads::{
     DeleteMyCommandsSetters as _, DeleteStickerFromSetSetters as _, DeleteWebhookSetters as _,
     EditChatInviteLinkSetters as _, EditForumTopicSetters as _, EditGeneralForumTopicSetters as _,
     EditMessageCaptionInlineSetters as _, EditMessageCaptionSetters as _,
-    EditMessageLiveLocationInlineSetters as _, EditMessageLiveLocationSetters as _,
+    EditMessageCaptionTargetSetters as _, EditMessageLiveLocationInlineSetters as _,
+    EditMessageLiveLocationSetters as _, EditMessageLiveLocationTargetSetters as _,
     EditMessageMediaInlineSetters as _, EditMessageMediaSetters as _,
-    EditMessageReplyMarkupInlineSetters as _, EditMessageReplyMarkupSetters as _,
+    EditMessageMediaTargetSetters as _, EditMessageReplyMarkupInlineSetters as _,
+    EditMessageReplyMarkupSetters as _, EditMessageReplyMarkupTargetSetters as _,
     EditMessageTextInlineSetters as _, EditMessageTextSetters as _,
-    ExportChatInviteLinkSetters as _, ForwardMessageSetters as _,
-    GetChatAdministratorsSetters as _, GetChatMemberCountSetters as _, GetChatMemberSetters as _,
-    GetChatMembersCountSetters as _, GetChatMenuButtonSetters as _, GetChatSetters as _,
-    GetCustomEmojiStickersSetters as _, GetFileSetters as _, GetForumTopicIconStickersSetters as _,
-    GetGameHighScoresSetters as _, GetMeSetters as _, GetMyCommandsSetters as _,
-    GetMyDefaultAdministratorRightsSetters as _, GetStickerSetSetters as _, GetUpdatesSetters as _,
-    GetUserProfilePhotosSetters as _, GetWebhookInfoSetters as _,
-    HideGeneralForumTopicSetters as _, KickChatMemberSetters as _, LeaveChatSetters as _,
-    LogOutSetters as _, PinChatMessageSetters as _, PromoteChatMemberSetters as _,
-    ReopenForumTopicSetters as _, ReopenGeneralForumTopicSetters as _,
-    RestrictChatMemberSetters as _, RevokeChatInviteLinkSetters as _, SendAnimationSetters as _,
-    SendAudioSetters as _, SendChatActionSetters as _, SendContactSetters as _,
-    SendDiceSetters as _, SendDocumentSetters as _, SendGameSetters as _, SendInvoiceSetters as _,
+    EditMessageTextTargetSetters as _, ExportChatInviteLinkSetters as _,
+    ForwardMessageSetters as _, GetChatAdministratorsSetters as _, GetChatMemberCountSetters as _,
+    GetChatMemberSetters as _, GetChatMembersCountSetters as _, GetChatMenuButtonSetters as _,
+    GetChatSetters as _, GetCustomEmojiStickersSetters as _, GetFileSetters as _,
+    GetForumTopicIconStickersSetters as _, GetGameHighScoresSetters as _, GetMeSetters as _,
+    GetMyCommandsSetters as _, GetMyDefaultAdministratorRightsSetters as _,
+    GetStickerSetSetters as _, GetUpdatesSetters as _, GetUserProfilePhotosSetters as _,
+    GetWebhookInfoSetters as _, HideGeneralForumTopicSetters as _, KickChatMemberSetters as _,
+    LeaveChatSetters as _, LogOutSetters as _, PinChatMessageSetters as _,
+    PromoteChatMemberSetters as _, ReopenForumTopicSetters as _,
+    ReopenGeneralForumTopicSetters as _, RestrictChatMemberSetters as _,
+    RevokeChatInviteLinkSetters as _, SendAnimationSetters as _, SendAudioSetters as _,
+    SendChatActionSetters as _, SendContactSetters as _, SendDiceSetters as _,
+    SendDocumentSetters as _, SendGameSetters as _, SendInvoiceSetters as _,
     SendLocationSetters as _, SendMediaGroupSetters as _, SendMessageSetters as _,
     SendPhotoSetters as _, SendPollSetters as _, SendStickerSetters as _, SendVenueSetters as _,
     SendVideoNoteSetters as _, SendVideoSetters as _, SendVoiceSetters as _,
     SetChatAdministratorCustomTitleSetters as _, SetChatDescriptionSetters as _,
     SetChatMenuButtonSetters as _, SetChatPermissionsSetters as _, SetChatPhotoSetters as _,
     SetChatStickerSetSetters as _, SetChatTitleSetters as _, SetGameScoreInlineSetters as _,
-    SetGameScoreSetters as _, SetMyCommandsSetters as _,
+    SetGameScoreSetters as _, SetGameScoreTargetSetters as _, SetMyCommandsSetters as _,
     SetMyDefaultAdministratorRightsSetters as _, SetPassportDataErrorsSetters as _,
     SetStickerPositionInSetSetters as _, SetStickerSetThumbSetters as _, SetWebhookSetters as _,
     StopMessageLiveLocationInlineSetters as _, StopMessageLiveLocationSetters as _,
-    StopPollSetters as _, UnbanChatMemberSetters as _, UnbanChatSenderChatSetters as _,
-    UnhideGeneralForumTopicSetters as _, UnpinAllChatMessagesSetters as _,
-    UnpinAllForumTopicMessagesSetters as _, UnpinChatMessageSetters as _,
-    UploadStickerFileSetters as _,
+    StopMessageLiveLocationTargetSetters as _, StopPollSetters as _, UnbanChatMemberSetters as _,
+    UnbanChatSenderChatSetters as _, UnhideGeneralForumTopicSetters as _,
+    UnpinAllChatMessagesSetters as _, UnpinAllForumTopicMessagesSetters as _,
+    UnpinChatMessageSetters as _, UploadStickerFileSetters as _,
 };
@@ -1,6 +1,6 @@
 //! Generated by `codegen_payloads`, do not edit by hand.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::types::{ReplyMarkup, True};
 
@@ -10,7 +10,7 @@ impl_payload! {
     /// See also: [`StopMessageLiveLocation`](crate::payloads::StopMessageLiveLocation)
     ///
     /// [`StopMessageLiveLocation`]: crate::payloads::StopMessageLiveLocation
-    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize)]
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
     pub StopMessageLiveLocationInline (StopMessageLiveLocationInlineSetters) => True {
         required {
             /// Identifier of the inline message
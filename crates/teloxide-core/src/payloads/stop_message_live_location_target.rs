@@ -0,0 +1,24 @@
+//! Generated by `codegen_payloads`, do not edit by hand.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{EditedMessage, ReplyMarkup, TargetMessage};
+
+impl_payload! {
+    /// Use this method to stop updating a live location message before _live\_period_ expires. On success, if the edited message is not an inline message, the edited Message is returned, otherwise _True_ is returned.
+    #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+    pub StopMessageLiveLocationTarget (StopMessageLiveLocationTargetSetters) => EditedMessage {
+        required {
+            /// Message to edit
+            #[serde(flatten)]
+            pub target: TargetMessage [into],
+        }
+        optional {
+            /// Additional interface options. A JSON-serialized object for an [inline keyboard], [custom reply keyboard], instructions to remove reply keyboard or to force a reply from the user.
+            ///
+            /// [inline keyboard]: https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating
+            /// [custom reply keyboard]: https://core.telegram.org/bots#keyboards
+            pub reply_markup: ReplyMarkup [into],
+        }
+    }
+}
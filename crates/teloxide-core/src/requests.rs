@@ -1,9 +1,11 @@
 //! Telegram API requests.
 
 pub use self::{
+    batch::{Batch, BatchItem, BatchResults},
     has_payload::HasPayload, json::JsonRequest, multipart::MultipartRequest,
     multipart_payload::MultipartPayload, payload::Payload, request::Request, requester::Requester,
     requester_ext::RequesterExt,
+    target_request::{TargetPayload, TargetRequest, TargetSend},
 };
 
 /// A type that is returned after making a request to Telegram.
@@ -12,6 +14,7 @@ pub type ResponseResult<T> = Result<T, crate::RequestError>;
 /// An output type of [`Payload`] in [`HasPayload`].
 pub type Output<T> = <<T as HasPayload>::Payload as Payload>::Output;
 
+mod batch;
 mod has_payload;
 mod json;
 mod multipart;
@@ -20,3 +23,4 @@ mod payload;
 mod request;
 mod requester;
 mod requester_ext;
+mod target_request;
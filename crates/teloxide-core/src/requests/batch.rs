@@ -0,0 +1,233 @@
+use std::{
+    any::Any,
+    fmt,
+    marker::PhantomData,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use futures::{future::BoxFuture, stream, FutureExt, StreamExt};
+
+use crate::requests::{Output, Request};
+
+/// A set of requests, which are sent concurrently.
+///
+/// Requests of different types can be added to a batch with [`add`], which
+/// returns a handle to the result of the request. After the batch is sent with
+/// [`send`], the results can be taken with [`BatchResults::take`] or iterated
+/// over, in the order the requests were added.
+///
+/// At most [`concurrency`] requests are sent at the same time. Requests of a
+/// [`Throttle`]d bot still wait for the limits, so a batch can't exceed them.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::{prelude::*, types::ChatId};
+///
+/// # async {
+/// let bot = Bot::new("TOKEN");
+///
+/// let mut batch = bot.batch();
+/// let me = batch.add(bot.get_me());
+/// let messages: Vec<_> =
+///     [ChatId(1), ChatId(2)].map(|chat| batch.add(bot.send_message(chat, "Hi!"))).into();
+///
+/// let mut results = batch.concurrency(4).send().await;
+/// let me = results.take(me)?;
+/// for message in messages {
+///     if let Err(err) = results.take(message) {
+///         log::warn!("Couldn't greet a chat: {err}");
+///     }
+/// }
+/// # Ok::<_, teloxide_core::RequestError>(()) };
+/// ```
+///
+/// [`add`]: Batch::add
+/// [`send`]: Batch::send
+/// [`concurrency`]: Batch::concurrency
+/// [`Throttle`]: crate::adaptors::Throttle
+#[must_use = "Batches are lazy and do nothing unless sent"]
+pub struct Batch<'a, E> {
+    id: u64,
+    requests: Vec<BoxFuture<'a, Result<Box<dyn Any + Send>, E>>>,
+    concurrency: usize,
+}
+
+/// A handle to the result of a request added to a [`Batch`].
+pub struct BatchItem<T> {
+    batch_id: u64,
+    index: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+/// Results of the requests of a [`Batch`].
+pub struct BatchResults<E> {
+    batch_id: u64,
+    results: Vec<Option<Result<Box<dyn Any + Send>, E>>>,
+}
+
+impl<'a, E> Batch<'a, E> {
+    /// The default number of requests sent at the same time.
+    pub const DEFAULT_CONCURRENCY: usize = 8;
+
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        // Distinguishes items of different batches
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            requests: Vec::new(),
+            concurrency: Self::DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Sets the maximum number of requests sent at the same time.
+    ///
+    /// ## Panics
+    ///
+    /// If `concurrency` is zero.
+    pub fn concurrency(self, concurrency: usize) -> Self {
+        assert_ne!(concurrency, 0, "Batch concurrency must be positive");
+        Self { concurrency, ..self }
+    }
+
+    /// Adds a request to the batch, returning a handle to its result.
+    pub fn add<R>(&mut self, request: R) -> BatchItem<Output<R>>
+    where
+        R: Request<Err = E>,
+        R::Send: 'a,
+        Output<R>: Send + 'static,
+    {
+        let index = self.requests.len();
+        let fut = request.send().map(|res| res.map(|out| Box::new(out) as Box<dyn Any + Send>));
+        self.requests.push(fut.boxed());
+
+        BatchItem { batch_id: self.id, index, phantom: PhantomData }
+    }
+
+    /// Returns the number of requests in the batch.
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Returns `true` if there are no requests in the batch.
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Sends all the requests of the batch.
+    ///
+    /// An error of one request doesn't affect the other ones.
+    pub async fn send(self) -> BatchResults<E> {
+        let results =
+            stream::iter(self.requests).buffered(self.concurrency).map(Some).collect().await;
+
+        BatchResults { batch_id: self.id, results }
+    }
+}
+
+impl<E> Default for Batch<'_, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> fmt::Debug for Batch<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Batch")
+            .field("len", &self.requests.len())
+            .field("concurrency", &self.concurrency)
+            .finish()
+    }
+}
+
+impl<T> fmt::Debug for BatchItem<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchItem")
+            .field("batch_id", &self.batch_id)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<E> BatchResults<E> {
+    /// Takes the result of a request.
+    ///
+    /// ## Panics
+    ///
+    /// If the result was already taken, or if `item` was returned by another
+    /// batch.
+    pub fn take<T: 'static>(&mut self, item: BatchItem<T>) -> Result<T, E> {
+        assert_eq!(item.batch_id, self.batch_id, "The result belongs to another batch");
+
+        let result = self.results[item.index].take().expect("The result was already taken");
+        result.map(|out| *out.downcast().expect("`BatchItem` has the type of its request"))
+    }
+}
+
+impl<E> IntoIterator for BatchResults<E> {
+    type Item = Result<Box<dyn Any + Send>, E>;
+    type IntoIter = std::iter::Flatten<std::vec::IntoIter<Option<Self::Item>>>;
+
+    /// Returns the results which weren't taken, in the order the requests were
+    /// added to the batch.
+    fn into_iter(self) -> Self::IntoIter {
+        self.results.into_iter().flatten()
+    }
+}
+
+impl<E> fmt::Debug for BatchResults<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchResults").field("len", &self.results.len()).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(all(feature = "mock_bot", feature = "test_fixtures"))]
+    #[tokio::test]
+    async fn keeps_order_and_errors() {
+        use crate::{
+            adaptors::MockBot,
+            payloads::{GetMe, SendMessage},
+            prelude::*,
+            types::{ChatId, Me},
+            ApiError, RequestError,
+        };
+
+        let bot = MockBot::new();
+        let me = Me::fixture();
+        bot.respond::<GetMe>(me.clone());
+        bot.respond_err::<SendMessage>(RequestError::Api(ApiError::BotBlocked));
+
+        let mut batch = bot.batch().concurrency(1);
+        let send = batch.add(bot.send_message(ChatId(1), "hi"));
+        let get_me = batch.add(bot.get_me());
+        assert_eq!(batch.len(), 2);
+
+        let mut results = batch.send().await;
+        assert_eq!(results.take(get_me).unwrap(), me);
+        assert!(matches!(results.take(send), Err(RequestError::Api(ApiError::BotBlocked))));
+        assert_eq!(results.into_iter().count(), 0);
+        assert_eq!(bot.request_names(), ["SendMessage", "GetMe"]);
+    }
+
+    #[cfg(all(feature = "mock_bot", feature = "test_fixtures"))]
+    #[tokio::test]
+    #[should_panic = "The result belongs to another batch"]
+    async fn rejects_items_of_other_batches() {
+        use crate::{adaptors::MockBot, payloads::GetMe, prelude::*, types::Me};
+
+        let bot = MockBot::new();
+        bot.respond::<GetMe>(Me::fixture());
+
+        let mut first = bot.batch();
+        let mut second = bot.batch();
+        first.add(bot.get_me());
+        let item = second.add(bot.get_me());
+
+        let mut results = first.send().await;
+        let _ = results.take(item);
+    }
+}
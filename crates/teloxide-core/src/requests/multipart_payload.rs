@@ -41,3 +41,13 @@ impl MultipartPayload for payloads::EditMessageMediaInline {
         self.media.files_mut().for_each(|f| f.move_into(into))
     }
 }
+
+impl MultipartPayload for payloads::EditMessageMediaTarget {
+    fn copy_files(&self, into: &mut dyn FnMut(InputFile)) {
+        self.media.files().for_each(|f| f.copy_into(into))
+    }
+
+    fn move_files(&mut self, into: &mut dyn FnMut(InputFile)) {
+        self.media.files_mut().for_each(|f| f.move_into(into))
+    }
+}
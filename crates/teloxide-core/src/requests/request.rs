@@ -95,6 +95,34 @@ where
     #[must_use = "Futures are lazy and do nothing unless polled or awaited"]
     fn send_ref(&self) -> Self::SendRef;
 
+    /// Send this request, returning [`ResponseMeta`] alongside the result.
+    ///
+    /// This is useful for diagnostics, e.g. for tracking latency of requests
+    /// or how often they're retried. Metadata is returned even if the request
+    /// has failed.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # async {
+    /// use teloxide_core::{prelude::*, requests::Request, types::ChatId, Bot};
+    ///
+    /// let bot = Bot::new("TOKEN");
+    ///
+    /// let (res, meta) = bot.send_message(ChatId(0xAAAAAAAA), "Hi there!").send_with_meta().await;
+    /// log::info!("status: {:?}, latency: {:?}, retries: {}", meta.status, meta.latency, meta.retries);
+    /// res.unwrap();
+    /// # };
+    /// ```
+    ///
+    /// [`ResponseMeta`]: crate::net::ResponseMeta
+    fn send_with_meta(self) -> crate::net::SendWithMeta<Self::Send>
+    where
+        Self: Sized,
+    {
+        crate::net::SendWithMeta::new(self.send())
+    }
+
     #[cfg(feature = "erased")]
     fn erase<'a>(self) -> crate::adaptors::erased::ErasedRequest<'a, Self::Payload, Self::Err>
     where
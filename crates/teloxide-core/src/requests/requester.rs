@@ -3,7 +3,11 @@
 
 use url::Url;
 
-use crate::{payloads::*, requests::Request, types::*};
+use crate::{
+    payloads::*,
+    requests::{Request, TargetRequest},
+    types::*,
+};
 
 /// Telegram Bot API client.
 ///
@@ -280,8 +284,6 @@ pub trait Requester {
     where
         C: Into<Recipient>;
 
-    type EditMessageLiveLocation: Request<Payload = EditMessageLiveLocation, Err = Self::Err>;
-
     /// For Telegram documentation see [`EditMessageLiveLocation`].
     fn edit_message_live_location<C>(
         &self,
@@ -289,14 +291,15 @@ pub trait Requester {
         message_id: MessageId,
         latitude: f64,
         longitude: f64,
-    ) -> Self::EditMessageLiveLocation
+    ) -> TargetRequest<'_, Self, EditMessageLiveLocation>
     where
-        C: Into<Recipient>;
-
-    type EditMessageLiveLocationInline: Request<
-        Payload = EditMessageLiveLocationInline,
-        Err = Self::Err,
-    >;
+        C: Into<Recipient>,
+    {
+        TargetRequest::new(
+            self,
+            EditMessageLiveLocation::new(chat_id, message_id, latitude, longitude),
+        )
+    }
 
     /// For Telegram documentation see [`EditMessageLiveLocationInline`].
     fn edit_message_live_location_inline<I>(
@@ -304,33 +307,66 @@ pub trait Requester {
         inline_message_id: I,
         latitude: f64,
         longitude: f64,
-    ) -> Self::EditMessageLiveLocationInline
+    ) -> TargetRequest<'_, Self, EditMessageLiveLocationInline>
     where
-        I: Into<String>;
+        I: Into<String>,
+    {
+        TargetRequest::new(
+            self,
+            EditMessageLiveLocationInline::new(inline_message_id, latitude, longitude),
+        )
+    }
+
+    type EditMessageLiveLocationTarget: Request<
+        Payload = EditMessageLiveLocationTarget,
+        Err = Self::Err,
+    >;
 
-    type StopMessageLiveLocation: Request<Payload = StopMessageLiveLocation, Err = Self::Err>;
+    /// For Telegram documentation see [`EditMessageLiveLocationTarget`].
+    fn edit_message_live_location_target<T>(
+        &self,
+        target: T,
+        latitude: f64,
+        longitude: f64,
+    ) -> Self::EditMessageLiveLocationTarget
+    where
+        T: Into<TargetMessage>;
 
     /// For Telegram documentation see [`StopMessageLiveLocation`].
     fn stop_message_live_location<C>(
         &self,
         chat_id: C,
         message_id: MessageId,
-    ) -> Self::StopMessageLiveLocation
+    ) -> TargetRequest<'_, Self, StopMessageLiveLocation>
     where
-        C: Into<Recipient>;
-
-    type StopMessageLiveLocationInline: Request<
-        Payload = StopMessageLiveLocationInline,
-        Err = Self::Err,
-    >;
+        C: Into<Recipient>,
+    {
+        TargetRequest::new(self, StopMessageLiveLocation::new(chat_id, message_id))
+    }
 
     /// For Telegram documentation see [`StopMessageLiveLocationInline`].
     fn stop_message_live_location_inline<I>(
         &self,
         inline_message_id: I,
-    ) -> Self::StopMessageLiveLocationInline
+    ) -> TargetRequest<'_, Self, StopMessageLiveLocationInline>
     where
-        I: Into<String>;
+        I: Into<String>,
+    {
+        TargetRequest::new(self, StopMessageLiveLocationInline::new(inline_message_id))
+    }
+
+    type StopMessageLiveLocationTarget: Request<
+        Payload = StopMessageLiveLocationTarget,
+        Err = Self::Err,
+    >;
+
+    /// For Telegram documentation see [`StopMessageLiveLocationTarget`].
+    fn stop_message_live_location_target<T>(
+        &self,
+        target: T,
+    ) -> Self::StopMessageLiveLocationTarget
+    where
+        T: Into<TargetMessage>;
 
     type SendVenue: Request<Payload = SendVenue, Err = Self::Err>;
 
@@ -395,7 +431,7 @@ pub trait Requester {
     /// For Telegram documentation see [`GetFile`].
     fn get_file<F>(&self, file_id: F) -> Self::GetFile
     where
-        F: Into<String>;
+        F: Into<FileId>;
 
     type BanChatMember: Request<Payload = BanChatMember, Err = Self::Err>;
 
@@ -827,53 +863,70 @@ pub trait Requester {
     where
         W: Into<String>;
 
-    type EditMessageText: Request<Payload = EditMessageText, Err = Self::Err>;
-
     /// For Telegram documentation see [`EditMessageText`].
     fn edit_message_text<C, T>(
         &self,
         chat_id: C,
         message_id: MessageId,
         text: T,
-    ) -> Self::EditMessageText
+    ) -> TargetRequest<'_, Self, EditMessageText>
     where
         C: Into<Recipient>,
-        T: Into<String>;
-
-    type EditMessageTextInline: Request<Payload = EditMessageTextInline, Err = Self::Err>;
+        T: Into<String>,
+    {
+        TargetRequest::new(self, EditMessageText::new(chat_id, message_id, text))
+    }
 
     /// For Telegram documentation see [`EditMessageTextInline`].
     fn edit_message_text_inline<I, T>(
         &self,
         inline_message_id: I,
         text: T,
-    ) -> Self::EditMessageTextInline
+    ) -> TargetRequest<'_, Self, EditMessageTextInline>
     where
         I: Into<String>,
-        T: Into<String>;
+        T: Into<String>,
+    {
+        TargetRequest::new(self, EditMessageTextInline::new(inline_message_id, text))
+    }
 
-    type EditMessageCaption: Request<Payload = EditMessageCaption, Err = Self::Err>;
+    type EditMessageTextTarget: Request<Payload = EditMessageTextTarget, Err = Self::Err>;
+
+    /// For Telegram documentation see [`EditMessageTextTarget`].
+    fn edit_message_text_target<Ta, T>(&self, target: Ta, text: T) -> Self::EditMessageTextTarget
+    where
+        Ta: Into<TargetMessage>,
+        T: Into<String>;
 
     /// For Telegram documentation see [`EditMessageCaption`].
     fn edit_message_caption<C>(
         &self,
         chat_id: C,
         message_id: MessageId,
-    ) -> Self::EditMessageCaption
+    ) -> TargetRequest<'_, Self, EditMessageCaption>
     where
-        C: Into<Recipient>;
-
-    type EditMessageCaptionInline: Request<Payload = EditMessageCaptionInline, Err = Self::Err>;
+        C: Into<Recipient>,
+    {
+        TargetRequest::new(self, EditMessageCaption::new(chat_id, message_id))
+    }
 
     /// For Telegram documentation see [`EditMessageCaptionInline`].
     fn edit_message_caption_inline<I>(
         &self,
         inline_message_id: I,
-    ) -> Self::EditMessageCaptionInline
+    ) -> TargetRequest<'_, Self, EditMessageCaptionInline>
     where
-        I: Into<String>;
+        I: Into<String>,
+    {
+        TargetRequest::new(self, EditMessageCaptionInline::new(inline_message_id))
+    }
+
+    type EditMessageCaptionTarget: Request<Payload = EditMessageCaptionTarget, Err = Self::Err>;
 
-    type EditMessageMedia: Request<Payload = EditMessageMedia, Err = Self::Err>;
+    /// For Telegram documentation see [`EditMessageCaptionTarget`].
+    fn edit_message_caption_target<T>(&self, target: T) -> Self::EditMessageCaptionTarget
+    where
+        T: Into<TargetMessage>;
 
     /// For Telegram documentation see [`EditMessageMedia`].
     fn edit_message_media<C>(
@@ -881,44 +934,68 @@ pub trait Requester {
         chat_id: C,
         message_id: MessageId,
         media: InputMedia,
-    ) -> Self::EditMessageMedia
+    ) -> TargetRequest<'_, Self, EditMessageMedia>
     where
-        C: Into<Recipient>;
-
-    type EditMessageMediaInline: Request<Payload = EditMessageMediaInline, Err = Self::Err>;
+        C: Into<Recipient>,
+    {
+        TargetRequest::new(self, EditMessageMedia::new(chat_id, message_id, media))
+    }
 
     /// For Telegram documentation see [`EditMessageMediaInline`].
     fn edit_message_media_inline<I>(
         &self,
         inline_message_id: I,
         media: InputMedia,
-    ) -> Self::EditMessageMediaInline
+    ) -> TargetRequest<'_, Self, EditMessageMediaInline>
     where
-        I: Into<String>;
+        I: Into<String>,
+    {
+        TargetRequest::new(self, EditMessageMediaInline::new(inline_message_id, media))
+    }
 
-    type EditMessageReplyMarkup: Request<Payload = EditMessageReplyMarkup, Err = Self::Err>;
+    type EditMessageMediaTarget: Request<Payload = EditMessageMediaTarget, Err = Self::Err>;
+
+    /// For Telegram documentation see [`EditMessageMediaTarget`].
+    fn edit_message_media_target<T>(
+        &self,
+        target: T,
+        media: InputMedia,
+    ) -> Self::EditMessageMediaTarget
+    where
+        T: Into<TargetMessage>;
 
     /// For Telegram documentation see [`EditMessageReplyMarkup`].
     fn edit_message_reply_markup<C>(
         &self,
         chat_id: C,
         message_id: MessageId,
-    ) -> Self::EditMessageReplyMarkup
+    ) -> TargetRequest<'_, Self, EditMessageReplyMarkup>
     where
-        C: Into<Recipient>;
-
-    type EditMessageReplyMarkupInline: Request<
-        Payload = EditMessageReplyMarkupInline,
-        Err = Self::Err,
-    >;
+        C: Into<Recipient>,
+    {
+        TargetRequest::new(self, EditMessageReplyMarkup::new(chat_id, message_id))
+    }
 
     /// For Telegram documentation see [`EditMessageReplyMarkupInline`].
     fn edit_message_reply_markup_inline<I>(
         &self,
         inline_message_id: I,
-    ) -> Self::EditMessageReplyMarkupInline
+    ) -> TargetRequest<'_, Self, EditMessageReplyMarkupInline>
     where
-        I: Into<String>;
+        I: Into<String>,
+    {
+        TargetRequest::new(self, EditMessageReplyMarkupInline::new(inline_message_id))
+    }
+
+    type EditMessageReplyMarkupTarget: Request<
+        Payload = EditMessageReplyMarkupTarget,
+        Err = Self::Err,
+    >;
+
+    /// For Telegram documentation see [`EditMessageReplyMarkupTarget`].
+    fn edit_message_reply_markup_target<T>(&self, target: T) -> Self::EditMessageReplyMarkupTarget
+    where
+        T: Into<TargetMessage>;
 
     type StopPoll: Request<Payload = StopPoll, Err = Self::Err>;
 
@@ -1093,22 +1170,24 @@ pub trait Requester {
     type SendGame: Request<Payload = SendGame, Err = Self::Err>;
 
     /// For Telegram documentation see [`SendGame`].
-    fn send_game<G>(&self, chat_id: u32, game_short_name: G) -> Self::SendGame
+    fn send_game<C, G>(&self, chat_id: C, game_short_name: G) -> Self::SendGame
     where
+        C: Into<ChatId>,
         G: Into<String>;
 
-    type SetGameScore: Request<Payload = SetGameScore, Err = Self::Err>;
-
     /// For Telegram documentation see [`SetGameScore`].
-    fn set_game_score(
+    fn set_game_score<C>(
         &self,
         user_id: UserId,
         score: u64,
-        chat_id: u32,
+        chat_id: C,
         message_id: MessageId,
-    ) -> Self::SetGameScore;
-
-    type SetGameScoreInline: Request<Payload = SetGameScoreInline, Err = Self::Err>;
+    ) -> TargetRequest<'_, Self, SetGameScore>
+    where
+        C: Into<ChatId>,
+    {
+        TargetRequest::new(self, SetGameScore::new(user_id, score, chat_id, message_id))
+    }
 
     /// For Telegram documentation see [`SetGameScoreInline`].
     fn set_game_score_inline<I>(
@@ -1116,9 +1195,24 @@ pub trait Requester {
         user_id: UserId,
         score: u64,
         inline_message_id: I,
-    ) -> Self::SetGameScoreInline
+    ) -> TargetRequest<'_, Self, SetGameScoreInline>
     where
-        I: Into<String>;
+        I: Into<String>,
+    {
+        TargetRequest::new(self, SetGameScoreInline::new(user_id, score, inline_message_id))
+    }
+
+    type SetGameScoreTarget: Request<Payload = SetGameScoreTarget, Err = Self::Err>;
+
+    /// For Telegram documentation see [`SetGameScoreTarget`].
+    fn set_game_score_target<T>(
+        &self,
+        user_id: UserId,
+        score: u64,
+        target: T,
+    ) -> Self::SetGameScoreTarget
+    where
+        T: Into<TargetMessage>;
 
     type GetGameHighScores: Request<Payload = GetGameHighScores, Err = Self::Err>;
 
@@ -1163,10 +1257,8 @@ macro_rules! forward_all {
             send_video_note,
             send_media_group,
             send_location,
-            edit_message_live_location,
-            edit_message_live_location_inline,
-            stop_message_live_location,
-            stop_message_live_location_inline,
+            edit_message_live_location_target,
+            stop_message_live_location_target,
             send_venue,
             send_contact,
             send_poll,
@@ -1224,14 +1316,10 @@ macro_rules! forward_all {
             delete_my_commands,
             answer_inline_query,
             answer_web_app_query,
-            edit_message_text,
-            edit_message_text_inline,
-            edit_message_caption,
-            edit_message_caption_inline,
-            edit_message_media,
-            edit_message_media_inline,
-            edit_message_reply_markup,
-            edit_message_reply_markup_inline,
+            edit_message_text_target,
+            edit_message_caption_target,
+            edit_message_media_target,
+            edit_message_reply_markup_target,
             stop_poll,
             delete_message,
             send_sticker,
@@ -1249,8 +1337,7 @@ macro_rules! forward_all {
             answer_pre_checkout_query,
             set_passport_data_errors,
             send_game,
-            set_game_score,
-            set_game_score_inline,
+            set_game_score_target,
             get_game_high_scores,
             approve_chat_join_request,
             decline_chat_join_request
@@ -1415,6 +1502,28 @@ fn codegen_requester_methods() {
                 format!(" where {where_clause}")
             };
 
+            // Methods with a sibling edit either a message in a chat or an inline
+            // message and are sent with the `_target` method accepting both
+            if m.sibling.is_some() {
+                let arg_names = m
+                    .params
+                    .iter()
+                    .filter(|p| !matches!(p.ty, Type::Option(_)))
+                    .map(|p| &p.name)
+                    .join(", ");
+
+                return format!(
+                    "
+/// For Telegram documentation see [`{Method}`].
+fn {method} {generics} (&self, {args}) -> TargetRequest<'_, Self, {Method}>{where_clause} {{
+    TargetRequest::new(self, {Method}::new({arg_names}))
+}}
+            ",
+                    Method = m.names.1,
+                    method = m.names.2,
+                );
+            }
+
             format!(
                 "
 type {Method}: Request<Payload = {Method}, Err = Self::Err>;
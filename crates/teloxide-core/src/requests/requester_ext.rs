@@ -1,4 +1,8 @@
-use crate::{adaptors::DefaultParseMode, requests::Requester, types::ParseMode};
+use crate::{
+    adaptors::{DefaultParseMode, DefaultProtectContent},
+    requests::{Batch, Requester},
+    types::ParseMode,
+};
 
 #[cfg(feature = "cache_me")]
 use crate::adaptors::CacheMe;
@@ -6,6 +10,9 @@ use crate::adaptors::CacheMe;
 #[cfg(feature = "erased")]
 use crate::adaptors::ErasedRequester;
 
+#[cfg(feature = "file_id_cache")]
+use crate::adaptors::FileIdCache;
+
 #[cfg(feature = "trace_adaptor")]
 use crate::adaptors::trace::{Settings, Trace};
 
@@ -24,6 +31,21 @@ pub trait RequesterExt: Requester {
         CacheMe::new(self)
     }
 
+    /// Add reuse of file ids of uploaded files, see [`FileIdCache`] for more.
+    #[cfg(feature = "file_id_cache")]
+    #[must_use]
+    fn cache_file_ids(self) -> FileIdCache<Self>
+    where
+        Self: Sized,
+    {
+        FileIdCache::new(self)
+    }
+
+    /// Creates an empty [`Batch`] of requests of this requester.
+    fn batch<'a>(&self) -> Batch<'a, Self::Err> {
+        Batch::new()
+    }
+
     /// Erase requester type.
     #[cfg(feature = "erased")]
     #[must_use]
@@ -94,6 +116,24 @@ pub trait RequesterExt: Requester {
     {
         DefaultParseMode::new(self, parse_mode)
     }
+
+    /// Specifies default value of `protect_content` parameter, which will be
+    /// used during all calls to methods that send, copy or forward messages
+    /// (e.g. [`send_message`], [`copy_message`] or [`forward_message`]).
+    ///
+    /// The default is only used if `protect_content` was not set explicitly
+    /// for a request.
+    ///
+    /// [`send_message`]: crate::requests::Requester::send_message
+    /// [`copy_message`]: crate::requests::Requester::copy_message
+    /// [`forward_message`]: crate::requests::Requester::forward_message
+    #[must_use]
+    fn protect_content(self, protect_content: bool) -> DefaultProtectContent<Self>
+    where
+        Self: Sized,
+    {
+        DefaultProtectContent::new(self, protect_content)
+    }
 }
 
 impl<T> RequesterExt for T
@@ -0,0 +1,274 @@
+use std::{
+    future::{Future, IntoFuture},
+    marker::PhantomData,
+    pin::Pin,
+    task::{self, Poll},
+};
+
+use crate::{
+    payloads::{
+        EditMessageCaption, EditMessageCaptionInline, EditMessageCaptionTarget,
+        EditMessageLiveLocation, EditMessageLiveLocationInline, EditMessageLiveLocationTarget,
+        EditMessageMedia, EditMessageMediaInline, EditMessageMediaTarget, EditMessageReplyMarkup,
+        EditMessageReplyMarkupInline, EditMessageReplyMarkupTarget, EditMessageText,
+        EditMessageTextInline, EditMessageTextTarget, SetGameScore, SetGameScoreInline,
+        SetGameScoreTarget, StopMessageLiveLocation, StopMessageLiveLocationInline,
+        StopMessageLiveLocationTarget,
+    },
+    requests::{HasPayload, Output, Payload, Request, Requester},
+    types::{EditedMessage, Message, TargetMessage, True},
+};
+
+/// A payload of a method which edits either a message in a chat or an inline
+/// message (e.g. [`EditMessageText`] or [`EditMessageTextInline`]).
+///
+/// Such payloads are sent with the method which accepts a [`TargetMessage`]
+/// (e.g. [`Requester::edit_message_text_target`]), see [`TargetRequest`].
+pub trait TargetPayload: Payload + Clone {
+    /// The payload of the method which accepts a [`TargetMessage`].
+    type Target: Payload<Output = EditedMessage>;
+
+    /// The request of `B` with [`TargetPayload::Target`] payload.
+    type Request<B: Requester + ?Sized>: Request<Payload = Self::Target, Err = B::Err>;
+
+    /// Creates a request of `bot` with the same parameters as `self`.
+    fn into_request<B: Requester + ?Sized>(self, bot: &B) -> Self::Request<B>;
+
+    /// Converts the output of [`TargetPayload::Target`] into the output of
+    /// `Self`.
+    fn narrow_output(edited: EditedMessage) -> Self::Output;
+}
+
+/// A request which is sent with the method accepting a [`TargetMessage`], but
+/// has the payload and the output of a method which edits a message of a known
+/// kind.
+///
+/// Returned by the [`Requester`] methods which edit either messages in chats
+/// or inline messages (e.g. [`Requester::edit_message_text`] and
+/// [`Requester::edit_message_text_inline`]), so that bot adaptors only need to
+/// implement the method accepting a [`TargetMessage`].
+#[must_use = "Requests are lazy and do nothing unless sent"]
+pub struct TargetRequest<'a, B: ?Sized, P> {
+    bot: &'a B,
+    payload: P,
+}
+
+impl<'a, B: ?Sized, P> TargetRequest<'a, B, P> {
+    pub fn new(bot: &'a B, payload: P) -> Self {
+        Self { bot, payload }
+    }
+}
+
+impl<B: ?Sized, P: Clone> Clone for TargetRequest<'_, B, P> {
+    fn clone(&self) -> Self {
+        Self { bot: self.bot, payload: self.payload.clone() }
+    }
+}
+
+impl<B, P> Request for TargetRequest<'_, B, P>
+where
+    B: Requester + ?Sized,
+    P: TargetPayload,
+{
+    type Err = B::Err;
+    type Send = TargetSend<<P::Request<B> as Request>::Send, P>;
+    type SendRef = TargetSend<<P::Request<B> as Request>::Send, P>;
+
+    fn send(self) -> Self::Send {
+        TargetSend::new(self.payload.into_request(self.bot).send())
+    }
+
+    fn send_ref(&self) -> Self::SendRef {
+        self.clone().send()
+    }
+}
+
+impl<B, P> IntoFuture for TargetRequest<'_, B, P>
+where
+    B: Requester + ?Sized,
+    P: TargetPayload,
+{
+    type Output = Result<Output<Self>, <Self as Request>::Err>;
+    type IntoFuture = <Self as Request>::Send;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.send()
+    }
+}
+
+impl<B, P> HasPayload for TargetRequest<'_, B, P>
+where
+    B: ?Sized,
+    P: Payload,
+{
+    type Payload = P;
+
+    fn payload_mut(&mut self) -> &mut Self::Payload {
+        &mut self.payload
+    }
+
+    fn payload_ref(&self) -> &Self::Payload {
+        &self.payload
+    }
+}
+
+/// Future returned by [`TargetRequest`].
+#[pin_project::pin_project]
+#[must_use = "Futures are lazy and do nothing unless polled or awaited"]
+pub struct TargetSend<F, P> {
+    #[pin]
+    inner: F,
+    _payload: PhantomData<fn() -> P>,
+}
+
+impl<F, P> TargetSend<F, P> {
+    fn new(inner: F) -> Self {
+        Self { inner, _payload: PhantomData }
+    }
+}
+
+impl<F, P, E> Future for TargetSend<F, P>
+where
+    F: Future<Output = Result<EditedMessage, E>>,
+    P: TargetPayload,
+{
+    type Output = Result<P::Output, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        self.project().inner.poll(cx).map_ok(P::narrow_output)
+    }
+}
+
+fn expect_message(edited: EditedMessage) -> Message {
+    match edited {
+        EditedMessage::Common(message) => *message,
+        EditedMessage::Inline(_) => {
+            unreachable!("Telegram returns the edited message for messages in chats")
+        }
+    }
+}
+
+fn expect_true(_: EditedMessage) -> True {
+    True
+}
+
+macro_rules! impl_target_payload {
+    (
+        $(
+            $P:ident => $Target:ident, $method:ident(
+                $($pre:ident,)* @$kind:ident { $($target_field:ident),* } $(, $post:ident)*
+            ) -> $narrow:ident { $($optional:ident),* };
+        )*
+    ) => {
+        $(
+            impl TargetPayload for $P {
+                type Target = $Target;
+                type Request<B: Requester + ?Sized> = B::$Target;
+
+                fn into_request<B: Requester + ?Sized>(self, bot: &B) -> Self::Request<B> {
+                    let $P { $($pre,)* $($target_field,)* $($post,)* $($optional,)* } = self;
+                    let target = impl_target_payload!(@$kind $($target_field),*);
+
+                    bot.$method($($pre,)* target $(, $post)*).with_payload_mut(|payload| {
+                        $(
+                            if $optional.is_some() {
+                                payload.$optional = $optional;
+                            }
+                        )*
+                    })
+                }
+
+                fn narrow_output(edited: EditedMessage) -> Self::Output {
+                    $narrow(edited)
+                }
+            }
+        )*
+    };
+    (@common $chat_id:ident, $message_id:ident) => {
+        TargetMessage::from(($chat_id, $message_id))
+    };
+    (@inline $inline_message_id:ident) => {
+        TargetMessage::from($inline_message_id)
+    };
+}
+
+impl_target_payload! {
+    EditMessageLiveLocation => EditMessageLiveLocationTarget, edit_message_live_location_target(
+        @common { chat_id, message_id }, latitude, longitude
+    ) -> expect_message { horizontal_accuracy, heading, proximity_alert_radius, reply_markup };
+    EditMessageLiveLocationInline => EditMessageLiveLocationTarget, edit_message_live_location_target(
+        @inline { inline_message_id }, latitude, longitude
+    ) -> expect_true { horizontal_accuracy, heading, proximity_alert_radius, reply_markup };
+    StopMessageLiveLocation => StopMessageLiveLocationTarget, stop_message_live_location_target(
+        @common { chat_id, message_id }
+    ) -> expect_message { reply_markup };
+    StopMessageLiveLocationInline => StopMessageLiveLocationTarget, stop_message_live_location_target(
+        @inline { inline_message_id }
+    ) -> expect_true { reply_markup };
+    EditMessageText => EditMessageTextTarget, edit_message_text_target(
+        @common { chat_id, message_id }, text
+    ) -> expect_message { parse_mode, entities, disable_web_page_preview, reply_markup };
+    EditMessageTextInline => EditMessageTextTarget, edit_message_text_target(
+        @inline { inline_message_id }, text
+    ) -> expect_true { parse_mode, entities, disable_web_page_preview, reply_markup };
+    EditMessageCaption => EditMessageCaptionTarget, edit_message_caption_target(
+        @common { chat_id, message_id }
+    ) -> expect_message { caption, parse_mode, caption_entities, reply_markup };
+    EditMessageCaptionInline => EditMessageCaptionTarget, edit_message_caption_target(
+        @inline { inline_message_id }
+    ) -> expect_true { caption, parse_mode, caption_entities, reply_markup };
+    EditMessageMedia => EditMessageMediaTarget, edit_message_media_target(
+        @common { chat_id, message_id }, media
+    ) -> expect_message { reply_markup };
+    EditMessageMediaInline => EditMessageMediaTarget, edit_message_media_target(
+        @inline { inline_message_id }, media
+    ) -> expect_true { reply_markup };
+    EditMessageReplyMarkup => EditMessageReplyMarkupTarget, edit_message_reply_markup_target(
+        @common { chat_id, message_id }
+    ) -> expect_message { reply_markup };
+    EditMessageReplyMarkupInline => EditMessageReplyMarkupTarget, edit_message_reply_markup_target(
+        @inline { inline_message_id }
+    ) -> expect_true { reply_markup };
+    SetGameScore => SetGameScoreTarget, set_game_score_target(
+        user_id, score, @common { chat_id, message_id }
+    ) -> expect_message { force, disable_edit_message };
+    SetGameScoreInline => SetGameScoreTarget, set_game_score_target(
+        user_id, score, @inline { inline_message_id }
+    ) -> expect_true { force, disable_edit_message };
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(all(feature = "mock_bot", feature = "test_fixtures"))]
+    #[tokio::test]
+    async fn sends_target_requests() {
+        use crate::{
+            adaptors::MockBot,
+            payloads::{EditMessageTextSetters, EditMessageTextTarget},
+            prelude::*,
+            types::{
+                ChatId, EditedMessage, Message, MessageId, ParseMode, TargetMessage, True, UserId,
+            },
+        };
+
+        let bot = MockBot::new();
+        let message = Message::text_fixture(ChatId(1), UserId(1), "edited");
+        bot.respond::<EditMessageTextTarget>(EditedMessage::Common(Box::new(message.clone())));
+        bot.respond::<EditMessageTextTarget>(EditedMessage::Inline(True));
+
+        let edited = bot
+            .edit_message_text(ChatId(1), MessageId(2), "edited")
+            .parse_mode(ParseMode::Html)
+            .await
+            .unwrap();
+        assert_eq!(edited, message);
+        bot.edit_message_text_inline("inline", "edited").await.unwrap();
+
+        let requests = bot.requests_of::<EditMessageTextTarget>();
+        assert_eq!(bot.request_names(), ["EditMessageTextTarget", "EditMessageTextTarget"]);
+        assert_eq!(requests[0].target, TargetMessage::from((ChatId(1), MessageId(2))));
+        assert_eq!(requests[0].parse_mode, Some(ParseMode::Html));
+        assert_eq!(requests[1].target, TargetMessage::from(String::from("inline")));
+        assert_eq!(requests[1].parse_mode, None);
+    }
+}
@@ -24,8 +24,15 @@ use serializers::MultipartSerializer;
 
 /// Serializes given value into [`Form`] **taking all input files out**.
 ///
+/// `client` is used to download files which are uploaded by the bot (see
+/// [`InputFile::download_and_upload`]).
+///
+/// [`InputFile::download_and_upload`]: crate::types::InputFile::download_and_upload
 /// [`Form`]:  reqwest::multipart::Form
-pub(crate) fn to_form<T>(val: &mut T) -> Result<impl Future<Output = Form>, Error>
+pub(crate) fn to_form<T>(
+    val: &mut T,
+    client: reqwest::Client,
+) -> Result<impl Future<Output = Form>, Error>
 where
     T: Serialize + MultipartPayload,
 {
@@ -39,7 +46,7 @@ where
         for file in iter {
             if file.needs_attach() {
                 let id = file.id().to_owned();
-                if let Some(part) = file.into_part() {
+                if let Some(part) = file.into_part(&client) {
                     form = form.part(id, part.await);
                 }
             }
@@ -54,7 +61,10 @@ where
 /// Serializes given value into [`Form`].
 ///
 /// [`Form`]:  reqwest::multipart::Form
-pub(crate) fn to_form_ref<T: ?Sized>(val: &T) -> Result<impl Future<Output = Form>, Error>
+pub(crate) fn to_form_ref<T: ?Sized>(
+    val: &T,
+    client: reqwest::Client,
+) -> Result<impl Future<Output = Form>, Error>
 where
     T: Serialize + MultipartPayload,
 {
@@ -68,7 +78,7 @@ where
         for file in iter {
             if file.needs_attach() {
                 let id = file.id().to_owned();
-                if let Some(part) = file.into_part() {
+                if let Some(part) = file.into_part(&client) {
                     form = form.part(id, part.await);
                 }
             }
@@ -87,6 +97,7 @@ mod tests {
     use super::to_form_ref;
     use crate::{
         payloads::{self, setters::*},
+        requests::MultipartPayload,
         types::{
             ChatId, InputFile, InputMedia, InputMediaAnimation, InputMediaAudio,
             InputMediaDocument, InputMediaPhoto, InputMediaVideo, InputSticker, MessageEntity,
@@ -101,6 +112,7 @@ mod tests {
             &payloads::SendPhoto::new(ChatId(0), InputFile::file_id("0")).caption_entities([
                 MessageEntity { kind: MessageEntityKind::Url, offset: 0, length: 0 },
             ]),
+            reqwest::Client::new(),
         )
         .unwrap()
         .await;
@@ -110,48 +122,56 @@ mod tests {
     async fn test_send_media_group() {
         const CAPTION: &str = "caption";
 
-        to_form_ref(&payloads::SendMediaGroup::new(
-            ChatId(0),
-            [
-                InputMedia::Photo(
-                    InputMediaPhoto::new(InputFile::file("../../media/teloxide-core-logo.png"))
-                        .caption(CAPTION)
-                        .parse_mode(ParseMode::MarkdownV2)
-                        .caption_entities(entities()),
-                ),
-                InputMedia::Video(
-                    InputMediaVideo::new(InputFile::file_id("17")).supports_streaming(true),
-                ),
-                InputMedia::Animation(
-                    InputMediaAnimation::new(InputFile::read(
-                        File::open("../../media/example.gif").await.unwrap(),
-                    ))
-                    .thumb(InputFile::read(
-                        File::open("../../media/teloxide-core-logo.png").await.unwrap(),
-                    ))
-                    .duration(17),
-                ),
-                InputMedia::Audio(
-                    InputMediaAudio::new(InputFile::url("https://example.com".parse().unwrap()))
+        to_form_ref(
+            &payloads::SendMediaGroup::new(
+                ChatId(0),
+                [
+                    InputMedia::Photo(
+                        InputMediaPhoto::new(InputFile::file("../../media/teloxide-core-logo.png"))
+                            .caption(CAPTION)
+                            .parse_mode(ParseMode::MarkdownV2)
+                            .caption_entities(entities()),
+                    ),
+                    InputMedia::Video(
+                        InputMediaVideo::new(InputFile::file_id("17")).supports_streaming(true),
+                    ),
+                    InputMedia::Animation(
+                        InputMediaAnimation::new(InputFile::read(
+                            File::open("../../media/example.gif").await.unwrap(),
+                        ))
+                        .thumbnail(InputFile::read(
+                            File::open("../../media/teloxide-core-logo.png").await.unwrap(),
+                        ))
+                        .duration(17),
+                    ),
+                    InputMedia::Audio(
+                        InputMediaAudio::new(InputFile::url(
+                            "https://example.com".parse().unwrap(),
+                        ))
                         .performer("a"),
-                ),
-                InputMedia::Document(InputMediaDocument::new(InputFile::memory(
-                    &b"Hello world!"[..],
-                ))),
-            ],
-        ))
+                    ),
+                    InputMedia::Document(InputMediaDocument::new(InputFile::memory(
+                        &b"Hello world!"[..],
+                    ))),
+                ],
+            ),
+            reqwest::Client::new(),
+        )
         .unwrap()
         .await;
     }
 
     #[tokio::test]
     async fn test_add_sticker_to_set() {
-        to_form_ref(&payloads::AddStickerToSet::new(
-            UserId(0),
-            "name",
-            InputSticker::Png(InputFile::file("../../media/teloxide-core-logo.png")),
-            "✈️⚙️",
-        ))
+        to_form_ref(
+            &payloads::AddStickerToSet::new(
+                UserId(0),
+                "name",
+                InputSticker::Png(InputFile::file("../../media/teloxide-core-logo.png")),
+                "✈️⚙️",
+            ),
+            reqwest::Client::new(),
+        )
         .unwrap()
         .await;
     }
@@ -164,13 +184,42 @@ mod tests {
                 InputFile::file("../../media/teloxide-core-logo.png"),
             )
             .caption_entities(entities())
-            .thumb(InputFile::read(File::open("../../media/teloxide-core-logo.png").await.unwrap()))
+            .thumbnail(InputFile::read(
+                File::open("../../media/teloxide-core-logo.png").await.unwrap(),
+            ))
             .allow_sending_without_reply(true),
+            reqwest::Client::new(),
         )
         .unwrap()
         .await;
     }
 
+    #[test]
+    fn thumbnails_are_attached() {
+        let mut video = payloads::SendVideo::new(ChatId(0), InputFile::memory(&b"video"[..]))
+            .thumbnail(InputFile::memory(&b"thumbnail"[..]));
+        let json = serde_json::to_value(&video).unwrap();
+        assert!(json["thumbnail"].as_str().unwrap().starts_with("attach://"));
+
+        let mut files = Vec::new();
+        video.move_files(&mut |f| files.push(f));
+        assert_eq!(files.len(), 2);
+
+        let mut group = payloads::SendMediaGroup::new(
+            ChatId(0),
+            [InputMedia::Document(
+                InputMediaDocument::new(InputFile::file_id("0"))
+                    .thumbnail(InputFile::memory(&b"thumbnail"[..])),
+            )],
+        );
+        let json = serde_json::to_value(&group).unwrap();
+        assert!(json["media"][0]["thumbnail"].as_str().unwrap().starts_with("attach://"));
+
+        let mut files = Vec::new();
+        group.move_files(&mut |f| files.push(f));
+        assert_eq!(files.iter().filter(|f| f.needs_attach()).count(), 1);
+    }
+
     fn entities() -> impl Iterator<Item = MessageEntity> {
         <_>::into_iter([
             MessageEntity::new(MessageEntityKind::Url, 0, 0),
@@ -27,6 +27,7 @@ pub use document::*;
 pub use encrypted_credentials::*;
 pub use encrypted_passport_element::*;
 pub use file::*;
+pub use file_id::*;
 pub use force_reply::*;
 pub use forum_topic::*;
 pub use forum_topic_closed::*;
@@ -66,15 +67,18 @@ pub use input_media::*;
 pub use input_message_content::*;
 pub use input_sticker::*;
 pub use invoice::*;
+pub use invoice_builder::*;
 pub use keyboard_button::*;
 pub use keyboard_button_poll_type::*;
 pub use keyboard_button_request_chat::*;
 pub use keyboard_button_request_user::*;
+pub use keyboard_button_request_users::*;
 pub use label_price::*;
 pub use location::*;
 pub use login_url::*;
 pub use mask_position::*;
 pub use me::*;
+pub use media_group::*;
 pub use menu_button::*;
 pub use message::*;
 pub use message_auto_delete_timer_changed::*;
@@ -91,6 +95,7 @@ pub use poll_answer::*;
 pub use poll_type::*;
 pub use pre_checkout_query::*;
 pub use proximity_alert_triggered::*;
+pub use reaction_type::*;
 pub use reply_keyboard_markup::*;
 pub use reply_keyboard_remove::*;
 pub use reply_markup::*;
@@ -102,6 +107,7 @@ pub use shipping_query::*;
 pub use sticker::*;
 pub use sticker_set::*;
 pub use successful_payment::*;
+pub use switch_inline_query_chosen_chat::*;
 pub use target_message::*;
 pub use thread_id::*;
 pub use unit_false::*;
@@ -110,6 +116,7 @@ pub use update::*;
 pub use user::*;
 pub use user_profile_photos::*;
 pub use user_shared::*;
+pub use users_shared::*;
 pub use venue::*;
 pub use video::*;
 pub use video_chat_ended::*;
@@ -148,6 +155,7 @@ mod dice;
 mod dice_emoji;
 mod document;
 mod file;
+mod file_id;
 mod force_reply;
 mod forum_topic;
 mod forum_topic_closed;
@@ -165,15 +173,18 @@ mod input_media;
 mod input_message_content;
 mod input_sticker;
 mod invoice;
+mod invoice_builder;
 mod keyboard_button;
 mod keyboard_button_poll_type;
 mod keyboard_button_request_chat;
 mod keyboard_button_request_user;
+mod keyboard_button_request_users;
 mod label_price;
 mod location;
 mod login_url;
 mod mask_position;
 mod me;
+mod media_group;
 mod menu_button;
 mod message;
 mod message_auto_delete_timer_changed;
@@ -187,6 +198,7 @@ mod poll_answer;
 mod poll_type;
 mod pre_checkout_query;
 mod proximity_alert_triggered;
+mod reaction_type;
 mod reply_keyboard_markup;
 mod reply_keyboard_remove;
 mod reply_markup;
@@ -198,6 +210,7 @@ mod shipping_query;
 mod sticker;
 mod sticker_set;
 mod successful_payment;
+mod switch_inline_query_chosen_chat;
 mod target_message;
 mod thread_id;
 mod unit_false;
@@ -206,6 +219,7 @@ mod update;
 mod user;
 mod user_profile_photos;
 mod user_shared;
+mod users_shared;
 mod venue;
 mod video;
 mod video_chat_ended;
@@ -248,10 +262,11 @@ mod passport_data;
 mod passport_element_error;
 mod passport_file;
 
-pub use non_telegram_types::{country_code::*, currency::*, until_date::*};
+pub use non_telegram_types::{country_code::*, currency::*, currency_amount::*, until_date::*};
 mod non_telegram_types {
     pub(super) mod country_code;
     pub(super) mod currency;
+    pub(super) mod currency_amount;
     pub(crate) mod mime;
     pub(super) mod until_date;
 }
@@ -266,7 +281,11 @@ pub use recipient::*;
 pub use seconds::*;
 pub use user_id::*;
 
-use serde::Serialize;
+#[cfg(feature = "test_fixtures")]
+mod fixtures;
+
+#[cfg(test)]
+pub(crate) mod testing;
 
 /// Converts an `i64` timestamp to a `choro::DateTime`, producing serde error
 /// for invalid timestamps
@@ -346,7 +365,7 @@ pub(crate) mod serde_date_from_unix_timestamp {
 }
 
 pub(crate) mod option_url_from_string {
-    use reqwest::Url;
+    use url::Url;
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
     pub(crate) fn serialize<S>(this: &Option<Url>, serializer: S) -> Result<S::Ok, S::Error>
@@ -363,7 +382,7 @@ pub(crate) mod option_url_from_string {
     where
         D: Deserializer<'de>,
     {
-        Ok(reqwest::Url::deserialize(deserializer).ok())
+        Ok(url::Url::deserialize(deserializer).ok())
     }
 
     #[test]
@@ -425,16 +444,6 @@ pub(crate) mod option_msg_id_as_int {
     }
 }
 
-pub(crate) fn serialize_reply_to_message_id<S>(
-    this: &Option<MessageId>,
-    serializer: S,
-) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    this.map(|MessageId(id)| id).serialize(serializer)
-}
-
 pub(crate) mod serde_rgb {
     use serde::{de::Visitor, Deserializer, Serializer};
 
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "network")]
+use url::Url;
 
-use crate::types::{Message, User};
+use crate::types::{Message, TargetMessage, User};
+#[cfg(feature = "network")]
+use crate::{payloads::AnswerCallbackQuerySetters, requests::Requester};
 
 /// This object represents an incoming callback query from a callback button in
 /// an [inline keyboard].
@@ -60,11 +64,76 @@ impl CallbackQuery {
 
         once(&self.from).chain(flatten(self.message.as_ref().map(Message::mentioned_users)))
     }
+
+    /// Returns the message with the callback button that originated the query.
+    ///
+    /// The result can be passed to methods accepting a [`TargetMessage`], such
+    /// as [`get_game_high_scores`]. Returns `None` if the query has neither
+    /// `message` nor `inline_message_id`.
+    ///
+    /// [`get_game_high_scores`]: crate::requests::Requester::get_game_high_scores
+    #[must_use]
+    pub fn target_message(&self) -> Option<TargetMessage> {
+        match (&self.message, &self.inline_message_id) {
+            (Some(message), _) => Some(message.into()),
+            (None, Some(id)) => Some(id.clone().into()),
+            (None, None) => None,
+        }
+    }
+
+    /// Creates a request which answers this query without any notification.
+    ///
+    /// Every callback query must be answered, otherwise the user's client
+    /// shows a progress bar until the query times out.
+    #[cfg(feature = "network")]
+    pub fn answer<B>(&self, bot: &B) -> B::AnswerCallbackQuery
+    where
+        B: Requester,
+    {
+        bot.answer_callback_query(&self.id)
+    }
+
+    /// Creates a request which answers this query with a notification at the
+    /// top of the chat screen.
+    #[cfg(feature = "network")]
+    pub fn answer_text<B, T>(&self, bot: &B, text: T) -> B::AnswerCallbackQuery
+    where
+        B: Requester,
+        T: Into<String>,
+    {
+        self.answer(bot).text(text)
+    }
+
+    /// Creates a request which answers this query with an alert.
+    #[cfg(feature = "network")]
+    pub fn answer_alert<B, T>(&self, bot: &B, text: T) -> B::AnswerCallbackQuery
+    where
+        B: Requester,
+        T: Into<String>,
+    {
+        self.answer(bot).text(text).show_alert(true)
+    }
+
+    /// Creates a request which answers this query with a URL, which will be
+    /// opened by the user's client.
+    ///
+    /// See [`AnswerCallbackQuery::url`] for the allowed URLs.
+    ///
+    /// [`AnswerCallbackQuery::url`]: crate::payloads::AnswerCallbackQuery::url
+    #[cfg(feature = "network")]
+    pub fn answer_url<B>(&self, bot: &B, url: Url) -> B::AnswerCallbackQuery
+    where
+        B: Requester,
+    {
+        self.answer(bot).url(url)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::types::UserId;
+    #[cfg(feature = "network")]
+    use crate::{payloads::AnswerCallbackQuery, requests::HasPayload, Bot};
 
     use super::*;
 
@@ -103,4 +172,39 @@ mod tests {
         let actual = serde_json::from_str::<CallbackQuery>(json).unwrap();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    #[cfg(feature = "network")]
+    fn answer_shortcuts() {
+        let bot = Bot::new("TOKEN");
+        let query = CallbackQuery {
+            id: "id".to_owned(),
+            from: User {
+                id: UserId(1),
+                is_bot: false,
+                first_name: "A".to_owned(),
+                last_name: None,
+                username: None,
+                language_code: None,
+                is_premium: false,
+                added_to_attachment_menu: false,
+            },
+            message: None,
+            inline_message_id: None,
+            chat_instance: "1".to_owned(),
+            data: None,
+            game_short_name: None,
+        };
+
+        let req = query.answer_alert(&bot, "text");
+        let payload: &AnswerCallbackQuery = req.payload_ref();
+        assert_eq!(payload.callback_query_id, "id");
+        assert_eq!(payload.text.as_deref(), Some("text"));
+        assert_eq!(payload.show_alert, Some(true));
+
+        let req = query.answer_text(&bot, "text");
+        assert_eq!(req.payload_ref().show_alert, None);
+
+        assert_eq!(query.target_message(), None);
+    }
 }
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::types::{
-    ChatId, ChatLocation, ChatPermissions, ChatPhoto, Message, Seconds, True, User,
+    ChatId, ChatLocation, ChatPermissions, ChatPhoto, Message, ReactionType, Seconds, True, User,
 };
 
 /// This object represents a chat.
@@ -47,6 +47,12 @@ pub struct Chat {
     /// [`GetChat`]: crate::payloads::GetChat
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub has_aggressive_anti_spam_enabled: bool,
+
+    /// List of available reactions allowed in the chat. If omitted, then all
+    /// emoji reactions are allowed. Returned only in [`GetChat`].
+    ///
+    /// [`GetChat`]: crate::payloads::GetChat
+    pub available_reactions: Option<Vec<ReactionType>>,
 }
 
 #[serde_with_macros::skip_serializing_none]
@@ -287,6 +293,33 @@ impl Chat {
         }
     }
 
+    /// If non-empty, the list of all active chat usernames, for supergroups.
+    /// Returned only in [`GetChat`].
+    ///
+    /// [`GetChat`]: crate::payloads::GetChat
+    #[must_use]
+    pub fn active_usernames(&self) -> Option<&[String]> {
+        if let ChatKind::Public(this) = &self.kind {
+            if let PublicChatKind::Supergroup(this) = &this.kind {
+                return this.active_usernames.as_deref();
+            }
+        }
+
+        None
+    }
+
+    /// `true`, if the supergroup chat is a forum (has topics enabled).
+    #[must_use]
+    pub fn is_forum(&self) -> bool {
+        if let ChatKind::Public(this) = &self.kind {
+            if let PublicChatKind::Supergroup(this) = &this.kind {
+                return this.is_forum;
+            }
+        }
+
+        false
+    }
+
     /// Unique identifier for the linked chat, i.e. the discussion group
     /// identifier for a channel and vice versa. Returned only in [`GetChat`].
     ///
@@ -496,6 +529,31 @@ impl Chat {
         }
     }
 
+    /// `True`, if the privacy settings of the other party restrict sending
+    /// voice and video note messages in the private chat. Returned only in
+    /// [`GetChat`].
+    ///
+    /// [`GetChat`]: crate::payloads::GetChat
+    #[must_use]
+    pub fn has_restricted_voice_and_video_messages(&self) -> Option<True> {
+        match &self.kind {
+            ChatKind::Private(this) => this.has_restricted_voice_and_video_messages,
+            _ => None,
+        }
+    }
+
+    /// Custom emoji identifier of emoji status of the other party in a private
+    /// chat. Returned only in [`GetChat`].
+    ///
+    /// [`GetChat`]: crate::payloads::GetChat
+    #[must_use]
+    pub fn emoji_status_custom_emoji_id(&self) -> Option<&str> {
+        match &self.kind {
+            ChatKind::Private(this) => this.emoji_status_custom_emoji_id.as_deref(),
+            _ => None,
+        }
+    }
+
     /// Returns all users that are "contained" in this `Chat`
     /// structure.
     ///
@@ -615,6 +673,7 @@ mod tests {
             message_auto_delete_time: None,
             has_hidden_members: false,
             has_aggressive_anti_spam_enabled: false,
+            available_reactions: None,
         };
         let actual = from_str(r#"{"id":-1,"type":"channel","username":"channel_name"}"#).unwrap();
         assert_eq!(expected, actual);
@@ -639,6 +698,7 @@ mod tests {
                 message_auto_delete_time: None,
                 has_hidden_members: false,
                 has_aggressive_anti_spam_enabled: false,
+                available_reactions: None,
             },
             from_str(r#"{"id":0,"type":"private","username":"username","first_name":"Anon"}"#)
                 .unwrap()
@@ -663,6 +723,7 @@ mod tests {
             message_auto_delete_time: None,
             has_hidden_members: false,
             has_aggressive_anti_spam_enabled: false,
+            available_reactions: None,
         };
 
         let json = to_string(&chat).unwrap();
@@ -671,6 +732,34 @@ mod tests {
         assert_eq!(chat, chat2);
     }
 
+    #[test]
+    fn supergroup_getters() {
+        let chat: Chat = from_str(
+            r#"{"id":-1,"type":"supergroup","title":"Forum","is_forum":true,"active_usernames":["forum"],"slow_mode_delay":10,"linked_chat_id":-2}"#,
+        )
+        .unwrap();
+
+        assert!(chat.is_forum());
+        assert_eq!(chat.active_usernames(), Some(&["forum".to_owned()][..]));
+        assert_eq!(chat.slow_mode_delay(), Some(Seconds::from_seconds(10)));
+        assert_eq!(chat.linked_chat_id(), Some(-2));
+        assert_eq!(chat.bio(), None);
+
+        let json = to_string(&chat).unwrap();
+        assert_eq!(from_str::<Chat>(&json).unwrap(), chat);
+    }
+
+    #[test]
+    fn available_reactions_de() {
+        let chat: Chat = from_str(
+            r#"{"id":-1,"type":"group","title":"Group","available_reactions":[{"type":"emoji","emoji":"👍"}]}"#,
+        )
+        .unwrap();
+
+        let expected = [ReactionType::Emoji { emoji: "👍".to_owned() }];
+        assert_eq!(chat.available_reactions.as_deref(), Some(&expected[..]));
+    }
+
     #[test]
     fn private_chat_de_wrong_type_field() {
         assert!(from_str::<Chat>(r#"{"id":0,"type":"WRONG"}"#).is_err());
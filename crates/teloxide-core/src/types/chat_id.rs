@@ -59,6 +59,46 @@ impl ChatId {
         }
     }
 
+    /// Returns the internal id of a channel or a supergroup, if this is an id
+    /// of one.
+    ///
+    /// Internal ids are used by Telegram clients, e.g. in
+    /// `https://t.me/c/<id>/<message id>` links to messages.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::ChatId;
+    ///
+    /// assert_eq!(ChatId(-1001234567890).as_channel_internal_id(), Some(1234567890));
+    /// assert_eq!(ChatId(-1234567890).as_channel_internal_id(), None);
+    /// assert_eq!(ChatId(1234567890).as_channel_internal_id(), None);
+    /// ```
+    #[must_use]
+    pub fn as_channel_internal_id(self) -> Option<u64> {
+        match self.to_bare() {
+            BareChatId::Channel(id) => Some(id),
+            BareChatId::User(_) | BareChatId::Group(_) => None,
+        }
+    }
+
+    /// Returns the id of a channel or a supergroup with the internal id `id`.
+    ///
+    /// This is the inverse of [`ChatId::as_channel_internal_id`], e.g. to get
+    /// the chat of a `https://t.me/c/<id>/<message id>` link.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::ChatId;
+    ///
+    /// assert_eq!(ChatId::from_channel_internal_id(1234567890), ChatId(-1001234567890));
+    /// ```
+    #[must_use]
+    pub fn from_channel_internal_id(id: u64) -> Self {
+        BareChatId::Channel(id).to_bot_api()
+    }
+
     /// Converts this id to "bare" MTProto peer id.
     ///
     /// See [`BareChatId`] for more.
@@ -90,7 +130,6 @@ impl PartialEq<UserId> for ChatId {
 
 impl BareChatId {
     /// Converts bare chat id back to normal bot API [`ChatId`].
-    #[allow(unused)]
     pub(crate) fn to_bot_api(self) -> ChatId {
         use BareChatId::*;
 
@@ -154,6 +193,17 @@ mod tests {
         ids.iter().copied().for_each(assert_identity);
     }
 
+    #[test]
+    fn channel_internal_id() {
+        let id = ChatId(-1001555296434);
+        assert!(id.is_channel_or_supergroup());
+        assert_eq!(id.as_channel_internal_id(), Some(1555296434));
+        assert_eq!(ChatId::from_channel_internal_id(1555296434), id);
+
+        assert_eq!(ChatId(-599075523).as_channel_internal_id(), None);
+        assert_eq!(ChatId(599075523).as_channel_internal_id(), None);
+    }
+
     #[test]
     fn display() {
         assert_eq!(ChatId(1).to_string(), "1");
@@ -2,7 +2,7 @@ use std::ops::Deref;
 
 use serde::{Deserialize, Serialize};
 
-use crate::types::{UntilDate, User};
+use crate::types::{ChatPermissions, UntilDate, User};
 
 /// This object contains information about one member of the chat.
 ///
@@ -164,6 +164,32 @@ pub struct Restricted {
     pub can_send_polls: bool,
 }
 
+impl Restricted {
+    /// Returns permissions of the restricted user.
+    #[must_use]
+    pub fn permissions(&self) -> ChatPermissions {
+        [
+            (self.can_send_messages, ChatPermissions::SEND_MESSAGES),
+            (self.can_send_audios, ChatPermissions::SEND_AUDIOS),
+            (self.can_send_documents, ChatPermissions::SEND_DOCUMENTS),
+            (self.can_send_photos, ChatPermissions::SEND_PHOTOS),
+            (self.can_send_videos, ChatPermissions::SEND_VIDEOS),
+            (self.can_send_video_notes, ChatPermissions::SEND_VIDEO_NOTES),
+            (self.can_send_voice_notes, ChatPermissions::SEND_VOICE_NOTES),
+            (self.can_send_polls, ChatPermissions::SEND_POLLS),
+            (self.can_send_other_messages, ChatPermissions::SEND_OTHER_MESSAGES),
+            (self.can_add_web_page_previews, ChatPermissions::ADD_WEB_PAGE_PREVIEWS),
+            (self.can_change_info, ChatPermissions::CHANGE_INFO),
+            (self.can_invite_users, ChatPermissions::INVITE_USERS),
+            (self.can_pin_messages, ChatPermissions::PIN_MESSAGES),
+            (self.can_manage_topics, ChatPermissions::MANAGE_TOPICS),
+        ]
+        .into_iter()
+        .filter(|&(allowed, _)| allowed)
+        .fold(ChatPermissions::empty(), |permissions, (_, permission)| permissions | permission)
+    }
+}
+
 /// User that was banned in the chat and can't return to it or view chat
 /// messages. This struct is part of the [`ChatMemberKind`] enum.
 #[serde_with_macros::skip_serializing_none]
@@ -468,6 +494,90 @@ impl ChatMemberKind {
         }
     }
 
+    /// Returns `true` if the user can change the chat title, photo and other
+    /// settings as an administrator.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat
+    /// - is an administrator in the given chat and has the [`can_change_info`]
+    ///   privilege.
+    /// Returns `false` otherwise. Non-administrators may also be permitted to
+    /// do this, see [`effective_permissions`].
+    ///
+    /// [`can_change_info`]: Administrator::can_change_info
+    /// [`effective_permissions`]: ChatMemberKind::effective_permissions
+    #[must_use]
+    pub fn can_change_info(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_change_info, .. }) => *can_change_info,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Returns `true` if the user can invite new users to the chat as an
+    /// administrator.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat
+    /// - is an administrator in the given chat and has the [`can_invite_users`]
+    ///   privilege.
+    /// Returns `false` otherwise. Non-administrators may also be permitted to
+    /// do this, see [`effective_permissions`].
+    ///
+    /// [`can_invite_users`]: Administrator::can_invite_users
+    /// [`effective_permissions`]: ChatMemberKind::effective_permissions
+    #[must_use]
+    pub fn can_invite_users(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_invite_users, .. }) => *can_invite_users,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Returns `true` if the user can pin messages as an administrator,
+    /// supergroups only.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat
+    /// - is an administrator in the given chat and has the [`can_pin_messages`]
+    ///   privilege.
+    /// Returns `false` otherwise. Non-administrators may also be permitted to
+    /// do this, see [`effective_permissions`].
+    ///
+    /// [`can_pin_messages`]: Administrator::can_pin_messages
+    /// [`effective_permissions`]: ChatMemberKind::effective_permissions
+    #[must_use]
+    pub fn can_pin_messages(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_pin_messages, .. }) => *can_pin_messages,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
+    /// Returns `true` if the user can create, rename, close, and reopen forum
+    /// topics as an administrator, supergroups only.
+    ///
+    /// I.e. returns `true` if the user
+    /// - is the owner of the chat
+    /// - is an administrator in the given chat and has the
+    ///   [`can_manage_topics`] privilege.
+    /// Returns `false` otherwise. Non-administrators may also be permitted to
+    /// do this, see [`effective_permissions`].
+    ///
+    /// [`can_manage_topics`]: Administrator::can_manage_topics
+    /// [`effective_permissions`]: ChatMemberKind::effective_permissions
+    #[must_use]
+    pub fn can_manage_topics(&self) -> bool {
+        match self {
+            Self::Owner(_) => true,
+            Self::Administrator(Administrator { can_manage_topics, .. }) => *can_manage_topics,
+            Self::Member | Self::Restricted(_) | Self::Left | Self::Banned(_) => false,
+        }
+    }
+
     /// Returns `true` if the user can add new administrators with a subset of
     /// his own privileges or demote administrators that he has promoted,
     /// directly or indirectly (promoted by administrators that were appointed
@@ -490,6 +600,33 @@ impl ChatMemberKind {
     }
 }
 
+/// Methods for checking user permissions.
+impl ChatMemberKind {
+    /// Returns what the user is allowed to do in a chat with the given default
+    /// `chat_permissions`.
+    ///
+    /// I.e. returns
+    /// - all permissions if the user is the owner or an administrator of the
+    ///   chat
+    /// - `chat_permissions` if the user is a common member of the chat
+    /// - [`Restricted::permissions`] limited by `chat_permissions` if the user
+    ///   is restricted in the chat
+    /// - no permissions if the user has left or was banned.
+    ///
+    /// Default permissions of a chat are returned by [`Chat::permissions`].
+    ///
+    /// [`Chat::permissions`]: crate::types::Chat::permissions
+    #[must_use]
+    pub fn effective_permissions(&self, chat_permissions: ChatPermissions) -> ChatPermissions {
+        match self {
+            Self::Owner(_) | Self::Administrator(_) => ChatPermissions::all(),
+            Self::Member => chat_permissions,
+            Self::Restricted(restricted) => restricted.permissions() & chat_permissions,
+            Self::Left | Self::Banned(_) => ChatPermissions::empty(),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ChatMemberStatus {
@@ -709,4 +846,51 @@ mod tests {
         let actual = serde_json::from_str::<ChatMember>(json).unwrap();
         assert_eq!(actual, expected)
     }
+
+    #[test]
+    fn permissions() {
+        let restricted = Restricted {
+            until_date: UntilDate::Forever,
+            is_member: true,
+            can_send_messages: true,
+            can_send_audios: false,
+            can_send_documents: false,
+            can_send_photos: true,
+            can_send_videos: false,
+            can_send_video_notes: false,
+            can_send_voice_notes: false,
+            can_send_other_messages: false,
+            can_add_web_page_previews: false,
+            can_change_info: false,
+            can_invite_users: true,
+            can_pin_messages: false,
+            can_manage_topics: false,
+            can_send_polls: false,
+        };
+        let chat_permissions = ChatPermissions::SEND_MEDIA_MESSAGES;
+
+        assert_eq!(
+            restricted.permissions(),
+            ChatPermissions::SEND_MESSAGES
+                | ChatPermissions::SEND_PHOTOS
+                | ChatPermissions::INVITE_USERS
+        );
+        assert_eq!(
+            ChatMemberKind::Restricted(restricted).effective_permissions(chat_permissions),
+            ChatPermissions::SEND_MESSAGES | ChatPermissions::SEND_PHOTOS
+        );
+        assert_eq!(
+            ChatMemberKind::Member.effective_permissions(chat_permissions),
+            chat_permissions
+        );
+        assert_eq!(
+            ChatMemberKind::Left.effective_permissions(chat_permissions),
+            ChatPermissions::empty()
+        );
+
+        let owner = ChatMemberKind::Owner(Owner { custom_title: None, is_anonymous: false });
+        assert!(owner.can_pin_messages() && owner.can_change_info());
+        assert_eq!(owner.effective_permissions(chat_permissions), ChatPermissions::all());
+        assert!(!ChatMemberKind::Member.can_invite_users());
+    }
 }
@@ -26,23 +26,22 @@ bitflags::bitflags! {
     /// assert!(!permissions_v0.contains(ChatPermissions::SEND_MESSAGES));
     ///
     /// // Union, add permissions
-    /// let permissions_v1 = permissions_v0 | ChatPermissions::SEND_VIDEOS;
+    /// let permissions_v1 = permissions_v0.with(ChatPermissions::SEND_VIDEOS);
     /// assert!(permissions_v1.contains(ChatPermissions::INVITE_USERS));
     /// assert!(permissions_v1.contains(ChatPermissions::SEND_VIDEOS));
     ///
-    /// // Implied by `SEND_VIDEOS`
-    /// assert!(permissions_v1.contains(ChatPermissions::SEND_MESSAGES));
+    /// // Media permissions are independent from `SEND_MESSAGES`
+    /// assert!(!permissions_v1.contains(ChatPermissions::SEND_MESSAGES));
     ///
     /// // Difference, remove permissions
-    /// let permissions_v2 = permissions_v1 - ChatPermissions::SEND_VIDEOS;
+    /// let permissions_v2 = permissions_v1.without(ChatPermissions::SEND_VIDEOS);
     /// assert!(!permissions_v2.contains(ChatPermissions::SEND_VIDEOS));
+    /// assert!(permissions_v2.contains(ChatPermissions::INVITE_USERS));
     ///
-    /// // Removing `SEND_VIDEOS` also removes `SEND_MESSAGES` and vice versa
-    /// // because `SEND_MESSAGES` is implied by `SEND_VIDEOS`
-    /// assert!(!permissions_v2.contains(ChatPermissions::SEND_MESSAGES));
-    ///
-    /// let permissions_v3 = permissions_v1 - ChatPermissions::SEND_MESSAGES;
-    /// assert!(!permissions_v3.contains(ChatPermissions::SEND_VIDEOS));
+    /// // Permissions that Telegram grants if `use_independent_chat_permissions`
+    /// // is not set
+    /// let permissions_v3 = ChatPermissions::SEND_OTHER_MESSAGES.implied();
+    /// assert!(permissions_v3.contains(ChatPermissions::SEND_MEDIA_MESSAGES));
     /// ```
     #[derive(Serialize, Deserialize)]
     #[serde(from = "ChatPermissionsRaw", into = "ChatPermissionsRaw")]
@@ -56,11 +55,19 @@ bitflags::bitflags! {
         const SEND_POLLS = (1 << 2) | Self::SEND_MESSAGES.bits;
 
         /// Set if the user is allowed to send animations, games, stickers and
-        /// use inline bots, implies `SEND_MEDIA_MESSAGES`.
+        /// use inline bots.
+        ///
+        /// Unless `use_independent_chat_permissions` is set, Telegram treats
+        /// this permission as implying `SEND_MEDIA_MESSAGES` (see
+        /// [`ChatPermissions::implied`]).
         const SEND_OTHER_MESSAGES = (1 << 3);
 
         /// Set if the user is allowed to add web page previews to
-        /// their messages, implies `SEND_MEDIA_MESSAGES`.
+        /// their messages.
+        ///
+        /// Unless `use_independent_chat_permissions` is set, Telegram treats
+        /// this permission as implying `SEND_MEDIA_MESSAGES` (see
+        /// [`ChatPermissions::implied`]).
         const ADD_WEB_PAGE_PREVIEWS = (1 << 4);
 
         /// Set if the user is allowed to change the chat title, photo and
@@ -77,35 +84,31 @@ bitflags::bitflags! {
         /// Set if the user is allowed to create, rename, close, and reopen forum topics.
         const MANAGE_TOPICS = (1 << 8);
 
-        /// Set if the user is allowed to send audios. implies
-        /// `SEND_MESSAGES`.
-        const SEND_AUDIOS = (1 << 9) | Self::SEND_MESSAGES.bits;
+        /// Set if the user is allowed to send audios.
+        const SEND_AUDIOS = (1 << 9);
 
-        /// Set if the user is allowed to send documents. implies
-        /// `SEND_MESSAGES`.
-        const SEND_DOCUMENTS = (1 << 10) | Self::SEND_MESSAGES.bits;
+        /// Set if the user is allowed to send documents.
+        const SEND_DOCUMENTS = (1 << 10);
 
-        /// Set if the user is allowed to send photos. implies
-        /// `SEND_MESSAGES`.
-        const SEND_PHOTOS = (1 << 11) | Self::SEND_MESSAGES.bits;
+        /// Set if the user is allowed to send photos.
+        const SEND_PHOTOS = (1 << 11);
 
-        /// Set if the user is allowed to send videos. implies
-        /// `SEND_MESSAGES`.
-        const SEND_VIDEOS = (1 << 12) | Self::SEND_MESSAGES.bits;
+        /// Set if the user is allowed to send videos.
+        const SEND_VIDEOS = (1 << 12);
 
-        /// Set if the user is allowed to send video notes. implies
-        /// `SEND_MESSAGES`.
-        const SEND_VIDEO_NOTES = (1 << 13) | Self::SEND_MESSAGES.bits;
+        /// Set if the user is allowed to send video notes.
+        const SEND_VIDEO_NOTES = (1 << 13);
 
-        /// Set if the user is allowed to send voice notes. implies
-        /// `SEND_MESSAGES`.
-        const SEND_VOICE_NOTES = (1 << 14) | Self::SEND_MESSAGES.bits;
+        /// Set if the user is allowed to send voice notes.
+        const SEND_VOICE_NOTES = (1 << 14);
 
-        /// Set if the user is allowed to send audios, documents,
-        /// photos, videos, video notes and voice notes, implies
-        /// `SEND_MESSAGES`, `SEND_AUDIOS`, `SEND_DOCUMENTS`,
-        /// `SEND_PHOTOS`, `SEND_VIDEOS`, `SEND_VIDEO_NOTES` and `SEND_VOICE_NOTES`.
-        /// Note: this is not a separate permission on it's own, this is just a alias for all the permissions mentioned.
+        /// Set if the user is allowed to send text messages, audios,
+        /// documents, photos, videos, video notes and voice notes.
+        ///
+        /// Note: this is not a separate permission on it's own, this is just
+        /// an alias for `SEND_MESSAGES`, `SEND_AUDIOS`, `SEND_DOCUMENTS`,
+        /// `SEND_PHOTOS`, `SEND_VIDEOS`, `SEND_VIDEO_NOTES` and
+        /// `SEND_VOICE_NOTES`.
         const SEND_MEDIA_MESSAGES = Self::SEND_MESSAGES.bits
                                             | Self::SEND_AUDIOS.bits
                                             | Self::SEND_DOCUMENTS.bits
@@ -118,6 +121,39 @@ bitflags::bitflags! {
 }
 
 impl ChatPermissions {
+    /// Returns these permissions with `other` permissions added.
+    #[must_use]
+    pub const fn with(self, other: Self) -> Self {
+        self.union(other)
+    }
+
+    /// Returns these permissions with `other` permissions removed.
+    #[must_use]
+    pub const fn without(self, other: Self) -> Self {
+        self.difference(other)
+    }
+
+    /// Returns permissions that are effectively granted by Telegram when
+    /// `use_independent_chat_permissions` parameter of
+    /// [`RestrictChatMember`] or [`SetChatPermissions`] is not set.
+    ///
+    /// In this case [`SEND_OTHER_MESSAGES`] and [`ADD_WEB_PAGE_PREVIEWS`]
+    /// imply [`SEND_MEDIA_MESSAGES`].
+    ///
+    /// [`RestrictChatMember`]: crate::payloads::RestrictChatMember
+    /// [`SetChatPermissions`]: crate::payloads::SetChatPermissions
+    /// [`SEND_OTHER_MESSAGES`]: ChatPermissions::SEND_OTHER_MESSAGES
+    /// [`ADD_WEB_PAGE_PREVIEWS`]: ChatPermissions::ADD_WEB_PAGE_PREVIEWS
+    /// [`SEND_MEDIA_MESSAGES`]: ChatPermissions::SEND_MEDIA_MESSAGES
+    #[must_use]
+    pub fn implied(self) -> Self {
+        if self.intersects(Self::SEND_OTHER_MESSAGES | Self::ADD_WEB_PAGE_PREVIEWS) {
+            self | Self::SEND_MEDIA_MESSAGES
+        } else {
+            self
+        }
+    }
+
     /// Checks for [`SEND_MESSAGES`] permission.
     ///
     /// [`SEND_MESSAGES`]: ChatPermissions::SEND_MESSAGES
@@ -371,7 +407,8 @@ mod tests {
     #[test]
     fn serialization() {
         let permissions = ChatPermissions::SEND_AUDIOS | ChatPermissions::PIN_MESSAGES;
-        let expected = r#"{"can_send_messages":true,"can_send_audios":true,"can_pin_messages":true,"can_manage_topics":false}"#;
+        let expected =
+            r#"{"can_send_audios":true,"can_pin_messages":true,"can_manage_topics":false}"#;
         let actual = serde_json::to_string(&permissions).unwrap();
         assert_eq!(expected, actual);
     }
@@ -379,8 +416,19 @@ mod tests {
     #[test]
     fn deserialization() {
         let json = r#"{"can_send_messages":true,"can_send_photos":true,"can_pin_messages":true}"#;
-        let expected = ChatPermissions::SEND_PHOTOS | ChatPermissions::PIN_MESSAGES;
+        let expected = ChatPermissions::SEND_MESSAGES
+            | ChatPermissions::SEND_PHOTOS
+            | ChatPermissions::PIN_MESSAGES;
         let actual = serde_json::from_str(json).unwrap();
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn independent_media_permissions() {
+        let json = r#"{"can_send_photos":true,"can_send_voice_notes":true}"#;
+        let expected = ChatPermissions::SEND_PHOTOS | ChatPermissions::SEND_VOICE_NOTES;
+        let actual: ChatPermissions = serde_json::from_str(json).unwrap();
+        assert_eq!(expected, actual);
+        assert!(!actual.can_send_messages());
+    }
 }
@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 use crate::types::{Location, User};
+#[cfg(feature = "network")]
+use crate::{
+    payloads::{EditMessageReplyMarkupInline, EditMessageTextInline},
+    requests::{Requester, TargetRequest},
+};
 
 /// Represents a [result] of an inline query that was chosen by the user and
 /// sent to their chat partner.
@@ -32,3 +37,39 @@ pub struct ChosenInlineResult {
     /// The query that was used to obtain the result.
     pub query: String,
 }
+
+impl ChosenInlineResult {
+    /// Creates a request which edits the text of the sent message.
+    ///
+    /// Returns `None` if the message has no `inline_message_id`, i.e. it was
+    /// sent without an inline keyboard.
+    #[cfg(feature = "network")]
+    pub fn edit_text<'b, B, T>(
+        &self,
+        bot: &'b B,
+        text: T,
+    ) -> Option<TargetRequest<'b, B, EditMessageTextInline>>
+    where
+        B: Requester,
+        T: Into<String>,
+    {
+        let id = self.inline_message_id.as_ref()?;
+        Some(bot.edit_message_text_inline(id, text))
+    }
+
+    /// Creates a request which edits the reply markup of the sent message.
+    ///
+    /// Returns `None` if the message has no `inline_message_id`, i.e. it was
+    /// sent without an inline keyboard.
+    #[cfg(feature = "network")]
+    pub fn edit_reply_markup<'b, B>(
+        &self,
+        bot: &'b B,
+    ) -> Option<TargetRequest<'b, B, EditMessageReplyMarkupInline>>
+    where
+        B: Requester,
+    {
+        let id = self.inline_message_id.as_ref()?;
+        Some(bot.edit_message_reply_markup_inline(id))
+    }
+}
@@ -15,3 +15,30 @@ pub struct Dice {
     /// base emoji, 1-64 for 🎰 base emoji
     pub value: u8,
 }
+
+impl Dice {
+    /// Returns `true` if the dice has the maximum value for its emoji, see
+    /// [`DiceEmoji::max_value`].
+    #[must_use]
+    pub fn is_max(&self) -> bool {
+        self.value == self.emoji.max_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_max() {
+        let dice: Dice = serde_json::from_str(r#"{"emoji":"🎰","value":64}"#).unwrap();
+        assert!(dice.is_max());
+
+        let dice: Dice = serde_json::from_str(r#"{"emoji":"🏀","value":5}"#).unwrap();
+        assert!(dice.is_max());
+
+        let dice: Dice = serde_json::from_str(r#"{"emoji":"🎯","value":5}"#).unwrap();
+        assert!(!dice.is_max());
+        assert!(dice.emoji.values().contains(&dice.value));
+    }
+}
@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Copy, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
@@ -26,3 +28,28 @@ pub enum DiceEmoji {
     #[serde(rename = "🎰")]
     SlotMachine,
 }
+
+impl DiceEmoji {
+    /// Returns the range of values of a dice with this emoji.
+    #[must_use]
+    pub const fn values(self) -> RangeInclusive<u8> {
+        1..=self.max_value()
+    }
+
+    /// Returns the maximum value of a dice with this emoji.
+    ///
+    /// This is the best possible result, e.g. a bullseye for [`Darts`], a
+    /// strike for [`Bowling`] or three sevens for [`SlotMachine`].
+    ///
+    /// [`Darts`]: DiceEmoji::Darts
+    /// [`Bowling`]: DiceEmoji::Bowling
+    /// [`SlotMachine`]: DiceEmoji::SlotMachine
+    #[must_use]
+    pub const fn max_value(self) -> u8 {
+        match self {
+            Self::Dice | Self::Darts | Self::Bowling => 6,
+            Self::Basketball | Self::Football => 5,
+            Self::SlotMachine => 64,
+        }
+    }
+}
@@ -1,7 +1,9 @@
-use std::ops::Deref;
+use std::{ops::Deref, path::Path};
 
 use serde::{Deserialize, Serialize};
 
+use crate::types::{FileId, FileUniqueId};
+
 /// This object represents a file ready to be downloaded.
 ///
 /// The file can be downloaded via the [`Bot::download_file(file_path, dst)`]
@@ -34,13 +36,13 @@ pub struct File {
 pub struct FileMeta {
     /// Identifier for this file.
     #[serde(rename = "file_id")]
-    pub id: String,
+    pub id: FileId,
 
     /// Unique identifier for this file, which is supposed to be the same over
     /// time and for different bots. Can't be used to download or reuse the
     /// file.
     #[serde(rename = "file_unique_id")]
-    pub unique_id: String,
+    pub unique_id: FileUniqueId,
 
     /// File size in bytes.
     #[serde(rename = "file_size")]
@@ -48,11 +50,36 @@ pub struct FileMeta {
     // but just in case something goes wrong with the TBA server
     // (see the test below)
     #[serde(default = "file_size_fallback")]
-    pub size: u32,
+    pub size: u64,
+}
+
+pub(crate) const fn file_size_fallback() -> u64 {
+    u64::MAX
+}
+
+impl File {
+    /// Returns `true` if [`path`] is an absolute path on the machine of a
+    /// local Bot API server, rather than a path relative to the file URL.
+    ///
+    /// Local Bot API servers running in the `--local` mode return such paths.
+    /// [`Download`] implementation of [`Bot`] with a custom [API url] reads
+    /// these files directly from the file system, so the bot must run on the
+    /// same machine as the server (or have access to its files at the same
+    /// path).
+    ///
+    /// [`path`]: File::path
+    /// [`Download`]: crate::net::Download
+    /// [`Bot`]: crate::Bot
+    /// [API url]: crate::Bot::api_url
+    #[must_use]
+    pub fn is_local(&self) -> bool {
+        is_local_path(&self.path)
+    }
 }
 
-pub(crate) const fn file_size_fallback() -> u32 {
-    u32::MAX
+pub(crate) fn is_local_path(path: &str) -> bool {
+    // Local Bot API servers don't run on Windows, but the bot itself might
+    path.starts_with('/') || Path::new(path).is_absolute()
 }
 
 /// Allows access to [`FileMeta`]'s fields for [`File`].
@@ -60,14 +87,14 @@ pub(crate) const fn file_size_fallback() -> u32 {
 /// ## Examples
 ///
 /// ```rust
-/// use teloxide_core::types::File;
+/// use teloxide_core::types::{File, FileId, FileUniqueId};
 /// #
-/// # let get_file = || File { meta: teloxide_core::types::FileMeta { id: String::new(), unique_id: String::new(), size: 0 }, path: String::new() };
+/// # let get_file = || File { meta: teloxide_core::types::FileMeta { id: FileId(String::new()), unique_id: FileUniqueId(String::new()), size: 0 }, path: String::new() };
 /// let file: File = get_file();
 ///
-/// let file_id: &str = &file.id;
-/// let file_unique_id: &str = &file.unique_id;
-/// let file_size: u32 = file.size;
+/// let file_id: &FileId = &file.id;
+/// let file_unique_id: &FileUniqueId = &file.unique_id;
+/// let file_size: u64 = file.size;
 /// #
 /// # let _ = (file_id, file_unique_id, file_size);
 /// ```
@@ -81,7 +108,7 @@ impl Deref for File {
 
 #[cfg(test)]
 mod tests {
-    use crate::types::{File, FileMeta};
+    use crate::types::{File, FileId, FileMeta, FileUniqueId};
 
     // As per <https://github.com/tdlib/telegram-bot-api/issues/192> file size is **not** optional,
     // But <https://github.com/tdlib/telegram-bot-api/issues/294> suggests that it can be missing in case Telegram servers are going insane.
@@ -96,9 +123,9 @@ mod tests {
             file,
             File {
                 meta: FileMeta {
-                    id: "FILE_ID".to_owned(),
-                    unique_id: "FILE_UNIQUE_ID".to_owned(),
-                    size: u32::MAX,
+                    id: FileId("FILE_ID".to_owned()),
+                    unique_id: FileUniqueId("FILE_UNIQUE_ID".to_owned()),
+                    size: u64::MAX,
                 },
                 path: "FILE_PATH".to_owned(),
             }
@@ -114,7 +141,11 @@ mod tests {
 
         assert_eq!(
             file,
-            FileMeta { id: "FILE_ID".to_owned(), unique_id: "FILE_UNIQUE_ID".to_owned(), size: 42 }
+            FileMeta {
+                id: FileId("FILE_ID".to_owned()),
+                unique_id: FileUniqueId("FILE_UNIQUE_ID".to_owned()),
+                size: 42
+            }
         );
     }
 
@@ -127,12 +158,24 @@ mod tests {
             file,
             File {
                 meta: FileMeta {
-                    id: "FILE_ID".to_owned(),
-                    unique_id: "FILE_UNIQUE_ID".to_owned(),
+                    id: FileId("FILE_ID".to_owned()),
+                    unique_id: FileUniqueId("FILE_UNIQUE_ID".to_owned()),
                     size: 42,
                 },
                 path: "FILE_PATH".to_owned(),
             }
         );
     }
+
+    #[test]
+    fn is_local() {
+        let json = r#"{"file_id":"FILE_ID","file_unique_id":"FILE_UNIQUE_ID","file_size":5000000000,"file_path":"/var/lib/telegram-bot-api/TOKEN/videos/file_0.mp4"}"#;
+        let file: File = serde_json::from_str(json).unwrap();
+
+        assert_eq!(file.size, 5_000_000_000);
+        assert!(file.is_local());
+
+        let file = File { path: "videos/file_0.mp4".to_owned(), ..file };
+        assert!(!file.is_local());
+    }
 }
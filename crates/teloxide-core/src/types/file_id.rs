@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+/// Identifier of a file, which can be used to download or reuse the file.
+///
+/// Note that the same file can have different file ids, even for the same bot.
+/// Use [`FileUniqueId`] to check whether two files are the same.
+#[derive(Clone, Debug, Default, derive_more::Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FileId(pub String);
+
+/// Unique identifier of a file, which is supposed to be the same over time and
+/// for different bots.
+///
+/// Can't be used to download or reuse the file, see [`FileId`] for that.
+#[derive(Clone, Debug, Default, derive_more::Display, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FileUniqueId(pub String);
+
+macro_rules! impl_string_conversions {
+    ($($ty:ident),*) => {
+        $(
+            impl $ty {
+                /// Returns the identifier as a string slice.
+                #[must_use]
+                pub fn as_str(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl From<String> for $ty {
+                fn from(id: String) -> Self {
+                    Self(id)
+                }
+            }
+
+            impl From<&str> for $ty {
+                fn from(id: &str) -> Self {
+                    Self(id.to_owned())
+                }
+            }
+
+            impl From<$ty> for String {
+                fn from($ty(id): $ty) -> Self {
+                    id
+                }
+            }
+        )*
+    };
+}
+
+impl_string_conversions!(FileId, FileUniqueId);
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{FileId, FileUniqueId};
+
+    #[test]
+    fn smoke_deser() {
+        let json = r#""AgADBAAD""#;
+        let id: FileId = serde_json::from_str(json).unwrap();
+        assert_eq!(id, FileId("AgADBAAD".to_owned()));
+    }
+
+    #[test]
+    fn smoke_ser() {
+        let id = FileUniqueId::from("AQADabwx");
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, r#""AQADabwx""#);
+        assert_eq!(id.to_string(), "AQADabwx");
+    }
+}
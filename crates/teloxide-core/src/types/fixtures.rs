@@ -0,0 +1,281 @@
+//! Constructors of realistic values for tests.
+//!
+//! Values built here have sensible defaults for all the fields which are not
+//! passed explicitly. Since all the fields are public, the defaults can be
+//! changed after construction.
+
+use chrono::{DateTime, Utc};
+
+use crate::types::{
+    CallbackQuery, Chat, ChatId, ChatKind, ChatMember, ChatMemberKind, ChatMemberUpdated,
+    ChatPrivate, ChatPublic, Me, MediaKind, MediaText, Message, MessageCommon, MessageEntity,
+    MessageEntityKind, MessageId, MessageKind, PublicChatGroup, PublicChatKind,
+    PublicChatSupergroup, Update, UpdateId, UpdateKind, User, UserId,
+};
+
+/// The date of all fixtures, the Unix epoch.
+fn date() -> DateTime<Utc> {
+    DateTime::from_timestamp(0, 0).unwrap()
+}
+
+impl User {
+    /// Returns a user (not a bot) with the given id, named "Test".
+    #[must_use]
+    pub fn fixture(id: UserId) -> Self {
+        Self {
+            id,
+            is_bot: false,
+            first_name: "Test".to_owned(),
+            last_name: None,
+            username: Some(format!("user{id}")),
+            language_code: Some("en".to_owned()),
+            is_premium: false,
+            added_to_attachment_menu: false,
+        }
+    }
+}
+
+impl Me {
+    /// Returns a bot with id `1` and username `test_bot`.
+    #[must_use]
+    pub fn fixture() -> Self {
+        Self {
+            user: User {
+                is_bot: true,
+                first_name: "Test Bot".to_owned(),
+                username: Some("test_bot".to_owned()),
+                language_code: None,
+                ..User::fixture(UserId(1))
+            },
+            can_join_groups: true,
+            can_read_all_group_messages: false,
+            supports_inline_queries: false,
+        }
+    }
+}
+
+impl Chat {
+    /// Returns a chat with the given id.
+    ///
+    /// The kind of the chat depends on the id: a private chat for
+    /// [user ids], a group for [group ids] and a supergroup otherwise.
+    ///
+    /// [user ids]: ChatId::is_user
+    /// [group ids]: ChatId::is_group
+    #[must_use]
+    pub fn fixture(id: ChatId) -> Self {
+        let kind = if id.is_user() {
+            ChatKind::Private(ChatPrivate {
+                username: Some(format!("user{id}")),
+                first_name: Some("Test".to_owned()),
+                last_name: None,
+                emoji_status_custom_emoji_id: None,
+                bio: None,
+                has_private_forwards: None,
+                has_restricted_voice_and_video_messages: None,
+            })
+        } else {
+            let kind = if id.is_group() {
+                PublicChatKind::Group(PublicChatGroup { permissions: None })
+            } else {
+                PublicChatKind::Supergroup(PublicChatSupergroup {
+                    username: None,
+                    active_usernames: None,
+                    is_forum: false,
+                    sticker_set_name: None,
+                    can_set_sticker_set: None,
+                    permissions: None,
+                    slow_mode_delay: None,
+                    linked_chat_id: None,
+                    location: None,
+                    join_to_send_messages: None,
+                    join_by_request: None,
+                })
+            };
+
+            ChatKind::Public(ChatPublic {
+                title: Some("Test".to_owned()),
+                kind,
+                description: None,
+                invite_link: None,
+                has_protected_content: None,
+            })
+        };
+
+        Self {
+            id,
+            kind,
+            photo: None,
+            pinned_message: None,
+            message_auto_delete_time: None,
+            has_hidden_members: false,
+            has_aggressive_anti_spam_enabled: false,
+            available_reactions: None,
+        }
+    }
+}
+
+impl Message {
+    /// Returns a text message sent by `user_id` to `chat_id`.
+    ///
+    /// As Telegram does, a [`BotCommand`] entity is added if `text` starts with
+    /// a command.
+    ///
+    /// [`BotCommand`]: MessageEntityKind::BotCommand
+    #[must_use]
+    pub fn text_fixture<T>(chat_id: ChatId, user_id: UserId, text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let text = text.into();
+        let entities = match text.split_whitespace().next() {
+            Some(command) if text.starts_with('/') && command.len() > 1 => {
+                vec![MessageEntity::new(
+                    MessageEntityKind::BotCommand,
+                    0,
+                    command.encode_utf16().count(),
+                )]
+            }
+            _ => Vec::new(),
+        };
+
+        Self {
+            id: MessageId(1),
+            thread_id: None,
+            date: date(),
+            chat: Chat::fixture(chat_id),
+            via_bot: None,
+            kind: MessageKind::Common(MessageCommon {
+                from: Some(User::fixture(user_id)),
+                sender_chat: None,
+                author_signature: None,
+                forward: None,
+                reply_to_message: None,
+                edit_date: None,
+                media_kind: MediaKind::Text(MediaText { text, entities }),
+                reply_markup: None,
+                is_topic_message: false,
+                is_automatic_forward: false,
+                has_protected_content: false,
+            }),
+        }
+    }
+}
+
+impl CallbackQuery {
+    /// Returns a callback query from `user_id` with the given `data`.
+    ///
+    /// The query doesn't have a [`message`], set it if handlers need one.
+    ///
+    /// [`message`]: CallbackQuery::message
+    #[must_use]
+    pub fn fixture<D>(user_id: UserId, data: D) -> Self
+    where
+        D: Into<String>,
+    {
+        Self {
+            id: "1".to_owned(),
+            from: User::fixture(user_id),
+            message: None,
+            inline_message_id: None,
+            chat_instance: "1".to_owned(),
+            data: Some(data.into()),
+            game_short_name: None,
+        }
+    }
+}
+
+impl ChatMemberUpdated {
+    /// Returns a change of the status of `user_id` in `chat_id` from `old` to
+    /// `new`.
+    ///
+    /// The change is performed by the user itself, set [`from`] if it should
+    /// be performed by someone else.
+    ///
+    /// [`from`]: ChatMemberUpdated::from
+    #[must_use]
+    pub fn fixture(
+        chat_id: ChatId,
+        user_id: UserId,
+        old: ChatMemberKind,
+        new: ChatMemberKind,
+    ) -> Self {
+        let user = User::fixture(user_id);
+        Self {
+            chat: Chat::fixture(chat_id),
+            from: user.clone(),
+            date: date(),
+            old_chat_member: ChatMember { user: user.clone(), kind: old },
+            new_chat_member: ChatMember { user, kind: new },
+            invite_link: None,
+        }
+    }
+}
+
+impl Update {
+    /// Returns an update with id `1` and the given `kind`.
+    #[must_use]
+    pub fn fixture(kind: UpdateKind) -> Self {
+        Self { id: UpdateId(1), kind, cx: None }
+    }
+
+    /// Returns an update with a text message, see [`Message::text_fixture`].
+    #[must_use]
+    pub fn message_fixture<T>(chat_id: ChatId, user_id: UserId, text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::fixture(UpdateKind::Message(Message::text_fixture(chat_id, user_id, text)))
+    }
+
+    /// Returns an update with a callback query, see [`CallbackQuery::fixture`].
+    #[must_use]
+    pub fn callback_query_fixture<D>(user_id: UserId, data: D) -> Self
+    where
+        D: Into<String>,
+    {
+        Self::fixture(UpdateKind::CallbackQuery(CallbackQuery::fixture(user_id, data)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixtures_roundtrip() {
+        let chat_ids = [ChatId(42), ChatId(-42), ChatId(-1001234567890)];
+        for chat_id in chat_ids {
+            let update = Update::message_fixture(chat_id, UserId(42), "/start payload");
+            let json = serde_json::to_string(&update).unwrap();
+            assert_eq!(serde_json::from_str::<Update>(&json).unwrap(), update);
+        }
+
+        let update = Update::callback_query_fixture(UserId(42), "data");
+        let json = serde_json::to_string(&update).unwrap();
+        assert_eq!(serde_json::from_str::<Update>(&json).unwrap(), update);
+
+        let member = ChatMemberUpdated::fixture(
+            ChatId(-42),
+            UserId(42),
+            ChatMemberKind::Left,
+            ChatMemberKind::Member,
+        );
+        let update = Update::fixture(UpdateKind::ChatMember(member));
+        let json = serde_json::to_string(&update).unwrap();
+        assert_eq!(serde_json::from_str::<Update>(&json).unwrap(), update);
+
+        let me = Me::fixture();
+        let json = serde_json::to_string(&me).unwrap();
+        assert_eq!(serde_json::from_str::<Me>(&json).unwrap(), me);
+    }
+
+    #[test]
+    fn text_fixture_entities() {
+        let message = Message::text_fixture(ChatId(1), UserId(1), "/start payload");
+        assert_eq!(message.entities().unwrap()[0].length, "/start".len());
+
+        let message = Message::text_fixture(ChatId(1), UserId(1), "hi");
+        assert!(message.entities().unwrap().is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::types::{CallbackGame, LoginUrl, True, WebAppInfo};
+use crate::types::{CallbackGame, LoginUrl, SwitchInlineQueryChosenChat, True, WebAppInfo};
 use serde::{Deserialize, Serialize};
 
 /// This object represents one button of an inline keyboard.
@@ -24,7 +24,7 @@ pub enum InlineKeyboardButtonKind {
     /// their privacy settings. This will only work in Telegram versions
     /// released after December 7, 2021. Older clients will display _unsupported
     /// message_.
-    Url(reqwest::Url),
+    Url(url::Url),
 
     /// An HTTPS URL used to automatically authorize the user. Can be used as a
     /// replacement for the [Telegram Login Widget].
@@ -71,6 +71,11 @@ pub enum InlineKeyboardButtonKind {
     /// the same chat – good for selecting something from multiple options.
     SwitchInlineQueryCurrentChat(String),
 
+    /// If set, pressing the button will prompt the user to select one of their
+    /// chats of the specified type, open that chat and insert the bot's
+    /// username and the specified inline query in the input field.
+    SwitchInlineQueryChosenChat(SwitchInlineQueryChosenChat),
+
     /// Description of the game that will be launched when the user presses the
     /// button.
     ///
@@ -103,7 +108,7 @@ impl InlineKeyboardButton {
     /// Constructor for `InlineKeyboardButton` with [`Url`] kind.
     ///
     /// [`Url`]: InlineKeyboardButtonKind::Url
-    pub fn url<T>(text: T, url: reqwest::Url) -> Self
+    pub fn url<T>(text: T, url: url::Url) -> Self
     where
         T: Into<String>,
     {
@@ -172,6 +177,23 @@ impl InlineKeyboardButton {
         )
     }
 
+    /// Constructor for `InlineKeyboardButton` with
+    /// [`SwitchInlineQueryChosenChat`] kind.
+    ///
+    /// [`SwitchInlineQueryChosenChat`]: InlineKeyboardButtonKind::SwitchInlineQueryChosenChat
+    pub fn switch_inline_query_chosen_chat<T>(
+        text: T,
+        switch_inline_query_chosen_chat: SwitchInlineQueryChosenChat,
+    ) -> Self
+    where
+        T: Into<String>,
+    {
+        Self::new(
+            text,
+            InlineKeyboardButtonKind::SwitchInlineQueryChosenChat(switch_inline_query_chosen_chat),
+        )
+    }
+
     /// Constructor for `InlineKeyboardButton` with [`CallbackGame`] kind.
     ///
     /// [`CallbackGame`]: InlineKeyboardButtonKind::CallbackGame
@@ -192,3 +214,19 @@ impl InlineKeyboardButton {
         Self::new(text, InlineKeyboardButtonKind::Pay(True))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn switch_inline_query_chosen_chat() {
+        let chosen_chat =
+            SwitchInlineQueryChosenChat::new().query("q").allow_user_chats().allow_group_chats();
+        let button = InlineKeyboardButton::switch_inline_query_chosen_chat("text", chosen_chat);
+        let json = r#"{"text":"text","switch_inline_query_chosen_chat":{"query":"q","allow_user_chats":true,"allow_group_chats":true}}"#;
+
+        assert_eq!(serde_json::to_string(&button).unwrap(), json);
+        assert_eq!(serde_json::from_str::<InlineKeyboardButton>(json).unwrap(), button);
+    }
+}
@@ -77,8 +77,8 @@ impl InlineKeyboardMarkup {
 mod tests {
     use super::*;
 
-    fn url(n: u32) -> reqwest::Url {
-        reqwest::Url::parse(&format!("https://example.com/{n}")).unwrap()
+    fn url(n: u32) -> url::Url {
+        url::Url::parse(&format!("https://example.com/{n}")).unwrap()
     }
 
     #[test]
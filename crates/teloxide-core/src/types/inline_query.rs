@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::types::{ChatType, Location, User};
+#[cfg(feature = "network")]
+use crate::{payloads::AnswerInlineQuerySetters, requests::Requester, types::InlineQueryResult};
 
 /// This object represents an incoming inline query.
 ///
@@ -33,3 +35,134 @@ pub struct InlineQuery {
     /// from a secret chat.
     pub chat_type: Option<ChatType>,
 }
+
+impl InlineQuery {
+    /// Maximal number of results in an answer to an inline query.
+    pub const MAX_RESULTS: usize = 50;
+
+    /// Creates a request which answers this query with `results`.
+    ///
+    /// Passing more than [`InlineQuery::MAX_RESULTS`] results fails to
+    /// compile.
+    #[cfg(feature = "network")]
+    pub fn answer<B, const N: usize>(
+        &self,
+        bot: &B,
+        results: [InlineQueryResult; N],
+    ) -> B::AnswerInlineQuery
+    where
+        B: Requester,
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = ResultsLimit::<N>::CHECK;
+
+        bot.answer_inline_query(&self.id, results)
+    }
+
+    /// Creates a request which answers this query with a page of at most
+    /// `PAGE_SIZE` results, picked from all `results` according to
+    /// [`InlineQuery::offset`].
+    ///
+    /// The offset of the next page is set as `next_offset`, so a user
+    /// scrolling the results will send the same query for the next page. The
+    /// last page has an empty `next_offset`. `PAGE_SIZE` greater than
+    /// [`InlineQuery::MAX_RESULTS`] fails to compile.
+    ///
+    /// [`InlineQuery::offset`]: InlineQuery#structfield.offset
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide_core::{
+    ///     types::{InlineQuery, InlineQueryResult},
+    ///     Bot,
+    /// };
+    ///
+    /// fn answer(bot: &Bot, query: &InlineQuery) {
+    ///     let results = (0..100).map(|i| InlineQueryResult::article(i.to_string(), "Title", "Text"));
+    ///     let _request = query.answer_page::<20, _, _>(bot, results);
+    /// }
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn answer_page<const PAGE_SIZE: usize, B, I>(
+        &self,
+        bot: &B,
+        results: I,
+    ) -> B::AnswerInlineQuery
+    where
+        B: Requester,
+        I: IntoIterator<Item = InlineQueryResult>,
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = ResultsLimit::<PAGE_SIZE>::CHECK;
+
+        let start: usize = self.offset.parse().unwrap_or(0);
+        let mut results = results.into_iter().skip(start).peekable();
+        let page: Vec<_> = results.by_ref().take(PAGE_SIZE).collect();
+
+        let next_offset = match results.peek() {
+            Some(_) => (start + page.len()).to_string(),
+            None => String::new(),
+        };
+
+        bot.answer_inline_query(&self.id, page).next_offset(next_offset)
+    }
+}
+
+#[cfg(feature = "network")]
+struct ResultsLimit<const N: usize>;
+
+#[cfg(feature = "network")]
+impl<const N: usize> ResultsLimit<N> {
+    const CHECK: () = assert!(N <= InlineQuery::MAX_RESULTS, "Too many inline query results");
+}
+
+#[cfg(all(test, feature = "network"))]
+mod tests {
+    use super::*;
+    use crate::{payloads::AnswerInlineQuery, requests::HasPayload, types::UserId, Bot};
+
+    fn query(offset: &str) -> InlineQuery {
+        InlineQuery {
+            id: "id".to_owned(),
+            from: User {
+                id: UserId(1),
+                is_bot: false,
+                first_name: "A".to_owned(),
+                last_name: None,
+                username: None,
+                language_code: None,
+                is_premium: false,
+                added_to_attachment_menu: false,
+            },
+            location: None,
+            query: "query".to_owned(),
+            offset: offset.to_owned(),
+            chat_type: Some(ChatType::Sender),
+        }
+    }
+
+    fn results() -> impl Iterator<Item = InlineQueryResult> {
+        (0..25).map(|i| InlineQueryResult::article(i.to_string(), "title", "text"))
+    }
+
+    #[test]
+    fn answer_page() {
+        let bot = Bot::new("TOKEN");
+
+        let req = query("").answer_page::<10, _, _>(&bot, results());
+        let payload: &AnswerInlineQuery = req.payload_ref();
+        assert_eq!(payload.results.len(), 10);
+        assert_eq!(payload.results[0].id(), "0");
+        assert_eq!(payload.next_offset.as_deref(), Some("10"));
+
+        let req = query("20").answer_page::<10, _, _>(&bot, results());
+        let payload: &AnswerInlineQuery = req.payload_ref();
+        assert_eq!(payload.results.len(), 5);
+        assert_eq!(payload.results[0].id(), "20");
+        assert_eq!(payload.next_offset.as_deref(), Some(""));
+
+        let req = query("15").answer_page::<10, _, _>(&bot, results());
+        assert_eq!(req.payload_ref().next_offset.as_deref(), Some(""));
+    }
+}
@@ -2,15 +2,16 @@
 
 use derive_more::From;
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 use crate::types::{
-    InlineQueryResultArticle, InlineQueryResultAudio, InlineQueryResultCachedAudio,
+    FileId, InlineQueryResultArticle, InlineQueryResultAudio, InlineQueryResultCachedAudio,
     InlineQueryResultCachedDocument, InlineQueryResultCachedGif, InlineQueryResultCachedMpeg4Gif,
     InlineQueryResultCachedPhoto, InlineQueryResultCachedSticker, InlineQueryResultCachedVideo,
     InlineQueryResultCachedVoice, InlineQueryResultContact, InlineQueryResultDocument,
     InlineQueryResultGame, InlineQueryResultGif, InlineQueryResultLocation,
     InlineQueryResultMpeg4Gif, InlineQueryResultPhoto, InlineQueryResultVenue,
-    InlineQueryResultVideo, InlineQueryResultVoice,
+    InlineQueryResultVideo, InlineQueryResultVoice, InputMessageContent, InputMessageContentText,
 };
 
 /// This object represents one result of an inline query.
@@ -53,6 +54,94 @@ pub enum InlineQueryResult {
     Voice(InlineQueryResultVoice),
 }
 
+impl InlineQueryResult {
+    /// Creates an article which sends a text message with `text`.
+    ///
+    /// Use [`InlineQueryResultArticle`] directly to set other fields.
+    pub fn article<I, T, M>(id: I, title: T, text: M) -> Self
+    where
+        I: Into<String>,
+        T: Into<String>,
+        M: Into<String>,
+    {
+        let content = InputMessageContent::Text(InputMessageContentText::new(text));
+        Self::Article(InlineQueryResultArticle::new(id, title, content))
+    }
+
+    /// Creates a photo result with a photo and its thumbnail located at the
+    /// given URLs.
+    pub fn photo<I>(id: I, photo_url: Url, thumb_url: Url) -> Self
+    where
+        I: Into<String>,
+    {
+        Self::Photo(InlineQueryResultPhoto::new(id, photo_url, thumb_url))
+    }
+
+    /// Creates a photo result with a photo stored on the Telegram servers.
+    pub fn cached_photo<I, F>(id: I, photo_file_id: F) -> Self
+    where
+        I: Into<String>,
+        F: Into<FileId>,
+    {
+        Self::CachedPhoto(InlineQueryResultCachedPhoto::new(id, photo_file_id))
+    }
+
+    /// Creates a sticker result with a sticker stored on the Telegram servers.
+    pub fn cached_sticker<I, F>(id: I, sticker_file_id: F) -> Self
+    where
+        I: Into<String>,
+        F: Into<FileId>,
+    {
+        Self::CachedSticker(InlineQueryResultCachedSticker::new(id, sticker_file_id))
+    }
+
+    /// Creates a location result.
+    pub fn location<I, T>(id: I, title: T, latitude: f64, longitude: f64) -> Self
+    where
+        I: Into<String>,
+        T: Into<String>,
+    {
+        Self::Location(InlineQueryResultLocation::new(id, title, latitude, longitude))
+    }
+
+    /// Creates a contact result.
+    pub fn contact<I, P, F>(id: I, phone_number: P, first_name: F) -> Self
+    where
+        I: Into<String>,
+        P: Into<String>,
+        F: Into<String>,
+    {
+        Self::Contact(InlineQueryResultContact::new(id, phone_number, first_name))
+    }
+
+    /// Returns the identifier of this result.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        match self {
+            Self::CachedAudio(r) => &r.id,
+            Self::CachedDocument(r) => &r.id,
+            Self::CachedGif(r) => &r.id,
+            Self::CachedMpeg4Gif(r) => &r.id,
+            Self::CachedPhoto(r) => &r.id,
+            Self::CachedSticker(r) => &r.id,
+            Self::CachedVideo(r) => &r.id,
+            Self::CachedVoice(r) => &r.id,
+            Self::Article(r) => &r.id,
+            Self::Audio(r) => &r.id,
+            Self::Contact(r) => &r.id,
+            Self::Game(r) => &r.id,
+            Self::Document(r) => &r.id,
+            Self::Gif(r) => &r.id,
+            Self::Location(r) => &r.id,
+            Self::Mpeg4Gif(r) => &r.id,
+            Self::Photo(r) => &r.id,
+            Self::Venue(r) => &r.id,
+            Self::Video(r) => &r.id,
+            Self::Voice(r) => &r.id,
+        }
+    }
+}
+
 mod raw {
     use super::*;
 
@@ -257,9 +346,9 @@ mod raw {
 #[cfg(test)]
 mod tests {
     use crate::types::{
-        inline_keyboard_markup::InlineKeyboardMarkup, parse_mode::ParseMode, InlineQueryResult,
-        InlineQueryResultArticle, InlineQueryResultAudio, InlineQueryResultCachedAudio,
-        InlineQueryResultCachedDocument, InlineQueryResultCachedGif,
+        inline_keyboard_markup::InlineKeyboardMarkup, parse_mode::ParseMode, FileId,
+        InlineQueryResult, InlineQueryResultArticle, InlineQueryResultAudio,
+        InlineQueryResultCachedAudio, InlineQueryResultCachedDocument, InlineQueryResultCachedGif,
         InlineQueryResultCachedMpeg4Gif, InlineQueryResultCachedPhoto,
         InlineQueryResultCachedSticker, InlineQueryResultCachedVideo, InlineQueryResultCachedVoice,
         InlineQueryResultContact, InlineQueryResultDocument, InlineQueryResultGame,
@@ -273,11 +362,22 @@ mod tests {
     use std::str::FromStr as _;
     use url::Url;
 
+    #[test]
+    fn article_shortcut() {
+        let structure = InlineQueryResult::article("id", "title", "text");
+
+        let expected_json = r#"{"type":"article","id":"id","title":"title","input_message_content":{"message_text":"text"}}"#;
+        let actual_json = serde_json::to_string(&structure).unwrap();
+
+        assert_eq!(expected_json, actual_json);
+        assert_eq!(structure.id(), "id");
+    }
+
     #[test]
     fn cached_audio_min() {
         let structure = InlineQueryResult::CachedAudio(InlineQueryResultCachedAudio {
             id: String::from("id"),
-            audio_file_id: String::from("audio_file_id"),
+            audio_file_id: FileId::from("audio_file_id"),
             caption: None,
             parse_mode: None,
             caption_entities: None,
@@ -296,7 +396,7 @@ mod tests {
     fn cached_audio_full() {
         let structure = InlineQueryResult::CachedAudio(InlineQueryResultCachedAudio {
             id: String::from("id"),
-            audio_file_id: String::from("audio_file_id"),
+            audio_file_id: FileId::from("audio_file_id"),
             caption: Some(String::from("caption")),
             parse_mode: Some(ParseMode::Html),
             reply_markup: Some(InlineKeyboardMarkup::default()),
@@ -320,7 +420,7 @@ mod tests {
     fn audio_min() {
         let structure = InlineQueryResult::Audio(InlineQueryResultAudio {
             id: String::from("id"),
-            audio_url: reqwest::Url::parse("http://audio_url/").unwrap(),
+            audio_url: url::Url::parse("http://audio_url/").unwrap(),
             title: String::from("title"),
             caption: None,
             parse_mode: None,
@@ -343,7 +443,7 @@ mod tests {
     fn audio_full() {
         let structure = InlineQueryResult::Audio(InlineQueryResultAudio {
             id: String::from("id"),
-            audio_url: reqwest::Url::parse("http://audio_url/").unwrap(),
+            audio_url: url::Url::parse("http://audio_url/").unwrap(),
             title: String::from("title"),
             caption: Some(String::from("caption")),
             parse_mode: Some(ParseMode::Html),
@@ -371,7 +471,7 @@ mod tests {
         let structure = InlineQueryResult::CachedDocument(InlineQueryResultCachedDocument {
             id: String::from("id"),
             title: String::from("title"),
-            document_file_id: String::from("document_file_id"),
+            document_file_id: FileId::from("document_file_id"),
             description: None,
             caption: None,
             parse_mode: None,
@@ -392,7 +492,7 @@ mod tests {
         let structure = InlineQueryResult::CachedDocument(InlineQueryResultCachedDocument {
             id: String::from("id"),
             title: String::from("title"),
-            document_file_id: String::from("document_file_id"),
+            document_file_id: FileId::from("document_file_id"),
             description: Some(String::from("description")),
             caption: Some(String::from("caption")),
             parse_mode: Some(ParseMode::Html),
@@ -421,7 +521,7 @@ mod tests {
             caption: None,
             parse_mode: None,
             caption_entities: None,
-            document_url: reqwest::Url::parse("http://document_url/").unwrap(),
+            document_url: url::Url::parse("http://document_url/").unwrap(),
             mime_type: Mime::from_str("application/pdf").unwrap(),
             description: None,
             reply_markup: None,
@@ -446,7 +546,7 @@ mod tests {
             caption: Some(String::from("caption")),
             parse_mode: Some(ParseMode::Html),
             caption_entities: None,
-            document_url: reqwest::Url::parse("http://document_url/").unwrap(),
+            document_url: url::Url::parse("http://document_url/").unwrap(),
             mime_type: Mime::from_str("application/pdf").unwrap(),
             description: Some(String::from("description")),
             reply_markup: Some(InlineKeyboardMarkup::default()),
@@ -456,7 +556,7 @@ mod tests {
                 disable_web_page_preview: Some(true),
                 entities: None,
             })),
-            thumb_url: Some(reqwest::Url::parse("http://thumb_url/").unwrap()),
+            thumb_url: Some(url::Url::parse("http://thumb_url/").unwrap()),
             thumb_width: Some(1),
             thumb_height: Some(1),
         });
@@ -472,7 +572,7 @@ mod tests {
     fn cached_gif_min() {
         let structure = InlineQueryResult::CachedGif(InlineQueryResultCachedGif {
             id: String::from("id"),
-            gif_file_id: String::from("gif_file_id"),
+            gif_file_id: FileId::from("gif_file_id"),
             title: None,
             caption: None,
             parse_mode: None,
@@ -492,7 +592,7 @@ mod tests {
     fn cached_gif_full() {
         let structure = InlineQueryResult::CachedGif(InlineQueryResultCachedGif {
             id: String::from("id"),
-            gif_file_id: String::from("gif_file_id"),
+            gif_file_id: FileId::from("gif_file_id"),
             title: Some(String::from("title")),
             caption: Some(String::from("caption")),
             parse_mode: Some(ParseMode::Html),
@@ -570,7 +670,7 @@ mod tests {
     fn cached_mpeg4_gif_min() {
         let structure = InlineQueryResult::CachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif {
             id: String::from("id"),
-            mpeg4_file_id: String::from("mpeg4_file_id"),
+            mpeg4_file_id: FileId::from("mpeg4_file_id"),
             title: None,
             caption: None,
             parse_mode: None,
@@ -590,7 +690,7 @@ mod tests {
     fn cached_mpeg4_gif_full() {
         let structure = InlineQueryResult::CachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif {
             id: String::from("id"),
-            mpeg4_file_id: String::from("mpeg4_file_id"),
+            mpeg4_file_id: FileId::from("mpeg4_file_id"),
             title: Some(String::from("title")),
             caption: Some(String::from("caption")),
             parse_mode: Some(ParseMode::Html),
@@ -668,7 +768,7 @@ mod tests {
     fn cached_photo_min() {
         let structure = InlineQueryResult::CachedPhoto(InlineQueryResultCachedPhoto {
             id: String::from("id"),
-            photo_file_id: String::from("photo_file_id"),
+            photo_file_id: FileId::from("photo_file_id"),
             title: None,
             description: None,
             caption: None,
@@ -689,7 +789,7 @@ mod tests {
     fn cached_photo_full() {
         let structure = InlineQueryResult::CachedPhoto(InlineQueryResultCachedPhoto {
             id: String::from("id"),
-            photo_file_id: String::from("photo_file_id"),
+            photo_file_id: FileId::from("photo_file_id"),
             title: Some(String::from("title")),
             description: Some(String::from("description")),
             caption: Some(String::from("caption")),
@@ -768,7 +868,7 @@ mod tests {
     fn cached_sticker_min() {
         let structure = InlineQueryResult::CachedSticker(InlineQueryResultCachedSticker {
             id: String::from("id"),
-            sticker_file_id: String::from("sticker_file_id"),
+            sticker_file_id: FileId::from("sticker_file_id"),
             reply_markup: None,
             input_message_content: None,
         });
@@ -784,7 +884,7 @@ mod tests {
     fn cached_sticker_full() {
         let structure = InlineQueryResult::CachedSticker(InlineQueryResultCachedSticker {
             id: String::from("id"),
-            sticker_file_id: String::from("sticker_file_id"),
+            sticker_file_id: FileId::from("sticker_file_id"),
             reply_markup: Some(InlineKeyboardMarkup::default()),
             input_message_content: Some(InputMessageContent::Text(InputMessageContentText {
                 message_text: String::from("message_text"),
@@ -805,7 +905,7 @@ mod tests {
     fn cached_video_min() {
         let structure = InlineQueryResult::CachedVideo(InlineQueryResultCachedVideo {
             id: String::from("id"),
-            video_file_id: String::from("video_file_id"),
+            video_file_id: FileId::from("video_file_id"),
             title: String::from("title"),
             description: None,
             caption: None,
@@ -827,7 +927,7 @@ mod tests {
     fn cached_video_full() {
         let structure = InlineQueryResult::CachedVideo(InlineQueryResultCachedVideo {
             id: String::from("id"),
-            video_file_id: String::from("video_file_id"),
+            video_file_id: FileId::from("video_file_id"),
             title: String::from("title"),
             description: Some(String::from("description")),
             caption: Some(String::from("caption")),
@@ -918,7 +1018,7 @@ mod tests {
     fn cached_voice_min() {
         let structure = InlineQueryResult::CachedVoice(InlineQueryResultCachedVoice {
             id: String::from("id"),
-            voice_file_id: String::from("voice_file_id"),
+            voice_file_id: FileId::from("voice_file_id"),
             title: String::from("title"),
             caption: None,
             parse_mode: None,
@@ -939,7 +1039,7 @@ mod tests {
     fn cached_voice_full() {
         let structure = InlineQueryResult::CachedVoice(InlineQueryResultCachedVoice {
             id: String::from("id"),
-            voice_file_id: String::from("voice_file_id"),
+            voice_file_id: FileId::from("voice_file_id"),
             title: String::from("title"),
             caption: Some(String::from("caption")),
             parse_mode: Some(ParseMode::Html),
@@ -21,7 +21,7 @@ pub struct InlineQueryResultArticle {
     pub reply_markup: Option<InlineKeyboardMarkup>,
 
     /// URL of the result.
-    pub url: Option<reqwest::Url>,
+    pub url: Option<url::Url>,
 
     /// Pass `true`, if you don't want the URL to be shown in the
     /// message.
@@ -31,7 +31,7 @@ pub struct InlineQueryResultArticle {
     pub description: Option<String>,
 
     /// Url of the thumbnail for the result.
-    pub thumb_url: Option<reqwest::Url>,
+    pub thumb_url: Option<url::Url>,
 
     /// Thumbnail width.
     pub thumb_width: Option<u32>,
@@ -89,7 +89,7 @@ impl InlineQueryResultArticle {
     }
 
     #[must_use]
-    pub fn url(mut self, val: reqwest::Url) -> Self {
+    pub fn url(mut self, val: url::Url) -> Self {
         self.url = Some(val);
         self
     }
@@ -109,7 +109,7 @@ impl InlineQueryResultArticle {
     }
 
     #[must_use]
-    pub fn thumb_url(mut self, val: reqwest::Url) -> Self {
+    pub fn thumb_url(mut self, val: url::Url) -> Self {
         self.thumb_url = Some(val);
         self
     }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
+use crate::types::{FileId, InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
 
 /// Represents a link to a file stored on the Telegram servers.
 ///
@@ -19,7 +19,7 @@ pub struct InlineQueryResultCachedDocument {
     pub title: String,
 
     /// A valid file identifier for the file.
-    pub document_file_id: String,
+    pub document_file_id: FileId,
 
     /// Short description of the result.
     pub description: Option<String>,
@@ -53,7 +53,7 @@ impl InlineQueryResultCachedDocument {
     where
         S1: Into<String>,
         S2: Into<String>,
-        S3: Into<String>,
+        S3: Into<FileId>,
     {
         Self {
             id: id.into(),
@@ -86,7 +86,7 @@ impl InlineQueryResultCachedDocument {
 
     pub fn document_file_id<S>(mut self, val: S) -> Self
     where
-        S: Into<String>,
+        S: Into<FileId>,
     {
         self.document_file_id = val.into();
         self
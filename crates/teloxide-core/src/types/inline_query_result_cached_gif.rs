@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
+use crate::types::{FileId, InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
 
 /// Represents a link to an animated GIF file stored on the Telegram servers.
 ///
@@ -16,7 +16,7 @@ pub struct InlineQueryResultCachedGif {
     pub id: String,
 
     /// A valid file identifier for the GIF file.
-    pub gif_file_id: String,
+    pub gif_file_id: FileId,
 
     /// Title for the result.
     pub title: Option<String>,
@@ -50,7 +50,7 @@ impl InlineQueryResultCachedGif {
     pub fn new<S1, S2>(id: S1, gif_file_id: S2) -> Self
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: Into<FileId>,
     {
         Self {
             id: id.into(),
@@ -74,7 +74,7 @@ impl InlineQueryResultCachedGif {
 
     pub fn gif_file_id<S>(mut self, val: S) -> Self
     where
-        S: Into<String>,
+        S: Into<FileId>,
     {
         self.gif_file_id = val.into();
         self
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
+use crate::types::{FileId, InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
 
 /// Represents a link to a photo stored on the Telegram servers.
 ///
@@ -16,7 +16,7 @@ pub struct InlineQueryResultCachedPhoto {
     pub id: String,
 
     /// A valid file identifier of the photo.
-    pub photo_file_id: String,
+    pub photo_file_id: FileId,
 
     /// Title for the result.
     pub title: Option<String>,
@@ -52,7 +52,7 @@ impl InlineQueryResultCachedPhoto {
     pub fn new<S1, S2>(id: S1, photo_file_id: S2) -> Self
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: Into<FileId>,
     {
         Self {
             id: id.into(),
@@ -77,7 +77,7 @@ impl InlineQueryResultCachedPhoto {
 
     pub fn photo_file_id<S>(mut self, val: S) -> Self
     where
-        S: Into<String>,
+        S: Into<FileId>,
     {
         self.photo_file_id = val.into();
         self
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{InlineKeyboardMarkup, InputMessageContent};
+use crate::types::{FileId, InlineKeyboardMarkup, InputMessageContent};
 
 /// Represents a link to a sticker stored on the Telegram servers.
 ///
@@ -16,7 +16,7 @@ pub struct InlineQueryResultCachedSticker {
     pub id: String,
 
     /// A valid file identifier of the sticker.
-    pub sticker_file_id: String,
+    pub sticker_file_id: FileId,
 
     /// [Inline keyboard] attached to the message.
     ///
@@ -31,7 +31,7 @@ impl InlineQueryResultCachedSticker {
     pub fn new<S1, S2>(id: S1, sticker_file_id: S2) -> Self
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: Into<FileId>,
     {
         Self {
             id: id.into(),
@@ -51,7 +51,7 @@ impl InlineQueryResultCachedSticker {
 
     pub fn sticker_file_id<S>(mut self, val: S) -> Self
     where
-        S: Into<String>,
+        S: Into<FileId>,
     {
         self.sticker_file_id = val.into();
         self
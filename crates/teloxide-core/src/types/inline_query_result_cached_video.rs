@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
+use crate::types::{FileId, InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
 
 /// Represents a link to a video file stored on the Telegram servers.
 ///
@@ -16,7 +16,7 @@ pub struct InlineQueryResultCachedVideo {
     pub id: String,
 
     /// A valid file identifier for the video file.
-    pub video_file_id: String,
+    pub video_file_id: FileId,
 
     /// Title for each result.
     pub title: String,
@@ -52,7 +52,7 @@ impl InlineQueryResultCachedVideo {
     pub fn new<S1, S2, S3>(id: S1, video_file_id: S2, title: S3) -> Self
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: Into<FileId>,
         S3: Into<String>,
     {
         Self {
@@ -78,7 +78,7 @@ impl InlineQueryResultCachedVideo {
 
     pub fn video_file_id<S>(mut self, val: S) -> Self
     where
-        S: Into<String>,
+        S: Into<FileId>,
     {
         self.video_file_id = val.into();
         self
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
+use crate::types::{FileId, InlineKeyboardMarkup, InputMessageContent, MessageEntity, ParseMode};
 
 /// Represents a link to a voice message stored on the Telegram servers.
 ///
@@ -16,7 +16,7 @@ pub struct InlineQueryResultCachedVoice {
     pub id: String,
 
     /// A valid file identifier for the voice message.
-    pub voice_file_id: String,
+    pub voice_file_id: FileId,
 
     /// Voice message title.
     pub title: String,
@@ -49,7 +49,7 @@ impl InlineQueryResultCachedVoice {
     pub fn new<S1, S2, S3>(id: S1, voice_file_id: S2, title: S3) -> Self
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: Into<FileId>,
         S3: Into<String>,
     {
         Self {
@@ -74,7 +74,7 @@ impl InlineQueryResultCachedVoice {
 
     pub fn voice_file_id<S>(mut self, val: S) -> Self
     where
-        S: Into<String>,
+        S: Into<FileId>,
     {
         self.voice_file_id = val.into();
         self
@@ -39,7 +39,7 @@ pub struct InlineQueryResultContact {
     pub input_message_content: Option<InputMessageContent>,
 
     /// Url of the thumbnail for the result.
-    pub thumb_url: Option<reqwest::Url>,
+    pub thumb_url: Option<url::Url>,
 
     /// Thumbnail width.
     pub thumb_width: Option<u32>,
@@ -122,7 +122,7 @@ impl InlineQueryResultContact {
     }
 
     #[must_use]
-    pub fn thumb_url(mut self, val: reqwest::Url) -> Self {
+    pub fn thumb_url(mut self, val: url::Url) -> Self {
         self.thumb_url = Some(val);
         self
     }
@@ -36,7 +36,7 @@ pub struct InlineQueryResultDocument {
     pub caption_entities: Option<Vec<MessageEntity>>,
 
     /// A valid URL for the file.
-    pub document_url: reqwest::Url,
+    pub document_url: url::Url,
 
     /// Mime type of the content of the file, either `application/pdf` or
     /// `application/zip`.
@@ -53,7 +53,7 @@ pub struct InlineQueryResultDocument {
     pub input_message_content: Option<InputMessageContent>,
 
     /// URL of the thumbnail (jpeg only) for the file.
-    pub thumb_url: Option<reqwest::Url>,
+    pub thumb_url: Option<url::Url>,
 
     /// Thumbnail width.
     pub thumb_width: Option<u32>,
@@ -102,7 +102,7 @@ impl InlineQueryResultDocument {
     }
 
     #[must_use]
-    pub fn document_url(mut self, val: reqwest::Url) -> Self {
+    pub fn document_url(mut self, val: url::Url) -> Self {
         self.document_url = val;
         self
     }
@@ -134,7 +134,7 @@ impl InlineQueryResultDocument {
     }
 
     #[must_use]
-    pub fn thumb_url(mut self, val: reqwest::Url) -> Self {
+    pub fn thumb_url(mut self, val: url::Url) -> Self {
         self.thumb_url = Some(val);
         self
     }
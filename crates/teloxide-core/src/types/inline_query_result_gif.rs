@@ -16,7 +16,7 @@ pub struct InlineQueryResultGif {
     pub id: String,
 
     /// A valid URL for the GIF file. File size must not exceed 1MB.
-    pub gif_url: reqwest::Url,
+    pub gif_url: url::Url,
 
     /// Width of the GIF.
     pub gif_width: Option<u32>,
@@ -28,7 +28,7 @@ pub struct InlineQueryResultGif {
     pub gif_duration: Option<Seconds>,
 
     /// URL of the static thumbnail for the result (jpeg or gif).
-    pub thumb_url: reqwest::Url,
+    pub thumb_url: url::Url,
 
     /// Title for the result.
     pub title: Option<String>,
@@ -58,7 +58,7 @@ pub struct InlineQueryResultGif {
 }
 
 impl InlineQueryResultGif {
-    pub fn new<S>(id: S, gif_url: reqwest::Url, thumb_url: reqwest::Url) -> Self
+    pub fn new<S>(id: S, gif_url: url::Url, thumb_url: url::Url) -> Self
     where
         S: Into<String>,
     {
@@ -87,7 +87,7 @@ impl InlineQueryResultGif {
     }
 
     #[must_use]
-    pub fn gif_url(mut self, val: reqwest::Url) -> Self {
+    pub fn gif_url(mut self, val: url::Url) -> Self {
         self.gif_url = val;
         self
     }
@@ -111,7 +111,7 @@ impl InlineQueryResultGif {
     }
 
     #[must_use]
-    pub fn thumb_url(mut self, val: reqwest::Url) -> Self {
+    pub fn thumb_url(mut self, val: url::Url) -> Self {
         self.thumb_url = val;
         self
     }
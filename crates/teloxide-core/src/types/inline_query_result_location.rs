@@ -49,7 +49,7 @@ pub struct InlineQueryResultLocation {
     pub input_message_content: Option<InputMessageContent>,
 
     /// Url of the thumbnail for the result.
-    pub thumb_url: Option<reqwest::Url>,
+    pub thumb_url: Option<url::Url>,
 
     /// Thumbnail width.
     pub thumb_width: Option<u32>,
@@ -146,7 +146,7 @@ impl InlineQueryResultLocation {
     }
 
     #[must_use]
-    pub fn thumb_url(mut self, val: reqwest::Url) -> Self {
+    pub fn thumb_url(mut self, val: url::Url) -> Self {
         self.thumb_url = Some(val);
         self
     }
@@ -19,7 +19,7 @@ pub struct InlineQueryResultMpeg4Gif {
     // FIXME: rename everything so that it doesn't have `mpeg4_` (and similarly for other
     // `InlineQueryResult*`)
     /// A valid URL for the MP4 file. File size must not exceed 1MB.
-    pub mpeg4_url: reqwest::Url,
+    pub mpeg4_url: url::Url,
 
     /// Video width.
     pub mpeg4_width: Option<u32>,
@@ -31,7 +31,7 @@ pub struct InlineQueryResultMpeg4Gif {
     pub mpeg4_duration: Option<Seconds>,
 
     /// URL of the static thumbnail (jpeg or gif) for the result.
-    pub thumb_url: reqwest::Url,
+    pub thumb_url: url::Url,
 
     /// Title for the result.
     pub title: Option<String>,
@@ -61,7 +61,7 @@ pub struct InlineQueryResultMpeg4Gif {
 }
 
 impl InlineQueryResultMpeg4Gif {
-    pub fn new<S>(id: S, mpeg4_url: reqwest::Url, thumb_url: reqwest::Url) -> Self
+    pub fn new<S>(id: S, mpeg4_url: url::Url, thumb_url: url::Url) -> Self
     where
         S: Into<String>,
     {
@@ -90,7 +90,7 @@ impl InlineQueryResultMpeg4Gif {
     }
 
     #[must_use]
-    pub fn mpeg4_url(mut self, val: reqwest::Url) -> Self {
+    pub fn mpeg4_url(mut self, val: url::Url) -> Self {
         self.mpeg4_url = val;
         self
     }
@@ -114,7 +114,7 @@ impl InlineQueryResultMpeg4Gif {
     }
 
     #[must_use]
-    pub fn thumb_url(mut self, val: reqwest::Url) -> Self {
+    pub fn thumb_url(mut self, val: url::Url) -> Self {
         self.thumb_url = val;
         self
     }
@@ -17,10 +17,10 @@ pub struct InlineQueryResultPhoto {
 
     /// A valid URL of the photo. Photo must be in **jpeg** format. Photo size
     /// must not exceed 5MB.
-    pub photo_url: reqwest::Url,
+    pub photo_url: url::Url,
 
     /// URL of the thumbnail for the photo.
-    pub thumb_url: reqwest::Url,
+    pub thumb_url: url::Url,
 
     /// Width of the photo.
     pub photo_width: Option<u32>,
@@ -59,7 +59,7 @@ pub struct InlineQueryResultPhoto {
 }
 
 impl InlineQueryResultPhoto {
-    pub fn new<S>(id: S, photo_url: reqwest::Url, thumb_url: reqwest::Url) -> Self
+    pub fn new<S>(id: S, photo_url: url::Url, thumb_url: url::Url) -> Self
     where
         S: Into<String>,
     {
@@ -88,13 +88,13 @@ impl InlineQueryResultPhoto {
     }
 
     #[must_use]
-    pub fn photo_url(mut self, val: reqwest::Url) -> Self {
+    pub fn photo_url(mut self, val: url::Url) -> Self {
         self.photo_url = val;
         self
     }
 
     #[must_use]
-    pub fn thumb_url<S>(mut self, val: reqwest::Url) -> Self {
+    pub fn thumb_url<S>(mut self, val: url::Url) -> Self {
         self.thumb_url = val;
         self
     }
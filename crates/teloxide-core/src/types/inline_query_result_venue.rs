@@ -52,7 +52,7 @@ pub struct InlineQueryResultVenue {
     pub input_message_content: Option<InputMessageContent>,
 
     /// Url of the thumbnail for the result.
-    pub thumb_url: Option<reqwest::Url>,
+    pub thumb_url: Option<url::Url>,
 
     /// Thumbnail width.
     pub thumb_width: Option<u32>,
@@ -167,7 +167,7 @@ impl InlineQueryResultVenue {
     }
 
     #[must_use]
-    pub fn thumb_url(mut self, val: reqwest::Url) -> Self {
+    pub fn thumb_url(mut self, val: url::Url) -> Self {
         self.thumb_url = Some(val);
         self
     }
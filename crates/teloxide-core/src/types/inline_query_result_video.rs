@@ -18,14 +18,14 @@ pub struct InlineQueryResultVideo {
     pub id: String,
 
     /// A valid URL for the embedded video player or video file.
-    pub video_url: reqwest::Url,
+    pub video_url: url::Url,
 
     /// Mime type of the content of video url, `text/html` or `video/mp4`.
     #[serde(with = "crate::types::non_telegram_types::mime::deser")]
     pub mime_type: Mime,
 
     /// URL of the thumbnail (jpeg only) for the video.
-    pub thumb_url: reqwest::Url,
+    pub thumb_url: url::Url,
 
     /// Title for the result.
     pub title: String,
@@ -74,9 +74,9 @@ pub struct InlineQueryResultVideo {
 impl InlineQueryResultVideo {
     pub fn new<S1, S2>(
         id: S1,
-        video_url: reqwest::Url,
+        video_url: url::Url,
         mime_type: Mime,
-        thumb_url: reqwest::Url,
+        thumb_url: url::Url,
         title: S2,
     ) -> Self
     where
@@ -110,7 +110,7 @@ impl InlineQueryResultVideo {
     }
 
     #[must_use]
-    pub fn video_url(mut self, val: reqwest::Url) -> Self {
+    pub fn video_url(mut self, val: url::Url) -> Self {
         self.video_url = val;
         self
     }
@@ -122,7 +122,7 @@ impl InlineQueryResultVideo {
     }
 
     #[must_use]
-    pub fn thumb_url(mut self, val: reqwest::Url) -> Self {
+    pub fn thumb_url(mut self, val: url::Url) -> Self {
         self.thumb_url = val;
         self
     }
@@ -17,7 +17,7 @@ pub struct InlineQueryResultVoice {
     pub id: String,
 
     /// A valid URL for the voice recording.
-    pub voice_url: reqwest::Url,
+    pub voice_url: url::Url,
 
     /// Recording title.
     pub title: String,
@@ -50,7 +50,7 @@ pub struct InlineQueryResultVoice {
 }
 
 impl InlineQueryResultVoice {
-    pub fn new<S1, S2>(id: S1, voice_url: reqwest::Url, title: S2) -> Self
+    pub fn new<S1, S2>(id: S1, voice_url: url::Url, title: S2) -> Self
     where
         S1: Into<String>,
         S2: Into<String>,
@@ -77,7 +77,7 @@ impl InlineQueryResultVoice {
     }
 
     #[must_use]
-    pub fn voice_url(mut self, val: reqwest::Url) -> Self {
+    pub fn voice_url(mut self, val: url::Url) -> Self {
         self.voice_url = val;
         self
     }
@@ -1,24 +1,35 @@
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+
+use std::{borrow::Cow, fmt, path::PathBuf, sync::Arc};
+
+use crate::types;
+
+#[cfg(feature = "network")]
 use bytes::{Bytes, BytesMut};
+#[cfg(feature = "network")]
 use futures::{
     future::{ready, Either},
-    stream,
+    stream, Stream, StreamExt,
 };
-use once_cell::sync::OnceCell;
+#[cfg(feature = "network")]
 use rc_box::ArcBox;
+#[cfg(feature = "network")]
 use reqwest::{multipart::Part, Body};
-use serde::Serialize;
+#[cfg(feature = "network")]
 use takecell::TakeCell;
+#[cfg(feature = "network")]
 use tokio::{
     io::{AsyncRead, AsyncReadExt, ReadBuf},
     sync::watch,
 };
+#[cfg(feature = "network")]
 use tokio_util::codec::{Decoder, FramedRead};
 
-use std::{
-    borrow::Cow, convert::Infallible, fmt, future::Future, io, iter, mem, path::PathBuf, pin::Pin,
-    sync::Arc, task,
-};
+#[cfg(feature = "network")]
+use std::{convert::Infallible, future::Future, io, iter, mem, pin::Pin, task, time::Duration};
 
+#[cfg(feature = "network")]
 use crate::types::InputSticker;
 
 /// This object represents the contents of a file to be uploaded.
@@ -33,10 +44,16 @@ pub struct InputFile {
 
 #[derive(Clone)]
 enum InnerFile {
+    #[cfg(feature = "network")]
     Read(Read),
     File(PathBuf),
     Bytes(bytes::Bytes),
     Url(url::Url),
+    #[cfg(feature = "network")]
+    Fetch {
+        url: url::Url,
+        max_size: u64,
+    },
     FileId(String),
 }
 
@@ -57,14 +74,60 @@ impl InputFile {
     /// [`SendAudio`]: crate::payloads::SendAudio
     /// [`SendDocument`]: crate::payloads::SendDocument
     /// [`SendVoice`]: crate::payloads::SendVoice
+    ///
+    /// To avoid these limitations, the file can be downloaded by the bot
+    /// itself, see [`InputFile::download_and_upload`].
     #[must_use]
     pub fn url(url: url::Url) -> Self {
         Self::new(Url(url))
     }
 
+    /// Creates an `InputFile` which is downloaded from an url by the bot and
+    /// then uploaded to Telegram.
+    ///
+    /// Unlike [`InputFile::url`], Telegram doesn't fetch the file itself, so
+    /// its limitations on the MIME type and size of files sent by url don't
+    /// apply. The tradeoffs are:
+    /// - The file goes through the bot, so it uses the bot's bandwidth. The
+    ///   file is streamed, it is never read into memory as a whole.
+    /// - Uploads are limited to 50MB by Telegram (unless a [local Bot API
+    ///   server] is used), larger files are not downloaded further.
+    /// - The file is downloaded every time it's sent, it's recommended to reuse
+    ///   the [`FileMeta::id`] of the sent file instead.
+    ///
+    /// If the file can't be downloaded or is too large, the request fails with
+    /// [`RequestError::Network`].
+    ///
+    /// To use a different size limit, see
+    /// [`InputFile::download_and_upload_with_limit`].
+    ///
+    /// [local Bot API server]: https://github.com/tdlib/telegram-bot-api
+    /// [`FileMeta::id`]: crate::types::FileMeta::id
+    /// [`RequestError::Network`]: crate::RequestError::Network
+    #[cfg(feature = "network")]
+    #[must_use]
+    pub fn download_and_upload(url: url::Url) -> Self {
+        /// Maximum size of files uploaded via `multipart/form-data`.
+        const MAX_UPLOAD_SIZE: u64 = 50 * 1024 * 1024;
+
+        Self::download_and_upload_with_limit(url, MAX_UPLOAD_SIZE)
+    }
+
+    /// Creates an `InputFile` which is downloaded from an url by the bot and
+    /// then uploaded to Telegram, if it's no more than `max_size` bytes in
+    /// size.
+    ///
+    /// See [`InputFile::download_and_upload`] for more details.
+    #[cfg(feature = "network")]
+    #[must_use]
+    pub fn download_and_upload_with_limit(url: url::Url, max_size: u64) -> Self {
+        Self::new(Fetch { url, max_size })
+    }
+
     /// Creates an `InputFile` from a file id.
     ///
-    /// File id can be obtained from
+    /// File id can be obtained from the [`FileMeta::id`] of a previously sent
+    /// or received file.
     ///
     /// Notes:
     /// - It is not possible to change the file type when resending by file id.
@@ -78,8 +141,9 @@ impl InputFile {
     ///   valid file_ids even for the same bot.
     ///
     /// [sizes]: crate::types::PhotoSize
-    pub fn file_id(file_id: impl Into<String>) -> Self {
-        Self::new(FileId(file_id.into()))
+    /// [`FileMeta::id`]: crate::types::FileMeta::id
+    pub fn file_id(file_id: impl Into<types::FileId>) -> Self {
+        Self::new(FileId(file_id.into().0))
     }
 
     /// Creates an `InputFile` from a file path.
@@ -102,6 +166,7 @@ impl InputFile {
     ///
     /// Note: in some cases (e.g. sending the same `InputFile` multiple times)
     /// this may read the whole `impl AsyncRead` into memory.
+    #[cfg(feature = "network")]
     pub fn read(it: impl AsyncRead + Send + Unpin + 'static) -> Self {
         Self::new(Read(Read::new(Arc::new(TakeCell::new(it)))))
     }
@@ -122,14 +187,35 @@ impl InputFile {
 
     /// Returns `true` if this file needs an attachment i.e. it's not a file_id
     /// or url that can be serialized without any additional multipart parts.
+    #[cfg(feature = "network")]
     pub(crate) fn needs_attach(&self) -> bool {
         !matches!(self.inner, Url(_) | FileId(_))
     }
 
+    /// Returns the contents of this file, if it was created from in-memory
+    /// bytes.
+    #[cfg(feature = "file_id_cache")]
+    pub(crate) fn as_bytes(&self) -> Option<&Bytes> {
+        match &self.inner {
+            Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Returns the path of this file, if it was created from a file path.
+    #[cfg(feature = "file_id_cache")]
+    pub(crate) fn as_path(&self) -> Option<&std::path::Path> {
+        match &self.inner {
+            File(path) => Some(path),
+            _ => None,
+        }
+    }
+
     /// Takes this file out.
     ///
     /// **Note**: this replaces `self` with a dummy value, this function should
     /// only be used when the file is about to get dropped.
+    #[cfg(feature = "network")]
     pub(crate) fn take(&mut self) -> Self {
         mem::replace(self, InputFile::file_id(String::new()))
     }
@@ -157,12 +243,17 @@ impl InputFile {
 
     /// Takes the file name or tries to guess it based on file name in the path
     /// if `File.0`. Returns an empty string if couldn't guess.
+    #[cfg(feature = "network")]
     fn take_or_guess_filename(&mut self) -> Cow<'static, str> {
         self.file_name.take().unwrap_or_else(|| match &self.inner {
             File(path_to_file) => match path_to_file.file_name() {
                 Some(name) => Cow::Owned(name.to_string_lossy().into_owned()),
                 None => Cow::Borrowed(""),
             },
+            Fetch { url, .. } => match url.path_segments().and_then(|mut s| s.next_back()) {
+                Some(name) => Cow::Owned(name.to_owned()),
+                None => Cow::Borrowed(""),
+            },
             _ => Cow::Borrowed(""),
         })
     }
@@ -171,11 +262,16 @@ impl InputFile {
 impl fmt::Debug for InnerFile {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            #[cfg(feature = "network")]
             Read(_) => f.debug_struct("Read").finish_non_exhaustive(),
             File(path) => f.debug_struct("File").field("path", path).finish(),
             Bytes(bytes) if f.alternate() => f.debug_tuple("Memory").field(bytes).finish(),
             Bytes(_) => f.debug_struct("Memory").finish_non_exhaustive(),
             Url(url) => f.debug_tuple("Url").field(url).finish(),
+            #[cfg(feature = "network")]
+            Fetch { url, max_size } => {
+                f.debug_struct("Fetch").field("url", url).field("max_size", max_size).finish()
+            }
             FileId(file_id) => f.debug_tuple("FileId").field(file_id).finish(),
         }
     }
@@ -192,8 +288,12 @@ impl Serialize for InputFile {
 
 // internal api
 
+#[cfg(feature = "network")]
 impl InputFile {
-    pub(crate) fn into_part(mut self) -> Option<impl Future<Output = Part>> {
+    pub(crate) fn into_part(
+        mut self,
+        client: &reqwest::Client,
+    ) -> Option<impl Future<Output = Part>> {
         let filename = self.take_or_guess_filename();
 
         match self.inner {
@@ -224,11 +324,77 @@ impl InputFile {
                 let stream = Part::stream(data).file_name(filename);
                 Some(Either::Right(Either::Left(ready(stream))))
             }
-            Read(read) => Some(Either::Right(Either::Right(read.into_part(filename)))),
+            Read(read) => {
+                Some(Either::Right(Either::Right(Either::Left(read.into_part(filename)))))
+            }
+            Fetch { url, max_size } => {
+                let client = client.clone();
+                let fut = async move {
+                    let body = match fetch(&client, url, max_size).await {
+                        Ok(stream) => Body::wrap_stream(stream),
+                        Err(err) => {
+                            // explicit type needed for `Bytes: From<?T>` in `wrap_stream`
+                            let err = Err::<Bytes, _>(err);
+                            Body::wrap_stream(stream::iter([err]))
+                        }
+                    };
+
+                    Part::stream(body).file_name(filename)
+                };
+
+                Some(Either::Right(Either::Right(Either::Right(fut))))
+            }
         }
     }
 }
 
+#[cfg(feature = "network")]
+/// Starts downloading a file from `url`, the returned stream fails if the file
+/// is larger than `max_size`.
+///
+/// The file is downloaded with the bot's `client`, so its proxy and TLS
+/// settings apply.
+async fn fetch(
+    client: &reqwest::Client,
+    url: url::Url,
+    max_size: u64,
+) -> io::Result<impl Stream<Item = io::Result<Bytes>> + Send + 'static> {
+    /// Timeout for downloading the whole file, the client's default timeout is
+    /// too short for large files.
+    const FETCH_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+    let too_large = move || {
+        let msg = format!("the file is larger than {max_size} bytes");
+        io::Error::new(io::ErrorKind::InvalidData, msg)
+    };
+
+    let response = client
+        .get(url)
+        .timeout(FETCH_TIMEOUT)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+    if response.content_length().map_or(false, |len| len > max_size) {
+        return Err(too_large());
+    }
+
+    // `Content-Length` may be missing or wrong, so the size is checked while
+    // downloading too
+    let mut size = 0;
+    let stream = response.bytes_stream().map(move |chunk| {
+        let chunk = chunk.map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+        size += chunk.len() as u64;
+        if size > max_size {
+            return Err(too_large());
+        }
+        Ok(chunk)
+    });
+
+    Ok(stream)
+}
+
+#[cfg(feature = "network")]
 /// Adaptor for `AsyncRead` that allows clonning and converting to
 /// `multipart/form-data`
 #[derive(Clone)]
@@ -239,6 +405,7 @@ struct Read {
     wait: watch::Receiver<()>,
 }
 
+#[cfg(feature = "network")]
 impl Read {
     fn new(it: Arc<TakeCell<dyn AsyncRead + Send + Unpin>>) -> Self {
         let (tx, rx) = watch::channel(());
@@ -354,9 +521,11 @@ impl Read {
     }
 }
 
+#[cfg(feature = "network")]
 /// Wrapper over an `ArcBox` that implements `AsyncRead`.
 struct ExclusiveArcAsyncRead(ArcBox<TakeCell<dyn AsyncRead + Send + Unpin>>);
 
+#[cfg(feature = "network")]
 impl AsyncRead for ExclusiveArcAsyncRead {
     fn poll_read(
         self: Pin<&mut Self>,
@@ -369,8 +538,10 @@ impl AsyncRead for ExclusiveArcAsyncRead {
     }
 }
 
+#[cfg(feature = "network")]
 struct BytesDecoder;
 
+#[cfg(feature = "network")]
 impl Decoder for BytesDecoder {
     type Item = Bytes;
     type Error = io::Error;
@@ -383,6 +554,7 @@ impl Decoder for BytesDecoder {
     }
 }
 
+#[cfg(feature = "network")]
 /// An internal trait that is used in expansion of `impl_payload!` used to work
 /// with input-file-like things (`InputFile` itself, `Option<InputFile>`,
 /// `InputSticker`)
@@ -392,6 +564,7 @@ pub(crate) trait InputFileLike {
     fn move_into(&mut self, into: &mut dyn FnMut(InputFile));
 }
 
+#[cfg(feature = "network")]
 impl InputFileLike for InputFile {
     fn copy_into(&self, into: &mut dyn FnMut(InputFile)) {
         into(self.clone())
@@ -402,6 +575,7 @@ impl InputFileLike for InputFile {
     }
 }
 
+#[cfg(feature = "network")]
 impl InputFileLike for Option<InputFile> {
     fn copy_into(&self, into: &mut dyn FnMut(InputFile)) {
         if let Some(this) = self {
@@ -416,6 +590,7 @@ impl InputFileLike for Option<InputFile> {
     }
 }
 
+#[cfg(feature = "network")]
 impl InputFileLike for InputSticker {
     fn copy_into(&self, into: &mut dyn FnMut(InputFile)) {
         let (Self::Png(input_file) | Self::Tgs(input_file) | Self::Webm(input_file)) = self;
@@ -429,3 +604,51 @@ impl InputFileLike for InputSticker {
         input_file.move_into(into)
     }
 }
+
+#[cfg(all(test, feature = "network"))]
+mod tests {
+    use futures::TryStreamExt;
+    use tokio::{io::AsyncWriteExt, net::TcpListener};
+
+    use super::*;
+
+    /// Serves `body` once, returns the url of the file.
+    async fn serve(body: &'static str) -> url::Url {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0; 1024];
+            let _ = socket.read(&mut request).await.unwrap();
+
+            let response =
+                format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n{body}", body.len());
+            socket.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        format!("http://{addr}/files/cat.png").parse().unwrap()
+    }
+
+    #[test]
+    fn download_and_upload() {
+        let url: url::Url = "https://example.com/files/cat.png".parse().unwrap();
+        let mut file = InputFile::download_and_upload(url.clone());
+
+        assert!(file.needs_attach());
+        assert!(file.attach_or_value().starts_with("attach://"));
+        assert_eq!(file.take_or_guess_filename(), "cat.png");
+        assert!(!InputFile::url(url).needs_attach());
+    }
+
+    #[tokio::test]
+    async fn fetch_limit() {
+        let url = serve("12345").await;
+        let chunks: Vec<_> =
+            fetch(&reqwest::Client::new(), url, 5).await.unwrap().try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), b"12345");
+
+        let url = serve("123456").await;
+        let err = fetch(&reqwest::Client::new(), url, 5).await.err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -1,3 +1,4 @@
+#[cfg(feature = "network")]
 use std::iter;
 
 use serde::Serialize;
@@ -101,7 +102,7 @@ pub struct InputMediaVideo {
     /// JPEG format and less than 200 kB in size. A thumbnail‘s width and
     /// height should not exceed 320. Ignored if the file is not uploaded
     /// using multipart/form-data.
-    pub thumb: Option<InputFile>,
+    pub thumbnail: Option<InputFile>,
 
     /// Caption of the video to be sent, 0-1024 characters.
     pub caption: Option<String>,
@@ -139,7 +140,7 @@ impl InputMediaVideo {
     pub const fn new(media: InputFile) -> Self {
         Self {
             media,
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             caption_entities: None,
@@ -156,11 +157,16 @@ impl InputMediaVideo {
         self
     }
 
-    pub fn thumb(mut self, val: InputFile) -> Self {
-        self.thumb = Some(val);
+    pub fn thumbnail(mut self, val: InputFile) -> Self {
+        self.thumbnail = Some(val);
         self
     }
 
+    #[deprecated(since = "0.9.2", note = "use `thumbnail` instead")]
+    pub fn thumb(self, val: InputFile) -> Self {
+        self.thumbnail(val)
+    }
+
     pub fn caption<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
@@ -226,7 +232,7 @@ pub struct InputMediaAnimation {
     /// JPEG format and less than 200 kB in size. A thumbnail‘s width and
     /// height should not exceed 320. Ignored if the file is not uploaded
     /// using multipart/form-data.
-    pub thumb: Option<InputFile>,
+    pub thumbnail: Option<InputFile>,
 
     /// Caption of the animation to be sent, 0-1024 characters.
     pub caption: Option<String>,
@@ -262,7 +268,7 @@ impl InputMediaAnimation {
     pub const fn new(media: InputFile) -> Self {
         Self {
             media,
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             width: None,
@@ -278,11 +284,16 @@ impl InputMediaAnimation {
         self
     }
 
-    pub fn thumb(mut self, val: InputFile) -> Self {
-        self.thumb = Some(val);
+    pub fn thumbnail(mut self, val: InputFile) -> Self {
+        self.thumbnail = Some(val);
         self
     }
 
+    #[deprecated(since = "0.9.2", note = "use `thumbnail` instead")]
+    pub fn thumb(self, val: InputFile) -> Self {
+        self.thumbnail(val)
+    }
+
     pub fn caption<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
@@ -342,7 +353,7 @@ pub struct InputMediaAudio {
     /// JPEG format and less than 200 kB in size. A thumbnail‘s width and
     /// height should not exceed 320. Ignored if the file is not uploaded
     /// using multipart/form-data.
-    pub thumb: Option<InputFile>,
+    pub thumbnail: Option<InputFile>,
 
     /// Caption of the audio to be sent, 0-1024 characters.
     pub caption: Option<String>,
@@ -373,7 +384,7 @@ impl InputMediaAudio {
     pub const fn new(media: InputFile) -> Self {
         Self {
             media,
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             performer: None,
@@ -388,11 +399,16 @@ impl InputMediaAudio {
         self
     }
 
-    pub fn thumb(mut self, val: InputFile) -> Self {
-        self.thumb = Some(val);
+    pub fn thumbnail(mut self, val: InputFile) -> Self {
+        self.thumbnail = Some(val);
         self
     }
 
+    #[deprecated(since = "0.9.2", note = "use `thumbnail` instead")]
+    pub fn thumb(self, val: InputFile) -> Self {
+        self.thumbnail(val)
+    }
+
     pub fn caption<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
@@ -450,7 +466,7 @@ pub struct InputMediaDocument {
     /// JPEG format and less than 200 kB in size. A thumbnail‘s width and
     /// height should not exceed 320. Ignored if the file is not uploaded
     /// using multipart/form-data.
-    pub thumb: Option<InputFile>,
+    pub thumbnail: Option<InputFile>,
 
     /// Caption of the document to be sent, 0-1024 characters.
     pub caption: Option<String>,
@@ -477,7 +493,7 @@ impl InputMediaDocument {
     pub const fn new(media: InputFile) -> Self {
         Self {
             media,
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             disable_content_type_detection: None,
@@ -490,11 +506,16 @@ impl InputMediaDocument {
         self
     }
 
-    pub fn thumb(mut self, val: InputFile) -> Self {
-        self.thumb = Some(val);
+    pub fn thumbnail(mut self, val: InputFile) -> Self {
+        self.thumbnail = Some(val);
         self
     }
 
+    #[deprecated(since = "0.9.2", note = "use `thumbnail` instead")]
+    pub fn thumb(self, val: InputFile) -> Self {
+        self.thumbnail(val)
+    }
+
     pub fn caption<S>(mut self, val: S) -> Self
     where
         S: Into<String>,
@@ -517,6 +538,36 @@ impl InputMediaDocument {
     }
 }
 
+impl From<InputMediaPhoto> for InputMedia {
+    fn from(media: InputMediaPhoto) -> Self {
+        Self::Photo(media)
+    }
+}
+
+impl From<InputMediaVideo> for InputMedia {
+    fn from(media: InputMediaVideo) -> Self {
+        Self::Video(media)
+    }
+}
+
+impl From<InputMediaAnimation> for InputMedia {
+    fn from(media: InputMediaAnimation) -> Self {
+        Self::Animation(media)
+    }
+}
+
+impl From<InputMediaAudio> for InputMedia {
+    fn from(media: InputMediaAudio) -> Self {
+        Self::Audio(media)
+    }
+}
+
+impl From<InputMediaDocument> for InputMedia {
+    fn from(media: InputMediaDocument) -> Self {
+        Self::Document(media)
+    }
+}
+
 impl From<InputMedia> for InputFile {
     fn from(media: InputMedia) -> InputFile {
         match media {
@@ -529,35 +580,36 @@ impl From<InputMedia> for InputFile {
     }
 }
 
+#[cfg(feature = "network")]
 impl InputMedia {
     /// Returns an iterator of all files in this input media
     pub(crate) fn files(&self) -> impl Iterator<Item = &InputFile> {
         use InputMedia::*;
 
-        let (media, thumb) = match self {
+        let (media, thumbnail) = match self {
             Photo(InputMediaPhoto { media, .. }) => (media, None),
-            Document(InputMediaDocument { media, thumb, .. })
-            | Audio(InputMediaAudio { media, thumb, .. })
-            | Animation(InputMediaAnimation { media, thumb, .. })
-            | Video(InputMediaVideo { media, thumb, .. }) => (media, thumb.as_ref()),
+            Document(InputMediaDocument { media, thumbnail, .. })
+            | Audio(InputMediaAudio { media, thumbnail, .. })
+            | Animation(InputMediaAnimation { media, thumbnail, .. })
+            | Video(InputMediaVideo { media, thumbnail, .. }) => (media, thumbnail.as_ref()),
         };
 
-        iter::once(media).chain(thumb)
+        iter::once(media).chain(thumbnail)
     }
 
     /// Returns an iterator of all files in this input media
     pub(crate) fn files_mut(&mut self) -> impl Iterator<Item = &mut InputFile> {
         use InputMedia::*;
 
-        let (media, thumb) = match self {
+        let (media, thumbnail) = match self {
             Photo(InputMediaPhoto { media, .. }) => (media, None),
-            Document(InputMediaDocument { media, thumb, .. })
-            | Audio(InputMediaAudio { media, thumb, .. })
-            | Animation(InputMediaAnimation { media, thumb, .. })
-            | Video(InputMediaVideo { media, thumb, .. }) => (media, thumb.as_mut()),
+            Document(InputMediaDocument { media, thumbnail, .. })
+            | Audio(InputMediaAudio { media, thumbnail, .. })
+            | Animation(InputMediaAnimation { media, thumbnail, .. })
+            | Video(InputMediaVideo { media, thumbnail, .. }) => (media, thumbnail.as_mut()),
         };
 
-        iter::once(media).chain(thumb)
+        iter::once(media).chain(thumbnail)
     }
 }
 
@@ -585,7 +637,7 @@ mod tests {
         let expected_json = r#"{"type":"video","media":"123456"}"#;
         let video = InputMedia::Video(InputMediaVideo {
             media: InputFile::file_id("123456"),
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             width: None,
@@ -605,7 +657,7 @@ mod tests {
         let expected_json = r#"{"type":"animation","media":"123456"}"#;
         let video = InputMedia::Animation(InputMediaAnimation {
             media: InputFile::file_id("123456"),
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             width: None,
@@ -624,7 +676,7 @@ mod tests {
         let expected_json = r#"{"type":"audio","media":"123456"}"#;
         let video = InputMedia::Audio(InputMediaAudio {
             media: InputFile::file_id("123456"),
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             duration: None,
@@ -642,7 +694,7 @@ mod tests {
         let expected_json = r#"{"type":"document","media":"123456"}"#;
         let video = InputMedia::Document(InputMediaDocument {
             media: InputFile::file_id("123456"),
-            thumb: None,
+            thumbnail: None,
             caption: None,
             parse_mode: None,
             caption_entities: None,
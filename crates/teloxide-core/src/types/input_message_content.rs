@@ -1,5 +1,5 @@
-use reqwest::Url;
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 use crate::types::{Currency, LabeledPrice, MessageEntity, ParseMode};
 
@@ -140,6 +140,24 @@ impl InputMessageContentLocation {
         self.live_period = Some(val);
         self
     }
+
+    #[must_use]
+    pub const fn horizontal_accuracy(mut self, val: f64) -> Self {
+        self.horizontal_accuracy = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub const fn heading(mut self, val: u16) -> Self {
+        self.heading = Some(val);
+        self
+    }
+
+    #[must_use]
+    pub const fn proximity_alert_radius(mut self, val: u32) -> Self {
+        self.proximity_alert_radius = Some(val);
+        self
+    }
 }
 
 /// Represents the content of a venue message to be sent as the result of
@@ -237,6 +255,22 @@ impl InputMessageContentVenue {
         self.foursquare_type = Some(val.into());
         self
     }
+
+    pub fn google_place_id<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.google_place_id = Some(val.into());
+        self
+    }
+
+    pub fn google_place_type<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.google_place_type = Some(val.into());
+        self
+    }
 }
 
 /// Represents the content of a contact message to be sent as the result of
@@ -611,6 +645,21 @@ mod tests {
         assert_eq!(expected_json, actual_json);
     }
 
+    #[test]
+    fn live_location_serialize() {
+        let expected_json = r#"{"latitude":59.08,"longitude":38.4326,"horizontal_accuracy":10.5,"live_period":60,"heading":90,"proximity_alert_radius":100}"#;
+        let location_content = InputMessageContent::Location(
+            InputMessageContentLocation::new(59.08, 38.4326)
+                .horizontal_accuracy(10.5)
+                .live_period(60)
+                .heading(90)
+                .proximity_alert_radius(100),
+        );
+
+        let actual_json = serde_json::to_string(&location_content).unwrap();
+        assert_eq!(expected_json, actual_json);
+    }
+
     #[test]
     fn venue_serialize() {
         let expected_json = r#"{"latitude":59.08,"longitude":38.4326,"title":"some title","address":"some address"}"#;
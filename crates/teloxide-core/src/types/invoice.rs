@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::{Currency, CurrencyAmount};
+
 /// This object contains basic information about an invoice.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#invoice).
@@ -28,3 +30,12 @@ pub struct Invoice {
     /// [`currencies.json`]: https://core.telegram.org/bots/payments/currencies.json
     pub total_amount: u32,
 }
+
+impl Invoice {
+    /// Returns the total price as a [`CurrencyAmount`], or `None` if the
+    /// currency is unknown.
+    #[must_use]
+    pub fn total(&self) -> Option<CurrencyAmount> {
+        Currency::from_code(&self.currency).map(|c| CurrencyAmount::new(self.total_amount, c))
+    }
+}
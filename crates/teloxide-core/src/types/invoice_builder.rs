@@ -0,0 +1,338 @@
+use thiserror::Error;
+use url::Url;
+
+use crate::types::{Currency, CurrencyAmount, LabeledPrice, ParseAmountError};
+#[cfg(feature = "network")]
+use crate::{
+    payloads::{CreateInvoiceLinkSetters, SendInvoiceSetters},
+    requests::Requester,
+    types::Recipient,
+};
+
+/// A builder of invoices, which checks Telegram's constraints before a request
+/// is made.
+///
+/// The same invoice can be sent to a chat via [`InvoiceBuilder::send_invoice`]
+/// or turned into a link via [`InvoiceBuilder::create_invoice_link`].
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::types::{Currency, InvoiceBuilder};
+///
+/// let invoice =
+///     InvoiceBuilder::new("Coffee", "A cup of coffee", "coffee-1", "TOKEN", Currency::USD)
+///         .price_major("Coffee", "1.45")
+///         .unwrap()
+///         .price_major("Tax", "0.15")
+///         .unwrap()
+///         .max_tip_amount(100)
+///         .suggested_tip_amounts([10, 50]);
+///
+/// assert_eq!(invoice.total_amount(), Some(160));
+/// assert!(invoice.validate().is_ok());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[must_use]
+pub struct InvoiceBuilder {
+    title: String,
+    description: String,
+    payload: String,
+    provider_token: String,
+    currency: Currency,
+    prices: Vec<LabeledPrice>,
+    max_tip_amount: Option<u32>,
+    suggested_tip_amounts: Vec<u32>,
+    provider_data: Option<String>,
+    photo_url: Option<Url>,
+}
+
+/// An error returned when an invoice violates Telegram's constraints.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum InvoiceError {
+    /// The title is not 1-32 characters long.
+    #[error("An invoice title must be 1-32 characters long")]
+    InvalidTitle,
+
+    /// The description is not 1-255 characters long.
+    #[error("An invoice description must be 1-255 characters long")]
+    InvalidDescription,
+
+    /// The payload is not 1-128 bytes long.
+    #[error("An invoice payload must be 1-128 bytes long")]
+    InvalidPayload,
+
+    /// An invoice has no prices.
+    #[error("An invoice must have at least one price")]
+    NoPrices,
+
+    /// The total amount doesn't fit into `u32`.
+    #[error("The total amount of an invoice is too large")]
+    TotalAmountOverflow,
+
+    /// An amount in major units is not a valid number, or it has more digits
+    /// past the decimal point than the currency allows.
+    #[error(transparent)]
+    InvalidAmount(#[from] ParseAmountError),
+
+    /// Suggested tip amounts are not 1-4 positive, strictly increasing amounts
+    /// not exceeding the maximum tip amount.
+    #[error("Invalid suggested tip amounts")]
+    InvalidSuggestedTips,
+}
+
+impl InvoiceBuilder {
+    /// Maximal number of suggested tip amounts.
+    pub const MAX_SUGGESTED_TIPS: usize = 4;
+
+    /// Creates a builder of an invoice without prices.
+    pub fn new<T, D, Pa, P>(
+        title: T,
+        description: D,
+        payload: Pa,
+        provider_token: P,
+        currency: Currency,
+    ) -> Self
+    where
+        T: Into<String>,
+        D: Into<String>,
+        Pa: Into<String>,
+        P: Into<String>,
+    {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            payload: payload.into(),
+            provider_token: provider_token.into(),
+            currency,
+            prices: Vec::new(),
+            max_tip_amount: None,
+            suggested_tip_amounts: Vec::new(),
+            provider_data: None,
+            photo_url: None,
+        }
+    }
+
+    /// Adds a price in the smallest units of the currency.
+    pub fn price<L>(mut self, label: L, amount: u32) -> Self
+    where
+        L: Into<String>,
+    {
+        self.prices.push(LabeledPrice::new(label, amount));
+        self
+    }
+
+    /// Adds a price in major units of the currency, e.g. `"1.45"` for
+    /// `US$ 1.45`.
+    ///
+    /// Returns an error if `amount` has more digits past the decimal point
+    /// than the currency allows (see [`Currency::exp`]).
+    pub fn price_major<L>(self, label: L, amount: &str) -> Result<Self, InvoiceError>
+    where
+        L: Into<String>,
+    {
+        let amount = CurrencyAmount::from_major_units(amount, self.currency)?;
+        Ok(self.price(label, amount.minor_units()))
+    }
+
+    /// Sets the maximum accepted amount for tips, in the smallest units of the
+    /// currency.
+    pub fn max_tip_amount(mut self, val: u32) -> Self {
+        self.max_tip_amount = Some(val);
+        self
+    }
+
+    /// Sets suggested amounts of tips, in the smallest units of the currency.
+    pub fn suggested_tip_amounts<I>(mut self, val: I) -> Self
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        self.suggested_tip_amounts = val.into_iter().collect();
+        self
+    }
+
+    /// Sets data about the invoice, which will be shared with the payment
+    /// provider.
+    pub fn provider_data<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.provider_data = Some(val.into());
+        self
+    }
+
+    /// Sets URL of the product photo.
+    pub fn photo_url(mut self, val: Url) -> Self {
+        self.photo_url = Some(val);
+        self
+    }
+
+    /// Returns the sum of all prices, or `None` if it overflows.
+    #[must_use]
+    pub fn total_amount(&self) -> Option<u32> {
+        self.prices.iter().try_fold(0u32, |acc, p| acc.checked_add(p.amount))
+    }
+
+    /// Checks the invoice against Telegram's constraints.
+    pub fn validate(&self) -> Result<(), InvoiceError> {
+        if !(1..=32).contains(&self.title.chars().count()) {
+            return Err(InvoiceError::InvalidTitle);
+        }
+
+        if !(1..=255).contains(&self.description.chars().count()) {
+            return Err(InvoiceError::InvalidDescription);
+        }
+
+        if !(1..=128).contains(&self.payload.len()) {
+            return Err(InvoiceError::InvalidPayload);
+        }
+
+        if self.prices.is_empty() {
+            return Err(InvoiceError::NoPrices);
+        }
+
+        if self.total_amount().is_none() {
+            return Err(InvoiceError::TotalAmountOverflow);
+        }
+
+        let tips = &self.suggested_tip_amounts;
+        let max_tip = self.max_tip_amount.unwrap_or(0);
+        let tips_ok = tips.len() <= Self::MAX_SUGGESTED_TIPS
+            && tips.first().map_or(true, |&t| t > 0)
+            && tips.windows(2).all(|w| w[0] < w[1])
+            && tips.last().map_or(true, |&t| t <= max_tip);
+        if !tips_ok {
+            return Err(InvoiceError::InvalidSuggestedTips);
+        }
+
+        Ok(())
+    }
+
+    /// Validates the invoice and creates a request which sends it to
+    /// `chat_id`.
+    #[cfg(feature = "network")]
+    pub fn send_invoice<B, C>(self, bot: &B, chat_id: C) -> Result<B::SendInvoice, InvoiceError>
+    where
+        B: Requester,
+        C: Into<Recipient>,
+    {
+        self.validate()?;
+
+        let mut req = bot.send_invoice(
+            chat_id,
+            self.title,
+            self.description,
+            self.payload,
+            self.provider_token,
+            self.currency.code(),
+            self.prices,
+        );
+        if !self.suggested_tip_amounts.is_empty() {
+            req = req.suggested_tip_amounts(self.suggested_tip_amounts);
+        }
+        if let Some(max_tip_amount) = self.max_tip_amount {
+            req = req.max_tip_amount(max_tip_amount);
+        }
+        if let Some(provider_data) = self.provider_data {
+            req = req.provider_data(provider_data);
+        }
+        if let Some(photo_url) = self.photo_url {
+            req = req.photo_url(photo_url);
+        }
+
+        Ok(req)
+    }
+
+    /// Validates the invoice and creates a request which creates a link for
+    /// it.
+    #[cfg(feature = "network")]
+    pub fn create_invoice_link<B>(self, bot: &B) -> Result<B::CreateInvoiceLink, InvoiceError>
+    where
+        B: Requester,
+    {
+        self.validate()?;
+
+        let mut req = bot.create_invoice_link(
+            self.title,
+            self.description,
+            self.payload,
+            self.provider_token,
+            self.currency.code(),
+            self.prices,
+        );
+        if !self.suggested_tip_amounts.is_empty() {
+            req = req.suggested_tip_amounts(self.suggested_tip_amounts);
+        }
+        if let Some(max_tip_amount) = self.max_tip_amount {
+            req = req.max_tip_amount(max_tip_amount);
+        }
+        if let Some(provider_data) = self.provider_data {
+            req = req.provider_data(provider_data);
+        }
+        if let Some(photo_url) = self.photo_url {
+            req = req.photo_url(photo_url);
+        }
+
+        Ok(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "network")]
+    use crate::{payloads::SendInvoice, requests::HasPayload, types::ChatId, Bot};
+
+    fn invoice() -> InvoiceBuilder {
+        InvoiceBuilder::new("Title", "Description", "payload", "token", Currency::USD)
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(invoice().validate(), Err(InvoiceError::NoPrices));
+        assert_eq!(
+            InvoiceBuilder::new("", "d", "p", "t", Currency::USD).price("a", 1).validate(),
+            Err(InvoiceError::InvalidTitle)
+        );
+
+        let mut long_payload = invoice().price("a", 1);
+        long_payload.payload = "a".repeat(129);
+        assert_eq!(long_payload.validate(), Err(InvoiceError::InvalidPayload));
+
+        assert_eq!(
+            invoice().price("a", u32::MAX).price("b", 1).validate(),
+            Err(InvoiceError::TotalAmountOverflow)
+        );
+        assert_eq!(
+            invoice().price("a", 1).suggested_tip_amounts([10]).validate(),
+            Err(InvoiceError::InvalidSuggestedTips)
+        );
+        assert_eq!(
+            invoice().price("a", 1).max_tip_amount(20).suggested_tip_amounts([10, 5]).validate(),
+            Err(InvoiceError::InvalidSuggestedTips)
+        );
+        assert!(invoice()
+            .price("a", 1)
+            .max_tip_amount(20)
+            .suggested_tip_amounts([5, 10, 15, 20])
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "network")]
+    fn send_invoice() {
+        let bot = Bot::new("TOKEN");
+        let req = invoice()
+            .price_major("Coffee", "2.50")
+            .unwrap()
+            .max_tip_amount(100)
+            .send_invoice(&bot, ChatId(1))
+            .unwrap();
+        let payload: &SendInvoice = req.payload_ref();
+
+        assert_eq!(payload.currency, "USD");
+        assert_eq!(payload.prices, [LabeledPrice::new("Coffee", 250)]);
+        assert_eq!(payload.max_tip_amount, Some(100));
+    }
+}
@@ -1,7 +1,8 @@
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::types::{
-    KeyboardButtonPollType, KeyboardButtonRequestChat, KeyboardButtonRequestUser, True, WebAppInfo,
+    KeyboardButtonPollType, KeyboardButtonRequestChat, KeyboardButtonRequestUser,
+    KeyboardButtonRequestUsers, True, WebAppInfo,
 };
 
 /// This object represents one button of the reply keyboard.
@@ -73,6 +74,13 @@ pub enum ButtonRequest {
     /// bot in a “user_shared” service message.
     RequestUser(KeyboardButtonRequestUser),
 
+    /// If this variant is used, pressing the button will open a list of
+    /// suitable users. Tapping on any user will send their identifiers to the
+    /// bot in a [`users_shared`] service message.
+    ///
+    /// [`users_shared`]: crate::types::MessageKind::UsersShared
+    RequestUsers(KeyboardButtonRequestUsers),
+
     /// If this variant is used, the user will be asked to create a poll and
     /// send it to the bot when the button is pressed.
     ///
@@ -115,6 +123,12 @@ struct RawRequest {
     #[serde(rename = "request_user")]
     user: Option<KeyboardButtonRequestUser>,
 
+    /// If specified, pressing the button will open a list of suitable users.
+    /// Identifiers of the selected users will be sent to the bot in a
+    /// “users_shared” service message. Available in private chats only.
+    #[serde(rename = "request_users")]
+    users: Option<KeyboardButtonRequestUsers>,
+
     /// If specified, the user will be asked to create a poll and
     /// send it to the bot when the button is pressed. Available in private
     /// chats only.
@@ -134,23 +148,25 @@ impl<'de> Deserialize<'de> for ButtonRequest {
     {
         let raw = RawRequest::deserialize(deserializer)?;
         match raw {
-            RawRequest { contact, location, chat, user, poll, web_app }
+            RawRequest { contact, location, chat, user, users, poll, web_app }
                 if 1 < (contact.is_some() as u8
                     + location.is_some() as u8
                     + chat.is_some() as u8
                     + user.is_some() as u8
+                    + users.is_some() as u8
                     + poll.is_some() as u8
                     + web_app.is_some() as u8) =>
             {
                 Err(D::Error::custom(
                     "`request_contact`, `request_location`, `request_chat`, `request_user`, \
-                     `request_poll` and `web_app` fields are mutually exclusive",
+                     `request_users`, `request_poll` and `web_app` fields are mutually exclusive",
                 ))
             }
             RawRequest { contact: Some(True), .. } => Ok(Self::Contact),
             RawRequest { location: Some(True), .. } => Ok(Self::Location),
             RawRequest { chat: Some(request_chat), .. } => Ok(Self::RequestChat(request_chat)),
             RawRequest { user: Some(request_user), .. } => Ok(Self::RequestUser(request_user)),
+            RawRequest { users: Some(request_users), .. } => Ok(Self::RequestUsers(request_users)),
             RawRequest { poll: Some(poll_type), .. } => Ok(Self::Poll(poll_type)),
             RawRequest { web_app: Some(web_app), .. } => Ok(Self::WebApp(web_app)),
 
@@ -159,11 +175,13 @@ impl<'de> Deserialize<'de> for ButtonRequest {
                 location: None,
                 chat: None,
                 user: None,
+                users: None,
                 poll: None,
                 web_app: None,
             } => Err(D::Error::custom(
                 "Either one of `request_contact`, `request_chat`, `request_user`, \
-                 `request_location`, `request_poll` and `web_app` fields is required",
+                 `request_users`, `request_location`, `request_poll` and `web_app` fields is \
+                 required",
             )),
         }
     }
@@ -179,6 +197,7 @@ impl Serialize for ButtonRequest {
             location: None,
             chat: None,
             user: None,
+            users: None,
             poll: None,
             web_app: None,
         };
@@ -188,6 +207,7 @@ impl Serialize for ButtonRequest {
             Self::Location => raw.location = Some(True),
             Self::RequestChat(request_chat) => raw.chat = Some(request_chat.clone()),
             Self::RequestUser(request_user) => raw.user = Some(request_user.clone()),
+            Self::RequestUsers(request_users) => raw.users = Some(request_users.clone()),
             Self::Poll(poll_type) => raw.poll = Some(poll_type.clone()),
             Self::WebApp(web_app) => raw.web_app = Some(web_app.clone()),
         };
@@ -228,6 +248,16 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn serialize_users_request() {
+        let request = KeyboardButtonRequestUsers::new(0).user_is_bot(false).max_quantity(3);
+        let button = KeyboardButton::new("").request(ButtonRequest::RequestUsers(request));
+        let expected =
+            r#"{"text":"","request_users":{"request_id":0,"user_is_bot":false,"max_quantity":3}}"#;
+        let actual = serde_json::to_string(&button).unwrap();
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn deserialize_no_request() {
         let json = r#"{"text":""}"#;
@@ -244,4 +274,13 @@ mod tests {
         let actual = serde_json::from_str(json).unwrap();
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn deserialize_users_request() {
+        let json = r#"{"text":"","request_users":{"request_id":1}}"#;
+        let expected = KeyboardButton::new("")
+            .request(ButtonRequest::RequestUsers(KeyboardButtonRequestUsers::new(1)));
+        let actual = serde_json::from_str(json).unwrap();
+        assert_eq!(expected, actual);
+    }
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::{Currency, CurrencyAmount};
+
 /// This object represents a portion of the price for goods or services.
 ///
 /// [The official docs](https://core.telegram.org/bots/api#labeledprice).
@@ -41,6 +43,20 @@ impl LabeledPrice {
         self.amount = val;
         self
     }
+
+    /// Creates a price from a [`CurrencyAmount`].
+    pub fn from_amount<S>(label: S, amount: CurrencyAmount) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(label, amount.minor_units())
+    }
+
+    /// Returns the amount of this price in `currency`.
+    #[must_use]
+    pub fn amount_in(&self, currency: Currency) -> CurrencyAmount {
+        CurrencyAmount::new(self.amount, currency)
+    }
 }
 
 #[cfg(test)]
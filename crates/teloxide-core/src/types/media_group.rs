@@ -0,0 +1,253 @@
+use std::vec;
+
+use thiserror::Error;
+
+use crate::types::{
+    InputMedia, InputMediaAudio, InputMediaDocument, InputMediaPhoto, InputMediaVideo,
+};
+
+/// A validated album of media, ready to be sent via
+/// [`Requester::send_media_group`].
+///
+/// Telegram requires an album to contain 2–10 items. Photos and videos can be
+/// freely mixed, but documents and audio files can only be grouped with media
+/// of the same type. Animations can't be sent as a part of an album at all.
+/// `MediaGroup` checks these constraints when it's constructed, so a malformed
+/// album is rejected before a request is made.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::types::{InputFile, InputMediaPhoto, InputMediaVideo, MediaGroup};
+///
+/// let album = MediaGroup::builder()
+///     .photo(InputMediaPhoto::new(InputFile::file_id("AAA")).caption("Look!"))
+///     .video(InputMediaVideo::new(InputFile::file_id("BBB")).spoiler())
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(album.len(), 2);
+/// ```
+///
+/// [`Requester::send_media_group`]: crate::requests::Requester::send_media_group
+#[derive(Clone, Debug)]
+pub struct MediaGroup {
+    media: Vec<InputMedia>,
+}
+
+/// A builder of [`MediaGroup`].
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct MediaGroupBuilder {
+    media: Vec<InputMedia>,
+}
+
+/// An error returned when a [`MediaGroup`] violates Telegram's album
+/// constraints.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum MediaGroupError {
+    /// An album has less than [`MediaGroup::MIN_LEN`] or more than
+    /// [`MediaGroup::MAX_LEN`] items.
+    #[error("A media group must contain 2-10 items, got {0}")]
+    InvalidLength(usize),
+
+    /// An item of an album can't be grouped with the other items.
+    #[error("A media group item at index {0} can't be grouped with the previous items")]
+    IncompatibleMedia(usize),
+
+    /// An item at the given index is an animation, which can't be a part of an
+    /// album.
+    #[error("A media group item at index {0} is an animation, which can't be sent in an album")]
+    Animation(usize),
+}
+
+impl MediaGroup {
+    /// Minimal number of items in an album.
+    pub const MIN_LEN: usize = 2;
+
+    /// Maximal number of items in an album.
+    pub const MAX_LEN: usize = 10;
+
+    /// Creates a media group, checking Telegram's album constraints.
+    pub fn new<I>(media: I) -> Result<Self, MediaGroupError>
+    where
+        I: IntoIterator<Item = InputMedia>,
+    {
+        let media: Vec<_> = media.into_iter().collect();
+
+        if !(Self::MIN_LEN..=Self::MAX_LEN).contains(&media.len()) {
+            return Err(MediaGroupError::InvalidLength(media.len()));
+        }
+
+        let kind = |m: &InputMedia| match m {
+            InputMedia::Photo(_) | InputMedia::Video(_) => Ok(AlbumKind::Visual),
+            InputMedia::Audio(_) => Ok(AlbumKind::Audio),
+            InputMedia::Document(_) => Ok(AlbumKind::Document),
+            InputMedia::Animation(_) => Err(()),
+        };
+
+        let mut album_kind = None;
+        for (idx, m) in media.iter().enumerate() {
+            let kind = kind(m).map_err(|()| MediaGroupError::Animation(idx))?;
+
+            match album_kind {
+                None => album_kind = Some(kind),
+                Some(k) if k == kind => {}
+                Some(_) => return Err(MediaGroupError::IncompatibleMedia(idx)),
+            }
+        }
+
+        Ok(Self { media })
+    }
+
+    /// Returns a builder of a media group.
+    pub fn builder() -> MediaGroupBuilder {
+        MediaGroupBuilder::default()
+    }
+
+    /// Returns items of this media group.
+    #[must_use]
+    pub fn media(&self) -> &[InputMedia] {
+        &self.media
+    }
+
+    /// Returns the number of items in this media group.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.media.len()
+    }
+
+    /// Returns `true` if this media group has no items.
+    ///
+    /// Media groups created by [`MediaGroup::new`] and
+    /// [`MediaGroupBuilder::build`] are never empty, since albums with less
+    /// than [`MediaGroup::MIN_LEN`] items are rejected.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.media.is_empty()
+    }
+
+    /// Returns items of this media group.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<InputMedia> {
+        self.media
+    }
+}
+
+impl MediaGroupBuilder {
+    /// Adds an arbitrary media to the album.
+    pub fn media<M>(mut self, media: M) -> Self
+    where
+        M: Into<InputMedia>,
+    {
+        self.media.push(media.into());
+        self
+    }
+
+    /// Adds a photo to the album.
+    pub fn photo(self, photo: InputMediaPhoto) -> Self {
+        self.media(photo)
+    }
+
+    /// Adds a video to the album.
+    pub fn video(self, video: InputMediaVideo) -> Self {
+        self.media(video)
+    }
+
+    /// Adds an audio file to the album.
+    pub fn audio(self, audio: InputMediaAudio) -> Self {
+        self.media(audio)
+    }
+
+    /// Adds a document to the album.
+    pub fn document(self, document: InputMediaDocument) -> Self {
+        self.media(document)
+    }
+
+    /// Validates the album, see [`MediaGroup::new`].
+    pub fn build(self) -> Result<MediaGroup, MediaGroupError> {
+        MediaGroup::new(self.media)
+    }
+}
+
+impl TryFrom<Vec<InputMedia>> for MediaGroup {
+    type Error = MediaGroupError;
+
+    fn try_from(media: Vec<InputMedia>) -> Result<Self, Self::Error> {
+        Self::new(media)
+    }
+}
+
+impl From<MediaGroup> for Vec<InputMedia> {
+    fn from(group: MediaGroup) -> Self {
+        group.media
+    }
+}
+
+impl IntoIterator for MediaGroup {
+    type Item = InputMedia;
+    type IntoIter = vec::IntoIter<InputMedia>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.media.into_iter()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AlbumKind {
+    Visual,
+    Audio,
+    Document,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{InputFile, InputMediaAnimation};
+
+    fn photo() -> InputMediaPhoto {
+        InputMediaPhoto::new(InputFile::file_id("photo"))
+    }
+
+    fn document() -> InputMediaDocument {
+        InputMediaDocument::new(InputFile::file_id("document"))
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(
+            MediaGroup::builder().photo(photo()).build().unwrap_err(),
+            MediaGroupError::InvalidLength(1)
+        );
+        assert_eq!(
+            MediaGroup::new(std::iter::repeat_with(|| photo().into()).take(11)).unwrap_err(),
+            MediaGroupError::InvalidLength(11)
+        );
+        assert_eq!(
+            MediaGroup::new(std::iter::repeat_with(|| photo().into()).take(10)).unwrap().len(),
+            10
+        );
+    }
+
+    #[test]
+    fn mixing() {
+        let video = InputMediaVideo::new(InputFile::file_id("video"));
+        let audio = InputMediaAudio::new(InputFile::file_id("audio"));
+        let animation = InputMediaAnimation::new(InputFile::file_id("animation"));
+
+        assert!(MediaGroup::builder().photo(photo()).video(video).build().is_ok());
+        assert!(MediaGroup::builder().document(document()).document(document()).build().is_ok());
+        assert_eq!(
+            MediaGroup::builder().photo(photo()).document(document()).build().unwrap_err(),
+            MediaGroupError::IncompatibleMedia(1)
+        );
+        assert_eq!(
+            MediaGroup::builder().audio(audio).document(document()).build().unwrap_err(),
+            MediaGroupError::IncompatibleMedia(1)
+        );
+        assert_eq!(
+            MediaGroup::builder().photo(photo()).media(animation).build().unwrap_err(),
+            MediaGroupError::Animation(1)
+        );
+    }
+}
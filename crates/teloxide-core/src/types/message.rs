@@ -10,8 +10,8 @@ use crate::types::{
     GeneralForumTopicHidden, GeneralForumTopicUnhidden, InlineKeyboardMarkup, Invoice, Location,
     MessageAutoDeleteTimerChanged, MessageEntity, MessageEntityRef, MessageId, PassportData,
     PhotoSize, Poll, ProximityAlertTriggered, Sticker, SuccessfulPayment, ThreadId, True, User,
-    UserShared, Venue, Video, VideoChatEnded, VideoChatParticipantsInvited, VideoChatScheduled,
-    VideoChatStarted, VideoNote, Voice, WebAppData, WriteAccessAllowed,
+    UserShared, UsersShared, Venue, Video, VideoChatEnded, VideoChatParticipantsInvited,
+    VideoChatScheduled, VideoChatStarted, VideoNote, Voice, WebAppData, WriteAccessAllowed,
 };
 
 /// This object represents a message.
@@ -62,6 +62,7 @@ pub enum MessageKind {
     Pinned(MessagePinned),
     ChatShared(MessageChatShared),
     UserShared(MessageUserShared),
+    UsersShared(MessageUsersShared),
     Invoice(MessageInvoice),
     SuccessfulPayment(MessageSuccessfulPayment),
     ConnectedWebsite(MessageConnectedWebsite),
@@ -95,7 +96,7 @@ pub struct MessageCommon {
     /// channel messages. The supergroup itself for messages from anonymous
     /// group administrators. The linked channel for messages automatically
     /// forwarded to the discussion group
-    pub sender_chat: Option<Chat>,
+    pub sender_chat: Option<Box<Chat>>,
 
     /// Signature of the post author for messages in channels, or the custom
     /// title of an anonymous group administrator.
@@ -261,6 +262,12 @@ pub struct MessageUserShared {
     pub user_shared: UserShared,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageUsersShared {
+    /// Users were shared with the bot.
+    pub users_shared: UsersShared,
+}
+
 #[serde_with_macros::skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MessageInvoice {
@@ -338,7 +345,7 @@ pub enum ForwardedFrom {
     /// The message was sent by an anonymous user on behalf of a group or
     /// channel.
     #[serde(rename = "forward_from_chat")]
-    Chat(Chat),
+    Chat(Box<Chat>),
     /// The message was sent by a user who disallow adding a link to their
     /// account in forwarded messages.
     #[serde(rename = "forward_sender_name")]
@@ -674,8 +681,8 @@ mod getters {
         MessageGroupChatCreated, MessageId, MessageInvoice, MessageLeftChatMember,
         MessageNewChatMembers, MessageNewChatPhoto, MessageNewChatTitle, MessagePassportData,
         MessagePinned, MessageProximityAlertTriggered, MessageSuccessfulPayment,
-        MessageSupergroupChatCreated, MessageUserShared, MessageVideoChatParticipantsInvited,
-        PhotoSize, User,
+        MessageSupergroupChatCreated, MessageUserShared, MessageUsersShared,
+        MessageVideoChatParticipantsInvited, PhotoSize, User,
     };
 
     use super::{
@@ -711,7 +718,7 @@ mod getters {
         #[must_use]
         pub fn sender_chat(&self) -> Option<&Chat> {
             match &self.kind {
-                Common(MessageCommon { sender_chat, .. }) => sender_chat.as_ref(),
+                Common(MessageCommon { sender_chat, .. }) => sender_chat.as_deref(),
                 _ => None,
             }
         }
@@ -742,7 +749,7 @@ mod getters {
         #[must_use]
         pub fn forward_from_chat(&self) -> Option<&Chat> {
             self.forward_from().and_then(|from| match from {
-                ForwardedFrom::Chat(chat) => Some(chat),
+                ForwardedFrom::Chat(chat) => Some(chat.as_ref()),
                 _ => None,
             })
         }
@@ -1294,6 +1301,14 @@ mod getters {
             }
         }
 
+        #[must_use]
+        pub fn shared_users(&self) -> Option<&types::UsersShared> {
+            match &self.kind {
+                UsersShared(MessageUsersShared { users_shared }) => Some(users_shared),
+                _ => None,
+            }
+        }
+
         #[must_use]
         pub fn dice(&self) -> Option<&types::Dice> {
             match &self.kind {
@@ -1523,7 +1538,7 @@ impl Message {
         //
         // The `url` produced by formatting is correct since username is
         // /[a-zA-Z0-9_]{5,32}/ and chat/message ids are integers.
-        Some(reqwest::Url::parse(&url).unwrap())
+        Some(url::Url::parse(&url).unwrap())
     }
 
     /// Produces a direct link to a comment on this post.
@@ -1762,6 +1777,7 @@ mod tests {
                     }),
                     photo: None,
                     has_aggressive_anti_spam_enabled: false,
+                    available_reactions: None,
                     pinned_message: None,
                     message_auto_delete_time: None,
                     has_hidden_members: false
@@ -1774,6 +1790,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn de_message_auto_delete_timer_changed() {
+        let json = r#"{
+            "message_id": 198283,
+            "chat": {
+              "id": -1001234567890,
+              "title": "Disappearing",
+              "type": "supergroup"
+            },
+            "date": 1567927221,
+            "message_auto_delete_timer_changed": {
+                "message_auto_delete_time": 86400
+            }
+          }"#;
+        let message = from_str::<Message>(json).unwrap();
+        let changed = message.message_auto_delete_timer_changed().unwrap();
+
+        assert_eq!(changed.message_auto_delete_time, Seconds::from_seconds(86400));
+        assert!(!changed.is_disabled());
+    }
+
+    #[test]
+    fn de_proximity_alert_triggered() {
+        let json = r#"{
+            "message_id": 198284,
+            "chat": {
+              "id": -1001234567890,
+              "title": "Travelers",
+              "type": "supergroup"
+            },
+            "date": 1567927221,
+            "proximity_alert_triggered": {
+                "traveler": {"id": 1, "is_bot": false, "first_name": "Traveler"},
+                "watcher": {"id": 2, "is_bot": false, "first_name": "Watcher"},
+                "distance": 150
+            }
+          }"#;
+        let message = from_str::<Message>(json).unwrap();
+        let alert = message.proximity_alert_triggered().unwrap();
+
+        assert_eq!(alert.traveler.id, UserId(1));
+        assert_eq!(alert.watcher.id, UserId(2));
+        assert_eq!(alert.distance, 150);
+        assert_eq!(message.mentioned_users().count(), 2);
+    }
+
+    #[test]
+    fn de_users_shared() {
+        let json = r#"{
+            "message_id": 198285,
+            "chat": {
+              "id": 250918540,
+              "first_name": "Андрей",
+              "type": "private"
+            },
+            "date": 1567927221,
+            "users_shared": {
+                "request_id": 348349,
+                "user_ids": [384939, 384940]
+            }
+          }"#;
+        let message = from_str::<Message>(json).unwrap();
+        let shared = message.shared_users().unwrap();
+
+        assert_eq!(shared.request_id, 348349);
+        assert_eq!(shared.user_ids, [UserId(384939), UserId(384940)]);
+        assert!(message.shared_user().is_none());
+    }
+
     #[test]
     fn de_media_group_forwarded() {
         let json = r#"{
@@ -1997,6 +2082,7 @@ mod tests {
             pinned_message: None,
             has_hidden_members: false,
             has_aggressive_anti_spam_enabled: false,
+            available_reactions: None,
         };
 
         assert!(message.from().unwrap().is_anonymous());
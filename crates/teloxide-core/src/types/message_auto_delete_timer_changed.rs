@@ -10,3 +10,11 @@ pub struct MessageAutoDeleteTimerChanged {
     /// New auto-delete time for messages in the chat
     pub message_auto_delete_time: Seconds,
 }
+
+impl MessageAutoDeleteTimerChanged {
+    /// Returns `true` if the auto-delete timer was disabled.
+    #[must_use]
+    pub fn is_disabled(&self) -> bool {
+        self.message_auto_delete_time.seconds() == 0
+    }
+}
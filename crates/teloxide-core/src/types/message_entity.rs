@@ -93,7 +93,7 @@ impl MessageEntity {
 
     /// Create a message entity representing a clickable text URL.
     #[must_use]
-    pub const fn text_link(url: reqwest::Url, offset: usize, length: usize) -> Self {
+    pub const fn text_link(url: url::Url, offset: usize, length: usize) -> Self {
         Self { kind: MessageEntityKind::TextLink { url }, offset, length }
     }
 
@@ -121,6 +121,19 @@ impl MessageEntity {
         Self { kind: MessageEntityKind::CustomEmoji { custom_emoji_id }, offset, length }
     }
 
+    /// Create a message entity representing a block quotation.
+    #[must_use]
+    pub const fn blockquote(offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::Blockquote, offset, length }
+    }
+
+    /// Create a message entity representing a collapsed-by-default block
+    /// quotation.
+    #[must_use]
+    pub const fn expandable_blockquote(offset: usize, length: usize) -> Self {
+        Self { kind: MessageEntityKind::ExpandableBlockquote, offset, length }
+    }
+
     #[must_use]
     pub fn kind(mut self, val: MessageEntityKind) -> Self {
         self.kind = val;
@@ -255,9 +268,11 @@ pub enum MessageEntityKind {
     Spoiler,
     Code,
     Pre { language: Option<String> },
-    TextLink { url: reqwest::Url },
+    TextLink { url: url::Url },
     TextMention { user: User },
     CustomEmoji { custom_emoji_id: String }, // FIXME(waffle): newtype this
+    Blockquote,
+    ExpandableBlockquote,
 }
 
 #[cfg(test)]
@@ -273,7 +288,7 @@ mod tests {
         assert_eq!(
             MessageEntity {
                 kind: MessageEntityKind::TextLink {
-                    url: reqwest::Url::parse("https://example.com").unwrap(),
+                    url: url::Url::parse("https://example.com").unwrap(),
                 },
                 offset: 1,
                 length: 2,
@@ -285,6 +300,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn blockquotes() {
+        use serde_json::{from_str, to_string};
+
+        let entity = MessageEntity::expandable_blockquote(1, 2);
+        let json = r#"{"type":"expandable_blockquote","offset":1,"length":2}"#;
+        assert_eq!(to_string(&entity).unwrap(), json);
+        assert_eq!(from_str::<MessageEntity>(json).unwrap(), entity);
+
+        assert_eq!(
+            from_str::<MessageEntity>(r#"{"type":"blockquote","offset":1,"length":2}"#).unwrap(),
+            MessageEntity::blockquote(1, 2)
+        );
+    }
+
     #[test]
     fn pre() {
         use serde_json::from_str;
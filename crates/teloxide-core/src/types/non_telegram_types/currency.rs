@@ -1,6 +1,15 @@
 use serde::{Deserialize, Serialize};
 
+// START BLOCK currencies
+// Generated by `codegen_currencies`, do not edit by hand.
+
 /// ISO 4217 currency.
+///
+/// The list of currencies and their exponents is generated from
+/// `currencies.json`, which follows the format of Telegram's
+/// [`currencies.json`].
+///
+/// [`currencies.json`]: https://core.telegram.org/bots/payments/currencies.json
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum Currency {
@@ -363,3 +372,530 @@ pub enum Currency {
     /// Zimbabwean dollar
     ZWL,
 }
+
+impl Currency {
+    /// Returns the number of digits past the decimal point for this currency,
+    /// i.e. the ISO 4217 minor unit.
+    ///
+    /// Amounts in the Bot API (e.g. [`LabeledPrice::amount`]) are passed in the
+    /// smallest units of the currency, so e.g. `US$ 1.45` is `145`. See the
+    /// `exp` parameter in [`currencies.json`].
+    ///
+    /// [`LabeledPrice::amount`]: crate::types::LabeledPrice::amount
+    /// [`currencies.json`]: https://core.telegram.org/bots/payments/currencies.json
+    #[must_use]
+    pub const fn exp(self) -> u32 {
+        use Currency::*;
+
+        match self {
+            BIF | CLP | DJF | GNF | ISK | JPY | KMF | KRW | PYG | RWF | UGX | UYI | VND | VUV
+            | XAF | XAG | XAU | XBA | XBB | XBC | XBD | XDR | XOF | XPD | XPF | XPT | XSU | XTS
+            | XUA | XXX => 0,
+            BHD | IQD | JOD | KWD | LYD | OMR | TND => 3,
+            CLF | UYW => 4,
+            _ => 2,
+        }
+    }
+
+    /// Returns a currency by its three-letter ISO 4217 code.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        use Currency::*;
+
+        let currency = match code {
+            "AED" => AED,
+            "AFN" => AFN,
+            "ALL" => ALL,
+            "AMD" => AMD,
+            "ANG" => ANG,
+            "AOA" => AOA,
+            "ARS" => ARS,
+            "AUD" => AUD,
+            "AWG" => AWG,
+            "AZN" => AZN,
+            "BAM" => BAM,
+            "BBD" => BBD,
+            "BDT" => BDT,
+            "BGN" => BGN,
+            "BHD" => BHD,
+            "BIF" => BIF,
+            "BMD" => BMD,
+            "BND" => BND,
+            "BOB" => BOB,
+            "BOV" => BOV,
+            "BRL" => BRL,
+            "BSD" => BSD,
+            "BTN" => BTN,
+            "BWP" => BWP,
+            "BYN" => BYN,
+            "BZD" => BZD,
+            "CAD" => CAD,
+            "CDF" => CDF,
+            "CHE" => CHE,
+            "CHF" => CHF,
+            "CHW" => CHW,
+            "CLF" => CLF,
+            "CLP" => CLP,
+            "CNY" => CNY,
+            "COP" => COP,
+            "COU" => COU,
+            "CRC" => CRC,
+            "CUC" => CUC,
+            "CUP" => CUP,
+            "CVE" => CVE,
+            "CZK" => CZK,
+            "DJF" => DJF,
+            "DKK" => DKK,
+            "DOP" => DOP,
+            "DZD" => DZD,
+            "EGP" => EGP,
+            "ERN" => ERN,
+            "ETB" => ETB,
+            "EUR" => EUR,
+            "FJD" => FJD,
+            "FKP" => FKP,
+            "GBP" => GBP,
+            "GEL" => GEL,
+            "GHS" => GHS,
+            "GIP" => GIP,
+            "GMD" => GMD,
+            "GNF" => GNF,
+            "GTQ" => GTQ,
+            "GYD" => GYD,
+            "HKD" => HKD,
+            "HNL" => HNL,
+            "HRK" => HRK,
+            "HTG" => HTG,
+            "HUF" => HUF,
+            "IDR" => IDR,
+            "ILS" => ILS,
+            "INR" => INR,
+            "IQD" => IQD,
+            "IRR" => IRR,
+            "ISK" => ISK,
+            "JMD" => JMD,
+            "JOD" => JOD,
+            "JPY" => JPY,
+            "KES" => KES,
+            "KGS" => KGS,
+            "KHR" => KHR,
+            "KMF" => KMF,
+            "KPW" => KPW,
+            "KRW" => KRW,
+            "KWD" => KWD,
+            "KYD" => KYD,
+            "KZT" => KZT,
+            "LAK" => LAK,
+            "LBP" => LBP,
+            "LKR" => LKR,
+            "LRD" => LRD,
+            "LSL" => LSL,
+            "LYD" => LYD,
+            "MAD" => MAD,
+            "MDL" => MDL,
+            "MGA" => MGA,
+            "MKD" => MKD,
+            "MMK" => MMK,
+            "MNT" => MNT,
+            "MOP" => MOP,
+            "MRU" => MRU,
+            "MUR" => MUR,
+            "MVR" => MVR,
+            "MWK" => MWK,
+            "MXN" => MXN,
+            "MXV" => MXV,
+            "MYR" => MYR,
+            "MZN" => MZN,
+            "NAD" => NAD,
+            "NGN" => NGN,
+            "NIO" => NIO,
+            "NOK" => NOK,
+            "NPR" => NPR,
+            "NZD" => NZD,
+            "OMR" => OMR,
+            "PAB" => PAB,
+            "PEN" => PEN,
+            "PGK" => PGK,
+            "PHP" => PHP,
+            "PKR" => PKR,
+            "PLN" => PLN,
+            "PYG" => PYG,
+            "QAR" => QAR,
+            "RON" => RON,
+            "RSD" => RSD,
+            "RUB" => RUB,
+            "RWF" => RWF,
+            "SAR" => SAR,
+            "SBD" => SBD,
+            "SCR" => SCR,
+            "SDG" => SDG,
+            "SEK" => SEK,
+            "SGD" => SGD,
+            "SHP" => SHP,
+            "SLL" => SLL,
+            "SOS" => SOS,
+            "SRD" => SRD,
+            "SSP" => SSP,
+            "STN" => STN,
+            "SVC" => SVC,
+            "SYP" => SYP,
+            "SZL" => SZL,
+            "THB" => THB,
+            "TJS" => TJS,
+            "TMT" => TMT,
+            "TND" => TND,
+            "TOP" => TOP,
+            "TRY" => TRY,
+            "TTD" => TTD,
+            "TWD" => TWD,
+            "TZS" => TZS,
+            "UAH" => UAH,
+            "UGX" => UGX,
+            "USD" => USD,
+            "USN" => USN,
+            "UYI" => UYI,
+            "UYU" => UYU,
+            "UYW" => UYW,
+            "UZS" => UZS,
+            "VES" => VES,
+            "VND" => VND,
+            "VUV" => VUV,
+            "WST" => WST,
+            "XAF" => XAF,
+            "XAG" => XAG,
+            "XAU" => XAU,
+            "XBA" => XBA,
+            "XBB" => XBB,
+            "XBC" => XBC,
+            "XBD" => XBD,
+            "XCD" => XCD,
+            "XDR" => XDR,
+            "XOF" => XOF,
+            "XPD" => XPD,
+            "XPF" => XPF,
+            "XPT" => XPT,
+            "XSU" => XSU,
+            "XTS" => XTS,
+            "XUA" => XUA,
+            "XXX" => XXX,
+            "YER" => YER,
+            "ZAR" => ZAR,
+            "ZMW" => ZMW,
+            "ZWL" => ZWL,
+            _ => return None,
+        };
+
+        Some(currency)
+    }
+
+    /// Returns the three-letter ISO 4217 code of this currency.
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        use Currency::*;
+
+        match self {
+            AED => "AED",
+            AFN => "AFN",
+            ALL => "ALL",
+            AMD => "AMD",
+            ANG => "ANG",
+            AOA => "AOA",
+            ARS => "ARS",
+            AUD => "AUD",
+            AWG => "AWG",
+            AZN => "AZN",
+            BAM => "BAM",
+            BBD => "BBD",
+            BDT => "BDT",
+            BGN => "BGN",
+            BHD => "BHD",
+            BIF => "BIF",
+            BMD => "BMD",
+            BND => "BND",
+            BOB => "BOB",
+            BOV => "BOV",
+            BRL => "BRL",
+            BSD => "BSD",
+            BTN => "BTN",
+            BWP => "BWP",
+            BYN => "BYN",
+            BZD => "BZD",
+            CAD => "CAD",
+            CDF => "CDF",
+            CHE => "CHE",
+            CHF => "CHF",
+            CHW => "CHW",
+            CLF => "CLF",
+            CLP => "CLP",
+            CNY => "CNY",
+            COP => "COP",
+            COU => "COU",
+            CRC => "CRC",
+            CUC => "CUC",
+            CUP => "CUP",
+            CVE => "CVE",
+            CZK => "CZK",
+            DJF => "DJF",
+            DKK => "DKK",
+            DOP => "DOP",
+            DZD => "DZD",
+            EGP => "EGP",
+            ERN => "ERN",
+            ETB => "ETB",
+            EUR => "EUR",
+            FJD => "FJD",
+            FKP => "FKP",
+            GBP => "GBP",
+            GEL => "GEL",
+            GHS => "GHS",
+            GIP => "GIP",
+            GMD => "GMD",
+            GNF => "GNF",
+            GTQ => "GTQ",
+            GYD => "GYD",
+            HKD => "HKD",
+            HNL => "HNL",
+            HRK => "HRK",
+            HTG => "HTG",
+            HUF => "HUF",
+            IDR => "IDR",
+            ILS => "ILS",
+            INR => "INR",
+            IQD => "IQD",
+            IRR => "IRR",
+            ISK => "ISK",
+            JMD => "JMD",
+            JOD => "JOD",
+            JPY => "JPY",
+            KES => "KES",
+            KGS => "KGS",
+            KHR => "KHR",
+            KMF => "KMF",
+            KPW => "KPW",
+            KRW => "KRW",
+            KWD => "KWD",
+            KYD => "KYD",
+            KZT => "KZT",
+            LAK => "LAK",
+            LBP => "LBP",
+            LKR => "LKR",
+            LRD => "LRD",
+            LSL => "LSL",
+            LYD => "LYD",
+            MAD => "MAD",
+            MDL => "MDL",
+            MGA => "MGA",
+            MKD => "MKD",
+            MMK => "MMK",
+            MNT => "MNT",
+            MOP => "MOP",
+            MRU => "MRU",
+            MUR => "MUR",
+            MVR => "MVR",
+            MWK => "MWK",
+            MXN => "MXN",
+            MXV => "MXV",
+            MYR => "MYR",
+            MZN => "MZN",
+            NAD => "NAD",
+            NGN => "NGN",
+            NIO => "NIO",
+            NOK => "NOK",
+            NPR => "NPR",
+            NZD => "NZD",
+            OMR => "OMR",
+            PAB => "PAB",
+            PEN => "PEN",
+            PGK => "PGK",
+            PHP => "PHP",
+            PKR => "PKR",
+            PLN => "PLN",
+            PYG => "PYG",
+            QAR => "QAR",
+            RON => "RON",
+            RSD => "RSD",
+            RUB => "RUB",
+            RWF => "RWF",
+            SAR => "SAR",
+            SBD => "SBD",
+            SCR => "SCR",
+            SDG => "SDG",
+            SEK => "SEK",
+            SGD => "SGD",
+            SHP => "SHP",
+            SLL => "SLL",
+            SOS => "SOS",
+            SRD => "SRD",
+            SSP => "SSP",
+            STN => "STN",
+            SVC => "SVC",
+            SYP => "SYP",
+            SZL => "SZL",
+            THB => "THB",
+            TJS => "TJS",
+            TMT => "TMT",
+            TND => "TND",
+            TOP => "TOP",
+            TRY => "TRY",
+            TTD => "TTD",
+            TWD => "TWD",
+            TZS => "TZS",
+            UAH => "UAH",
+            UGX => "UGX",
+            USD => "USD",
+            USN => "USN",
+            UYI => "UYI",
+            UYU => "UYU",
+            UYW => "UYW",
+            UZS => "UZS",
+            VES => "VES",
+            VND => "VND",
+            VUV => "VUV",
+            WST => "WST",
+            XAF => "XAF",
+            XAG => "XAG",
+            XAU => "XAU",
+            XBA => "XBA",
+            XBB => "XBB",
+            XBC => "XBC",
+            XBD => "XBD",
+            XCD => "XCD",
+            XDR => "XDR",
+            XOF => "XOF",
+            XPD => "XPD",
+            XPF => "XPF",
+            XPT => "XPT",
+            XSU => "XSU",
+            XTS => "XTS",
+            XUA => "XUA",
+            XXX => "XXX",
+            YER => "YER",
+            ZAR => "ZAR",
+            ZMW => "ZMW",
+            ZWL => "ZWL",
+        }
+    }
+}
+// END BLOCK currencies
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exp() {
+        assert_eq!(Currency::USD.exp(), 2);
+        assert_eq!(Currency::JPY.exp(), 0);
+        assert_eq!(Currency::KWD.exp(), 3);
+    }
+
+    /// Generates [`Currency`] and its methods from `currencies.json`.
+    #[test]
+    #[cfg(feature = "network")]
+    fn codegen_currencies() {
+        use std::collections::BTreeMap;
+
+        use crate::codegen::{
+            add_hidden_preamble, ensure_file_contents, project_root, reformat, replace_block,
+        };
+
+        #[derive(Deserialize)]
+        struct Entry {
+            code: String,
+            title: String,
+            exp: u32,
+        }
+
+        let path = project_root().join("src/types/non_telegram_types/currency.rs");
+        let json = std::fs::read_to_string(project_root().join("currencies.json")).unwrap();
+        let currencies: BTreeMap<String, Entry> = serde_json::from_str(&json).unwrap();
+
+        let mut variants = String::new();
+        let mut exps = BTreeMap::<_, Vec<_>>::new();
+        let mut from_code = String::new();
+        let mut code = String::new();
+        for c in currencies.values() {
+            variants.push_str(&format!("/// {}\n{},\n", c.title, c.code));
+            exps.entry(c.exp).or_default().push(&*c.code);
+            from_code.push_str(&format!("{0:?} => {0},\n", c.code));
+            code.push_str(&format!("{0} => {0:?},\n", c.code));
+        }
+        // The most common exponent goes to the catch-all arm
+        let (&default_exp, _) = exps.iter().max_by_key(|(_, codes)| codes.len()).unwrap();
+        let mut exp = String::new();
+        for (e, codes) in exps.iter().filter(|&(&e, _)| e != default_exp) {
+            exp.push_str(&format!("{} => {e},\n", codes.join(" | ")));
+        }
+        exp.push_str(&format!("_ => {default_exp},\n"));
+
+        let block = format!(
+            "\
+/// ISO 4217 currency.
+///
+/// The list of currencies and their exponents is generated from `currencies.json`, which follows \
+             the format of Telegram's [`currencies.json`].
+///
+/// [`currencies.json`]: https://core.telegram.org/bots/payments/currencies.json
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum Currency {{
+{variants}}}
+
+impl Currency {{
+    /// Returns the number of digits past the decimal point for this currency, i.e. the ISO 4217 \
+             minor unit.
+    ///
+    /// Amounts in the Bot API (e.g. [`LabeledPrice::amount`]) are passed in the smallest units \
+             of the currency, so e.g. `US$ 1.45` is `145`. See the `exp` parameter in \
+             [`currencies.json`].
+    ///
+    /// [`LabeledPrice::amount`]: crate::types::LabeledPrice::amount
+    /// [`currencies.json`]: https://core.telegram.org/bots/payments/currencies.json
+    #[must_use]
+    pub const fn exp(self) -> u32 {{
+        use Currency::*;
+
+        match self {{
+{exp}        }}
+    }}
+
+    /// Returns a currency by its three-letter ISO 4217 code.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {{
+        use Currency::*;
+
+        let currency = match code {{
+{from_code}            _ => return None,
+        }};
+
+        Some(currency)
+    }}
+
+    /// Returns the three-letter ISO 4217 code of this currency.
+    #[must_use]
+    pub const fn code(self) -> &'static str {{
+        use Currency::*;
+
+        match self {{
+{code}        }}
+    }}
+}}
+"
+        );
+
+        let contents = reformat(replace_block(
+            &path,
+            "currencies",
+            &add_hidden_preamble("codegen_currencies", block),
+        ));
+        ensure_file_contents(&path, &contents);
+    }
+
+    #[test]
+    fn code_matches_serde() {
+        for currency in [Currency::USD, Currency::EUR, Currency::XXX] {
+            assert_eq!(serde_json::to_value(currency).unwrap(), currency.code());
+            assert_eq!(Currency::from_code(currency.code()), Some(currency));
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+
+use thiserror::Error;
+
+use crate::types::Currency;
+
+/// An amount of money in a specific currency.
+///
+/// The Bot API passes amounts in the smallest units of a currency (e.g. `145`
+/// for `US$ 1.45`). `CurrencyAmount` knows the number of digits past the
+/// decimal point for each currency (see [`Currency::exp`]), so it can convert
+/// amounts from and to major units.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide_core::types::{Currency, CurrencyAmount};
+///
+/// let amount = CurrencyAmount::from_major_units("1.45", Currency::USD).unwrap();
+/// assert_eq!(amount.minor_units(), 145);
+/// assert_eq!(amount.to_major_string(), "1.45");
+///
+/// let amount = CurrencyAmount::new(1500, Currency::JPY);
+/// assert_eq!(amount.to_string(), "1500 JPY");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CurrencyAmount {
+    amount: u32,
+    currency: Currency,
+}
+
+/// An error returned by [`CurrencyAmount::from_major_units`].
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("Invalid amount {amount:?} for currency {currency:?}")]
+pub struct ParseAmountError {
+    amount: String,
+    currency: Currency,
+}
+
+impl CurrencyAmount {
+    /// Creates an amount from the smallest units of `currency`.
+    #[must_use]
+    pub const fn new(amount: u32, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Parses an amount in major units of `currency`, e.g. `"1.45"` for
+    /// `US$ 1.45`.
+    ///
+    /// Returns an error if `amount` is not a non-negative decimal number, has
+    /// more digits past the decimal point than the currency allows, or doesn't
+    /// fit into `u32` smallest units.
+    pub fn from_major_units(amount: &str, currency: Currency) -> Result<Self, ParseAmountError> {
+        parse_major(amount, currency)
+            .map(|amount| Self { amount, currency })
+            .ok_or_else(|| ParseAmountError { amount: amount.to_owned(), currency })
+    }
+
+    /// Returns the amount in the smallest units of the currency.
+    #[must_use]
+    pub const fn minor_units(self) -> u32 {
+        self.amount
+    }
+
+    /// Returns the currency of this amount.
+    #[must_use]
+    pub const fn currency(self) -> Currency {
+        self.currency
+    }
+
+    /// Formats the amount in major units, e.g. `"1.45"` for `US$ 1.45`.
+    #[must_use]
+    pub fn to_major_string(self) -> String {
+        let exp = self.currency.exp();
+        if exp == 0 {
+            return self.amount.to_string();
+        }
+
+        let scale = 10u32.pow(exp);
+        format!("{}.{:0width$}", self.amount / scale, self.amount % scale, width = exp as usize)
+    }
+}
+
+impl fmt::Display for CurrencyAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.to_major_string(), self.currency.code())
+    }
+}
+
+fn parse_major(amount: &str, currency: Currency) -> Option<u32> {
+    let (int, frac) = amount.split_once('.').unwrap_or((amount, ""));
+    let exp = currency.exp();
+
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if int.is_empty() || !is_digits(int) || !is_digits(frac) || frac.len() > exp as usize {
+        return None;
+    }
+
+    let int_part: u32 = int.parse().ok()?;
+    let frac_part: u32 = if frac.is_empty() { 0 } else { frac.parse().ok()? };
+    let frac_scale = 10u32.pow(exp - frac.len() as u32);
+
+    int_part.checked_mul(10u32.pow(exp))?.checked_add(frac_part * frac_scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(amount: &str, currency: Currency) -> Option<u32> {
+        CurrencyAmount::from_major_units(amount, currency).ok().map(CurrencyAmount::minor_units)
+    }
+
+    #[test]
+    fn from_major_units() {
+        assert_eq!(parse("1.45", Currency::USD), Some(145));
+        assert_eq!(parse("1.4", Currency::USD), Some(140));
+        assert_eq!(parse("12", Currency::USD), Some(1200));
+        assert_eq!(parse("1.455", Currency::USD), None);
+        assert_eq!(parse("100", Currency::JPY), Some(100));
+        assert_eq!(parse("1.5", Currency::JPY), None);
+        assert_eq!(parse("1.005", Currency::KWD), Some(1005));
+        assert_eq!(parse("-1", Currency::USD), None);
+        assert_eq!(parse(".5", Currency::USD), None);
+        assert_eq!(parse("99999999", Currency::USD), None);
+    }
+
+    #[test]
+    fn to_major_string() {
+        assert_eq!(CurrencyAmount::new(145, Currency::USD).to_major_string(), "1.45");
+        assert_eq!(CurrencyAmount::new(5, Currency::EUR).to_major_string(), "0.05");
+        assert_eq!(CurrencyAmount::new(5, Currency::JPY).to_major_string(), "5");
+        assert_eq!(CurrencyAmount::new(1005, Currency::KWD).to_string(), "1.005 KWD");
+    }
+}
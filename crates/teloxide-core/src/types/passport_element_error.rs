@@ -35,39 +35,190 @@ impl PassportElementError {
         self.kind = val;
         self
     }
+
+    /// Creates an error in one of the data fields of an element.
+    pub fn data_field<M, F, H>(
+        message: M,
+        r#type: PassportElementErrorDataFieldType,
+        field_name: F,
+        data_hash: H,
+    ) -> Self
+    where
+        M: Into<String>,
+        F: Into<String>,
+        H: Into<String>,
+    {
+        Self::new(message, PassportElementErrorDataField::new(r#type, field_name, data_hash).into())
+    }
+
+    /// Creates an error with the front side of a document.
+    pub fn front_side<M, H>(
+        message: M,
+        r#type: PassportElementErrorFrontSideType,
+        file_hash: H,
+    ) -> Self
+    where
+        M: Into<String>,
+        H: Into<String>,
+    {
+        Self::new(message, PassportElementErrorFrontSide::new(r#type, file_hash).into())
+    }
+
+    /// Creates an error with the reverse side of a document.
+    pub fn reverse_side<M, H>(
+        message: M,
+        r#type: PassportElementErrorReverseSideType,
+        file_hash: H,
+    ) -> Self
+    where
+        M: Into<String>,
+        H: Into<String>,
+    {
+        Self::new(message, PassportElementErrorReverseSide::new(r#type, file_hash).into())
+    }
+
+    /// Creates an error with the selfie with a document.
+    pub fn selfie<M, H>(message: M, r#type: PassportElementErrorSelfieType, file_hash: H) -> Self
+    where
+        M: Into<String>,
+        H: Into<String>,
+    {
+        Self::new(message, PassportElementErrorSelfie::new(r#type, file_hash).into())
+    }
+
+    /// Creates an error with a document scan.
+    pub fn file<M, H>(message: M, r#type: PassportElementErrorFileType, file_hash: H) -> Self
+    where
+        M: Into<String>,
+        H: Into<String>,
+    {
+        Self::new(message, PassportElementErrorFile::new(r#type, file_hash).into())
+    }
+
+    /// Creates an error with a list of document scans.
+    pub fn files<M, I>(message: M, r#type: PassportElementErrorFilesType, file_hashes: I) -> Self
+    where
+        M: Into<String>,
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let file_hashes = file_hashes.into_iter().map(Into::into);
+        Self::new(message, PassportElementErrorFiles::new(r#type, file_hashes).into())
+    }
+
+    /// Creates an error with one of the files that constitute the translation
+    /// of a document.
+    pub fn translation_file<M, H>(
+        message: M,
+        r#type: PassportElementErrorTranslationFileType,
+        file_hash: H,
+    ) -> Self
+    where
+        M: Into<String>,
+        H: Into<String>,
+    {
+        Self::new(message, PassportElementErrorTranslationFile::new(r#type, file_hash).into())
+    }
+
+    /// Creates an error with the translated version of a document.
+    pub fn translation_files<M, I>(
+        message: M,
+        r#type: PassportElementErrorTranslationFilesType,
+        file_hashes: I,
+    ) -> Self
+    where
+        M: Into<String>,
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let file_hashes = file_hashes.into_iter().map(Into::into);
+        Self::new(message, PassportElementErrorTranslationFiles::new(r#type, file_hashes).into())
+    }
+
+    /// Creates an error in an unspecified place of an element.
+    pub fn unspecified<M, H>(
+        message: M,
+        r#type: PassportElementErrorUnspecifiedType,
+        element_hash: H,
+    ) -> Self
+    where
+        M: Into<String>,
+        H: Into<String>,
+    {
+        Self::new(message, PassportElementErrorUnspecified::new(r#type, element_hash).into())
+    }
 }
 
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "source")]
+#[serde(rename_all = "snake_case")]
 pub enum PassportElementErrorKind {
     #[serde(rename = "data")]
     DataField(PassportElementErrorDataField),
-
-    #[serde(rename = "snake_case")]
     FrontSide(PassportElementErrorFrontSide),
-
-    #[serde(rename = "snake_case")]
     ReverseSide(PassportElementErrorReverseSide),
-
-    #[serde(rename = "snake_case")]
     Selfie(PassportElementErrorSelfie),
-
-    #[serde(rename = "snake_case")]
     File(PassportElementErrorFile),
-
-    #[serde(rename = "snake_case")]
     Files(PassportElementErrorFiles),
-
-    #[serde(rename = "snake_case")]
     TranslationFile(PassportElementErrorTranslationFile),
-
-    #[serde(rename = "snake_case")]
     TranslationFiles(PassportElementErrorTranslationFiles),
-
-    #[serde(rename = "snake_case")]
     Unspecified(PassportElementErrorUnspecified),
 }
 
+impl From<PassportElementErrorDataField> for PassportElementErrorKind {
+    fn from(error: PassportElementErrorDataField) -> Self {
+        Self::DataField(error)
+    }
+}
+
+impl From<PassportElementErrorFrontSide> for PassportElementErrorKind {
+    fn from(error: PassportElementErrorFrontSide) -> Self {
+        Self::FrontSide(error)
+    }
+}
+
+impl From<PassportElementErrorReverseSide> for PassportElementErrorKind {
+    fn from(error: PassportElementErrorReverseSide) -> Self {
+        Self::ReverseSide(error)
+    }
+}
+
+impl From<PassportElementErrorSelfie> for PassportElementErrorKind {
+    fn from(error: PassportElementErrorSelfie) -> Self {
+        Self::Selfie(error)
+    }
+}
+
+impl From<PassportElementErrorFile> for PassportElementErrorKind {
+    fn from(error: PassportElementErrorFile) -> Self {
+        Self::File(error)
+    }
+}
+
+impl From<PassportElementErrorFiles> for PassportElementErrorKind {
+    fn from(error: PassportElementErrorFiles) -> Self {
+        Self::Files(error)
+    }
+}
+
+impl From<PassportElementErrorTranslationFile> for PassportElementErrorKind {
+    fn from(error: PassportElementErrorTranslationFile) -> Self {
+        Self::TranslationFile(error)
+    }
+}
+
+impl From<PassportElementErrorTranslationFiles> for PassportElementErrorKind {
+    fn from(error: PassportElementErrorTranslationFiles) -> Self {
+        Self::TranslationFiles(error)
+    }
+}
+
+impl From<PassportElementErrorUnspecified> for PassportElementErrorKind {
+    fn from(error: PassportElementErrorUnspecified) -> Self {
+        Self::Unspecified(error)
+    }
+}
+
 /// Represents an issue in one of the data fields that was provided by the
 /// user.
 ///
@@ -415,11 +566,11 @@ pub struct PassportElementErrorUnspecified {
 }
 
 impl PassportElementErrorUnspecified {
-    pub fn new<S>(r#type: PassportElementErrorUnspecifiedType, file_hash: S) -> Self
+    pub fn new<S>(r#type: PassportElementErrorUnspecifiedType, element_hash: S) -> Self
     where
         S: Into<String>,
     {
-        Self { r#type, element_hash: file_hash.into() }
+        Self { r#type, element_hash: element_hash.into() }
     }
 
     #[must_use]
@@ -524,15 +675,19 @@ pub enum PassportElementErrorTranslationFilesType {
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PassportElementErrorUnspecifiedType {
-    DataField,
-    FrontSide,
-    ReverseSide,
-    Selfie,
-    File,
-    Files,
-    TranslationFile,
-    TranslationFiles,
-    Unspecified,
+    PersonalDetails,
+    Passport,
+    DriverLicense,
+    IdentityCard,
+    InternalPassport,
+    Address,
+    UtilityBill,
+    BankStatement,
+    RentalAgreement,
+    PassportRegistration,
+    TemporaryRegistration,
+    PhoneNumber,
+    Email,
 }
 
 #[cfg(test)]
@@ -555,4 +710,63 @@ mod tests {
             r#"{"message":"This is an error message!","source":"data","type":"internal_passport","field_name":"The field name","data_hash":"This is a data hash"}"#
         );
     }
+
+    #[test]
+    fn serialize_sources() {
+        let errors = [
+            PassportElementError::front_side("", PassportElementErrorFrontSideType::Passport, "h"),
+            PassportElementError::reverse_side(
+                "",
+                PassportElementErrorReverseSideType::IdentityCard,
+                "h",
+            ),
+            PassportElementError::selfie("", PassportElementErrorSelfieType::DriverLicense, "h"),
+            PassportElementError::file("", PassportElementErrorFileType::UtilityBill, "h"),
+            PassportElementError::files("", PassportElementErrorFilesType::BankStatement, ["h"]),
+            PassportElementError::translation_file(
+                "",
+                PassportElementErrorTranslationFileType::Passport,
+                "h",
+            ),
+            PassportElementError::translation_files(
+                "",
+                PassportElementErrorTranslationFilesType::RentalAgreement,
+                ["h"],
+            ),
+            PassportElementError::unspecified("", PassportElementErrorUnspecifiedType::Email, "h"),
+        ];
+
+        let sources: Vec<_> = errors
+            .iter()
+            .map(|e| serde_json::to_value(e).unwrap()["source"].as_str().unwrap().to_owned())
+            .collect();
+
+        assert_eq!(
+            sources,
+            [
+                "front_side",
+                "reverse_side",
+                "selfie",
+                "file",
+                "files",
+                "translation_file",
+                "translation_files",
+                "unspecified"
+            ]
+        );
+    }
+
+    #[test]
+    fn serialize_unspecified() {
+        let error = PassportElementError::unspecified(
+            "Error",
+            PassportElementErrorUnspecifiedType::PhoneNumber,
+            "hash",
+        );
+
+        assert_eq!(
+            serde_json::to_string(&error).unwrap(),
+            r#"{"message":"Error","source":"unspecified","type":"phone_number","element_hash":"hash"}"#
+        );
+    }
 }
@@ -1,13 +1,23 @@
-use crate::types::User;
+use crate::types::{Chat, User};
 use serde::{Deserialize, Serialize};
 
 #[serde_with_macros::skip_serializing_none]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PollAnswer {
     /// Unique poll identifier.
     pub poll_id: String,
 
+    /// The chat that changed the answer to the poll, if the voter is
+    /// anonymous.
+    pub voter_chat: Option<Chat>,
+
     /// The user, who changed the answer to the poll.
+    ///
+    /// If the voter is anonymous (see [`voter_chat`]), this is a placeholder
+    /// user `136817688` ([@Channel_Bot]).
+    ///
+    /// [`voter_chat`]: PollAnswer::voter_chat
+    /// [@Channel_Bot]: https://t.me/Channel_Bot
     pub user: User,
 
     /// 0-based identifiers of answer options, chosen by the user.
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{Currency, OrderInfo, User};
+use crate::types::{Currency, CurrencyAmount, OrderInfo, User};
+#[cfg(feature = "network")]
+use crate::{payloads::AnswerPreCheckoutQuerySetters, requests::Requester};
 
 /// This object contains information about an incoming pre-checkout query.
 ///
@@ -38,3 +40,34 @@ pub struct PreCheckoutQuery {
     #[serde(default)]
     pub order_info: OrderInfo,
 }
+
+impl PreCheckoutQuery {
+    /// Returns the total price as a [`CurrencyAmount`].
+    #[must_use]
+    pub fn total(&self) -> CurrencyAmount {
+        CurrencyAmount::new(self.total_amount, self.currency)
+    }
+
+    /// Creates a request which confirms that the bot is ready to proceed with
+    /// the order.
+    #[cfg(feature = "network")]
+    pub fn answer_ok<B>(&self, bot: &B) -> B::AnswerPreCheckoutQuery
+    where
+        B: Requester,
+    {
+        bot.answer_pre_checkout_query(&self.id, true)
+    }
+
+    /// Creates a request which rejects this query, e.g. because the goods are
+    /// sold out.
+    ///
+    /// `error_message` is shown to the user.
+    #[cfg(feature = "network")]
+    pub fn answer_error<B, M>(&self, bot: &B, error_message: M) -> B::AnswerPreCheckoutQuery
+    where
+        B: Requester,
+        M: Into<String>,
+    {
+        bot.answer_pre_checkout_query(&self.id, false).error_message(error_message)
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// The type of a reaction.
+///
+/// [The official docs](https://core.telegram.org/bots/api#reactiontype).
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "snake_case")]
+pub enum ReactionType {
+    /// A reaction with a normal emoji.
+    Emoji {
+        /// The reaction emoji, e.g. "👍".
+        emoji: String,
+    },
+
+    /// A reaction with a custom emoji.
+    CustomEmoji {
+        /// Custom emoji identifier.
+        custom_emoji_id: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let reaction = ReactionType::Emoji { emoji: "👍".to_owned() };
+        assert_eq!(serde_json::to_string(&reaction).unwrap(), r#"{"type":"emoji","emoji":"👍"}"#);
+
+        let json = r#"{"type":"custom_emoji","custom_emoji_id":"5312536423851630001"}"#;
+        let reaction =
+            ReactionType::CustomEmoji { custom_emoji_id: "5312536423851630001".to_owned() };
+        assert_eq!(serde_json::from_str::<ReactionType>(json).unwrap(), reaction);
+    }
+}
@@ -114,7 +114,9 @@ impl KeyboardMarkup {
         Self { one_time_keyboard: true, ..self }
     }
 
-    // FIXME: document
+    /// Sets [`input_field_placeholder`].
+    ///
+    /// [`input_field_placeholder`]: KeyboardMarkup::input_field_placeholder
     pub fn input_field_placeholder<T>(self, val: T) -> Self
     where
         T: Into<String>,
@@ -125,7 +127,29 @@ impl KeyboardMarkup {
     /// Sets [`selective`] to `true`.
     ///
     /// [`selective`]: KeyboardMarkup::selective
-    pub fn selective<T>(self) -> Self {
+    pub fn selective(self) -> Self {
         Self { selective: true, ..self }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize() {
+        let markup = KeyboardMarkup::new([[KeyboardButton::new("A")]]);
+        assert_eq!(serde_json::to_string(&markup).unwrap(), r#"{"keyboard":[[{"text":"A"}]]}"#);
+
+        let markup = markup
+            .persistent()
+            .resize_keyboard()
+            .one_time_keyboard()
+            .input_field_placeholder("Choose")
+            .selective();
+        assert_eq!(
+            serde_json::to_string(&markup).unwrap(),
+            r#"{"keyboard":[[{"text":"A"}]],"is_persistent":true,"resize_keyboard":true,"one_time_keyboard":true,"input_field_placeholder":"Choose","selective":true}"#
+        );
+    }
+}
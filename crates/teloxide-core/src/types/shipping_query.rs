@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::types::{ShippingAddress, User};
+#[cfg(feature = "network")]
+use crate::{payloads::AnswerShippingQuerySetters, requests::Requester, types::ShippingOption};
 
 /// This object contains information about an incoming shipping query.
 ///
@@ -20,3 +22,29 @@ pub struct ShippingQuery {
     /// User specified shipping address.
     pub shipping_address: ShippingAddress,
 }
+
+impl ShippingQuery {
+    /// Creates a request which accepts this query, offering `shipping_options`
+    /// to the user.
+    #[cfg(feature = "network")]
+    pub fn answer_ok<B, O>(&self, bot: &B, shipping_options: O) -> B::AnswerShippingQuery
+    where
+        B: Requester,
+        O: IntoIterator<Item = ShippingOption>,
+    {
+        bot.answer_shipping_query(&self.id, true).shipping_options(shipping_options)
+    }
+
+    /// Creates a request which rejects this query, e.g. because delivery to
+    /// the specified address is not possible.
+    ///
+    /// `error_message` is shown to the user.
+    #[cfg(feature = "network")]
+    pub fn answer_error<B, M>(&self, bot: &B, error_message: M) -> B::AnswerShippingQuery
+    where
+        B: Requester,
+        M: Into<String>,
+    {
+        bot.answer_shipping_query(&self.id, false).error_message(error_message)
+    }
+}
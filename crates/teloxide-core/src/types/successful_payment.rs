@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{Currency, OrderInfo};
+use crate::types::{Currency, CurrencyAmount, OrderInfo};
 
 /// This object contains basic information about a successful payment.
 ///
@@ -38,3 +38,11 @@ pub struct SuccessfulPayment {
     /// Provider payment identifier.
     pub provider_payment_charge_id: String,
 }
+
+impl SuccessfulPayment {
+    /// Returns the total price as a [`CurrencyAmount`].
+    #[must_use]
+    pub fn total(&self) -> CurrencyAmount {
+        CurrencyAmount::new(self.total_amount, self.currency)
+    }
+}
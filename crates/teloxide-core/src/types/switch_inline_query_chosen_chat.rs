@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+/// This object represents an inline button that switches the current user to
+/// inline mode in a chosen chat, with an optional default inline query.
+///
+/// [The official docs](https://core.telegram.org/bots/api#switchinlinequerychosenchat).
+#[serde_with_macros::skip_serializing_none]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct SwitchInlineQueryChosenChat {
+    /// The default inline query to be inserted in the input field. If left
+    /// empty, only the bot's username will be inserted.
+    pub query: Option<String>,
+
+    /// `true`, if private chats with users can be chosen.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_user_chats: bool,
+
+    /// `true`, if private chats with bots can be chosen.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_bot_chats: bool,
+
+    /// `true`, if group and supergroup chats can be chosen.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_group_chats: bool,
+
+    /// `true`, if channel chats can be chosen.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_channel_chats: bool,
+}
+
+impl SwitchInlineQueryChosenChat {
+    /// Creates a new [`SwitchInlineQueryChosenChat`] which doesn't allow any
+    /// chat types yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn query<S>(mut self, val: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.query = Some(val.into());
+        self
+    }
+
+    /// Sets [`allow_user_chats`] to `true`.
+    ///
+    /// [`allow_user_chats`]: SwitchInlineQueryChosenChat::allow_user_chats
+    #[must_use]
+    pub fn allow_user_chats(mut self) -> Self {
+        self.allow_user_chats = true;
+        self
+    }
+
+    /// Sets [`allow_bot_chats`] to `true`.
+    ///
+    /// [`allow_bot_chats`]: SwitchInlineQueryChosenChat::allow_bot_chats
+    #[must_use]
+    pub fn allow_bot_chats(mut self) -> Self {
+        self.allow_bot_chats = true;
+        self
+    }
+
+    /// Sets [`allow_group_chats`] to `true`.
+    ///
+    /// [`allow_group_chats`]: SwitchInlineQueryChosenChat::allow_group_chats
+    #[must_use]
+    pub fn allow_group_chats(mut self) -> Self {
+        self.allow_group_chats = true;
+        self
+    }
+
+    /// Sets [`allow_channel_chats`] to `true`.
+    ///
+    /// [`allow_channel_chats`]: SwitchInlineQueryChosenChat::allow_channel_chats
+    #[must_use]
+    pub fn allow_channel_chats(mut self) -> Self {
+        self.allow_channel_chats = true;
+        self
+    }
+}
@@ -1,19 +1,55 @@
-use crate::types::{MessageId, Recipient};
+use crate::types::{Message, MessageId, Recipient, True};
 
 use serde::{Deserialize, Serialize};
 
 /// A message in chat or inline message.
+///
+/// Methods which edit messages accept it in their `_target` flavor (e.g.
+/// [`edit_message_text_target`]) and return an [`EditedMessage`]. The older
+/// pairs (e.g. [`edit_message_text`] and [`edit_message_text_inline`]) are
+/// still available, if the kind of the message is known upfront. They are
+/// sent with the `_target` method too.
+///
+/// [`edit_message_text_target`]: crate::requests::Requester::edit_message_text_target
+/// [`edit_message_text`]: crate::requests::Requester::edit_message_text
+/// [`edit_message_text_inline`]: crate::requests::Requester::edit_message_text_inline
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum TargetMessage {
+    /// A message in a chat.
     Common {
         chat_id: Recipient,
         #[serde(flatten)]
         message_id: MessageId,
     },
-    Inline {
-        inline_message_id: String,
-    },
+
+    /// A message sent via the bot in inline mode.
+    Inline { inline_message_id: String },
+}
+
+/// Result of editing a [`TargetMessage`].
+///
+/// Telegram returns the edited [`Message`] for messages in chats and _True_
+/// for inline messages.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EditedMessage {
+    /// The edited message in a chat.
+    Common(Box<Message>),
+
+    /// An inline message was edited.
+    Inline(True),
+}
+
+impl EditedMessage {
+    /// Returns the edited message, if it was a message in a chat.
+    #[must_use]
+    pub fn message(&self) -> Option<&Message> {
+        match self {
+            Self::Common(message) => Some(message),
+            Self::Inline(_) => None,
+        }
+    }
 }
 
 impl From<String> for TargetMessage {
@@ -21,3 +57,66 @@ impl From<String> for TargetMessage {
         Self::Inline { inline_message_id }
     }
 }
+
+impl<C> From<(C, MessageId)> for TargetMessage
+where
+    C: Into<Recipient>,
+{
+    fn from((chat_id, message_id): (C, MessageId)) -> Self {
+        Self::Common { chat_id: chat_id.into(), message_id }
+    }
+}
+
+impl From<&Message> for TargetMessage {
+    fn from(message: &Message) -> Self {
+        (message.chat.id, message.id).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{ChatId, EditedMessage, MessageId, TargetMessage, True};
+    #[cfg(feature = "network")]
+    use crate::{
+        payloads::{EditMessageTextTarget, GetGameHighScores},
+        types::UserId,
+    };
+
+    #[test]
+    #[cfg(feature = "network")]
+    fn serialize() {
+        let common = TargetMessage::Common { chat_id: ChatId(-1).into(), message_id: MessageId(2) };
+        let payload = GetGameHighScores::new(UserId(3), common);
+        assert_eq!(
+            serde_json::to_string(&payload).unwrap(),
+            r#"{"user_id":3,"chat_id":-1,"message_id":2}"#
+        );
+
+        let payload = GetGameHighScores::new(UserId(3), "inline".to_owned());
+        assert_eq!(
+            serde_json::to_string(&payload).unwrap(),
+            r#"{"user_id":3,"inline_message_id":"inline"}"#
+        );
+
+        let payload = EditMessageTextTarget::new((ChatId(-1), MessageId(2)), "text");
+        assert_eq!(
+            serde_json::to_string(&payload).unwrap(),
+            r#"{"chat_id":-1,"message_id":2,"text":"text"}"#
+        );
+    }
+
+    #[test]
+    fn edited_message_inline() {
+        let edited: EditedMessage = serde_json::from_str("true").unwrap();
+        assert_eq!(edited, EditedMessage::Inline(True));
+        assert!(edited.message().is_none());
+    }
+
+    #[test]
+    fn from_tuple() {
+        assert_eq!(
+            TargetMessage::from((ChatId(-1), MessageId(2))),
+            TargetMessage::Common { chat_id: ChatId(-1).into(), message_id: MessageId(2) }
+        );
+    }
+}
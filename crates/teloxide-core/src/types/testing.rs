@@ -0,0 +1,79 @@
+//! Harness for (de)serialization round-trip tests of types.
+//!
+//! The corpus of real Telegram payloads lives in `test_data/types`, with one
+//! directory per type. Every payload is deserialized, serialized back and
+//! deserialized again, and both deserialized values must be equal. This
+//! catches regressions such as a field which is skipped when serializing, but
+//! lacks `#[serde(default)]`.
+//!
+//! To add a payload, put it into the directory of its type. To add a type,
+//! create a directory for it and register it in [`check`].
+
+use std::{fmt::Debug, fs, path::Path};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::types::{Chat, Me, Message, Update};
+
+/// Checks that `json` survives a `deserialize → serialize → deserialize`
+/// round-trip as `T`.
+pub(crate) fn roundtrip<T>(json: &str) -> Result<T, String>
+where
+    T: DeserializeOwned + Serialize + PartialEq + Debug,
+{
+    let first: T = serde_json::from_str(json).map_err(|err| format!("deserialization: {err}"))?;
+    let serialized =
+        serde_json::to_string(&first).map_err(|err| format!("serialization: {err}"))?;
+    let second: T = serde_json::from_str(&serialized)
+        .map_err(|err| format!("deserialization of `{serialized}`: {err}"))?;
+
+    if first != second {
+        return Err(format!("values differ after a round-trip:\n{first:?}\n{second:?}"));
+    }
+
+    Ok(second)
+}
+
+/// Checks a payload from the corpus directory `dir`.
+fn check(dir: &str, json: &str) -> Result<(), String> {
+    macro_rules! corpus {
+        ($($dir:literal => $T:ty),* $(,)?) => {
+            match dir {
+                $( $dir => roundtrip::<$T>(json).map(drop), )*
+                _ => panic!("unknown corpus directory `{dir}`, register it in `check`"),
+            }
+        };
+    }
+
+    corpus! {
+        "chat" => Chat,
+        "me" => Me,
+        "message" => Message,
+        "update" => Update,
+    }
+}
+
+#[test]
+fn corpus() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("test_data/types");
+    let mut checked = 0;
+    let mut failures = Vec::new();
+
+    for dir in fs::read_dir(root).unwrap() {
+        let dir = dir.unwrap();
+        let name = dir.file_name().into_string().unwrap();
+
+        for file in fs::read_dir(dir.path()).unwrap() {
+            let path = file.unwrap().path();
+            let json = fs::read_to_string(&path).unwrap();
+
+            checked += 1;
+            if let Err(err) = check(&name, &json) {
+                failures.push(format!("{}: {err}", path.display()));
+            }
+        }
+    }
+
+    assert!(checked > 0, "the corpus is empty");
+    assert!(failures.is_empty(), "round-trip failed:\n{}", failures.join("\n"));
+}
@@ -130,6 +130,36 @@ pub enum UpdateKind {
 }
 
 impl Update {
+    /// Deserializes an update from JSON bytes, e.g. from a body of a webhook
+    /// request.
+    ///
+    /// The bytes are parsed in place, without copying them into a `String`.
+    /// Unlike plain deserialization, if the update is of an unknown kind, the
+    /// returned [`UpdateKind::Error`] contains the raw update.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide_core::types::{Update, UpdateKind};
+    ///
+    /// let json = br#"{"update_id": 1, "new_update_kind": {"field": 17}}"#;
+    /// let update = Update::from_json_bytes(json).unwrap();
+    /// assert!(matches!(update.kind, UpdateKind::Error(v) if v["new_update_kind"]["field"] == 17));
+    /// ```
+    pub fn from_json_bytes(bytes: &[u8]) -> serde_json::Result<Self> {
+        // Validating UTF-8 upfront is faster than `serde_json::from_slice`, which
+        // validates each string separately
+        let json = std::str::from_utf8(bytes).map_err(serde::de::Error::custom)?;
+        let mut update: Self = serde_json::from_str(json)?;
+
+        // See HACK comment in `crate::net::request::deserialize_response`
+        if let UpdateKind::Error(value) = &mut update.kind {
+            *value = serde_json::from_str(json).unwrap_or_default();
+        }
+
+        Ok(update)
+    }
+
     /// Returns the user that performed the action that caused this update, if
     /// known.
     ///
@@ -165,7 +195,7 @@ impl Update {
     /// Note that this function may return quite a few users as it scans
     /// replies, pinned messages, message entities, "via bot" fields and more.
     /// Also note that this function can return duplicate users.
-    pub fn mentioned_users(&self) -> impl Iterator<Item=&User> {
+    pub fn mentioned_users(&self) -> impl Iterator<Item = &User> {
         use either::Either::{Left as L, Right as R};
         use std::iter::{empty, once};
 
@@ -219,7 +249,16 @@ impl Update {
         }
     }
 
-    /// Returns the chat in which is update has happened, if any.
+    /// Returns the name of the kind of this update, e.g. `"message"`.
+    ///
+    /// The name is a static string, so it can be used as a label of metrics
+    /// without allocating. See [`UpdateKind::name`].
+    #[must_use]
+    pub fn kind_name(&self) -> &'static str {
+        self.kind.name()
+    }
+
+    /// Returns the chat in which this update has happened, if any.
     #[must_use]
     pub fn chat(&self) -> Option<&Chat> {
         use UpdateKind::*;
@@ -244,6 +283,66 @@ impl Update {
     }
 }
 
+impl UpdateKind {
+    /// The number of distinct [discriminants] of update kinds.
+    ///
+    /// [discriminants]: UpdateKind::discriminant
+    pub const DISCRIMINANT_COUNT: usize = 15;
+
+    /// Returns a number which identifies the kind of this update, in the range
+    /// `0..UpdateKind::DISCRIMINANT_COUNT`.
+    ///
+    /// Unlike the [name], it can be used as an index, e.g. into an array of
+    /// per-kind counters.
+    ///
+    /// [name]: UpdateKind::name
+    #[must_use]
+    pub fn discriminant(&self) -> usize {
+        match self {
+            UpdateKind::Message(_) => 0,
+            UpdateKind::EditedMessage(_) => 1,
+            UpdateKind::ChannelPost(_) => 2,
+            UpdateKind::EditedChannelPost(_) => 3,
+            UpdateKind::InlineQuery(_) => 4,
+            UpdateKind::ChosenInlineResult(_) => 5,
+            UpdateKind::CallbackQuery(_) => 6,
+            UpdateKind::ShippingQuery(_) => 7,
+            UpdateKind::PreCheckoutQuery(_) => 8,
+            UpdateKind::Poll(_) => 9,
+            UpdateKind::PollAnswer(_) => 10,
+            UpdateKind::MyChatMember(_) => 11,
+            UpdateKind::ChatMember(_) => 12,
+            UpdateKind::ChatJoinRequest(_) => 13,
+            UpdateKind::Error(_) => 14,
+        }
+    }
+
+    /// Returns the name of the kind of this update, as in the Bot API (e.g.
+    /// `"edited_message"`), or `"error"` for [`UpdateKind::Error`].
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        const NAMES: [&str; UpdateKind::DISCRIMINANT_COUNT] = [
+            "message",
+            "edited_message",
+            "channel_post",
+            "edited_channel_post",
+            "inline_query",
+            "chosen_inline_result",
+            "callback_query",
+            "shipping_query",
+            "pre_checkout_query",
+            "poll",
+            "poll_answer",
+            "my_chat_member",
+            "chat_member",
+            "chat_join_request",
+            "error",
+        ];
+
+        NAMES[self.discriminant()]
+    }
+}
+
 impl UpdateId {
     /// Returns the offset for the **next** update that can be used for polling.
     ///
@@ -448,6 +547,7 @@ mod test {
                     message_auto_delete_time: None,
                     has_hidden_members: false,
                     has_aggressive_anti_spam_enabled: false,
+                    available_reactions: None,
                 },
                 kind: MessageKind::Common(MessageCommon {
                     from: Some(User {
@@ -597,6 +697,49 @@ mod test {
         }
     }
 
+    #[test]
+    fn chat_and_user() {
+        let json = r#"{
+            "update_id": 1,
+            "callback_query": {
+                "id": "1",
+                "from": {"id": 1, "is_bot": false, "first_name": "A"},
+                "message": {
+                    "message_id": 1,
+                    "from": {"id": 2, "is_bot": true, "first_name": "B", "username": "b_bot"},
+                    "chat": {"id": 1, "type": "private", "first_name": "A"},
+                    "date": 0,
+                    "text": "hi"
+                },
+                "chat_instance": "1",
+                "data": "data"
+            }
+        }"#;
+
+        let update: Update = serde_json::from_str(json).unwrap();
+        assert_eq!(update.chat().unwrap().id, ChatId(1));
+        assert_eq!(update.from().unwrap().id, UserId(1));
+
+        let mut ids: Vec<_> = update.mentioned_users().map(|user| user.id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids, [UserId(1), UserId(2)]);
+
+        let json = r#"{
+            "update_id": 1,
+            "inline_query": {
+                "id": "1",
+                "from": {"id": 1, "is_bot": false, "first_name": "A"},
+                "query": "",
+                "offset": ""
+            }
+        }"#;
+
+        let update: Update = serde_json::from_str(json).unwrap();
+        assert_eq!(update.chat(), None);
+        assert_eq!(update.from().unwrap().id, UserId(1));
+    }
+
     #[test]
     fn new_update_kind_error() {
         let json = r#"{
@@ -613,6 +756,59 @@ mod test {
         }
     }
 
+    #[test]
+    fn from_json_bytes() {
+        let json = r#"{
+            "update_id": 1,
+            "inline_query": {
+                "id": "1",
+                "from": {"id": 1, "is_bot": false, "first_name": "A"},
+                "query": "",
+                "offset": ""
+            }
+        }"#;
+
+        let update = Update::from_json_bytes(json.as_bytes()).unwrap();
+        assert_eq!(update, serde_json::from_str(json).unwrap());
+        assert!(Update::from_json_bytes(b"{}").is_err());
+        assert!(Update::from_json_bytes(b"\xff").is_err());
+    }
+
+    #[test]
+    fn kind_name() {
+        let json = r#"{
+            "update_id": 1,
+            "poll_answer": {
+                "poll_id": "1",
+                "user": {"id": 1, "is_bot": false, "first_name": "A"},
+                "option_ids": []
+            }
+        }"#;
+
+        let update: Update = serde_json::from_str(json).unwrap();
+        assert_eq!(update.kind_name(), "poll_answer");
+        assert_eq!(update.kind.discriminant(), 10);
+
+        // The name is the same as the field of the update
+        let value = serde_json::to_value(&update).unwrap();
+        assert!(value.get(update.kind_name()).is_some());
+
+        let update = Update::from_json_bytes(br#"{"update_id": 1, "new_kind": {}}"#).unwrap();
+        assert_eq!(update.kind_name(), "error");
+        assert_eq!(update.kind.discriminant(), UpdateKind::DISCRIMINANT_COUNT - 1);
+    }
+
+    /// `Update`s are moved around a lot by the dispatcher, so they shouldn't
+    /// grow unnoticed.
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn size() {
+        use std::mem::size_of;
+
+        assert!(size_of::<Message>() <= 1272);
+        assert!(size_of::<UpdateKind>() <= 1504);
+    }
+
     #[test]
     fn issue_523() {
         let json = r#"{
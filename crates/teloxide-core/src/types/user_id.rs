@@ -14,8 +14,8 @@ impl UserId {
     /// Returns an URL that links to the user with this id in the form of
     /// `tg://user/?id=<...>`.
     #[must_use]
-    pub fn url(self) -> reqwest::Url {
-        reqwest::Url::parse(&format!("tg://user/?id={self}")).unwrap()
+    pub fn url(self) -> url::Url {
+        url::Url::parse(&format!("tg://user/?id={self}")).unwrap()
     }
 
     /// Returns `true` if this is the id of the special user used by telegram
@@ -94,4 +94,14 @@ mod tests {
 
         assert_eq!(id.url(), "tg://user/?id=17".parse().unwrap());
     }
+
+    #[test]
+    fn special_users() {
+        assert!(UserId(777000).is_telegram());
+        assert!(UserId(1087968824).is_anonymous());
+        assert!(UserId(136817688).is_channel());
+
+        let id = UserId(17);
+        assert!(!id.is_telegram() && !id.is_anonymous() && !id.is_channel());
+    }
 }
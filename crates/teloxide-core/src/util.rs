@@ -51,6 +51,8 @@ pub(crate) fn mentioned_users_from_entities(
         | Code
         | Pre { language: _ }
         | TextLink { url: _ }
-        | CustomEmoji { custom_emoji_id: _ } => None,
+        | CustomEmoji { custom_emoji_id: _ }
+        | Blockquote
+        | ExpandableBlockquote => None,
     })
 }
@@ -215,17 +215,37 @@
 //! [`examples/dispatching_features.rs`]: https://github.com/teloxide/teloxide/blob/master/crates/teloxide/examples/dispatching_features.rs
 //! [`Update`]: crate::types::Update
 
+pub mod access;
+pub mod captcha;
+pub mod conversation;
 pub mod dialogue;
+pub mod join_flood;
+pub mod roles;
 
 mod dispatcher;
 mod distribution;
 mod filter_ext;
+mod flow;
+mod from_captures;
+mod handle;
 mod handler_description;
 mod handler_ext;
+mod matched_branches;
+mod plugin;
 
 pub use crate::utils::shutdown_token::{IdleShutdownError, ShutdownToken};
-pub use dispatcher::{Dispatcher, DispatcherBuilder, UpdateHandler};
+pub use dispatcher::{Dispatcher, DispatcherBuilder, HandlerPanic, IsEdited, UpdateHandler};
 pub use distribution::DefaultKey;
 pub use filter_ext::{MessageFilterExt, UpdateFilterExt};
-pub use handler_description::DpHandlerDescription;
-pub use handler_ext::{filter_command, HandlerExt};
+pub use flow::{endpoint_flow, Flow};
+pub use from_captures::FromCaptures;
+pub use handle::DispatcherHandle;
+pub use handler_description::{DpHandlerDescription, HandlerOutline};
+pub use handler_ext::{
+    filter_command, filter_command_in_caption, filter_from_admin, filter_language,
+    filter_pre_checkout_query_payload, filter_regex, filter_shipping_query_payload, filter_spam,
+    filter_start_payload, filter_tracked_inline_result, filter_tracked_poll_answer, named,
+    HandlerExt,
+};
+pub use matched_branches::MatchedBranches;
+pub use plugin::{Plugin, PluginManager, PluginSettings};
@@ -0,0 +1,247 @@
+//! Restricting bots to known chats and users.
+//!
+//! Private and internal bots often must not be used by anyone else.
+//! [`AccessList`] holds sets of allowed and blocked chats and users, and
+//! [`HandlerExt::filter_access`] drops updates which are not allowed. The bot
+//! can also leave group chats which are not allowed.
+//!
+//! Rules can be replaced at any time with [`AccessList::reload`], e.g. after
+//! re-reading a config file:
+//!
+//! ```no_run
+//! use teloxide::{
+//!     dispatching::{
+//!         access::{AccessList, AccessRules},
+//!         HandlerExt, UpdateHandler,
+//!     },
+//!     prelude::*,
+//!     types::UserId,
+//! };
+//!
+//! # async fn run(handler: UpdateHandler<teloxide::RequestError>) {
+//! let bot = Bot::from_env();
+//! let access = AccessList::new(AccessRules::new().allow_user(UserId(1)).leave_disallowed_chats());
+//!
+//! let handler = dptree::entry().filter_access::<Bot>().chain(handler);
+//!
+//! Dispatcher::builder(bot, handler)
+//!     .dependencies(dptree::deps![access.clone()])
+//!     .build()
+//!     .dispatch()
+//!     .await;
+//!
+//! // Later
+//! access.reload(AccessRules::new().allow_user(UserId(1)).allow_user(UserId(2)));
+//! # }
+//! ```
+//!
+//! [`HandlerExt::filter_access`]: crate::dispatching::HandlerExt::filter_access
+
+use std::{collections::HashSet, sync::Arc};
+
+use arc_swap::ArcSwap;
+use dptree::{di::DependencyMap, Handler};
+
+use crate::{
+    dispatching::DpHandlerDescription,
+    requests::{Request, Requester},
+    types::{ChatId, Update, UserId},
+};
+
+/// Rules of an [`AccessList`].
+///
+/// An update is allowed if:
+///
+///  - neither its chat nor its user is blocked, and
+///  - there are no allowed chats and users, or its chat or its user is allowed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[must_use]
+pub struct AccessRules {
+    /// Allowed chats.
+    pub allowed_chats: HashSet<ChatId>,
+
+    /// Allowed users.
+    pub allowed_users: HashSet<UserId>,
+
+    /// Blocked chats.
+    pub blocked_chats: HashSet<ChatId>,
+
+    /// Blocked users.
+    pub blocked_users: HashSet<UserId>,
+
+    /// Whether the bot leaves group chats which are not allowed.
+    pub leave_disallowed_chats: bool,
+}
+
+impl AccessRules {
+    /// Creates rules which allow everything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows `chat_id`.
+    pub fn allow_chat(mut self, chat_id: ChatId) -> Self {
+        self.allowed_chats.insert(chat_id);
+        self
+    }
+
+    /// Allows `user_id`.
+    pub fn allow_user(mut self, user_id: UserId) -> Self {
+        self.allowed_users.insert(user_id);
+        self
+    }
+
+    /// Blocks `chat_id`.
+    pub fn block_chat(mut self, chat_id: ChatId) -> Self {
+        self.blocked_chats.insert(chat_id);
+        self
+    }
+
+    /// Blocks `user_id`.
+    pub fn block_user(mut self, user_id: UserId) -> Self {
+        self.blocked_users.insert(user_id);
+        self
+    }
+
+    /// Makes the bot leave group chats which are not allowed.
+    pub fn leave_disallowed_chats(self) -> Self {
+        Self { leave_disallowed_chats: true, ..self }
+    }
+
+    /// Returns `true` if updates from `chat_id` and `user_id` are allowed.
+    #[must_use]
+    pub fn is_allowed(&self, chat_id: Option<ChatId>, user_id: Option<UserId>) -> bool {
+        let chat_blocked = chat_id.map_or(false, |id| self.blocked_chats.contains(&id));
+        let user_blocked = user_id.map_or(false, |id| self.blocked_users.contains(&id));
+        if chat_blocked || user_blocked {
+            return false;
+        }
+
+        if self.allowed_chats.is_empty() && self.allowed_users.is_empty() {
+            return true;
+        }
+
+        chat_id.map_or(false, |id| self.allowed_chats.contains(&id))
+            || user_id.map_or(false, |id| self.allowed_users.contains(&id))
+    }
+}
+
+/// Hot-reloadable [`AccessRules`].
+///
+/// See the [module-level documentation](self).
+#[derive(Debug)]
+pub struct AccessList {
+    rules: ArcSwap<AccessRules>,
+}
+
+impl AccessList {
+    /// Creates an access list with `rules`.
+    #[must_use]
+    pub fn new(rules: AccessRules) -> Arc<Self> {
+        Arc::new(Self { rules: ArcSwap::from_pointee(rules) })
+    }
+
+    /// Returns the current rules.
+    #[must_use]
+    pub fn rules(&self) -> Arc<AccessRules> {
+        self.rules.load_full()
+    }
+
+    /// Replaces the rules.
+    ///
+    /// Updates which are being handled are not affected.
+    pub fn reload(&self, rules: AccessRules) {
+        self.rules.store(Arc::new(rules));
+    }
+
+    /// Returns `true` if `update` is allowed by the current rules.
+    #[must_use]
+    pub fn is_allowed(&self, update: &Update) -> bool {
+        let chat_id = update.chat().map(|chat| chat.id);
+        let user_id = update.from().map(|user| user.id);
+        self.rules.load().is_allowed(chat_id, user_id)
+    }
+}
+
+/// Returns a handler that drops updates which are not allowed by an
+/// [`AccessList`].
+///
+/// If [`AccessRules::leave_disallowed_chats()`] was used, the bot `R` leaves
+/// group chats from which updates are dropped.
+///
+/// A call to this function is the same as
+/// `dptree::entry().filter_access::<R>()`.
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::Update`]
+///  - `R`
+///  - `Arc<AccessList>`
+#[must_use]
+#[track_caller]
+pub fn filter_access<R, Output>() -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    R: Requester + Clone + Send + Sync + 'static,
+    R::Err: std::fmt::Debug + Send,
+    Output: Send + Sync + 'static,
+{
+    dptree::filter_async(|update: Update, bot: R, access: Arc<AccessList>| async move {
+        if access.is_allowed(&update) {
+            return true;
+        }
+
+        let chat = update.chat().filter(|chat| !chat.is_private());
+        if let Some(chat) = chat.filter(|_| access.rules.load().leave_disallowed_chats) {
+            log::info!("Leaving a disallowed chat {}", chat.id);
+            if let Err(err) = bot.leave_chat(chat.id).send().await {
+                log::error!("Couldn't leave a disallowed chat {}: {err:?}", chat.id);
+            }
+        }
+
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use super::*;
+    use crate::{adaptors::MockBot, payloads::LeaveChat, types::True};
+
+    #[test]
+    fn rules() {
+        let everyone = AccessRules::new().block_user(UserId(3));
+        assert!(everyone.is_allowed(Some(ChatId(-1)), Some(UserId(1))));
+        assert!(!everyone.is_allowed(Some(ChatId(-1)), Some(UserId(3))));
+
+        let rules = AccessRules::new().allow_chat(ChatId(-1)).allow_user(UserId(1));
+        assert!(rules.is_allowed(Some(ChatId(-1)), Some(UserId(2))));
+        assert!(rules.is_allowed(None, Some(UserId(1))));
+        assert!(!rules.is_allowed(Some(ChatId(-2)), Some(UserId(2))));
+        assert!(!rules.is_allowed(None, None));
+    }
+
+    #[tokio::test]
+    async fn leaves_chats() {
+        let bot = MockBot::new();
+        bot.respond::<LeaveChat>(True);
+
+        let access = AccessList::new(AccessRules::new().allow_user(UserId(1)));
+        let handler = filter_access::<MockBot, ()>().endpoint(|| async {});
+        let dispatch = |update: Update| {
+            handler.dispatch(dptree::deps![update, bot.clone(), Arc::clone(&access)])
+        };
+
+        let from_stranger = || Update::message_fixture(ChatId(-1), UserId(2), "hi");
+        assert!(matches!(dispatch(from_stranger()).await, ControlFlow::Continue(_)));
+        assert!(bot.requests_of::<LeaveChat>().is_empty());
+
+        access.reload(access.rules().as_ref().clone().leave_disallowed_chats());
+        assert!(matches!(dispatch(from_stranger()).await, ControlFlow::Continue(_)));
+        assert_eq!(bot.requests_of::<LeaveChat>().len(), 1);
+
+        let from_user = Update::message_fixture(ChatId(-1), UserId(1), "hi");
+        assert!(matches!(dispatch(from_user).await, ControlFlow::Break(())));
+    }
+}
@@ -0,0 +1,580 @@
+//! Verification of new group members.
+//!
+//! When a user joins a group, [`HandlerExt::verify_new_members`] restricts them
+//! and posts a [`Challenge`] with inline buttons. If the user presses the right
+//! button, the restrictions are lifted. If the user presses a wrong button or
+//! doesn't answer in time, they are kicked from the group.
+//!
+//! Pending verifications are kept in a [dialogue storage] as one
+//! [`CaptchaState`] per chat, so they survive restarts with a persistent
+//! storage: verifications which have expired meanwhile are completed when
+//! another user joins the chat.
+//!
+//! The bot must be an administrator which can restrict and ban users, and
+//! [`AllowedUpdate::ChatMember`] must be requested explicitly, since Telegram
+//! doesn't send `chat_member` updates by default.
+//!
+//! ```no_run
+//! use std::{sync::Arc, time::Duration};
+//!
+//! use teloxide::{
+//!     dispatching::{
+//!         captcha::{Captcha, CaptchaState, MathChallenge},
+//!         dialogue::InMemStorage,
+//!         HandlerExt, UpdateHandler,
+//!     },
+//!     prelude::*,
+//! };
+//!
+//! # async fn run(handler: UpdateHandler<teloxide::RequestError>) {
+//! let bot = Bot::from_env();
+//! let captcha = Captcha::new(MathChallenge).timeout(Duration::from_secs(60));
+//!
+//! let handler =
+//!     dptree::entry().verify_new_members::<Bot, InMemStorage<CaptchaState>>().chain(handler);
+//!
+//! Dispatcher::builder(bot, handler)
+//!     .dependencies(dptree::deps![Arc::new(captcha), InMemStorage::<CaptchaState>::new()])
+//!     .build()
+//!     .dispatch()
+//!     .await;
+//! # }
+//! ```
+//!
+//! [`HandlerExt::verify_new_members`]: crate::dispatching::HandlerExt::verify_new_members
+//! [dialogue storage]: crate::dispatching::dialogue::Storage
+//! [`AllowedUpdate::ChatMember`]: crate::types::AllowedUpdate::ChatMember
+
+use std::{
+    collections::hash_map::RandomState,
+    fmt::{self, Debug},
+    hash::{BuildHasher, Hasher},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use dptree::{di::DependencyMap, Handler, HandlerDescription};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dispatching::{dialogue::Storage, DpHandlerDescription},
+    payloads::{AnswerCallbackQuerySetters, SendMessageSetters, UnbanChatMemberSetters},
+    requests::{Request, Requester},
+    types::{
+        CallbackQuery, ChatId, ChatMemberUpdated, ChatPermissions, InlineKeyboardButton,
+        InlineKeyboardMarkup, MessageId, Update, UpdateKind, UserId,
+    },
+};
+
+/// The prefix of callback data of challenge buttons.
+const CALLBACK_PREFIX: &str = "captcha:";
+
+/// A generator of puzzles for new members.
+pub trait Challenge: Send + Sync {
+    /// Returns a new puzzle.
+    fn generate(&self) -> Puzzle;
+}
+
+/// A question with several options, one of which is right.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    /// The text of the question.
+    pub question: String,
+
+    /// Texts of the buttons.
+    pub options: Vec<String>,
+
+    /// The index of the right option in `options`.
+    pub answer: usize,
+}
+
+/// A challenge with a single button to press.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ButtonChallenge {
+    question: String,
+    button: String,
+}
+
+impl ButtonChallenge {
+    /// Creates a challenge with `question` and a `button` to press.
+    #[must_use]
+    pub fn new(question: impl Into<String>, button: impl Into<String>) -> Self {
+        Self { question: question.into(), button: button.into() }
+    }
+}
+
+impl Default for ButtonChallenge {
+    fn default() -> Self {
+        Self::new("please press the button below to show that you're not a bot.", "I'm not a bot")
+    }
+}
+
+impl Challenge for ButtonChallenge {
+    fn generate(&self) -> Puzzle {
+        Puzzle { question: self.question.clone(), options: vec![self.button.clone()], answer: 0 }
+    }
+}
+
+/// A challenge asking for the sum of two small numbers, with four options.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MathChallenge;
+
+impl Challenge for MathChallenge {
+    fn generate(&self) -> Puzzle {
+        let (a, b) = (random(10) + 2, random(10) + 2);
+        let sum = a + b;
+        let answer = random(4);
+
+        // Wrong options are the neighbours of the right one, `sum >= 4` so they
+        // are all positive
+        let options = (0..4).map(|i| (sum + i - answer).to_string()).collect();
+
+        Puzzle { question: format!("how much is {a} + {b}?"), options, answer: answer as usize }
+    }
+}
+
+/// Returns a random number in `0..bound`.
+fn random(bound: u64) -> u64 {
+    RandomState::new().build_hasher().finish() % bound
+}
+
+/// Verifications pending in a chat.
+///
+/// This is the dialogue type of the storage used by
+/// [`HandlerExt::verify_new_members`].
+///
+/// [`HandlerExt::verify_new_members`]: crate::dispatching::HandlerExt::verify_new_members
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaptchaState {
+    /// Members which haven't answered yet.
+    pub pending: Vec<PendingMember>,
+}
+
+/// A member which hasn't answered a challenge yet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingMember {
+    /// The member.
+    pub user_id: UserId,
+
+    /// The message with the challenge.
+    pub message_id: MessageId,
+
+    /// The index of the right option.
+    pub answer: usize,
+
+    /// When the member is kicked if they don't answer.
+    pub deadline: SystemTime,
+}
+
+/// Settings of new member verification.
+///
+/// See the [module-level documentation](self).
+pub struct Captcha {
+    challenge: Arc<dyn Challenge>,
+    timeout: Duration,
+    // Serializes read-modify-write cycles of `CaptchaState`s, since answers and
+    // timeouts are handled concurrently.
+    lock: tokio::sync::Mutex<()>,
+}
+
+impl Captcha {
+    /// Creates settings which verify new members with `challenge` and give
+    /// them 2 minutes to answer.
+    #[must_use]
+    pub fn new<C>(challenge: C) -> Self
+    where
+        C: Challenge + 'static,
+    {
+        Self {
+            challenge: Arc::new(challenge),
+            timeout: Duration::from_secs(2 * 60),
+            lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Sets the time new members have to answer.
+    #[must_use]
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    async fn challenge<R, S>(self: Arc<Self>, bot: R, storage: Arc<S>, update: &ChatMemberUpdated)
+    where
+        R: Requester + Clone + Send + Sync + 'static,
+        R::Err: Debug + Send,
+        S: Storage<CaptchaState> + ?Sized + Send + Sync + 'static,
+        S::Error: Debug + Send,
+    {
+        let (chat_id, user) = (update.chat.id, &update.new_chat_member.user);
+
+        let restrict = bot.restrict_chat_member(chat_id, user.id, ChatPermissions::empty());
+        if let Err(err) = restrict.send().await {
+            log::error!("Couldn't restrict {} in chat {chat_id}: {err:?}", user.id);
+            return;
+        }
+
+        let puzzle = self.challenge.generate();
+        let buttons = puzzle.options.into_iter().enumerate().map(|(i, option)| {
+            InlineKeyboardButton::callback(option, format!("{CALLBACK_PREFIX}{}:{i}", user.id))
+        });
+        let text = format!("{}, {}", user.full_name(), puzzle.question);
+        let message = match bot
+            .send_message(chat_id, text)
+            .reply_markup(InlineKeyboardMarkup::new([buttons]))
+            .send()
+            .await
+        {
+            Ok(message) => message,
+            Err(err) => {
+                log::error!("Couldn't send a challenge to chat {chat_id}: {err:?}");
+                return;
+            }
+        };
+
+        let member = PendingMember {
+            user_id: user.id,
+            message_id: message.id,
+            answer: puzzle.answer,
+            deadline: SystemTime::now() + self.timeout,
+        };
+        self.modify(&storage, chat_id, |state| {
+            state.pending.retain(|pending| pending.user_id != member.user_id);
+            state.pending.push(member);
+        })
+        .await;
+
+        let timeout = self.timeout;
+        tokio::spawn(async move {
+            tokio::time::sleep(timeout).await;
+            self.expire(&bot, &storage, chat_id).await;
+        });
+    }
+
+    async fn answer<R, S>(&self, bot: &R, storage: &Arc<S>, query: &CallbackQuery, data: &str)
+    where
+        R: Requester,
+        R::Err: Debug,
+        S: Storage<CaptchaState> + ?Sized,
+        S::Error: Debug,
+    {
+        let Some(chat_id) = query.message.as_ref().map(|message| message.chat.id) else { return };
+        let Some((user_id, option)) = data.split_once(':') else { return };
+        let (Ok(user_id), Ok(option)) = (user_id.parse().map(UserId), option.parse::<usize>())
+        else {
+            return;
+        };
+
+        if user_id != query.from.id {
+            answer_callback_query(bot, query, Some("This is not for you")).await;
+            return;
+        }
+
+        let mut member = None;
+        self.modify(storage, chat_id, |state| {
+            let index = state.pending.iter().position(|pending| pending.user_id == user_id);
+            member = index.map(|index| state.pending.remove(index));
+        })
+        .await;
+        answer_callback_query(bot, query, None).await;
+
+        let Some(member) = member else { return };
+        if option == member.answer {
+            let lift = bot.restrict_chat_member(chat_id, user_id, ChatPermissions::all());
+            if let Err(err) = lift.send().await {
+                log::error!("Couldn't lift restrictions of {user_id} in chat {chat_id}: {err:?}");
+            }
+            delete_message(bot, chat_id, member.message_id).await;
+        } else {
+            kick(bot, chat_id, &member).await;
+        }
+    }
+
+    /// Kicks members of `chat_id` which haven't answered in time.
+    async fn expire<R, S>(&self, bot: &R, storage: &Arc<S>, chat_id: ChatId)
+    where
+        R: Requester,
+        R::Err: Debug,
+        S: Storage<CaptchaState> + ?Sized,
+        S::Error: Debug,
+    {
+        let now = SystemTime::now();
+        let mut expired = Vec::new();
+        self.modify(storage, chat_id, |state| {
+            let (overdue, pending) = state.pending.drain(..).partition(|m| m.deadline <= now);
+            expired = overdue;
+            state.pending = pending;
+        })
+        .await;
+
+        for member in &expired {
+            kick(bot, chat_id, member).await;
+        }
+    }
+
+    /// Applies `f` to the state of `chat_id`, removing the state if there are
+    /// no pending members left.
+    async fn modify<S, F>(&self, storage: &Arc<S>, chat_id: ChatId, f: F)
+    where
+        S: Storage<CaptchaState> + ?Sized,
+        S::Error: Debug,
+        F: FnOnce(&mut CaptchaState),
+    {
+        let _guard = self.lock.lock().await;
+
+        let mut state = match Arc::clone(storage).get_dialogue(chat_id).await {
+            Ok(state) => state,
+            Err(err) => {
+                log::error!("Couldn't get the captcha state of chat {chat_id}: {err:?}");
+                return;
+            }
+        };
+        let existed = state.is_some();
+        let state = state.get_or_insert_with(CaptchaState::default);
+        f(state);
+
+        let res = if !state.pending.is_empty() {
+            Arc::clone(storage).update_dialogue(chat_id, state.clone()).await
+        } else if existed {
+            Arc::clone(storage).remove_dialogue(chat_id).await
+        } else {
+            Ok(())
+        };
+        if let Err(err) = res {
+            log::error!("Couldn't save the captcha state of chat {chat_id}: {err:?}");
+        }
+    }
+}
+
+impl Debug for Captcha {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Captcha").field("timeout", &self.timeout).finish_non_exhaustive()
+    }
+}
+
+async fn kick<R>(bot: &R, chat_id: ChatId, member: &PendingMember)
+where
+    R: Requester,
+    R::Err: Debug,
+{
+    let user_id = member.user_id;
+    log::info!("Kicking unverified {user_id} from chat {chat_id}");
+
+    // Unbanning right after banning removes the user from the chat, but lets
+    // them join again later
+    let res = match bot.ban_chat_member(chat_id, user_id).send().await {
+        Ok(_) => bot.unban_chat_member(chat_id, user_id).only_if_banned(true).send().await,
+        Err(err) => Err(err),
+    };
+    if let Err(err) = res {
+        log::error!("Couldn't kick {user_id} from chat {chat_id}: {err:?}");
+    }
+    delete_message(bot, chat_id, member.message_id).await;
+}
+
+async fn delete_message<R>(bot: &R, chat_id: ChatId, message_id: MessageId)
+where
+    R: Requester,
+    R::Err: Debug,
+{
+    if let Err(err) = bot.delete_message(chat_id, message_id).send().await {
+        log::error!("Couldn't delete a challenge in chat {chat_id}: {err:?}");
+    }
+}
+
+async fn answer_callback_query<R>(bot: &R, query: &CallbackQuery, text: Option<&str>)
+where
+    R: Requester,
+    R::Err: Debug,
+{
+    let res = match text {
+        Some(text) => bot.answer_callback_query(&query.id).text(text).send().await,
+        None => bot.answer_callback_query(&query.id).send().await,
+    };
+    if let Err(err) = res {
+        log::error!("Couldn't answer a callback query: {err:?}");
+    }
+}
+
+/// Returns `true` if `update` reports that a user (not a bot) joined a group.
+fn is_user_joined(update: &ChatMemberUpdated) -> bool {
+    !update.chat.is_private()
+        && !update.new_chat_member.user.is_bot
+        && !update.old_chat_member.is_present()
+        && update.new_chat_member.is_present()
+}
+
+/// Returns a handler that verifies new group members according to
+/// [`Captcha`] settings.
+///
+/// Updates about users joining groups and callback queries from challenge
+/// buttons are handled and dropped, all the other updates are passed
+/// forwards.
+///
+/// A call to this function is the same as
+/// `dptree::entry().verify_new_members::<R, S>()`.
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::Update`]
+///  - `R`
+///  - `Arc<Captcha>`
+///  - `Arc<S>`
+#[must_use]
+#[track_caller]
+pub fn verify_new_members<R, S, Output>(
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    R: Requester + Clone + Send + Sync + 'static,
+    R::Err: Debug + Send,
+    S: Storage<CaptchaState> + ?Sized + Send + Sync + 'static,
+    S::Error: Debug + Send,
+    Output: Send + Sync + 'static,
+{
+    let description = DpHandlerDescription::filter_async().labelled("verify_new_members");
+    dptree::filter_async_with_description(
+        description,
+        |update: Update, bot: R, captcha: Arc<Captcha>, storage: Arc<S>| async move {
+            match &update.kind {
+                UpdateKind::ChatMember(member) if is_user_joined(member) => {
+                    captcha.expire(&bot, &storage, member.chat.id).await;
+                    captcha.challenge(bot, storage, member).await;
+                    false
+                }
+                UpdateKind::CallbackQuery(query) => {
+                    let data = query.data.as_deref().and_then(|d| d.strip_prefix(CALLBACK_PREFIX));
+                    match data {
+                        Some(data) => {
+                            captcha.answer(&bot, &storage, query, data).await;
+                            false
+                        }
+                        None => true,
+                    }
+                }
+                _ => true,
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use super::*;
+    use crate::{
+        adaptors::MockBot,
+        dispatching::dialogue::InMemStorage,
+        payloads::{
+            AnswerCallbackQuery, BanChatMember, DeleteMessage, RestrictChatMember, SendMessage,
+            UnbanChatMember,
+        },
+        types::{ChatMemberKind, Message, True},
+    };
+
+    struct Fixed;
+
+    impl Challenge for Fixed {
+        fn generate(&self) -> Puzzle {
+            Puzzle {
+                question: "2 + 2?".to_owned(),
+                options: vec!["3".into(), "4".into()],
+                answer: 1,
+            }
+        }
+    }
+
+    #[test]
+    fn math_challenge() {
+        for _ in 0..100 {
+            let puzzle = MathChallenge.generate();
+            let (a, b) = puzzle.question["how much is ".len()..]
+                .trim_end_matches('?')
+                .split_once(" + ")
+                .unwrap();
+            let sum = a.parse::<u64>().unwrap() + b.parse::<u64>().unwrap();
+            assert_eq!(puzzle.options[puzzle.answer], sum.to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn verifies() {
+        let chat_id = ChatId(-1);
+        let bot = MockBot::new();
+        let captcha = Arc::new(Captcha::new(Fixed));
+        let storage = InMemStorage::<CaptchaState>::new();
+
+        let handler =
+            verify_new_members::<MockBot, InMemStorage<CaptchaState>, ()>().endpoint(|| async {});
+        let dispatch = |update: Update| {
+            let deps = dptree::deps![update, bot.clone(), Arc::clone(&captcha), storage.clone()];
+            handler.dispatch(deps)
+        };
+        let join = |user_id| {
+            Update::fixture(UpdateKind::ChatMember(ChatMemberUpdated::fixture(
+                chat_id,
+                user_id,
+                ChatMemberKind::Left,
+                ChatMemberKind::Member,
+            )))
+        };
+        let press = |user_id, data: &str| {
+            let mut query = CallbackQuery::fixture(user_id, data);
+            query.message = Some(Message::text_fixture(chat_id, UserId(1), "challenge"));
+            Update::fixture(UpdateKind::CallbackQuery(query))
+        };
+
+        for _ in 0..2 {
+            bot.respond::<RestrictChatMember>(True);
+            bot.respond::<SendMessage>(Message::text_fixture(chat_id, UserId(1), "challenge"));
+            bot.respond::<AnswerCallbackQuery>(True);
+            bot.respond::<DeleteMessage>(True);
+        }
+        bot.respond::<RestrictChatMember>(True);
+        bot.respond::<BanChatMember>(True);
+        bot.respond::<UnbanChatMember>(True);
+
+        assert!(matches!(dispatch(join(UserId(2))).await, ControlFlow::Continue(_)));
+        assert!(matches!(dispatch(join(UserId(3))).await, ControlFlow::Continue(_)));
+        let restrict = bot.requests_of::<RestrictChatMember>();
+        assert_eq!(restrict[0].permissions, ChatPermissions::empty());
+        assert_eq!(storage.clone().get_dialogue(chat_id).await.unwrap().unwrap().pending.len(), 2);
+
+        assert!(matches!(
+            dispatch(press(UserId(2), "captcha:2:1")).await,
+            ControlFlow::Continue(_)
+        ));
+        assert_eq!(bot.requests_of::<RestrictChatMember>()[2].permissions, ChatPermissions::all());
+
+        assert!(matches!(
+            dispatch(press(UserId(3), "captcha:3:0")).await,
+            ControlFlow::Continue(_)
+        ));
+        assert_eq!(bot.requests_of::<BanChatMember>()[0].user_id, UserId(3));
+        assert_eq!(storage.clone().get_dialogue(chat_id).await.unwrap(), None);
+
+        let other = press(UserId(2), "other");
+        assert!(matches!(dispatch(other).await, ControlFlow::Break(())));
+    }
+
+    #[tokio::test]
+    async fn kicks_on_timeout() {
+        let chat_id = ChatId(-1);
+        let bot = MockBot::new();
+        let captcha = Arc::new(Captcha::new(Fixed).timeout(Duration::ZERO));
+        let storage = InMemStorage::<CaptchaState>::new();
+        bot.respond::<RestrictChatMember>(True);
+        bot.respond::<SendMessage>(Message::text_fixture(chat_id, UserId(1), "challenge"));
+
+        let member = ChatMemberUpdated::fixture(
+            chat_id,
+            UserId(2),
+            ChatMemberKind::Left,
+            ChatMemberKind::Member,
+        );
+        Arc::clone(&captcha).challenge(bot.clone(), storage.clone(), &member).await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(bot.requests_of::<BanChatMember>()[0].user_id, UserId(2));
+        assert_eq!(storage.get_dialogue(chat_id).await.unwrap(), None);
+    }
+}
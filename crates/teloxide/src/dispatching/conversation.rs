@@ -0,0 +1,460 @@
+//! Linear conversations inside a handler.
+//!
+//! For simple linear flows (ask a question, wait for the answer, ask the next
+//! one...), explicit [dialogue] states can be replaced with plain `async` code:
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use teloxide::{
+//!     dispatching::conversation::{self, ConversationError, Conversations},
+//!     prelude::*,
+//! };
+//!
+//! # async fn run() {
+//! let bot = Bot::from_env();
+//!
+//! let handler = Update::filter_message().chain(conversation::intercept_replies()).endpoint(
+//!     |bot: Bot, msg: Message, conv: Arc<Conversations>| async move {
+//!         // Run the conversation in a separate task, see the note below
+//!         tokio::spawn(async move {
+//!             let Some(user) = msg.from() else { return Ok(()) };
+//!             let (chat_id, user_id) = (msg.chat.id, user.id);
+//!
+//!             let name = conv.ask(&bot, chat_id, user_id, "What's your name?").await?;
+//!             let age = conv.ask(&bot, chat_id, user_id, "How old are you?").await?;
+//!             bot.send_message(msg.chat.id, format!("Hi, {name} ({age})!"))
+//!                 .await
+//!                 .map_err(ConversationError::Request)?;
+//!             Ok::<_, ConversationError<teloxide::RequestError>>(())
+//!         });
+//!         respond(())
+//!     },
+//! );
+//!
+//! Dispatcher::builder(bot, handler)
+//!     .dependencies(dptree::deps![Conversations::new()])
+//!     .build()
+//!     .dispatch()
+//!     .await;
+//! # }
+//! ```
+//!
+//! Replies are delivered to waiting conversations by the
+//! [`intercept_replies`] handler, which must run before other handlers of
+//! messages. Messages which no conversation waits for are passed to other
+//! handlers as usual.
+//!
+//! ## Note
+//!
+//! By default, a [`Dispatcher`] handles updates from the same chat
+//! sequentially. A handler waiting for a reply would thus block the reply
+//! itself (until the timeout), so conversations must be run in a separate task,
+//! as in the example above, or the dispatcher must be configured not to group
+//! updates, using [`DispatcherBuilder::distribution_function`].
+//!
+//! Conversations waiting for replies are recorded in a dialogue [`Storage`]
+//! (an [`InMemStorage`] by default, see [`Conversations::with_storage`]). The
+//! conversations themselves are `async` code, so they are lost when the bot
+//! restarts; replies to them are then handled as usual.
+//!
+//! [dialogue]: crate::dispatching::dialogue
+//! [`Storage`]: crate::dispatching::dialogue::Storage
+//! [`InMemStorage`]: crate::dispatching::dialogue::InMemStorage
+//! [`Dispatcher`]: crate::dispatching::Dispatcher
+//! [`DispatcherBuilder::distribution_function`]: crate::dispatching::DispatcherBuilder::distribution_function
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, SystemTime},
+};
+
+use dptree::{di::DependencyMap, Handler};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::{sync::oneshot, time::Instant};
+
+use crate::{
+    dispatching::{
+        dialogue::{ErasedStorage, InMemStorage, Storage},
+        DpHandlerDescription,
+    },
+    requests::Requester,
+    types::{ChatId, Message, UserId},
+};
+
+/// An error returned from [`Conversations::ask`] and
+/// [`Conversations::await_reply`].
+#[derive(Debug, Error)]
+pub enum ConversationError<E> {
+    /// Sending a question has failed.
+    #[error("request error: {0}")]
+    Request(#[source] E),
+
+    /// No reply has arrived in time.
+    #[error("no reply has arrived in time")]
+    Timeout,
+
+    /// Another conversation has started waiting for the same reply.
+    #[error("another conversation has started waiting for the same reply")]
+    Cancelled,
+
+    /// Recording a conversation in the storage has failed.
+    #[error("storage error: {0}")]
+    Storage(#[source] Box<dyn std::error::Error + Send + Sync>),
+}
+
+/// A registry of conversations waiting for replies.
+///
+/// See the [module-level documentation](self).
+pub struct Conversations {
+    timeout: Duration,
+    next_id: AtomicU64,
+    storage: Arc<ErasedStorage<PendingReplies>>,
+    // Serializes read-modify-write accesses of `storage`
+    storage_lock: tokio::sync::Mutex<()>,
+    senders: Mutex<HashMap<u64, oneshot::Sender<Message>>>,
+}
+
+/// Conversations waiting for replies in a chat.
+///
+/// This is the dialogue type of a storage passed to
+/// [`Conversations::with_storage`].
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PendingReplies {
+    waiters: Vec<Waiter>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Waiter {
+    id: u64,
+    user_id: Option<UserId>,
+    text_only: bool,
+}
+
+impl Conversations {
+    /// Creates a registry which waits for replies for 5 minutes.
+    #[must_use]
+    pub fn new() -> Arc<Self> {
+        Self::with_timeout(Duration::from_secs(5 * 60))
+    }
+
+    /// Creates a registry which waits for replies for `timeout`.
+    #[must_use]
+    pub fn with_timeout(timeout: Duration) -> Arc<Self> {
+        Self::with_storage(InMemStorage::new().erase(), timeout)
+    }
+
+    /// Creates a registry which records waiting conversations in `storage` and
+    /// waits for replies for `timeout`.
+    #[must_use]
+    pub fn with_storage(
+        storage: Arc<ErasedStorage<PendingReplies>>,
+        timeout: Duration,
+    ) -> Arc<Self> {
+        // Ids must not repeat after a restart, since a persistent storage may
+        // still contain waiters of the previous run
+        let first_id = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |since_epoch| since_epoch.as_nanos() as u64);
+
+        Arc::new(Self {
+            timeout,
+            next_id: AtomicU64::new(first_id),
+            storage,
+            storage_lock: tokio::sync::Mutex::new(()),
+            senders: Mutex::default(),
+        })
+    }
+
+    /// Sends `question` to `chat_id` and returns the text of the next text
+    /// message from `user_id` in the chat.
+    ///
+    /// Other messages, including messages of `user_id` without text, are
+    /// handled as usual. See also [`Conversations::await_reply_from`].
+    pub async fn ask<R>(
+        &self,
+        bot: &R,
+        chat_id: ChatId,
+        user_id: UserId,
+        question: impl Into<String>,
+    ) -> Result<String, ConversationError<R::Err>>
+    where
+        R: Requester,
+    {
+        bot.send_message(chat_id, question).await.map_err(ConversationError::Request)?;
+
+        let reply = self.wait(chat_id, Some(user_id), true).await?;
+        Ok(reply.text().unwrap_or_default().to_owned())
+    }
+
+    /// Returns the next message in `chat_id`.
+    ///
+    /// If another conversation starts waiting for the next message in the same
+    /// chat, this one is [cancelled].
+    ///
+    /// [cancelled]: ConversationError::Cancelled
+    pub async fn await_reply<E>(&self, chat_id: ChatId) -> Result<Message, ConversationError<E>> {
+        self.wait(chat_id, None, false).await
+    }
+
+    /// Returns the next message from `user_id` in `chat_id`.
+    ///
+    /// Messages from other users are handled as usual. If another conversation
+    /// starts waiting for a message from the same user in the same chat, this
+    /// one is [cancelled].
+    ///
+    /// [cancelled]: ConversationError::Cancelled
+    pub async fn await_reply_from<E>(
+        &self,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> Result<Message, ConversationError<E>> {
+        self.wait(chat_id, Some(user_id), false).await
+    }
+
+    /// Delivers `message` to a conversation waiting for it.
+    ///
+    /// Returns the message back if no conversation is waiting for it.
+    pub async fn offer(&self, message: Message) -> Option<Message> {
+        let chat_id = message.chat.id;
+        let user_id = message.from().map(|user| user.id);
+
+        let _guard = self.storage_lock.lock().await;
+        let mut pending = match Arc::clone(&self.storage).get_dialogue(chat_id).await {
+            Ok(Some(pending)) => pending,
+            Ok(None) => return Some(message),
+            Err(err) => {
+                log::error!("Couldn't get conversations waiting in {chat_id}: {err}");
+                return Some(message);
+            }
+        };
+
+        let accepts = |waiter: &Waiter| !waiter.text_only || message.text().is_some();
+        // Conversations waiting for the sender take precedence over the ones
+        // waiting for anyone
+        let position = pending
+            .waiters
+            .iter()
+            .position(|waiter| {
+                waiter.user_id.is_some() && waiter.user_id == user_id && accepts(waiter)
+            })
+            .or_else(|| {
+                pending
+                    .waiters
+                    .iter()
+                    .position(|waiter| waiter.user_id.is_none() && accepts(waiter))
+            });
+        let Some(position) = position else { return Some(message) };
+
+        let waiter = pending.waiters.remove(position);
+        if let Err(err) = self.save(chat_id, pending).await {
+            log::error!("Couldn't update conversations waiting in {chat_id}: {err}");
+        }
+
+        // If the conversation has gone, handle the message as usual
+        match self.senders.lock().unwrap().remove(&waiter.id) {
+            Some(tx) => tx.send(message).err(),
+            None => Some(message),
+        }
+    }
+
+    async fn wait<E>(
+        &self,
+        chat_id: ChatId,
+        user_id: Option<UserId>,
+        text_only: bool,
+    ) -> Result<Message, ConversationError<E>> {
+        let deadline = Instant::now() + self.timeout;
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (tx, mut rx) = oneshot::channel();
+        self.senders.lock().unwrap().insert(id, tx);
+
+        if let Err(err) = self.register(chat_id, Waiter { id, user_id, text_only }).await {
+            self.senders.lock().unwrap().remove(&id);
+            return Err(ConversationError::Storage(err));
+        }
+
+        match tokio::time::timeout_at(deadline, &mut rx).await {
+            Ok(Ok(message)) => Ok(message),
+            Ok(Err(_)) => Err(ConversationError::Cancelled),
+            Err(_) => {
+                // `offer` sends the reply while holding the lock, so if it has taken the
+                // sender right before the deadline, the reply is already in `rx`
+                if self.senders.lock().unwrap().remove(&id).is_none() {
+                    if let Ok(message) = rx.try_recv() {
+                        return Ok(message);
+                    }
+                }
+                if let Err(err) = self.unregister(chat_id, id).await {
+                    log::error!("Couldn't update conversations waiting in {chat_id}: {err}");
+                }
+                Err(ConversationError::Timeout)
+            }
+        }
+    }
+
+    /// Records `waiter`, cancelling a conversation waiting for the same reply.
+    async fn register(
+        &self,
+        chat_id: ChatId,
+        waiter: Waiter,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let _guard = self.storage_lock.lock().await;
+        let mut pending =
+            Arc::clone(&self.storage).get_dialogue(chat_id).await?.unwrap_or_default();
+
+        pending.waiters.retain(|old| {
+            let cancelled = old.user_id == waiter.user_id;
+            if cancelled {
+                // Dropping the sender cancels the conversation
+                self.senders.lock().unwrap().remove(&old.id);
+            }
+            !cancelled
+        });
+        pending.waiters.push(waiter);
+
+        Arc::clone(&self.storage).update_dialogue(chat_id, pending).await
+    }
+
+    async fn unregister(
+        &self,
+        chat_id: ChatId,
+        id: u64,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let _guard = self.storage_lock.lock().await;
+        let Some(mut pending) = Arc::clone(&self.storage).get_dialogue(chat_id).await? else {
+            return Ok(());
+        };
+
+        let len = pending.waiters.len();
+        pending.waiters.retain(|waiter| waiter.id != id);
+        if pending.waiters.len() == len {
+            return Ok(());
+        }
+
+        self.save(chat_id, pending).await
+    }
+
+    async fn save(
+        &self,
+        chat_id: ChatId,
+        pending: PendingReplies,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let storage = Arc::clone(&self.storage);
+        if pending.waiters.is_empty() {
+            storage.remove_dialogue(chat_id).await
+        } else {
+            storage.update_dialogue(chat_id, pending).await
+        }
+    }
+}
+
+impl fmt::Debug for Conversations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Conversations").field("timeout", &self.timeout).finish_non_exhaustive()
+    }
+}
+
+/// Returns a handler that delivers replies to waiting conversations.
+///
+/// Messages which are delivered are not passed further, other messages are.
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::Message`]
+///  - `Arc<Conversations>`
+#[must_use]
+pub fn intercept_replies<Output>() -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    Output: Send + Sync + 'static,
+{
+    dptree::filter_async(|message: Message, conversations: Arc<Conversations>| async move {
+        conversations.offer(message).await.is_some()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{adaptors::MockBot, payloads::SendMessage};
+
+    async fn is_waiting(conversations: &Conversations, chat_id: ChatId) -> bool {
+        Arc::clone(&conversations.storage).get_dialogue(chat_id).await.unwrap().is_some()
+    }
+
+    #[tokio::test]
+    async fn ask() {
+        let bot = MockBot::new();
+        bot.respond::<SendMessage>(Message::text_fixture(ChatId(1), UserId(2), "What?"));
+
+        let conversations = Conversations::new();
+        let conv = Arc::clone(&conversations);
+        let question =
+            tokio::spawn(async move { conv.ask(&bot, ChatId(1), UserId(1), "What?").await });
+
+        // Wait until the conversation starts waiting
+        while !is_waiting(&conversations, ChatId(1)).await {
+            tokio::task::yield_now().await;
+        }
+
+        let other_chat = Message::text_fixture(ChatId(2), UserId(1), "no");
+        assert!(conversations.offer(other_chat).await.is_some());
+        let other_user = Message::text_fixture(ChatId(1), UserId(2), "no");
+        assert!(conversations.offer(other_user).await.is_some());
+
+        let mut dice = Message::text_fixture(ChatId(1), UserId(1), "");
+        dice.kind = serde_json::from_value::<Message>(serde_json::json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": {"id": 1, "type": "private"},
+            "dice": {"emoji": "🎲", "value": 1}
+        }))
+        .unwrap()
+        .kind;
+        assert!(conversations.offer(dice).await.is_some());
+
+        let reply = Message::text_fixture(ChatId(1), UserId(1), "yes");
+        assert!(conversations.offer(reply).await.is_none());
+
+        assert_eq!(question.await.unwrap().unwrap(), "yes");
+        assert!(!is_waiting(&conversations, ChatId(1)).await);
+    }
+
+    #[tokio::test]
+    async fn cancel() {
+        let conversations = Conversations::new();
+        let conv = Arc::clone(&conversations);
+        let first =
+            tokio::spawn(async move { conv.await_reply_from::<()>(ChatId(1), UserId(1)).await });
+
+        while !is_waiting(&conversations, ChatId(1)).await {
+            tokio::task::yield_now().await;
+        }
+
+        let conv = Arc::clone(&conversations);
+        let second =
+            tokio::spawn(async move { conv.await_reply_from::<()>(ChatId(1), UserId(1)).await });
+
+        assert!(matches!(first.await.unwrap(), Err(ConversationError::Cancelled)));
+
+        let reply = Message::text_fixture(ChatId(1), UserId(1), "yes");
+        assert!(conversations.offer(reply).await.is_none());
+        assert_eq!(second.await.unwrap().unwrap().text(), Some("yes"));
+    }
+
+    #[tokio::test]
+    async fn timeout() {
+        let conversations = Conversations::with_timeout(Duration::from_millis(10));
+
+        let reply = conversations.await_reply_from::<()>(ChatId(1), UserId(1)).await;
+        assert!(matches!(reply, Err(ConversationError::Timeout)));
+        assert!(!is_waiting(&conversations, ChatId(1)).await);
+        assert!(conversations.senders.lock().unwrap().is_empty());
+    }
+}
@@ -102,7 +102,7 @@ pub use self::{SqliteStorage, SqliteStorageError};
 pub use get_chat_id::GetChatId;
 pub use storage::*;
 
-use dptree::{prelude::DependencyMap, Handler};
+use dptree::{prelude::DependencyMap, Handler, HandlerDescription};
 use teloxide_core::types::ChatId;
 
 use std::{fmt::Debug, marker::PhantomData, sync::Arc};
@@ -213,6 +213,7 @@ where
 ///
 /// [`HandlerExt::enter_dialogue`]: super::HandlerExt::enter_dialogue
 #[must_use]
+#[track_caller]
 pub fn enter<Upd, S, D, Output>() -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
 where
     S: Storage<D> + ?Sized + Send + Sync + 'static,
@@ -221,11 +222,87 @@ where
     Upd: GetChatId + Clone + Send + Sync + 'static,
     Output: Send + Sync + 'static,
 {
-    dptree::filter_map(|storage: Arc<S>, upd: Upd| {
+    let description = DpHandlerDescription::filter_map().labelled("enter_dialogue");
+    dptree::filter_map_with_description(description, |storage: Arc<S>, upd: Upd| {
         let chat_id = upd.chat_id()?;
         Some(Dialogue::new(storage, chat_id))
     })
-    .filter_map_async(|dialogue: Dialogue<D, S>| async move {
+    .chain(get_state::<S, D, Output>())
+}
+
+/// Enters a dialogue context with the given storage.
+///
+/// This works just like [`enter`], but takes the storage as an argument
+/// instead of requiring `Arc<S>` to be a dependency. This allows, for example,
+/// to use different storages (or state types) in different branches of a
+/// handler tree:
+///
+/// ```no_run
+/// use teloxide::{
+///     dispatching::dialogue::{self, InMemStorage},
+///     dptree::case,
+///     prelude::*,
+/// };
+///
+/// #[derive(Clone, Default)]
+/// enum State {
+///     #[default]
+///     Start,
+///     ReceiveName,
+/// }
+///
+/// let storage = InMemStorage::<State>::new();
+/// let handler = Update::filter_message()
+///     .chain(dialogue::enter_with_storage::<Message, _, State, _>(storage))
+///     .branch(case![State::Start].endpoint(start))
+///     .branch(case![State::ReceiveName].endpoint(receive_name));
+///
+/// async fn start(dialogue: Dialogue<State, InMemStorage<State>>) -> ResponseResult<()> {
+///     # let _ = dialogue;
+///     Ok(())
+/// }
+///
+/// async fn receive_name(msg: Message) -> ResponseResult<()> {
+///     # let _ = msg;
+///     Ok(())
+/// }
+/// # let _: dptree::Handler<'static, dptree::di::DependencyMap, ResponseResult<()>, _> = handler;
+/// ```
+///
+/// ## Dependency requirements
+///
+///  - `Upd`
+#[must_use]
+#[track_caller]
+pub fn enter_with_storage<Upd, S, D, Output>(
+    storage: Arc<S>,
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    S: Storage<D> + ?Sized + Send + Sync + 'static,
+    <S as Storage<D>>::Error: Debug + Send,
+    D: Default + Send + Sync + 'static,
+    Upd: GetChatId + Clone + Send + Sync + 'static,
+    Output: Send + Sync + 'static,
+{
+    let description = DpHandlerDescription::filter_map().labelled("enter_dialogue");
+    dptree::filter_map_with_description(description, move |upd: Upd| {
+        let chat_id = upd.chat_id()?;
+        Some(Dialogue::new(Arc::clone(&storage), chat_id))
+    })
+    .chain(get_state::<S, D, Output>())
+}
+
+/// Fetches the state of the [`Dialogue`], or the default one.
+#[track_caller]
+fn get_state<S, D, Output>() -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    S: Storage<D> + ?Sized + Send + Sync + 'static,
+    <S as Storage<D>>::Error: Debug + Send,
+    D: Default + Send + Sync + 'static,
+    Output: Send + Sync + 'static,
+{
+    let description = DpHandlerDescription::filter_map_async().labelled("dialogue_state");
+    dptree::filter_map_async_with_description(description, |dialogue: Dialogue<D, S>| async move {
         match dialogue.get_or_default().await {
             Ok(dialogue) => Some(dialogue),
             Err(err) => {
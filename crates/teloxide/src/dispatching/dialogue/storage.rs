@@ -78,6 +78,40 @@ pub trait Storage<D> {
         chat_id: ChatId,
     ) -> BoxFuture<'static, Result<Option<D>, Self::Error>>;
 
+    /// Moves a dialogue indexed by `old` to `new`, e.g. when a group is
+    /// migrated to a supergroup.
+    ///
+    /// A dialogue indexed by `new`, if any, is replaced. If the dialogue
+    /// indexed by `old` does not exist, this function does nothing.
+    ///
+    /// The default implementation gets the dialogue, updates it under `new` and
+    /// then removes it under `old`, so it isn't atomic. The bundled storages
+    /// override it.
+    ///
+    /// See also [`DispatcherBuilder::migrate_dialogues`].
+    ///
+    /// [`DispatcherBuilder::migrate_dialogues`]: crate::dispatching::DispatcherBuilder::migrate_dialogues
+    #[must_use = "Futures are lazy and do nothing unless polled with .await"]
+    fn migrate_chat(
+        self: Arc<Self>,
+        old: ChatId,
+        new: ChatId,
+    ) -> BoxFuture<'static, Result<(), Self::Error>>
+    where
+        D: Send + 'static,
+        Self: Send + Sync + 'static,
+    {
+        Box::pin(async move {
+            let dialogue = Arc::clone(&self).get_dialogue(old).await?;
+            if let Some(dialogue) = dialogue {
+                Arc::clone(&self).update_dialogue(new, dialogue).await?;
+                self.remove_dialogue(old).await?;
+            }
+
+            Ok(())
+        })
+    }
+
     /// Erases [`Self::Error`] to [`std::error::Error`].
     #[must_use]
     fn erase(self: Arc<Self>) -> Arc<ErasedStorage<D>>
@@ -131,6 +165,19 @@ where
             async move { Arc::clone(&self.0).get_dialogue(chat_id).await.map_err(|e| e.into()) },
         )
     }
+
+    fn migrate_chat(
+        self: Arc<Self>,
+        old: ChatId,
+        new: ChatId,
+    ) -> BoxFuture<'static, Result<(), Self::Error>>
+    where
+        D: Send + 'static,
+    {
+        Box::pin(
+            async move { Arc::clone(&self.0).migrate_chat(old, new).await.map_err(|e| e.into()) },
+        )
+    }
 }
 
 #[cfg(test)]
@@ -147,4 +194,62 @@ mod tests {
         Arc::clone(&erased).remove_dialogue(chat_id).await.unwrap();
         assert_eq!(Arc::clone(&erased).get_dialogue(chat_id).await.unwrap(), None);
     }
+
+    async fn check_migrate_chat<S>(storage: Arc<S>)
+    where
+        S: Storage<i32> + Send + Sync + 'static,
+        S::Error: std::fmt::Debug + Send,
+    {
+        let (old, new) = (ChatId(-1), ChatId(-1001));
+
+        Arc::clone(&storage).update_dialogue(old, 1).await.unwrap();
+        Arc::clone(&storage).update_dialogue(new, 2).await.unwrap();
+
+        Arc::clone(&storage).migrate_chat(old, new).await.unwrap();
+        assert_eq!(Arc::clone(&storage).get_dialogue(old).await.unwrap(), None);
+        assert_eq!(Arc::clone(&storage).get_dialogue(new).await.unwrap(), Some(1));
+
+        // The dialogue has already been moved
+        Arc::clone(&storage).migrate_chat(old, new).await.unwrap();
+        assert_eq!(Arc::clone(&storage).get_dialogue(new).await.unwrap(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn migrate_chat() {
+        check_migrate_chat(InMemStorage::new()).await;
+    }
+
+    #[tokio::test]
+    async fn default_migrate_chat() {
+        /// A storage which doesn't override `migrate_chat`.
+        struct Plain(Arc<InMemStorage<i32>>);
+
+        impl Storage<i32> for Plain {
+            type Error = InMemStorageError;
+
+            fn remove_dialogue(
+                self: Arc<Self>,
+                chat_id: ChatId,
+            ) -> BoxFuture<'static, Result<(), Self::Error>> {
+                Arc::clone(&self.0).remove_dialogue(chat_id)
+            }
+
+            fn update_dialogue(
+                self: Arc<Self>,
+                chat_id: ChatId,
+                dialogue: i32,
+            ) -> BoxFuture<'static, Result<(), Self::Error>> {
+                Arc::clone(&self.0).update_dialogue(chat_id, dialogue)
+            }
+
+            fn get_dialogue(
+                self: Arc<Self>,
+                chat_id: ChatId,
+            ) -> BoxFuture<'static, Result<Option<i32>, Self::Error>> {
+                Arc::clone(&self.0).get_dialogue(chat_id)
+            }
+        }
+
+        check_migrate_chat(Arc::new(Plain(InMemStorage::new()))).await;
+    }
 }
@@ -74,4 +74,21 @@ where
     ) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
         Box::pin(async move { Ok(self.map.lock().await.get(&chat_id).map(ToOwned::to_owned)) })
     }
+
+    fn migrate_chat(
+        self: Arc<Self>,
+        old: ChatId,
+        new: ChatId,
+    ) -> BoxFuture<'static, Result<(), Self::Error>>
+    where
+        D: Send + 'static,
+    {
+        Box::pin(async move {
+            let mut map = self.map.lock().await;
+            if let Some(dialogue) = map.remove(&old) {
+                map.insert(new, dialogue);
+            }
+            Ok(())
+        })
+    }
 }
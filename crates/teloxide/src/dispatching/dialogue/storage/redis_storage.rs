@@ -108,4 +108,19 @@ where
                 .transpose()
         })
     }
+
+    fn migrate_chat(
+        self: Arc<Self>,
+        ChatId(old): ChatId,
+        ChatId(new): ChatId,
+    ) -> BoxFuture<'static, Result<(), Self::Error>> {
+        Box::pin(async move {
+            let mut conn = self.conn.lock().await;
+            // `RENAME` fails if there is no such key
+            if conn.exists(old).await? {
+                conn.rename(old, new).await?;
+            }
+            Ok(())
+        })
+    }
 }
@@ -121,6 +121,33 @@ where
                 .transpose()
         })
     }
+
+    fn migrate_chat(
+        self: Arc<Self>,
+        ChatId(old): ChatId,
+        ChatId(new): ChatId,
+    ) -> BoxFuture<'static, Result<(), Self::Error>> {
+        Box::pin(async move {
+            let mut tx = self.pool.begin().await?;
+            sqlx::query(
+                "
+            INSERT INTO teloxide_dialogues SELECT ?, dialogue FROM teloxide_dialogues WHERE \
+                 chat_id = ?
+            ON CONFLICT(chat_id) DO UPDATE SET dialogue=excluded.dialogue
+                ",
+            )
+            .bind(new)
+            .bind(old)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query("DELETE FROM teloxide_dialogues WHERE chat_id = ?")
+                .bind(old)
+                .execute(&mut *tx)
+                .await?;
+            tx.commit().await?;
+            Ok(())
+        })
+    }
 }
 
 async fn get_dialogue(
@@ -66,4 +66,16 @@ where
         log::trace!("Requested a dialogue #{}", chat_id);
         <S as Storage<D>>::get_dialogue(self.inner.clone(), chat_id)
     }
+
+    fn migrate_chat(
+        self: Arc<Self>,
+        old: ChatId,
+        new: ChatId,
+    ) -> BoxFuture<'static, Result<(), Self::Error>>
+    where
+        D: Send + 'static,
+    {
+        log::trace!("Migrating dialogue #{} to #{}", old, new);
+        <S as Storage<D>>::migrate_chat(self.inner.clone(), old, new)
+    }
 }
@@ -1,12 +1,17 @@
 use crate::{
     dispatching::{
-        distribution::default_distribution_function, DefaultKey, DpHandlerDescription,
+        dialogue::Storage,
+        distribution::default_distribution_function,
+        flow::FlowState,
+        handle::{CountingErrorHandler, Health},
+        DefaultKey, DispatcherHandle, DpHandlerDescription, HandlerOutline, MatchedBranches,
         ShutdownToken,
     },
     error_handlers::{ErrorHandler, LoggingErrorHandler},
     requests::{Request, Requester},
-    types::{Update, UpdateKind},
-    update_listeners::{self, UpdateListener},
+    stop::StopToken,
+    types::{AllowedUpdate, ChatId, ChatMigration, Update, UpdateId, UpdateKind},
+    update_listeners::{self, multi_bot::MultiBotListener, UpdateListener},
 };
 
 use dptree::di::{DependencyMap, DependencySupplier};
@@ -14,21 +19,26 @@ use either::Either;
 use futures::{
     future::{self, BoxFuture},
     stream::FuturesUnordered,
-    FutureExt as _, StreamExt as _,
+    FutureExt as _, Stream, StreamExt as _,
 };
+use thiserror::Error;
 use tokio_stream::wrappers::ReceiverStream;
 
 use std::{
+    any::Any,
     collections::HashMap,
     fmt::Debug,
     future::Future,
     hash::Hash,
+    mem,
     ops::{ControlFlow, Deref},
+    panic::AssertUnwindSafe,
     pin::pin,
     sync::{
         atomic::{AtomicBool, AtomicU32, Ordering},
         Arc,
     },
+    time::Duration,
 };
 
 /// The builder for [`Dispatcher`].
@@ -38,14 +48,24 @@ use std::{
 pub struct DispatcherBuilder<R, Err, Key> {
     bot: R,
     dependencies: DependencyMap,
+    dependencies_fn: Option<DependenciesFn>,
     handler: Arc<UpdateHandler<Err>>,
     default_handler: DefaultHandler,
     error_handler: Arc<dyn ErrorHandler<Err> + Send + Sync>,
+    panic_handler: PanicHandler<Err>,
+    handler_timeout: Option<Duration>,
+    edits_as_messages: bool,
+    chat_migrations: Vec<MigrateChatFn>,
     ctrlc_handler: bool,
     distribution_f: fn(&Update) -> Option<Key>,
     worker_queue_size: usize,
 }
 
+enum PanicHandler<Err> {
+    Handler(Arc<dyn ErrorHandler<HandlerPanic> + Send + Sync>),
+    Forward(fn(HandlerPanic) -> Err),
+}
+
 impl<R, Err, Key> DispatcherBuilder<R, Err, Key>
 where
     R: Clone + Requester + Clone + Send + Sync + 'static,
@@ -79,14 +99,177 @@ where
         Self { error_handler: handler, ..self }
     }
 
+    /// Specifies a handler that will be called when a handler panics.
+    ///
+    /// Panics are caught separately for each update, so a panicking handler
+    /// doesn't stop dispatching of other updates.
+    ///
+    /// By default, it is [`LoggingErrorHandler`]. See also
+    /// [`DispatcherBuilder::forward_panics`].
+    #[must_use]
+    pub fn panic_handler(self, handler: Arc<dyn ErrorHandler<HandlerPanic> + Send + Sync>) -> Self {
+        Self { panic_handler: PanicHandler::Handler(handler), ..self }
+    }
+
+    /// Passes panics of handlers to the [error handler], converting them into
+    /// `Err`.
+    ///
+    /// See also [`DispatcherBuilder::panic_handler`].
+    ///
+    /// [error handler]: DispatcherBuilder::error_handler
+    #[must_use]
+    pub fn forward_panics(self) -> Self
+    where
+        Err: From<HandlerPanic>,
+    {
+        Self { panic_handler: PanicHandler::Forward(Err::from), ..self }
+    }
+
     /// Specifies dependencies that can be used inside of handlers.
     ///
     /// By default, there is no dependencies.
+    ///
+    /// Besides these, handlers can always get the [`Update`] being handled,
+    /// either as `Update` or as `Arc<Update>`. The latter doesn't clone the
+    /// update, so prefer it in handlers that only need to look at the update.
     #[must_use]
     pub fn dependencies(self, dependencies: DependencyMap) -> Self {
         Self { dependencies, ..self }
     }
 
+    /// Specifies a function which computes additional dependencies for each
+    /// update.
+    ///
+    /// The function is called once per update, before the handlers, so values
+    /// that are specific to an update (e.g. a tracing span, or the locale of
+    /// the user) can be computed once and then used by all handlers. The
+    /// returned dependencies take precedence over the ones passed to
+    /// [`DispatcherBuilder::dependencies`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide::{dispatching::Dispatcher, dptree, types::Update, Bot};
+    ///
+    /// #[derive(Clone)]
+    /// struct Locale(String);
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// let handler = dptree::endpoint(|locale: Locale| async move {
+    ///     log::info!("The locale of the user is {}", locale.0);
+    ///     Ok(())
+    /// });
+    ///
+    /// let dp = Dispatcher::builder(bot, handler)
+    ///     .dependencies_fn(|upd: &Update| {
+    ///         let code = upd.from().and_then(|user| user.language_code.clone());
+    ///         dptree::deps![Locale(code.unwrap_or_else(|| "en".to_owned()))]
+    ///     })
+    ///     .build();
+    /// # let _: Dispatcher<_, (), _> = dp;
+    /// ```
+    #[must_use]
+    pub fn dependencies_fn<F>(self, f: F) -> Self
+    where
+        F: Fn(&Update) -> DependencyMap + Send + Sync + 'static,
+    {
+        Self { dependencies_fn: Some(Arc::new(f)), ..self }
+    }
+
+    /// Makes edited messages and channel posts go through the same handlers as
+    /// new ones.
+    ///
+    /// [`UpdateKind::EditedMessage`] and [`UpdateKind::EditedChannelPost`]
+    /// updates are turned into [`UpdateKind::Message`] and
+    /// [`UpdateKind::ChannelPost`] before they are handled, so handlers like
+    /// [`Update::filter_message`] process them too. The dispatcher also
+    /// requests edits from Telegram whenever the handlers need new messages or
+    /// channel posts.
+    ///
+    /// Handlers can still tell edits apart with the [`IsEdited`] dependency,
+    /// which is added to every update when this option is enabled.
+    ///
+    /// By default, edits are handled separately.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide::{
+    ///     dispatching::{Dispatcher, IsEdited, UpdateFilterExt},
+    ///     prelude::*,
+    /// };
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// let handler =
+    ///     Update::filter_message().endpoint(|msg: Message, IsEdited(edited): IsEdited| async move {
+    ///         log::info!("Message {} (edited: {edited})", msg.id);
+    ///         Ok(())
+    ///     });
+    ///
+    /// let dp = Dispatcher::builder(bot, handler).handle_edits_as_messages().build();
+    /// # let _: Dispatcher<_, (), _> = dp;
+    /// ```
+    ///
+    /// [`Update::filter_message`]: crate::dispatching::UpdateFilterExt::filter_message
+    #[must_use]
+    pub fn handle_edits_as_messages(self) -> Self {
+        Self { edits_as_messages: true, ..self }
+    }
+
+    /// Moves dialogues in `storage` when a group is migrated to a supergroup.
+    ///
+    /// The id of a group changes when it's migrated, so its dialogue would be
+    /// lost. When the dispatcher receives a service message about a migration
+    /// (see [`Message::chat_migration`]), it calls [`Storage::migrate_chat`]
+    /// before the handlers, so they can already use the new id. Errors are
+    /// logged.
+    ///
+    /// Can be called multiple times to migrate dialogues in several storages.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide::{
+    ///     dispatching::{dialogue::InMemStorage, Dispatcher},
+    ///     dptree, Bot,
+    /// };
+    ///
+    /// #[derive(Clone, Default)]
+    /// enum State {
+    ///     #[default]
+    ///     Start,
+    /// }
+    ///
+    /// let bot = Bot::new("TOKEN");
+    /// let storage = InMemStorage::<State>::new();
+    /// let handler = dptree::entry() /* ... */;
+    ///
+    /// let dp = Dispatcher::builder(bot, handler)
+    ///     .dependencies(dptree::deps![storage.clone()])
+    ///     .migrate_dialogues(storage)
+    ///     .build();
+    /// # let _: Dispatcher<_, (), _> = dp;
+    /// ```
+    ///
+    /// [`Message::chat_migration`]: crate::types::Message::chat_migration
+    #[must_use]
+    pub fn migrate_dialogues<S, D>(mut self, storage: Arc<S>) -> Self
+    where
+        S: Storage<D> + ?Sized + Send + Sync + 'static,
+        S::Error: Debug + Send,
+        D: Send + 'static,
+    {
+        self.chat_migrations.push(Arc::new(move |old, new| {
+            let storage = Arc::clone(&storage);
+            Box::pin(async move {
+                if let Err(err) = storage.migrate_chat(old, new).await {
+                    log::error!("Couldn't migrate the dialogue of {old} to {new}: {err:?}");
+                }
+            })
+        }));
+        self
+    }
+
     /// Enables the `^C` handler that [`shutdown`]s dispatching.
     ///
     /// [`shutdown`]: ShutdownToken::shutdown
@@ -104,6 +287,18 @@ where
         Self { worker_queue_size: size, ..self }
     }
 
+    /// Specifies the maximum time of handling a single update.
+    ///
+    /// If handlers of an update take longer, they are cancelled, an error is
+    /// logged, and the worker continues with the next update. This prevents
+    /// one stuck handler from blocking all the updates of its chat.
+    ///
+    /// By default, there is no timeout.
+    #[must_use]
+    pub fn handler_timeout(self, timeout: Duration) -> Self {
+        Self { handler_timeout: Some(timeout), ..self }
+    }
+
     /// Specifies the distribution function that decides how updates are grouped
     /// before execution.
     ///
@@ -134,6 +329,21 @@ where
     /// This pair nicely with dialogue system, which has state attached to
     /// chats.
     ///
+    /// ## Key requirements
+    ///
+    /// The key type `K` must implement [`Hash`] and [`Eq`], since updates are
+    /// grouped by equal keys. To run the dispatcher, it must also be [`Clone`],
+    /// [`Send`] and `'static`.
+    ///
+    /// `f` is a function pointer, so it can't capture anything; it should be
+    /// cheap, since it's called for every update before it's handled.
+    ///
+    /// Updates with the same key are handled in the order they were received
+    /// by the dispatcher, one after another. No order is guaranteed between
+    /// updates with different keys, or between updates for which `f` returns
+    /// `None`. A group of updates is served by its own worker, which is
+    /// removed when it's idle, so there may be any number of distinct keys.
+    ///
     /// ## Examples
     ///
     /// Grouping updates by user who caused this update to happen:
@@ -170,9 +380,14 @@ where
         let Self {
             bot,
             dependencies,
+            dependencies_fn,
             handler,
             default_handler,
             error_handler,
+            panic_handler,
+            handler_timeout,
+            edits_as_messages,
+            chat_migrations,
             ctrlc_handler,
             distribution_f: _,
             worker_queue_size,
@@ -181,9 +396,14 @@ where
         DispatcherBuilder {
             bot,
             dependencies,
+            dependencies_fn,
             handler,
             default_handler,
             error_handler,
+            panic_handler,
+            handler_timeout,
+            edits_as_messages,
+            chat_migrations,
             ctrlc_handler,
             distribution_f: f,
             worker_queue_size,
@@ -196,9 +416,14 @@ where
         let Self {
             bot,
             dependencies,
+            dependencies_fn,
             handler,
             default_handler,
             error_handler,
+            panic_handler,
+            handler_timeout,
+            edits_as_messages,
+            chat_migrations,
             distribution_f,
             worker_queue_size,
             ctrlc_handler,
@@ -207,13 +432,32 @@ where
         // If the `ctrlc_handler` feature is not enabled, don't emit a warning.
         let _ = ctrlc_handler;
 
+        let health = Arc::new(Health::default());
+        let error_handler: Arc<dyn ErrorHandler<Err> + Send + Sync> =
+            Arc::new(CountingErrorHandler { inner: error_handler, health: Arc::clone(&health) });
+        let panic_handler = match panic_handler {
+            PanicHandler::Handler(handler) => handler,
+            PanicHandler::Forward(into_err) => {
+                let error_handler = Arc::clone(&error_handler);
+                Arc::new(move |panic| Arc::clone(&error_handler).handle_error(into_err(panic)))
+            }
+        };
+
         let dp = Dispatcher {
             bot,
             dependencies,
-            handler,
-            default_handler,
-            error_handler,
+            handlers: Arc::new(Handlers {
+                dependencies_fn,
+                handler,
+                default_handler,
+                error_handler,
+                panic_handler,
+                timeout: handler_timeout,
+                edits_as_messages,
+                chat_migrations,
+            }),
             state: ShutdownToken::new(),
+            health,
             distribution_f,
             worker_queue_size,
             workers: HashMap::new(),
@@ -253,8 +497,7 @@ pub struct Dispatcher<R, Err, Key> {
     bot: R,
     dependencies: DependencyMap,
 
-    handler: Arc<UpdateHandler<Err>>,
-    default_handler: DefaultHandler,
+    handlers: Arc<Handlers<Err>>,
 
     distribution_f: fn(&Update) -> Option<Key>,
     worker_queue_size: usize,
@@ -265,13 +508,24 @@ pub struct Dispatcher<R, Err, Key> {
     // The default TX part that consume updates concurrently.
     default_worker: Option<Worker>,
 
-    error_handler: Arc<dyn ErrorHandler<Err> + Send + Sync>,
-
     state: ShutdownToken,
+    health: Arc<Health>,
+}
+
+/// Handlers of updates, shared by workers.
+struct Handlers<Err> {
+    dependencies_fn: Option<DependenciesFn>,
+    handler: Arc<UpdateHandler<Err>>,
+    default_handler: DefaultHandler,
+    error_handler: Arc<dyn ErrorHandler<Err> + Send + Sync>,
+    panic_handler: Arc<dyn ErrorHandler<HandlerPanic> + Send + Sync>,
+    timeout: Option<Duration>,
+    edits_as_messages: bool,
+    chat_migrations: Vec<MigrateChatFn>,
 }
 
 struct Worker {
-    tx: tokio::sync::mpsc::Sender<Update>,
+    tx: tokio::sync::mpsc::Sender<(Update, DependencyMap)>,
     handle: tokio::task::JoinHandle<()>,
     is_waiting: Arc<AtomicBool>,
 }
@@ -285,6 +539,46 @@ pub type UpdateHandler<Err> =
 
 type DefaultHandler = Arc<dyn Fn(Arc<Update>) -> BoxFuture<'static, ()> + Send + Sync>;
 
+type DependenciesFn = Arc<dyn Fn(&Update) -> DependencyMap + Send + Sync>;
+
+type MigrateChatFn = Arc<dyn Fn(ChatId, ChatId) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// Whether a message or a channel post is edited.
+///
+/// This dependency is only available if the dispatcher is built with
+/// [`DispatcherBuilder::handle_edits_as_messages`]. For other kinds of updates
+/// it's `IsEdited(false)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IsEdited(pub bool);
+
+/// A panic of a handler, caught by [`Dispatcher`].
+///
+/// See [`DispatcherBuilder::panic_handler`] and
+/// [`DispatcherBuilder::forward_panics`].
+#[derive(Debug, Error)]
+#[error("a handler has panicked while handling update {}: {message}", .update_id.0)]
+pub struct HandlerPanic {
+    /// The update which was being handled.
+    pub update_id: UpdateId,
+
+    /// The panic message.
+    pub message: String,
+}
+
+impl HandlerPanic {
+    fn new(update_id: UpdateId, payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast_ref::<&str>() {
+                Some(message) => (*message).to_owned(),
+                None => "Box<dyn Any>".to_owned(),
+            },
+        };
+
+        Self { update_id, message }
+    }
+}
+
 impl<R, Err> Dispatcher<R, Err, DefaultKey>
 where
     R: Requester + Clone + Send + Sync + 'static,
@@ -301,12 +595,19 @@ where
         DispatcherBuilder {
             bot,
             dependencies: DependencyMap::new(),
+            dependencies_fn: None,
             handler: Arc::new(handler),
             default_handler: Arc::new(|upd| {
                 log::warn!("Unhandled update: {:?}", upd);
                 Box::pin(async {})
             }),
             error_handler: LoggingErrorHandler::new(),
+            panic_handler: PanicHandler::Handler(LoggingErrorHandler::with_custom_text(
+                "A handler has panicked",
+            )),
+            handler_timeout: None,
+            edits_as_messages: false,
+            chat_migrations: Vec::new(),
             ctrlc_handler: false,
             worker_queue_size: DEFAULT_WORKER_QUEUE_SIZE,
             distribution_f: default_distribution_function,
@@ -327,13 +628,17 @@ where
     ///
     /// Each time a handler is invoked, [`Dispatcher`] adds the following
     /// dependencies (in addition to those passed to
-    /// [`DispatcherBuilder::dependencies`]):
+    /// [`DispatcherBuilder::dependencies`] and returned by
+    /// [`DispatcherBuilder::dependencies_fn`]):
     ///
-    ///  - Your bot passed to [`Dispatcher::builder`];
+    ///  - Your bot passed to [`Dispatcher::builder`], or the bot which has
+    ///    received the update, if it was received by a [`MultiBotListener`]
+    ///    (see [`Dispatcher::dispatch_with_multi_bot_listener`]);
     ///  - An update from Telegram;
     ///  - [`crate::types::Me`] (can be used in [`HandlerExt::filter_command`]).
     ///
     /// [`HandlerExt::filter_command`]: crate::dispatching::HandlerExt::filter_command
+    /// [`MultiBotListener`]: crate::update_listeners::multi_bot::MultiBotListener
     pub async fn dispatch(&mut self)
     where
         R: Requester + Clone,
@@ -381,22 +686,82 @@ where
         Eh: ErrorHandler<UListener::Err> + 'a,
         UListener::Err: Debug,
     {
+        self.prepare_dispatching().await?;
+        update_listener.hint_allowed_updates(&mut self.allowed_updates().into_iter());
+
+        let stop_token = update_listener.stop_token();
+        let stream =
+            update_listener.as_stream().map(|res| res.map(|upd| (upd, DependencyMap::new())));
+        self.dispatch_stream(stream, stop_token, &update_listener_error_handler).await;
+
+        Ok(())
+    }
+
+    /// Starts your bot with a [`MultiBotListener`], which receives updates of
+    /// several bots, and `update_listener_error_handler`.
+    ///
+    /// Handlers are injected with the bot which has received the update and
+    /// its [`crate::types::Me`], instead of the ones of the bot passed to
+    /// [`Dispatcher::builder`]. Other dependencies are the same as the ones of
+    /// [`Dispatcher::dispatch`].
+    ///
+    /// ## Panics
+    ///
+    /// If the initial telegram api calls (`get_me` of every bot) fail.
+    pub async fn dispatch_with_multi_bot_listener<'a, LErr, Eh>(
+        &'a mut self,
+        mut update_listener: MultiBotListener<R, LErr>,
+        update_listener_error_handler: Arc<Eh>,
+    ) where
+        LErr: Send + 'static,
+        Eh: ErrorHandler<LErr> + 'a,
+        R::Err: Debug,
+    {
+        self.prepare_dispatching().await.expect("Couldn't prepare dispatching context");
+        update_listener.fetch_me().await.expect("Couldn't prepare dispatching context");
+        update_listener.hint_allowed_updates(&mut self.allowed_updates().into_iter());
+
+        let stop_token = update_listener.stop_token();
+        let stream = update_listener.as_stream();
+        self.dispatch_stream(stream, stop_token, &update_listener_error_handler).await;
+    }
+
+    async fn prepare_dispatching(&mut self) -> Result<(), R::Err> {
         // FIXME: there should be a way to check if dependency is already inserted
         let me = self.bot.get_me().send().await?;
         self.dependencies.insert(me);
         self.dependencies.insert(self.bot.clone());
 
-        let description = self.handler.description();
-        let allowed_updates = description.allowed_updates();
+        Ok(())
+    }
+
+    fn allowed_updates(&self) -> Vec<AllowedUpdate> {
+        let description = self.handlers.handler.description();
+        let mut allowed_updates = description.allowed_updates();
+        if self.handlers.edits_as_messages {
+            add_edits(&mut allowed_updates);
+        }
         log::debug!("hinting allowed updates: {:?}", allowed_updates);
-        update_listener.hint_allowed_updates(&mut allowed_updates.into_iter());
 
-        let mut stop_token = Some(update_listener.stop_token());
+        allowed_updates
+    }
+
+    /// Dispatches updates from `stream`, each with its own dependencies, until
+    /// it ends.
+    async fn dispatch_stream<S, LErr, LErrHandler>(
+        &mut self,
+        stream: S,
+        stop_token: StopToken,
+        err_handler: &Arc<LErrHandler>,
+    ) where
+        S: Stream<Item = Result<(Update, DependencyMap), LErr>>,
+        LErrHandler: ErrorHandler<LErr>,
+    {
+        let mut stop_token = Some(stop_token);
 
         self.state.start_dispatching();
 
         {
-            let stream = update_listener.as_stream();
             tokio::pin!(stream);
 
             loop {
@@ -409,7 +774,7 @@ where
 
                 match res {
                     Either::Left(upd) => match upd {
-                        Some(upd) => self.process_update(upd, &update_listener_error_handler).await,
+                        Some(upd) => self.process_update(upd, err_handler).await,
                         None => break,
                     },
                     Either::Right(()) => {
@@ -435,18 +800,19 @@ where
             .await;
 
         self.state.done();
-        Ok(())
     }
 
     async fn process_update<LErr, LErrHandler>(
         &mut self,
-        update: Result<Update, LErr>,
+        update: Result<(Update, DependencyMap), LErr>,
         err_handler: &Arc<LErrHandler>,
     ) where
         LErrHandler: ErrorHandler<LErr>,
     {
         match update {
-            Ok(upd) => {
+            Ok((upd, upd_deps)) => {
+                self.health.update_received();
+
                 if let UpdateKind::Error(err) = upd.kind {
                     log::error!(
                         "Cannot parse an update.\nError: {:?}\n\
@@ -460,15 +826,11 @@ where
                 let worker = match (self.distribution_f)(&upd) {
                     Some(key) => self.workers.entry(key).or_insert_with(|| {
                         let deps = self.dependencies.clone();
-                        let handler = Arc::clone(&self.handler);
-                        let default_handler = Arc::clone(&self.default_handler);
-                        let error_handler = Arc::clone(&self.error_handler);
+                        let handlers = Arc::clone(&self.handlers);
 
                         spawn_worker(
                             deps,
-                            handler,
-                            default_handler,
-                            error_handler,
+                            handlers,
                             Arc::clone(&self.current_number_of_active_workers),
                             Arc::clone(&self.max_number_of_active_workers),
                             self.worker_queue_size,
@@ -476,23 +838,18 @@ where
                     }),
                     None => self.default_worker.get_or_insert_with(|| {
                         let deps = self.dependencies.clone();
-                        let handler = Arc::clone(&self.handler);
-                        let default_handler = Arc::clone(&self.default_handler);
-                        let error_handler = Arc::clone(&self.error_handler);
+                        let handlers = Arc::clone(&self.handlers);
 
-                        spawn_default_worker(
-                            deps,
-                            handler,
-                            default_handler,
-                            error_handler,
-                            self.worker_queue_size,
-                        )
+                        spawn_default_worker(deps, handlers, self.worker_queue_size)
                     }),
                 };
 
-                worker.tx.send(upd).await.expect("TX is dead");
+                worker.tx.send((upd, upd_deps)).await.expect("TX is dead");
+            }
+            Err(err) => {
+                self.health.listener_error();
+                err_handler.clone().handle_error(err).await
             }
-            Err(err) => err_handler.clone().handle_error(err).await,
         }
     }
 
@@ -543,6 +900,90 @@ where
     pub fn shutdown_token(&self) -> ShutdownToken {
         self.state.clone()
     }
+
+    /// Returns an outline of the handler tree passed to
+    /// [`Dispatcher::builder`].
+    ///
+    /// This is useful for debugging, e.g. when a branch doesn't match updates
+    /// you expect it to. Print the outline with `{}` for a human-readable tree,
+    /// or use [`HandlerOutline::to_dot`] to get a graph in the Graphviz dot
+    /// format.
+    ///
+    /// ```no_run
+    /// use teloxide::{dispatching::UpdateFilterExt, prelude::*};
+    ///
+    /// # async fn f() {
+    /// let bot = Bot::from_env();
+    /// let handler = Update::filter_message().endpoint(|| async { respond(()) });
+    /// let dp = Dispatcher::builder(bot, handler).build();
+    ///
+    /// println!("{}", dp.describe());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn describe(&self) -> HandlerOutline {
+        self.handlers.handler.description().outline()
+    }
+}
+
+impl<R, Err, Key> Dispatcher<R, Err, Key>
+where
+    R: Requester + Clone + Send + Sync + 'static,
+    Err: Send + Sync + 'static,
+    Key: Hash + Eq + Clone + Send + 'static,
+{
+    /// Starts your bot with the default parameters in a separate task.
+    ///
+    /// The returned handle can be used to check the health of the dispatcher
+    /// and to stop it. See [`Dispatcher::dispatch`] and [`DispatcherHandle`].
+    ///
+    /// ## Panics
+    ///
+    /// If called outside of a tokio runtime.
+    pub fn spawn(self) -> DispatcherHandle<R, Err, Key>
+    where
+        <R as Requester>::GetUpdates: Send,
+    {
+        self.spawn_inner(|mut dp| async move {
+            dp.dispatch().await;
+            dp
+        })
+    }
+
+    /// Starts your bot with custom `update_listener` and
+    /// `update_listener_error_handler` in a separate task.
+    ///
+    /// See [`Dispatcher::dispatch_with_listener`] and [`DispatcherHandle`].
+    ///
+    /// ## Panics
+    ///
+    /// If called outside of a tokio runtime.
+    pub fn spawn_with_listener<UListener, Eh>(
+        self,
+        update_listener: UListener,
+        update_listener_error_handler: Arc<Eh>,
+    ) -> DispatcherHandle<R, Err, Key>
+    where
+        UListener: UpdateListener + Send + 'static,
+        Eh: ErrorHandler<UListener::Err> + Send + Sync + 'static,
+        UListener::Err: Debug + Send,
+    {
+        self.spawn_inner(|mut dp| async move {
+            dp.dispatch_with_listener(update_listener, update_listener_error_handler).await;
+            dp
+        })
+    }
+
+    fn spawn_inner<F, Fut>(self, f: F) -> DispatcherHandle<R, Err, Key>
+    where
+        F: FnOnce(Self) -> Fut,
+        Fut: Future<Output = Self> + Send + 'static,
+    {
+        let state = self.state.clone();
+        let health = Arc::clone(&self.health);
+
+        DispatcherHandle::new(tokio::spawn(f(self)), state, health)
+    }
 }
 
 impl<R, Err, Key> Dispatcher<R, Err, Key> {
@@ -570,9 +1011,7 @@ impl<R, Err, Key> Dispatcher<R, Err, Key> {
 
 fn spawn_worker<Err>(
     deps: DependencyMap,
-    handler: Arc<UpdateHandler<Err>>,
-    default_handler: DefaultHandler,
-    error_handler: Arc<dyn ErrorHandler<Err> + Send + Sync>,
+    handlers: Arc<Handlers<Err>>,
     current_number_of_active_workers: Arc<AtomicU32>,
     max_number_of_active_workers: Arc<AtomicU32>,
     queue_size: usize,
@@ -587,7 +1026,7 @@ where
     let deps = Arc::new(deps);
 
     let handle = tokio::spawn(async move {
-        while let Some(update) = rx.recv().await {
+        while let Some((update, upd_deps)) = rx.recv().await {
             is_waiting_local.store(false, Ordering::Relaxed);
             {
                 let current = current_number_of_active_workers.fetch_add(1, Ordering::Relaxed) + 1;
@@ -595,11 +1034,9 @@ where
             }
 
             let deps = Arc::clone(&deps);
-            let handler = Arc::clone(&handler);
-            let default_handler = Arc::clone(&default_handler);
-            let error_handler = Arc::clone(&error_handler);
+            let handlers = Arc::clone(&handlers);
 
-            handle_update(update, deps, handler, default_handler, error_handler).await;
+            handle_update(update, upd_deps, deps, handlers).await;
 
             current_number_of_active_workers.fetch_sub(1, Ordering::Relaxed);
             is_waiting_local.store(true, Ordering::Relaxed);
@@ -611,9 +1048,7 @@ where
 
 fn spawn_default_worker<Err>(
     deps: DependencyMap,
-    handler: Arc<UpdateHandler<Err>>,
-    default_handler: DefaultHandler,
-    error_handler: Arc<dyn ErrorHandler<Err> + Send + Sync>,
+    handlers: Arc<Handlers<Err>>,
     queue_size: usize,
 ) -> Worker
 where
@@ -623,36 +1058,135 @@ where
 
     let deps = Arc::new(deps);
 
-    let handle = tokio::spawn(ReceiverStream::new(rx).for_each_concurrent(None, move |update| {
-        let deps = Arc::clone(&deps);
-        let handler = Arc::clone(&handler);
-        let default_handler = Arc::clone(&default_handler);
-        let error_handler = Arc::clone(&error_handler);
+    let handle = tokio::spawn(ReceiverStream::new(rx).for_each_concurrent(
+        None,
+        move |(update, upd_deps)| {
+            let deps = Arc::clone(&deps);
+            let handlers = Arc::clone(&handlers);
 
-        handle_update(update, deps, handler, default_handler, error_handler)
-    }));
+            handle_update(update, upd_deps, deps, handlers)
+        },
+    ));
 
     Worker { tx, handle, is_waiting: Arc::new(AtomicBool::new(true)) }
 }
 
 async fn handle_update<Err>(
     update: Update,
+    update_deps: DependencyMap,
     deps: Arc<DependencyMap>,
-    handler: Arc<UpdateHandler<Err>>,
-    default_handler: DefaultHandler,
-    error_handler: Arc<dyn ErrorHandler<Err> + Send + Sync>,
+    handlers: Arc<Handlers<Err>>,
 ) where
     Err: Send + Sync + 'static,
 {
+    let mut update = update;
+    let update_id = update.id;
+    let chat_id = update.chat().map(|chat| chat.id);
     let mut deps = deps.deref().clone();
+    if handlers.edits_as_messages {
+        deps.insert(unedit(&mut update));
+    }
+    // E.g. the bot which has received the update, if it was received by a
+    // `MultiBotListener`
+    deps.insert_container(update_deps);
     deps.insert(update);
+    let branches = MatchedBranches::recorded();
+    deps.insert(branches.clone());
+    let flow = FlowState::default();
+    deps.insert(flow.clone());
+    // Share the same update as `Arc<Update>`, so handlers can get it without
+    // cloning
+    let update: Arc<Update> = deps.get();
+    deps.insert(Arc::clone(&update));
+
+    let handling = async {
+        // Called here, so that their panics are caught like the ones of handlers
+        if let Some((old, new)) = chat_migration(&update) {
+            for migrate_chat in &handlers.chat_migrations {
+                migrate_chat(old, new).await;
+            }
+        }
+        if let Some(dependencies_fn) = &handlers.dependencies_fn {
+            deps.insert_container(dependencies_fn(&update));
+        }
+
+        match handlers.handler.dispatch(deps).await {
+            ControlFlow::Break(Ok(())) if flow.is_stopped() => {
+                (handlers.default_handler)(Arc::clone(&update)).await;
+            }
+            ControlFlow::Break(Ok(())) => {}
+            ControlFlow::Break(Err(err)) => {
+                Arc::clone(&handlers.error_handler).handle_error(err).await
+            }
+            ControlFlow::Continue(_) if flow.is_passed_on() => {}
+            ControlFlow::Continue(deps) => {
+                let update = deps.get();
+                (handlers.default_handler)(update).await;
+            }
+        }
+    };
+
+    let handling = AssertUnwindSafe(handling).catch_unwind();
+    let res = match handlers.timeout {
+        Some(timeout) => match tokio::time::timeout(timeout, handling).await {
+            Ok(res) => res,
+            Err(_) => {
+                let branches: Vec<_> = branches.latest().iter().collect();
+                log::error!(
+                    "Handling of update {} (chat: {:?}, branches: {:?}) has timed out after {:?} \
+                     and was cancelled",
+                    update_id.0,
+                    chat_id,
+                    branches,
+                    timeout,
+                );
+                return;
+            }
+        },
+        None => handling.await,
+    };
+
+    if let Err(payload) = res {
+        let panic = HandlerPanic::new(update_id, payload);
+        Arc::clone(&handlers.panic_handler).handle_error(panic).await;
+    }
+}
+
+/// Turns an edited message or channel post into a new one.
+fn unedit(update: &mut Update) -> IsEdited {
+    let kind = match &update.kind {
+        UpdateKind::EditedMessage(_) | UpdateKind::EditedChannelPost(_) => {
+            mem::replace(&mut update.kind, UpdateKind::Error(serde_json::Value::Null))
+        }
+        _ => return IsEdited(false),
+    };
+
+    update.kind = match kind {
+        UpdateKind::EditedMessage(message) => UpdateKind::Message(message),
+        UpdateKind::EditedChannelPost(post) => UpdateKind::ChannelPost(post),
+        _ => unreachable!(),
+    };
+    IsEdited(true)
+}
+
+/// Returns the old and the new id of a group migrated to a supergroup.
+fn chat_migration(update: &Update) -> Option<(ChatId, ChatId)> {
+    let UpdateKind::Message(message) = &update.kind else { return None };
+
+    match *message.chat_migration()? {
+        ChatMigration::To { chat_id } => Some((message.chat.id, chat_id)),
+        ChatMigration::From { chat_id } => Some((chat_id, message.chat.id)),
+    }
+}
 
-    match handler.dispatch(deps).await {
-        ControlFlow::Break(Ok(())) => {}
-        ControlFlow::Break(Err(err)) => error_handler.clone().handle_error(err).await,
-        ControlFlow::Continue(deps) => {
-            let update = deps.get();
-            (default_handler)(update).await;
+/// Adds the edits of the allowed messages and channel posts.
+fn add_edits(allowed_updates: &mut Vec<AllowedUpdate>) {
+    for (new, edited) in [
+        (AllowedUpdate::Message, AllowedUpdate::EditedMessage),
+        (AllowedUpdate::ChannelPost, AllowedUpdate::EditedChannelPost),
+    ] {
+        if allowed_updates.contains(&new) && !allowed_updates.contains(&edited) {
+            allowed_updates.push(edited);
         }
     }
 }
@@ -670,6 +1204,7 @@ mod tests {
     use teloxide_core::Bot;
 
     use super::*;
+    use crate::test;
 
     #[tokio::test]
     async fn test_tokio_spawn() {
@@ -685,4 +1220,280 @@ mod tests {
         .await
         .unwrap();
     }
+
+    #[tokio::test]
+    async fn catches_panics() {
+        use std::sync::Mutex;
+
+        use crate::types::{ChatId, UserId};
+
+        #[derive(Debug)]
+        struct Panicked(HandlerPanic);
+
+        impl From<HandlerPanic> for Panicked {
+            fn from(panic: HandlerPanic) -> Self {
+                Self(panic)
+            }
+        }
+
+        let updates =
+            ["boom", "ok"].map(|text| Update::message_fixture(ChatId(1), UserId(1), text));
+
+        let handled = Arc::new(AtomicU32::new(0));
+        let errors = Arc::new(Mutex::new(Vec::new()));
+        let handler = dptree::endpoint(|upd: Update, handled: Arc<AtomicU32>| async move {
+            match upd.kind {
+                UpdateKind::Message(msg) if msg.text() == Some("boom") => panic!("boom"),
+                _ => handled.fetch_add(1, Ordering::Relaxed),
+            };
+            Ok(())
+        });
+
+        let errors_clone = Arc::clone(&errors);
+        let dispatcher = Dispatcher::builder(test::mock_bot(), handler)
+            .dependencies(dptree::deps![Arc::clone(&handled)])
+            .error_handler(Arc::new(move |Panicked(panic)| {
+                errors_clone.lock().unwrap().push(panic.message);
+                async {}
+            }))
+            .forward_panics()
+            .build();
+        test::dispatch(dispatcher, updates).await;
+
+        assert_eq!(handled.load(Ordering::Relaxed), 1);
+        assert_eq!(*errors.lock().unwrap(), ["boom"]);
+    }
+
+    #[tokio::test]
+    async fn shares_update() {
+        use crate::{
+            dispatching::UpdateFilterExt,
+            types::{ChatId, Message, UserId},
+        };
+
+        let updates = [Update::message_fixture(ChatId(1), UserId(1), "hi")];
+
+        let handled = Arc::new(AtomicU32::new(0));
+        let handler = dptree::entry()
+            .branch(
+                Update::filter_message()
+                    .filter(|_: Message| false)
+                    .endpoint(|| async { unreachable!() }),
+            )
+            .branch(dptree::endpoint(|upd: Arc<Update>, handled: Arc<AtomicU32>| async move {
+                assert_eq!(upd.chat().unwrap().id, ChatId(1));
+                handled.fetch_add(1, Ordering::Relaxed);
+                Ok::<_, ()>(())
+            }));
+
+        let dispatcher = Dispatcher::builder(test::mock_bot(), handler)
+            .dependencies(dptree::deps![Arc::clone(&handled)])
+            .build();
+        test::dispatch(dispatcher, updates).await;
+
+        assert_eq!(handled.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn computes_dependencies_per_update() {
+        use crate::types::{ChatId, UserId};
+
+        let updates = [1, 2].map(|id| Update::message_fixture(ChatId(id), UserId(1), "hi"));
+
+        let handled = Arc::new(AtomicU32::new(0));
+        let handler = dptree::endpoint(|chat_id: ChatId, handled: Arc<AtomicU32>| async move {
+            handled.fetch_add(chat_id.0 as u32, Ordering::Relaxed);
+            Ok::<_, Infallible>(())
+        });
+
+        let dispatcher = Dispatcher::builder(test::mock_bot(), handler)
+            .dependencies(dptree::deps![Arc::clone(&handled), ChatId(100)])
+            .dependencies_fn(|upd| dptree::deps![upd.chat().unwrap().id])
+            .build();
+        test::dispatch(dispatcher, updates).await;
+
+        assert_eq!(handled.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn handles_edits_as_messages() {
+        use std::sync::Mutex;
+
+        use crate::{
+            dispatching::UpdateFilterExt,
+            types::{ChatId, Message, UserId},
+        };
+
+        let new = Update::message_fixture(ChatId(1), UserId(1), "hi");
+        let mut edited = Update::message_fixture(ChatId(2), UserId(1), "hi");
+        if let UpdateKind::Message(message) = edited.kind {
+            edited.kind = UpdateKind::EditedMessage(message);
+        }
+        let updates = [new, edited];
+
+        type Handled = Arc<Mutex<Vec<(ChatId, bool)>>>;
+
+        let handled = Handled::default();
+        let handler = Update::filter_message().endpoint(
+            |msg: Message, IsEdited(edited): IsEdited, handled: Handled| async move {
+                handled.lock().unwrap().push((msg.chat.id, edited));
+                Ok::<_, Infallible>(())
+            },
+        );
+
+        let dispatcher = Dispatcher::builder(test::mock_bot(), handler)
+            .dependencies(dptree::deps![Arc::clone(&handled)])
+            .handle_edits_as_messages()
+            .build();
+        test::dispatch(dispatcher, updates).await;
+
+        let mut handled = handled.lock().unwrap().clone();
+        handled.sort();
+        assert_eq!(handled, [(ChatId(1), false), (ChatId(2), true)]);
+
+        let mut allowed_updates = vec![AllowedUpdate::Message];
+        add_edits(&mut allowed_updates);
+        assert_eq!(allowed_updates, [AllowedUpdate::Message, AllowedUpdate::EditedMessage]);
+    }
+
+    #[tokio::test]
+    async fn handles_flows() {
+        use std::sync::Mutex;
+
+        use crate::{
+            dispatching::{endpoint_flow, Flow, UpdateFilterExt},
+            types::{ChatId, Message, UserId},
+        };
+
+        let updates = ["pass", "stop", "handle"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, text)| Update::message_fixture(ChatId(i as i64), UserId(1), text));
+
+        type Handled = Arc<Mutex<Vec<(&'static str, String)>>>;
+
+        let handled = Handled::default();
+        let record = |handled: &Handled, by: &'static str, msg: &Message| {
+            handled.lock().unwrap().push((by, msg.text().unwrap().to_owned()));
+        };
+        let handler = Update::filter_message()
+            .branch(endpoint_flow(move |msg: Message, handled: Handled| async move {
+                record(&handled, "first", &msg);
+                Ok::<_, Infallible>(match msg.text() {
+                    Some("stop") => Flow::Stop,
+                    Some("handle") => Flow::Handled,
+                    _ => Flow::PassOn,
+                })
+            }))
+            .branch(dptree::filter(|msg: Message| msg.text() == Some("handle")).endpoint(
+                move |msg: Message, handled: Handled| async move {
+                    record(&handled, "second", &msg);
+                    Ok(())
+                },
+            ));
+
+        let default_handled = Arc::clone(&handled);
+        let dispatcher = Dispatcher::builder(test::mock_bot(), handler)
+            .dependencies(dptree::deps![Arc::clone(&handled)])
+            .default_handler(move |update| {
+                let default_handled = Arc::clone(&default_handled);
+                async move {
+                    if let UpdateKind::Message(msg) = &update.kind {
+                        record(&default_handled, "default", msg);
+                    }
+                }
+            })
+            .build();
+        test::dispatch(dispatcher, updates).await;
+
+        let mut handled = handled.lock().unwrap().clone();
+        handled.sort();
+        let expected =
+            [("default", "stop"), ("first", "handle"), ("first", "pass"), ("first", "stop")];
+        assert_eq!(handled, expected.map(|(by, text)| (by, text.to_owned())));
+    }
+
+    #[tokio::test]
+    async fn migrates_dialogues() {
+        use crate::dispatching::dialogue::InMemStorage;
+
+        let update = serde_json::from_str(
+            r#"{"update_id":1,"message":{"chat":{"id":-1,"title":"test","type":"group"},"date":1629404938,"from":{"first_name":"A","id":1,"is_bot":false},"message_id":16,"migrate_to_chat_id":-1001}}"#,
+        )
+        .unwrap();
+        let updates = [update];
+
+        let storage = InMemStorage::<u8>::new();
+        Arc::clone(&storage).update_dialogue(ChatId(-1), 1).await.unwrap();
+
+        let handler = dptree::endpoint(|| async { Ok::<_, Infallible>(()) });
+        let dispatcher = Dispatcher::builder(test::mock_bot(), handler)
+            .migrate_dialogues(Arc::clone(&storage))
+            .build();
+        test::dispatch(dispatcher, updates).await;
+
+        assert_eq!(Arc::clone(&storage).get_dialogue(ChatId(-1)).await.unwrap(), None);
+        assert_eq!(Arc::clone(&storage).get_dialogue(ChatId(-1001)).await.unwrap(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn keeps_order_within_key() {
+        use std::sync::Mutex;
+
+        use crate::{
+            dispatching::UpdateFilterExt,
+            types::{ChatId, Message, UserId},
+        };
+
+        // Different chats, but the same user
+        let updates = [(1, "slow"), (2, "fast")]
+            .map(|(chat, text)| Update::message_fixture(ChatId(chat), UserId(1), text));
+
+        let handled = Arc::new(Mutex::new(Vec::<String>::new()));
+        let handler = Update::filter_message().endpoint(
+            |msg: Message, handled: Arc<Mutex<Vec<String>>>| async move {
+                let text = msg.text().unwrap().to_owned();
+                if text == "slow" {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                }
+                handled.lock().unwrap().push(text);
+                Ok::<_, Infallible>(())
+            },
+        );
+
+        let dispatcher = Dispatcher::builder(test::mock_bot(), handler)
+            .dependencies(dptree::deps![Arc::clone(&handled)])
+            .distribution_function(|upd| upd.from().map(|user| user.id))
+            .build();
+        test::dispatch(dispatcher, updates).await;
+
+        assert_eq!(*handled.lock().unwrap(), ["slow", "fast"]);
+    }
+
+    #[tokio::test]
+    async fn cancels_stuck_handlers() {
+        use crate::types::{ChatId, UserId};
+
+        let updates =
+            ["stuck", "ok"].map(|text| Update::message_fixture(ChatId(1), UserId(1), text));
+
+        let handled = Arc::new(AtomicU32::new(0));
+        let handler = dptree::endpoint(|upd: Update, handled: Arc<AtomicU32>| async move {
+            if let UpdateKind::Message(msg) = upd.kind {
+                if msg.text() == Some("stuck") {
+                    future::pending::<()>().await;
+                }
+            }
+            handled.fetch_add(1, Ordering::Relaxed);
+            Ok::<_, Infallible>(())
+        });
+
+        let dispatcher = Dispatcher::builder(test::mock_bot(), handler)
+            .dependencies(dptree::deps![Arc::clone(&handled)])
+            .handler_timeout(Duration::from_millis(10))
+            .build();
+        test::dispatch(dispatcher, updates).await;
+
+        assert_eq!(handled.load(Ordering::Relaxed), 1);
+    }
 }
@@ -1,6 +1,11 @@
 #![allow(clippy::redundant_closure_call)]
 
-use dptree::{di::DependencyMap, Handler};
+use std::{ops::ControlFlow, sync::Arc};
+
+use dptree::{
+    di::{DependencyMap, DependencySupplier},
+    Handler, HandlerDescription,
+};
 
 use crate::{
     dispatching::DpHandlerDescription,
@@ -28,22 +33,52 @@ macro_rules! define_ext {
     };
 
     (@impl $for_ty:ty, $func:ident, $proj_fn:expr, $Allowed:ident) => {
+        #[track_caller]
         fn $func() -> Handler<'static, DependencyMap, Out, DpHandlerDescription> {
-            dptree::filter_map_with_description(DpHandlerDescription::of(AllowedUpdate::$Allowed), move |input: $for_ty| {
-                $proj_fn(input)
-            })
+            let description = DpHandlerDescription::of(AllowedUpdate::$Allowed)
+                .labelled(concat!(stringify!($for_ty), "::", stringify!($func)));
+            filter_map_ref::<$for_ty, _, _>(description, $proj_fn)
         }
     };
 
     (@impl $for_ty:ty, $func:ident, $proj_fn:expr) => {
+        #[track_caller]
         fn $func() -> Handler<'static, DependencyMap, Out, DpHandlerDescription> {
-            dptree::filter_map(move |input: $for_ty| {
-                $proj_fn(input)
-            })
+            let description = DpHandlerDescription::filter_map()
+                .labelled(concat!(stringify!($for_ty), "::", stringify!($func)));
+            filter_map_ref::<$for_ty, _, _>(description, $proj_fn)
         }
     };
 }
 
+/// The same as [`dptree::filter_map_with_description`], but the input is
+/// borrowed from the dependencies instead of being cloned, so that only the
+/// projected value is cloned.
+///
+/// This matters for big handler trees: every branch starting with e.g.
+/// `Update::filter_message()` would otherwise deep-clone the whole update.
+fn filter_map_ref<Input, Projected, Out>(
+    description: DpHandlerDescription,
+    proj: fn(&Input) -> Option<Projected>,
+) -> Handler<'static, DependencyMap, Out, DpHandlerDescription>
+where
+    Input: Send + Sync + 'static,
+    Projected: Send + Sync + 'static,
+    Out: Send + Sync + 'static,
+{
+    dptree::from_fn_with_description(description, move |deps: DependencyMap, cont| async move {
+        let input: Arc<Input> = deps.get();
+        let Some(projected) = proj(&input) else { return ControlFlow::Continue(deps) };
+
+        let mut intermediate = deps.clone();
+        intermediate.insert(projected);
+        match cont(intermediate).await {
+            ControlFlow::Continue(_) => ControlFlow::Continue(deps),
+            done => done,
+        }
+    })
+}
+
 mod private {
     use teloxide_core::types::{Message, Update};
 
@@ -61,7 +96,7 @@ macro_rules! define_message_ext {
             MessageFilterExt, Message =>
             $((
                 $func,
-                (|x| $fn_name(&x).map(ToOwned::to_owned)),
+                (|x: &Message| $fn_name(x).map(ToOwned::to_owned)),
                 concat!("Applies the [`", stringify!($fn_name), "`] filter.")
             ),)*
         }
@@ -103,6 +138,9 @@ define_message_ext! {
     (filter_channel_chat_created, Message::channel_chat_created),
     (filter_message_auto_delete_timer_changed, Message::message_auto_delete_timer_changed),
     (filter_pinned, Message::pinned_message),
+    (filter_shared_chat, Message::shared_chat),
+    (filter_shared_user, Message::shared_user),
+    (filter_shared_users, Message::shared_users),
     (filter_invoice, Message::invoice),
     (filter_successful_payment, Message::successful_payment),
     (filter_connected_website, Message::connected_website),
@@ -129,8 +167,8 @@ macro_rules! define_update_ext {
             UpdateFilterExt, Update =>
             $((
                 $func,
-                |update: Update| match update.kind {
-                    $kind(x) => Some(x),
+                |update: &Update| match &update.kind {
+                    $kind(x) => Some(x.clone()),
                     _ => None,
                 },
                 concat!("Filters out [`", stringify!($kind), "`] objects."),
@@ -0,0 +1,130 @@
+use std::{
+    ops::ControlFlow,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc,
+    },
+};
+
+use dptree::{
+    di::{DependencyMap, Injectable},
+    Handler, HandlerDescription,
+};
+
+use crate::dispatching::DpHandlerDescription;
+
+/// What should happen with an update after an [`endpoint_flow`] handler has
+/// processed it.
+///
+/// Usually the first matched endpoint handles an update and the rest of the
+/// branches are not tried. `Flow` lets an endpoint decide otherwise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Flow {
+    /// The update is handled, later branches are not tried.
+    ///
+    /// This is what endpoints returning `Ok(())` do.
+    Handled,
+
+    /// The update is handled, but later branches are tried too.
+    ///
+    /// If none of them matches, the update is still considered handled, i.e.
+    /// the default handler of [`Dispatcher`] is not called.
+    ///
+    /// [`Dispatcher`]: crate::dispatching::Dispatcher
+    PassOn,
+
+    /// The update is not handled: later branches are not tried and the update
+    /// is passed to the default handler of [`Dispatcher`].
+    ///
+    /// [`Dispatcher`]: crate::dispatching::Dispatcher
+    Stop,
+}
+
+/// Returns an endpoint which decides what happens with the update after it, by
+/// returning a [`Flow`].
+///
+/// Errors returned by `f` are handled as errors of ordinary endpoints.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide::{
+///     dispatching::{endpoint_flow, Flow},
+///     prelude::*,
+/// };
+///
+/// let handler = Update::filter_message()
+///     // Logs all messages, but lets the next branches handle them too
+///     .branch(endpoint_flow(|msg: Message| async move {
+///         log::info!("Message in {}", msg.chat.id);
+///         respond(Flow::PassOn)
+///     }))
+///     .branch(dptree::endpoint(|bot: Bot, msg: Message| async move {
+///         bot.send_message(msg.chat.id, "Hi!").await?;
+///         respond(())
+///     }));
+/// # let _: teloxide::dispatching::UpdateHandler<teloxide::RequestError> = handler;
+/// ```
+#[must_use]
+#[track_caller]
+pub fn endpoint_flow<F, Args, Err, Output>(
+    f: F,
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    F: Injectable<DependencyMap, Result<Flow, Err>, Args> + Send + Sync + 'static,
+    Result<(), Err>: Into<Output>,
+    Output: Send + Sync + 'static,
+{
+    let f = Arc::new(f);
+
+    dptree::from_fn_with_description(DpHandlerDescription::endpoint(), move |mut deps, _cont| {
+        let f = Arc::clone(&f);
+        async move {
+            let flow = match f.inject(&deps)().await {
+                Ok(flow) => flow,
+                Err(err) => return ControlFlow::Break(Err(err).into()),
+            };
+
+            // `DependencyMap` can only check for a dependency by removing it
+            if let Some(state) = deps.remove::<FlowState>() {
+                state.set(flow);
+                deps.insert(FlowState::clone(&state));
+            }
+            match flow {
+                Flow::Handled | Flow::Stop => ControlFlow::Break(Ok(()).into()),
+                Flow::PassOn => ControlFlow::Continue(deps),
+            }
+        }
+    })
+}
+
+/// The last non-[`Flow::Handled`] flow of an update, shared between
+/// [`endpoint_flow`]s and [`Dispatcher`].
+///
+/// [`Dispatcher`]: crate::dispatching::Dispatcher
+#[derive(Clone, Debug, Default)]
+pub(crate) struct FlowState(Arc<AtomicU8>);
+
+impl FlowState {
+    const PASSED_ON: u8 = 1;
+    const STOPPED: u8 = 2;
+
+    fn set(&self, flow: Flow) {
+        let state = match flow {
+            Flow::Handled => return,
+            Flow::PassOn => Self::PASSED_ON,
+            Flow::Stop => Self::STOPPED,
+        };
+        self.0.store(state, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if an endpoint has passed the update on.
+    pub(crate) fn is_passed_on(&self) -> bool {
+        self.0.load(Ordering::Relaxed) == Self::PASSED_ON
+    }
+
+    /// Returns `true` if an endpoint has stopped the dispatching.
+    pub(crate) fn is_stopped(&self) -> bool {
+        self.0.load(Ordering::Relaxed) == Self::STOPPED
+    }
+}
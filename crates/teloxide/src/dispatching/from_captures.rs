@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use regex::Captures;
+
+/// Types that can be extracted from regex [`Captures`].
+///
+/// This trait is used by [`HandlerExt::filter_regex`]. It's implemented for:
+///
+///  - `()`, which ignores the groups;
+///  - tuples of up to 6 elements implementing [`FromStr`], where the `n`-th
+///    element is parsed from the `n`-th capture group;
+///  - `Vec<Option<String>>`, which contains all the capture groups, including
+///    the whole match.
+///
+/// [`HandlerExt::filter_regex`]: crate::dispatching::HandlerExt::filter_regex
+pub trait FromCaptures: Sized {
+    /// Extracts `Self` from `captures`.
+    ///
+    /// Returns `None` if a capture group is missing or can't be parsed.
+    fn from_captures(captures: &Captures<'_>) -> Option<Self>;
+}
+
+impl FromCaptures for () {
+    fn from_captures(_: &Captures<'_>) -> Option<Self> {
+        Some(())
+    }
+}
+
+impl FromCaptures for Vec<Option<String>> {
+    fn from_captures(captures: &Captures<'_>) -> Option<Self> {
+        Some(captures.iter().map(|group| group.map(|m| m.as_str().to_owned())).collect())
+    }
+}
+
+macro_rules! impl_from_captures {
+    ($($T:ident = $i:literal),*) => {
+        impl<$($T),*> FromCaptures for ($($T,)*)
+        where
+            $($T: FromStr,)*
+        {
+            fn from_captures(captures: &Captures<'_>) -> Option<Self> {
+                Some(($(captures.get($i)?.as_str().parse::<$T>().ok()?,)*))
+            }
+        }
+    };
+}
+
+impl_from_captures!(A = 1);
+impl_from_captures!(A = 1, B = 2);
+impl_from_captures!(A = 1, B = 2, C = 3);
+impl_from_captures!(A = 1, B = 2, C = 3, D = 4);
+impl_from_captures!(A = 1, B = 2, C = 3, D = 4, E = 5);
+impl_from_captures!(A = 1, B = 2, C = 3, D = 4, E = 5, F = 6);
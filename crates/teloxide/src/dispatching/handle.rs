@@ -0,0 +1,228 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, SystemTime},
+};
+
+use futures::future::BoxFuture;
+use tokio::task::{JoinError, JoinHandle};
+
+use crate::{
+    dispatching::{Dispatcher, ShutdownToken},
+    error_handlers::ErrorHandler,
+};
+
+/// A handle of a [`Dispatcher`] running in a separate task.
+///
+/// The handle allows supervisors to check the health of the dispatching loop,
+/// and to stop it (e.g. in order to restart it).
+///
+/// Created by [`Dispatcher::spawn`] and [`Dispatcher::spawn_with_listener`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use teloxide::{dispatching::UpdateHandler, prelude::*};
+///
+/// # async fn run(handler: UpdateHandler<teloxide::RequestError>) {
+/// let bot = Bot::from_env();
+/// let handle = Dispatcher::builder(bot, handler).build().spawn();
+///
+/// tokio::time::sleep(Duration::from_secs(60)).await;
+///
+/// let stale = handle
+///     .last_update_at()
+///     .map_or(true, |at| at.elapsed().unwrap_or_default() > Duration::from_secs(60 * 60));
+/// if !handle.is_running() || stale {
+///     log::warn!("Dispatcher looks stuck, errors so far: {}", handle.handler_errors());
+///     let _dispatcher = handle.stop().await;
+/// }
+/// # }
+/// ```
+#[must_use = "dropping a handle doesn't stop the dispatcher, use `DispatcherHandle::stop`"]
+pub struct DispatcherHandle<R, Err, Key> {
+    join_handle: JoinHandle<Dispatcher<R, Err, Key>>,
+    state: ShutdownToken,
+    health: Arc<Health>,
+}
+
+impl<R, Err, Key> DispatcherHandle<R, Err, Key> {
+    pub(crate) fn new(
+        join_handle: JoinHandle<Dispatcher<R, Err, Key>>,
+        state: ShutdownToken,
+        health: Arc<Health>,
+    ) -> Self {
+        Self { join_handle, state, health }
+    }
+
+    /// Returns `true` if the dispatcher hasn't stopped yet.
+    ///
+    /// A dispatcher stops after it's shut down, after its update listener
+    /// ends, or if it panics.
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        !self.join_handle.is_finished()
+    }
+
+    /// Returns the time when the last update was received, if any.
+    #[must_use]
+    pub fn last_update_at(&self) -> Option<SystemTime> {
+        *self.health.last_update_at.lock().unwrap()
+    }
+
+    /// Returns the number of updates received so far.
+    #[must_use]
+    pub fn updates_received(&self) -> u64 {
+        self.health.updates_received.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of errors returned from handlers so far.
+    #[must_use]
+    pub fn handler_errors(&self) -> u64 {
+        self.health.handler_errors.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of errors returned from the update listener so far.
+    #[must_use]
+    pub fn listener_errors(&self) -> u64 {
+        self.health.listener_errors.load(Ordering::Relaxed)
+    }
+
+    /// Returns a shutdown token of the dispatcher.
+    pub fn shutdown_token(&self) -> ShutdownToken {
+        self.state.clone()
+    }
+
+    /// Shuts the dispatcher down and waits for it to stop.
+    ///
+    /// Returns the dispatcher back, so that it can be started again, or an
+    /// error if the dispatching task has panicked.
+    pub async fn stop(self) -> Result<Dispatcher<R, Err, Key>, JoinError> {
+        loop {
+            match self.state.shutdown() {
+                Ok(shutdown) => {
+                    shutdown.await;
+                    break;
+                }
+                Err(_) if self.join_handle.is_finished() => break,
+                // The dispatcher hasn't started dispatching yet (e.g. it's
+                // still calling `get_me`), so it can't be shut down right now
+                Err(_) => tokio::time::sleep(Duration::from_millis(10)).await,
+            }
+        }
+
+        self.join_handle.await
+    }
+
+    /// Waits for the dispatcher to stop, without shutting it down.
+    ///
+    /// See [`DispatcherHandle::stop`].
+    pub async fn join(self) -> Result<Dispatcher<R, Err, Key>, JoinError> {
+        self.join_handle.await
+    }
+}
+
+/// Health statistics of a [`Dispatcher`].
+#[derive(Default)]
+pub(crate) struct Health {
+    last_update_at: Mutex<Option<SystemTime>>,
+    updates_received: AtomicU64,
+    handler_errors: AtomicU64,
+    listener_errors: AtomicU64,
+}
+
+impl Health {
+    pub(crate) fn update_received(&self) {
+        self.updates_received.fetch_add(1, Ordering::Relaxed);
+        *self.last_update_at.lock().unwrap() = Some(SystemTime::now());
+    }
+
+    pub(crate) fn listener_error(&self) {
+        self.listener_errors.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// An error handler which counts errors before passing them to another one.
+pub(crate) struct CountingErrorHandler<Err> {
+    pub(crate) inner: Arc<dyn ErrorHandler<Err> + Send + Sync>,
+    pub(crate) health: Arc<Health>,
+}
+
+impl<Err> ErrorHandler<Err> for CountingErrorHandler<Err> {
+    fn handle_error(self: Arc<Self>, error: Err) -> BoxFuture<'static, ()> {
+        self.health.handler_errors.fetch_add(1, Ordering::Relaxed);
+        Arc::clone(&self.inner).handle_error(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use futures::{stream, StreamExt};
+
+    use crate::{
+        error_handlers::LoggingErrorHandler,
+        stop::{mk_stop_token, StopFlag, StopToken},
+        test,
+        types::{ChatId, Update, UpdateKind, UserId},
+        update_listeners::StatefulListener,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reports_health() {
+        let updates =
+            ["ok", "fail"].map(|text| Update::message_fixture(ChatId(1), UserId(1), text));
+        let handler = dptree::endpoint(|update: Update| async move {
+            match update.kind {
+                UpdateKind::Message(msg) if msg.text() == Some("fail") => Err(()),
+                _ => Ok(()),
+            }
+        });
+
+        let handle = Dispatcher::builder(test::mock_bot(), handler)
+            .error_handler(Arc::new(|()| async {}))
+            .build()
+            .spawn_with_listener(test::update_listener(updates), LoggingErrorHandler::new());
+
+        while handle.is_running() {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+
+        assert_eq!(handle.updates_received(), 2);
+        assert_eq!(handle.handler_errors(), 1);
+        assert_eq!(handle.listener_errors(), 0);
+        assert!(handle.last_update_at().is_some());
+        handle.stop().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn stops_before_dispatching() {
+        // A listener which yields nothing until it's stopped
+        let (token, flag) = mk_stop_token();
+        let listener = StatefulListener::new(
+            (token, Some(flag)),
+            |(_, flag): &mut (StopToken, Option<StopFlag>)| {
+                stream::pending::<Result<Update, Infallible>>().take_until(flag.take().unwrap())
+            },
+            |(token, _): &mut (StopToken, _)| token.clone(),
+        );
+
+        let handle =
+            Dispatcher::builder(test::mock_bot(), dptree::endpoint(|| async { Ok::<_, ()>(()) }))
+                .build()
+                .spawn_with_listener(listener, LoggingErrorHandler::new());
+
+        // The dispatching task hasn't even started yet
+        tokio::time::timeout(Duration::from_secs(5), handle.stop())
+            .await
+            .expect("the dispatcher must stop")
+            .unwrap();
+    }
+}
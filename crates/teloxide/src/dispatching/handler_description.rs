@@ -1,4 +1,9 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    fmt::{self, Write},
+    panic::Location,
+    sync::Arc,
+};
 
 use dptree::{
     description::{EventKind, InterestSet},
@@ -9,37 +14,350 @@ use teloxide_core::types::AllowedUpdate;
 /// Handler description that is used by [`Dispatcher`].
 ///
 /// [`Dispatcher`]: crate::dispatching::Dispatcher
+#[derive(Clone)]
 pub struct DpHandlerDescription {
     allowed: InterestSet<Kind>,
+    outline: Outline,
 }
 
 impl DpHandlerDescription {
+    #[track_caller]
     pub(crate) fn of(allowed: AllowedUpdate) -> Self {
         let mut set = HashSet::with_capacity(1);
         set.insert(Kind(allowed));
-        Self { allowed: InterestSet::new_filter(set) }
+        Self { allowed: InterestSet::new_filter(set), outline: Outline::node("filter_map") }
+    }
+
+    /// Replaces the label under which this handler is shown in its outline.
+    pub(crate) fn labelled(self, label: &'static str) -> Self {
+        Self { outline: self.outline.labelled(label, None), ..self }
+    }
+
+    /// Same as [`DpHandlerDescription::labelled`], but also shows `arg` next
+    /// to the label.
+    pub(crate) fn labelled_with(self, label: &'static str, arg: &'static str) -> Self {
+        Self { outline: self.outline.labelled(label, Some(arg)), ..self }
     }
 
     pub(crate) fn allowed_updates(&self) -> Vec<AllowedUpdate> {
         self.allowed.observed.iter().map(|&Kind(x)| x).collect()
     }
+
+    /// Returns the outline of the handler tree this description belongs to.
+    ///
+    /// See [`HandlerOutline`] for more information.
+    #[must_use]
+    pub fn outline(&self) -> HandlerOutline {
+        HandlerOutline { root: self.outline.clone() }
+    }
+
+    #[track_caller]
+    fn user_defined_as(label: &'static str) -> Self {
+        Self { allowed: HandlerDescription::user_defined(), outline: Outline::node(label) }
+    }
 }
 
 impl HandlerDescription for DpHandlerDescription {
+    #[track_caller]
     fn entry() -> Self {
-        Self { allowed: HandlerDescription::entry() }
+        Self { allowed: HandlerDescription::entry(), outline: Outline::node("entry") }
     }
 
+    #[track_caller]
     fn user_defined() -> Self {
-        Self { allowed: HandlerDescription::user_defined() }
+        Self::user_defined_as("handler")
+    }
+
+    fn merge_chain(&self, other: &Self) -> Self {
+        Self {
+            allowed: self.allowed.merge_chain(&other.allowed),
+            outline: self.outline.merge_chain(&other.outline),
+        }
+    }
+
+    fn merge_branch(&self, other: &Self) -> Self {
+        Self {
+            allowed: self.allowed.merge_branch(&other.allowed),
+            outline: self.outline.merge_branch(&other.outline),
+        }
+    }
+
+    #[track_caller]
+    fn map() -> Self {
+        Self::user_defined_as("map")
+    }
+
+    #[track_caller]
+    fn map_async() -> Self {
+        Self::user_defined_as("map_async")
+    }
+
+    #[track_caller]
+    fn filter() -> Self {
+        Self::user_defined_as("filter")
+    }
+
+    #[track_caller]
+    fn filter_async() -> Self {
+        Self::user_defined_as("filter_async")
+    }
+
+    #[track_caller]
+    fn filter_map() -> Self {
+        Self::user_defined_as("filter_map")
+    }
+
+    #[track_caller]
+    fn filter_map_async() -> Self {
+        Self::user_defined_as("filter_map_async")
+    }
+
+    #[track_caller]
+    fn inspect() -> Self {
+        Self::user_defined_as("inspect")
+    }
+
+    #[track_caller]
+    fn inspect_async() -> Self {
+        Self::user_defined_as("inspect_async")
+    }
+
+    #[track_caller]
+    fn endpoint() -> Self {
+        Self::user_defined_as("endpoint")
+    }
+}
+
+/// An outline of a handler tree, for debugging purposes.
+///
+/// Handlers are opaque functions, so they can't be inspected after they were
+/// built. Instead, every handler constructed by `dptree` (a filter, a map, an
+/// endpoint, ...) or by `teloxide` (e.g. [`Update::filter_message`],
+/// [`HandlerExt::filter_command`], [`HandlerExt::enter_dialogue`]) remembers
+/// its kind and the place in the source code where it was constructed. This
+/// structure combines them into a tree, which is useful to find out why a
+/// branch doesn't match an update: chained handlers are listed one after
+/// another, while branches are nested under the handler they are attached to.
+///
+/// Dialogue states matched with `dptree::case!` show up as `filter` or
+/// `filter_map` pointing to the `case!` invocation.
+///
+/// Use the [`Display`] implementation to get a human-readable outline, and
+/// [`HandlerOutline::to_dot`] to get a graph in the [Graphviz] dot format:
+///
+/// ```text
+/// entry (src/main.rs:12:19)
+/// ├─ Update::filter_message (src/main.rs:13:17)
+/// │  filter_command (src/main.rs:13:43)
+/// │  ├─ filter_map (src/main.rs:14:25)
+/// │  │  endpoint (src/main.rs:14:56)
+/// │  └─ endpoint (src/main.rs:15:25)
+/// └─ Update::filter_callback_query (src/main.rs:17:17)
+///    endpoint (src/main.rs:17:50)
+/// ```
+///
+/// See also [`Dispatcher::describe`] and [`DpHandlerDescription::outline`].
+///
+/// [`Display`]: std::fmt::Display
+/// [`Update::filter_message`]: crate::dispatching::UpdateFilterExt::filter_message
+/// [`HandlerExt::filter_command`]: crate::dispatching::HandlerExt::filter_command
+/// [`HandlerExt::enter_dialogue`]: crate::dispatching::HandlerExt::enter_dialogue
+/// [`Dispatcher::describe`]: crate::dispatching::Dispatcher::describe
+/// [Graphviz]: https://graphviz.org
+#[derive(Clone, Debug)]
+pub struct HandlerOutline {
+    root: Outline,
+}
+
+impl HandlerOutline {
+    /// Returns the outline as a graph in the [Graphviz] dot format.
+    ///
+    /// Endpoints are drawn as boxes, edges from a handler to its branches are
+    /// labelled with the order in which the branches are tried.
+    ///
+    /// [Graphviz]: https://graphviz.org
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = Dot::default();
+        self.root.dot(&mut dot);
+
+        let escape = |s: String| s.replace('\\', "\\\\").replace('"', "\\\"");
+
+        let mut out = String::from("digraph handler {\n");
+        for (id, node) in dot.nodes.iter().enumerate() {
+            let shape = if node.label == "endpoint" { "box" } else { "ellipse" };
+            let label = escape(DisplayLabel(node).to_string());
+            let location = escape(node.location.to_string());
+            let _ = writeln!(out, "    n{id} [label=\"{label}\\n{location}\", shape={shape}];");
+        }
+        for (from, to, label) in dot.edges {
+            let _ = match label {
+                Some(label) => writeln!(out, "    n{from} -> n{to} [label=\"{label}\"];"),
+                None => writeln!(out, "    n{from} -> n{to};"),
+            };
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+impl fmt::Display for HandlerOutline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut lines = Vec::new();
+        self.root.lines(&mut lines);
+        for line in lines {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Outline {
+    Node(Node),
+    Chain(Arc<[Outline]>),
+    /// The first element is the handler the branches are attached to.
+    Branch(Arc<[Outline]>),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    label: &'static str,
+    arg: Option<&'static str>,
+    location: &'static Location<'static>,
+}
+
+impl Outline {
+    #[track_caller]
+    fn node(label: &'static str) -> Self {
+        Self::Node(Node { label, arg: None, location: Location::caller() })
+    }
+
+    fn labelled(self, label: &'static str, arg: Option<&'static str>) -> Self {
+        match self {
+            Self::Node(node) => Self::Node(Node { label, arg, ..node }),
+            other => other,
+        }
+    }
+
+    fn is_entry(&self) -> bool {
+        matches!(self, Self::Node(Node { label: "entry", .. }))
     }
 
     fn merge_chain(&self, other: &Self) -> Self {
-        Self { allowed: self.allowed.merge_chain(&other.allowed) }
+        // `entry` is a no-op in a chain, so it would only clutter the outline
+        if self.is_entry() {
+            return other.clone();
+        }
+        if other.is_entry() {
+            return self.clone();
+        }
+
+        let mut items = Vec::new();
+        for outline in [self, other] {
+            match outline {
+                Self::Chain(chain) => items.extend(chain.iter().cloned()),
+                outline => items.push(outline.clone()),
+            }
+        }
+        Self::Chain(items.into())
     }
 
     fn merge_branch(&self, other: &Self) -> Self {
-        Self { allowed: self.allowed.merge_branch(&other.allowed) }
+        let mut items = match self {
+            Self::Branch(items) => items.to_vec(),
+            head => vec![head.clone()],
+        };
+        items.push(other.clone());
+        Self::Branch(items.into())
+    }
+
+    fn lines(&self, out: &mut Vec<String>) {
+        match self {
+            Self::Node(node) => out.push(format!("{} ({})", DisplayLabel(node), node.location)),
+            Self::Chain(items) => items.iter().for_each(|item| item.lines(out)),
+            Self::Branch(items) => {
+                let (head, branches) = items.split_first().expect("branch always has a head");
+                head.lines(out);
+
+                for (i, branch) in branches.iter().enumerate() {
+                    let last = i + 1 == branches.len();
+                    let mut branch_lines = Vec::new();
+                    branch.lines(&mut branch_lines);
+
+                    for (j, line) in branch_lines.into_iter().enumerate() {
+                        let prefix = match (j, last) {
+                            (0, false) => "├─ ",
+                            (0, true) => "└─ ",
+                            (_, false) => "│  ",
+                            (_, true) => "   ",
+                        };
+                        out.push(format!("{prefix}{line}"));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Adds nodes and edges of this outline to `dot`, returning the ids of
+    /// its first and last nodes.
+    fn dot(&self, dot: &mut Dot) -> (Vec<usize>, Vec<usize>) {
+        match self {
+            Self::Node(node) => {
+                dot.nodes.push(*node);
+                let id = dot.nodes.len() - 1;
+                (vec![id], vec![id])
+            }
+            Self::Chain(items) => {
+                let mut iter = items.iter();
+                let first = iter.next().expect("chain is never empty");
+                let (entries, mut exits) = first.dot(dot);
+
+                for item in iter {
+                    let (next_entries, next_exits) = item.dot(dot);
+                    dot.connect(&exits, &next_entries, None);
+                    exits = next_exits;
+                }
+                (entries, exits)
+            }
+            Self::Branch(items) => {
+                let (head, branches) = items.split_first().expect("branch always has a head");
+                let (entries, exits) = head.dot(dot);
+
+                for (i, branch) in branches.iter().enumerate() {
+                    let (branch_entries, _) = branch.dot(dot);
+                    dot.connect(&exits, &branch_entries, Some(i + 1));
+                }
+                (entries, exits)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Dot {
+    nodes: Vec<Node>,
+    edges: Vec<(usize, usize, Option<usize>)>,
+}
+
+impl Dot {
+    fn connect(&mut self, from: &[usize], to: &[usize], label: Option<usize>) {
+        for &from in from {
+            for &to in to {
+                self.edges.push((from, to, label));
+            }
+        }
+    }
+}
+
+struct DisplayLabel<'a>(&'a Node);
+
+impl fmt::Display for DisplayLabel<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.arg {
+            Some(arg) => write!(f, "{}({arg:?})", self.0.label),
+            None => f.write_str(self.0.label),
+        }
     }
 }
 
@@ -122,6 +440,59 @@ mod tests {
         assert_eq!(v, [Message, MyChatMember])
     }
 
+    #[test]
+    fn outline() {
+        use dptree::{di::DependencyMap, Handler};
+
+        use crate::{
+            dispatching::{DpHandlerDescription, HandlerExt, MessageFilterExt, UpdateFilterExt},
+            types::{Message, Update},
+        };
+
+        let h: Handler<'static, DependencyMap, (), DpHandlerDescription> = dptree::entry()
+            .branch(
+                Update::filter_message()
+                    .filter(|| true)
+                    .branch(Message::filter_text().endpoint(|| async {}))
+                    .branch(dptree::endpoint(|| async {})),
+            )
+            .branch(Update::filter_callback_query().named("callback").endpoint(|| async {}));
+
+        let outline = h.description().outline();
+        let text = outline.to_string();
+
+        // Locations are on the right, strip them to not depend on line numbers
+        let labels: Vec<_> = text
+            .lines()
+            .map(|line| {
+                let (label, location) = line.rsplit_once(" (").unwrap();
+                assert!(location.contains("handler_description.rs"), "{location}");
+                label
+            })
+            .collect();
+
+        assert_eq!(
+            labels,
+            [
+                "entry",
+                "├─ Update::filter_message",
+                "│  filter",
+                "│  ├─ Message::filter_text",
+                "│  │  endpoint",
+                "│  └─ endpoint",
+                "└─ Update::filter_callback_query",
+                "   named(\"callback\")",
+                "   endpoint",
+            ]
+        );
+
+        let dot = outline.to_dot();
+        assert!(dot.starts_with("digraph handler {\n"));
+        assert!(dot.contains("    n0 -> n1 [label=\"1\"];\n"));
+        assert!(dot.contains("label=\"named(\\\"callback\\\")\\n"));
+        assert_eq!(dot.matches("shape=box").count(), 3);
+    }
+
     #[test]
     #[ignore = "this test requires `macros` feature"]
     #[cfg(not(feature = "macros"))]
@@ -1,19 +1,38 @@
 use crate::{
     dispatching::{
+        access, captcha,
         dialogue::{GetChatId, Storage},
-        DpHandlerDescription,
+        join_flood,
+        roles::{self, Roles},
+        DpHandlerDescription, FromCaptures, MatchedBranches,
+    },
+    requests::Requester,
+    types::{ChosenInlineResult, Me, Message, PollAnswer, PreCheckoutQuery, ShippingQuery, Update},
+    utils::{
+        admin_cache::AdminCache,
+        antispam::{AntiSpam, Verdict},
+        command::BotCommands,
+        deep_link, i18n,
+        inline_results::InlineResultTracker,
+        polls::PollTracker,
     },
-    types::{Me, Message},
-    utils::command::BotCommands,
 };
-use dptree::{di::DependencyMap, Handler};
+use dptree::{di::DependencyMap, Handler, HandlerDescription};
+use regex::Regex;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, str::FromStr, sync::Arc};
 
 /// Extension methods for working with `dptree` handlers.
 pub trait HandlerExt<Output> {
     /// Returns a handler that accepts a parsed command `C`.
     ///
+    /// The text of a message is parsed with [`BotCommands::parse`], using the
+    /// username of the bot, so commands addressed to other bots (e.g.
+    /// `/help@other_bot`) are not accepted. The parsed command is passed
+    /// further as a dependency, while messages that aren't commands `C` fall
+    /// through to the next branches. This makes each command (or a group of
+    /// commands) a separate branch of the dispatching tree.
+    ///
     /// ## Dependency requirements
     ///
     ///  - [`crate::types::Message`]
@@ -23,6 +42,256 @@ pub trait HandlerExt<Output> {
     where
         C: BotCommands + Send + Sync + 'static;
 
+    /// Returns a handler that accepts a parsed command `C`, sent either as the
+    /// text of a message or as the caption of a media message (e.g. `/tag cute`
+    /// attached to a photo).
+    ///
+    /// Otherwise, this works just like [`HandlerExt::filter_command`].
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::Message`]
+    ///  - [`crate::types::Me`]
+    #[must_use]
+    fn filter_command_in_caption<C>(self) -> Self
+    where
+        C: BotCommands + Send + Sync + 'static;
+
+    /// Returns a handler that accepts a payload `T` of a `/start` command,
+    /// i.e. of a [deep link].
+    ///
+    /// Messages without a payload, or with a payload that can't be parsed as
+    /// `T`, are not accepted.
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::Message`]
+    ///  - [`crate::types::Me`]
+    ///
+    /// [deep link]: crate::utils::deep_link
+    #[must_use]
+    fn filter_start_payload<T>(self) -> Self
+    where
+        T: FromStr + Send + Sync + 'static;
+
+    /// Returns a handler that accepts messages with text matching `pattern`,
+    /// passing the capture groups forwards as `T`.
+    ///
+    /// The pattern is compiled once, when the handler is created. Messages
+    /// for which the capture groups can't be extracted as `T` (see
+    /// [`FromCaptures`]) are not accepted.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide::{dispatching::HandlerExt, prelude::*};
+    ///
+    /// let handler = Update::filter_message().filter_regex::<(u32,)>(r"^set (\d+)$").endpoint(
+    ///     |bot: Bot, msg: Message, (value,): (u32,)| async move {
+    ///         bot.send_message(msg.chat.id, format!("Set to {value}")).await?;
+    ///         respond(())
+    ///     },
+    /// );
+    /// # let _: teloxide::dispatching::UpdateHandler<teloxide::RequestError> = handler;
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// If `pattern` is not a valid regex.
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::Message`]
+    #[must_use]
+    fn filter_regex<T>(self, pattern: &str) -> Self
+    where
+        T: FromCaptures + Send + Sync + 'static;
+
+    /// Returns a handler that accepts updates from users whose
+    /// [language code] is one of `languages`.
+    ///
+    /// A language without a region (e.g. `pt`) also accepts all of its
+    /// regions (e.g. `pt-BR`). Updates without a user or without a language
+    /// code are not accepted.
+    ///
+    /// See also [`utils::i18n`](crate::utils::i18n).
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::Update`]
+    ///
+    /// [language code]: crate::types::User::language_code
+    #[must_use]
+    fn filter_language(self, languages: &[&str]) -> Self;
+
+    /// Returns a handler that accepts a [`ShippingQuery`] together with its
+    /// invoice payload parsed as `T`.
+    ///
+    /// Queries with a payload that can't be parsed as `T` are not accepted.
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::ShippingQuery`]
+    #[must_use]
+    fn filter_shipping_query_payload<T>(self) -> Self
+    where
+        T: FromStr + Send + Sync + 'static;
+
+    /// Returns a handler that accepts a [`PreCheckoutQuery`] together with its
+    /// invoice payload parsed as `T`.
+    ///
+    /// Queries with a payload that can't be parsed as `T` are not accepted.
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::PreCheckoutQuery`]
+    #[must_use]
+    fn filter_pre_checkout_query_payload<T>(self) -> Self
+    where
+        T: FromStr + Send + Sync + 'static;
+
+    /// Returns a handler that accepts data `T` of a [`ChosenInlineResult`],
+    /// tracked by an [`InlineResultTracker`].
+    ///
+    /// Results which weren't tracked are not accepted.
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::ChosenInlineResult`]
+    ///  - `Arc<InlineResultTracker<T>>`
+    #[must_use]
+    fn filter_tracked_inline_result<T>(self) -> Self
+    where
+        T: Clone + Send + Sync + 'static;
+
+    /// Returns a handler that accepts answers to polls tracked by a
+    /// [`PollTracker`], passing the [`PollMessage`] of the poll forwards.
+    ///
+    /// Answers to polls which weren't tracked are not accepted.
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::PollAnswer`]
+    ///  - `Arc<PollTracker>`
+    ///
+    /// [`PollMessage`]: crate::utils::polls::PollMessage
+    #[must_use]
+    fn filter_tracked_poll_answer(self) -> Self;
+
+    /// Returns a handler that accepts updates from administrators of a chat,
+    /// passing their [`ChatMember`] record forwards.
+    ///
+    /// Administrators are fetched with the bot `R` and cached in an
+    /// [`AdminCache`]. Updates without a chat or a user, updates from private
+    /// chats, and updates for which administrators couldn't be fetched are not
+    /// accepted. Messages of anonymous administrators are not accepted either,
+    /// see [`AdminCache::get`].
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::Update`]
+    ///  - `R`
+    ///  - `Arc<AdminCache>`
+    ///
+    /// [`ChatMember`]: crate::types::ChatMember
+    #[must_use]
+    fn filter_from_admin<R>(self) -> Self
+    where
+        R: Requester + Clone + Send + Sync + 'static,
+        R::Err: Debug + Send;
+
+    /// Returns a handler that drops spam messages, deleting them and muting
+    /// their senders with the bot `R` according to an [`AntiSpam`].
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::Message`]
+    ///  - `R`
+    ///  - `Arc<AntiSpam>`
+    ///
+    /// See also [`antispam`](crate::utils::antispam).
+    #[must_use]
+    fn filter_spam<R>(self) -> Self
+    where
+        R: Requester + Clone + Send + Sync + 'static,
+        R::Err: Debug + Send;
+
+    /// Returns a handler that drops updates which are not allowed by an
+    /// [`AccessList`], possibly leaving disallowed chats with the bot `R`.
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::Update`]
+    ///  - `R`
+    ///  - `Arc<AccessList>`
+    ///
+    /// See also [`access`](crate::dispatching::access).
+    ///
+    /// [`AccessList`]: crate::dispatching::access::AccessList
+    #[must_use]
+    fn filter_access<R>(self) -> Self
+    where
+        R: Requester + Clone + Send + Sync + 'static,
+        R::Err: Debug + Send;
+
+    /// Returns a handler that makes the bot `R` leave groups it's added to too
+    /// often, according to a [`JoinFloodGuard`].
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::Update`]
+    ///  - `R`
+    ///  - `Arc<JoinFloodGuard>`
+    ///
+    /// See also [`join_flood`](crate::dispatching::join_flood).
+    ///
+    /// [`JoinFloodGuard`]: crate::dispatching::join_flood::JoinFloodGuard
+    #[must_use]
+    fn filter_join_flood<R>(self) -> Self
+    where
+        R: Requester + Clone + Send + Sync + 'static,
+        R::Err: Debug + Send;
+
+    /// Returns a handler that verifies new group members according to
+    /// [`Captcha`] settings, with the bot `R`, keeping pending verifications in
+    /// the storage `S`.
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::Update`]
+    ///  - `R`
+    ///  - `Arc<Captcha>`
+    ///  - `Arc<S>`
+    ///
+    /// See also [`captcha`](crate::dispatching::captcha).
+    ///
+    /// [`Captcha`]: crate::dispatching::captcha::Captcha
+    #[must_use]
+    fn verify_new_members<R, S>(self) -> Self
+    where
+        R: Requester + Clone + Send + Sync + 'static,
+        R::Err: Debug + Send,
+        S: Storage<captcha::CaptchaState> + ?Sized + Send + Sync + 'static,
+        <S as Storage<captcha::CaptchaState>>::Error: Debug + Send;
+
+    /// Returns a handler that accepts updates from users with `role` in the
+    /// role store `S`.
+    ///
+    /// Updates without a user, and updates for which roles couldn't be
+    /// fetched, are not accepted.
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::Update`]
+    ///  - `Arc<S>`
+    ///
+    /// See also [`roles`](crate::dispatching::roles).
+    #[must_use]
+    fn require_role<S>(self, role: impl Into<String>) -> Self
+    where
+        S: Roles + ?Sized + Send + Sync + 'static,
+        S::Error: Debug + Send;
+
     /// Passes [`Dialogue<D, S>`] and `D` as handler dependencies.
     ///
     /// It does so by the following steps:
@@ -48,12 +317,47 @@ pub trait HandlerExt<Output> {
         <S as Storage<D>>::Error: Debug + Send,
         D: Default + Send + Sync + 'static,
         Upd: GetChatId + Clone + Send + Sync + 'static;
+
+    /// Names the branch built so far, adding `name` to [`MatchedBranches`]
+    /// for the updates which pass through it.
+    ///
+    /// Names are `&'static str`, so recording them doesn't require formatting
+    /// strings.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use teloxide::{
+    ///     dispatching::{HandlerExt, MatchedBranches},
+    ///     prelude::*,
+    /// };
+    ///
+    /// let handler = Update::filter_message().named("messages").branch(
+    ///     dptree::filter(|msg: Message| msg.text().is_some()).named("text").endpoint(
+    ///         |branches: MatchedBranches| async move {
+    ///             // Prints `messages/text`
+    ///             println!("{}", branches.iter().collect::<Vec<_>>().join("/"));
+    ///             respond(())
+    ///         },
+    ///     ),
+    /// );
+    /// # let _: teloxide::dispatching::UpdateHandler<teloxide::RequestError> = handler;
+    /// ```
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`MatchedBranches`] (provided by [`Dispatcher`])
+    ///
+    /// [`Dispatcher`]: crate::dispatching::Dispatcher
+    #[must_use]
+    fn named(self, name: &'static str) -> Self;
 }
 
 impl<Output> HandlerExt<Output> for Handler<'static, DependencyMap, Output, DpHandlerDescription>
 where
     Output: Send + Sync + 'static,
 {
+    #[track_caller]
     fn filter_command<C>(self) -> Self
     where
         C: BotCommands + Send + Sync + 'static,
@@ -61,6 +365,121 @@ where
         self.chain(filter_command::<C, Output>())
     }
 
+    #[track_caller]
+    fn filter_command_in_caption<C>(self) -> Self
+    where
+        C: BotCommands + Send + Sync + 'static,
+    {
+        self.chain(filter_command_in_caption::<C, Output>())
+    }
+
+    #[track_caller]
+    fn filter_start_payload<T>(self) -> Self
+    where
+        T: FromStr + Send + Sync + 'static,
+    {
+        self.chain(filter_start_payload::<T, Output>())
+    }
+
+    #[track_caller]
+    fn filter_regex<T>(self, pattern: &str) -> Self
+    where
+        T: FromCaptures + Send + Sync + 'static,
+    {
+        self.chain(filter_regex::<T, Output>(pattern))
+    }
+
+    #[track_caller]
+    fn filter_language(self, languages: &[&str]) -> Self {
+        self.chain(filter_language::<Output>(languages))
+    }
+
+    #[track_caller]
+    fn filter_shipping_query_payload<T>(self) -> Self
+    where
+        T: FromStr + Send + Sync + 'static,
+    {
+        self.chain(filter_shipping_query_payload::<T, Output>())
+    }
+
+    #[track_caller]
+    fn filter_pre_checkout_query_payload<T>(self) -> Self
+    where
+        T: FromStr + Send + Sync + 'static,
+    {
+        self.chain(filter_pre_checkout_query_payload::<T, Output>())
+    }
+
+    #[track_caller]
+    fn filter_tracked_inline_result<T>(self) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        self.chain(filter_tracked_inline_result::<T, Output>())
+    }
+
+    #[track_caller]
+    fn filter_tracked_poll_answer(self) -> Self {
+        self.chain(filter_tracked_poll_answer::<Output>())
+    }
+
+    #[track_caller]
+    fn filter_from_admin<R>(self) -> Self
+    where
+        R: Requester + Clone + Send + Sync + 'static,
+        R::Err: Debug + Send,
+    {
+        self.chain(filter_from_admin::<R, Output>())
+    }
+
+    #[track_caller]
+    fn filter_spam<R>(self) -> Self
+    where
+        R: Requester + Clone + Send + Sync + 'static,
+        R::Err: Debug + Send,
+    {
+        self.chain(filter_spam::<R, Output>())
+    }
+
+    #[track_caller]
+    fn filter_access<R>(self) -> Self
+    where
+        R: Requester + Clone + Send + Sync + 'static,
+        R::Err: Debug + Send,
+    {
+        self.chain(access::filter_access::<R, Output>())
+    }
+
+    #[track_caller]
+    fn filter_join_flood<R>(self) -> Self
+    where
+        R: Requester + Clone + Send + Sync + 'static,
+        R::Err: Debug + Send,
+    {
+        self.chain(join_flood::filter_join_flood::<R, Output>())
+    }
+
+    #[track_caller]
+    fn verify_new_members<R, S>(self) -> Self
+    where
+        R: Requester + Clone + Send + Sync + 'static,
+        R::Err: Debug + Send,
+        S: Storage<captcha::CaptchaState> + ?Sized + Send + Sync + 'static,
+        <S as Storage<captcha::CaptchaState>>::Error: Debug + Send,
+    {
+        self.chain(captcha::verify_new_members::<R, S, Output>())
+    }
+
+    #[track_caller]
+    fn require_role<S>(self, role: impl Into<String>) -> Self
+    where
+        S: Roles + ?Sized + Send + Sync + 'static,
+        S::Error: Debug + Send,
+    {
+        self.chain(roles::require_role::<S, Output>(role))
+    }
+
+    #[track_caller]
     fn enter_dialogue<Upd, S, D>(self) -> Self
     where
         S: Storage<D> + ?Sized + Send + Sync + 'static,
@@ -70,6 +489,11 @@ where
     {
         self.chain(super::dialogue::enter::<Upd, S, D, Output>())
     }
+
+    #[track_caller]
+    fn named(self, name: &'static str) -> Self {
+        self.chain(named::<Output>(name))
+    }
 }
 
 /// Returns a handler that accepts a parsed command `C`.
@@ -83,13 +507,508 @@ where
 ///  - [`crate::types::Message`]
 ///  - [`crate::types::Me`]
 #[must_use]
+#[track_caller]
 pub fn filter_command<C, Output>() -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
 where
     C: BotCommands + Send + Sync + 'static,
     Output: Send + Sync + 'static,
 {
-    dptree::filter_map(move |message: Message, me: Me| {
-        let bot_name = me.user.username.expect("Bots must have a username");
-        message.text().and_then(|text| C::parse(text, &bot_name).ok())
+    let description = DpHandlerDescription::filter_map().labelled("filter_command");
+    dptree::filter_map_with_description(description, move |message: Message, me: Me| {
+        C::parse(message.text()?, me.username()).ok()
+    })
+}
+
+/// Returns a handler that accepts a parsed command `C` in the text or the
+/// caption of a message.
+///
+/// A call to this function is the same as
+/// `dptree::entry().filter_command_in_caption()`.
+///
+/// See [`HandlerExt::filter_command_in_caption`].
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::Message`]
+///  - [`crate::types::Me`]
+#[must_use]
+#[track_caller]
+pub fn filter_command_in_caption<C, Output>(
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    C: BotCommands + Send + Sync + 'static,
+    Output: Send + Sync + 'static,
+{
+    let description = DpHandlerDescription::filter_map().labelled("filter_command_in_caption");
+    dptree::filter_map_with_description(description, move |message: Message, me: Me| {
+        let text = message.text().or_else(|| message.caption())?;
+        C::parse(text, me.username()).ok()
+    })
+}
+
+/// Returns a handler that accepts a payload `T` of a `/start` command.
+///
+/// A call to this function is the same as
+/// `dptree::entry().filter_start_payload()`.
+///
+/// See [`HandlerExt::filter_start_payload`].
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::Message`]
+///  - [`crate::types::Me`]
+#[must_use]
+#[track_caller]
+pub fn filter_start_payload<T, Output>(
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    T: FromStr + Send + Sync + 'static,
+    Output: Send + Sync + 'static,
+{
+    let description = DpHandlerDescription::filter_map().labelled("filter_start_payload");
+    dptree::filter_map_with_description(description, move |message: Message, me: Me| {
+        let text = message.text()?;
+        deep_link::parse_start_payload(text, me.username()).and_then(|p| p.parse::<T>().ok())
+    })
+}
+
+/// Returns a handler that accepts messages with text matching `pattern`.
+///
+/// A call to this function is the same as
+/// `dptree::entry().filter_regex(pattern)`.
+///
+/// See [`HandlerExt::filter_regex`].
+///
+/// ## Panics
+///
+/// If `pattern` is not a valid regex.
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::Message`]
+#[must_use]
+#[track_caller]
+pub fn filter_regex<T, Output>(
+    pattern: &str,
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    T: FromCaptures + Send + Sync + 'static,
+    Output: Send + Sync + 'static,
+{
+    let regex = Regex::new(pattern).expect("Invalid regex");
+
+    let description = DpHandlerDescription::filter_map().labelled("filter_regex");
+    dptree::filter_map_with_description(description, move |message: Message| {
+        let captures = regex.captures(message.text()?)?;
+        T::from_captures(&captures)
     })
 }
+
+/// Returns a handler that accepts updates from users whose language code is
+/// one of `languages`.
+///
+/// A call to this function is the same as
+/// `dptree::entry().filter_language(languages)`.
+///
+/// See [`HandlerExt::filter_language`].
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::Update`]
+#[must_use]
+#[track_caller]
+pub fn filter_language<Output>(
+    languages: &[&str],
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    Output: Send + Sync + 'static,
+{
+    let languages: Vec<String> = languages.iter().map(|&language| language.to_owned()).collect();
+
+    let description = DpHandlerDescription::filter().labelled("filter_language");
+    dptree::filter_with_description(description, move |update: Update| {
+        let Some(code) = update.from().and_then(|user| user.language_code.as_deref()) else {
+            return false;
+        };
+
+        languages.iter().any(|language| {
+            code.eq_ignore_ascii_case(language)
+                || i18n::language(code).eq_ignore_ascii_case(language)
+        })
+    })
+}
+
+/// Returns a handler that accepts an invoice payload `T` of a
+/// [`ShippingQuery`].
+///
+/// A call to this function is the same as
+/// `dptree::entry().filter_shipping_query_payload()`.
+///
+/// See [`HandlerExt::filter_shipping_query_payload`].
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::ShippingQuery`]
+#[must_use]
+#[track_caller]
+pub fn filter_shipping_query_payload<T, Output>(
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    T: FromStr + Send + Sync + 'static,
+    Output: Send + Sync + 'static,
+{
+    let description = DpHandlerDescription::filter_map().labelled("filter_shipping_query_payload");
+    dptree::filter_map_with_description(description, move |query: ShippingQuery| {
+        query.invoice_payload.parse::<T>().ok()
+    })
+}
+
+/// Returns a handler that accepts an invoice payload `T` of a
+/// [`PreCheckoutQuery`].
+///
+/// A call to this function is the same as
+/// `dptree::entry().filter_pre_checkout_query_payload()`.
+///
+/// See [`HandlerExt::filter_pre_checkout_query_payload`].
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::PreCheckoutQuery`]
+#[must_use]
+#[track_caller]
+pub fn filter_pre_checkout_query_payload<T, Output>(
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    T: FromStr + Send + Sync + 'static,
+    Output: Send + Sync + 'static,
+{
+    let description =
+        DpHandlerDescription::filter_map().labelled("filter_pre_checkout_query_payload");
+    dptree::filter_map_with_description(description, move |query: PreCheckoutQuery| {
+        query.invoice_payload.parse::<T>().ok()
+    })
+}
+
+/// Returns a handler that accepts data `T` of a [`ChosenInlineResult`], tracked
+/// by an [`InlineResultTracker`].
+///
+/// A call to this function is the same as
+/// `dptree::entry().filter_tracked_inline_result()`.
+///
+/// See [`HandlerExt::filter_tracked_inline_result`].
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::ChosenInlineResult`]
+///  - `Arc<InlineResultTracker<T>>`
+#[must_use]
+#[track_caller]
+pub fn filter_tracked_inline_result<T, Output>(
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    T: Clone + Send + Sync + 'static,
+    Output: Send + Sync + 'static,
+{
+    let description = DpHandlerDescription::filter_map().labelled("filter_tracked_inline_result");
+    dptree::filter_map_with_description(
+        description,
+        move |chosen: ChosenInlineResult, tracker: Arc<InlineResultTracker<T>>| {
+            tracker.choose(&chosen)
+        },
+    )
+}
+
+/// Returns a handler that accepts answers to polls tracked by a
+/// [`PollTracker`].
+///
+/// A call to this function is the same as
+/// `dptree::entry().filter_tracked_poll_answer()`.
+///
+/// See [`HandlerExt::filter_tracked_poll_answer`].
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::PollAnswer`]
+///  - `Arc<PollTracker>`
+#[must_use]
+#[track_caller]
+pub fn filter_tracked_poll_answer<Output>(
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    Output: Send + Sync + 'static,
+{
+    let description = DpHandlerDescription::filter_map().labelled("filter_tracked_poll_answer");
+    dptree::filter_map_with_description(
+        description,
+        move |answer: PollAnswer, tracker: Arc<PollTracker>| tracker.get(&answer),
+    )
+}
+
+/// Returns a handler that accepts updates from administrators of a chat.
+///
+/// A call to this function is the same as
+/// `dptree::entry().filter_from_admin::<R>()`.
+///
+/// See [`HandlerExt::filter_from_admin`].
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::Update`]
+///  - `R`
+///  - `Arc<AdminCache>`
+#[must_use]
+#[track_caller]
+pub fn filter_from_admin<R, Output>(
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    R: Requester + Clone + Send + Sync + 'static,
+    R::Err: Debug + Send,
+    Output: Send + Sync + 'static,
+{
+    let description = DpHandlerDescription::filter_map_async().labelled("filter_from_admin");
+    dptree::filter_map_async_with_description(
+        description,
+        |update: Update, bot: R, cache: Arc<AdminCache>| async move {
+            let chat = update.chat()?;
+            if chat.is_private() {
+                return None;
+            }
+            let (chat_id, user_id) = (chat.id, update.from()?.id);
+
+            match cache.get(&bot, chat_id, user_id).await {
+                Ok(member) => member,
+                Err(err) => {
+                    log::error!("Couldn't fetch administrators of {chat_id}: {err:?}");
+                    None
+                }
+            }
+        },
+    )
+}
+
+/// Returns a handler that drops spam messages.
+///
+/// A call to this function is the same as
+/// `dptree::entry().filter_spam::<R>()`.
+///
+/// See [`HandlerExt::filter_spam`].
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::Message`]
+///  - `R`
+///  - `Arc<AntiSpam>`
+#[must_use]
+#[track_caller]
+pub fn filter_spam<R, Output>() -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    R: Requester + Clone + Send + Sync + 'static,
+    R::Err: Debug + Send,
+    Output: Send + Sync + 'static,
+{
+    let description = DpHandlerDescription::filter_async().labelled("filter_spam");
+    dptree::filter_async_with_description(
+        description,
+        |message: Message, bot: R, antispam: Arc<AntiSpam>| async move {
+            match antispam.enforce(&bot, &message).await {
+                Ok(verdict) => verdict == Verdict::Clean,
+                Err(err) => {
+                    log::error!("Couldn't punish spam in {}: {err:?}", message.chat.id);
+                    false
+                }
+            }
+        },
+    )
+}
+
+/// Returns a handler that adds `name` to [`MatchedBranches`].
+///
+/// A call to this function is the same as `dptree::entry().named(name)`.
+///
+/// See [`HandlerExt::named`].
+///
+/// ## Dependency requirements
+///
+///  - [`MatchedBranches`]
+#[must_use]
+#[track_caller]
+pub fn named<Output>(
+    name: &'static str,
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    Output: Send + Sync + 'static,
+{
+    let description = DpHandlerDescription::map().labelled_with("named", name);
+    dptree::map_with_description(description, move |branches: MatchedBranches| branches.with(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use super::*;
+    use crate::{
+        dispatching::roles::RoleCommand,
+        types::{CallbackQuery, ChatId, UpdateKind, UserId},
+    };
+
+    #[tokio::test]
+    async fn filter_command() {
+        let handler = dptree::entry()
+            .filter_command::<RoleCommand>()
+            .endpoint(|cmd: RoleCommand| async move { cmd });
+
+        let dispatch = |text: &str| {
+            let message = Message::text_fixture(ChatId(1), UserId(1), text);
+            handler.dispatch(dptree::deps![message, Me::fixture()])
+        };
+        let roles = RoleCommand::Roles { user_id: UserId(5) };
+
+        assert_eq!(dispatch("/roles 5").await, ControlFlow::Break(roles.clone()));
+        assert_eq!(dispatch("/roles@test_bot 5").await, ControlFlow::Break(roles));
+        assert!(matches!(dispatch("/roles@other_bot 5").await, ControlFlow::Continue(_)));
+        assert!(matches!(dispatch("/roles").await, ControlFlow::Continue(_)));
+        assert!(matches!(dispatch("roles 5").await, ControlFlow::Continue(_)));
+    }
+
+    #[tokio::test]
+    async fn filter_shipping_query_payload() {
+        let handler = dptree::entry()
+            .filter_shipping_query_payload::<u32>()
+            .endpoint(|order: u32| async move { order });
+
+        let dispatch = |payload: &str| {
+            let query: ShippingQuery = serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "from": { "id": 1, "is_bot": false, "first_name": "Test" },
+                "invoice_payload": payload,
+                "shipping_address": {
+                    "country_code": "US",
+                    "state": "",
+                    "city": "City",
+                    "street_line1": "Street",
+                    "street_line2": "",
+                    "post_code": "0",
+                },
+            }))
+            .unwrap();
+            handler.dispatch(dptree::deps![query])
+        };
+
+        assert_eq!(dispatch("5").await, ControlFlow::Break(5));
+        assert!(matches!(dispatch("five").await, ControlFlow::Continue(_)));
+    }
+
+    #[tokio::test]
+    async fn filter_pre_checkout_query_payload() {
+        let handler = dptree::entry()
+            .filter_pre_checkout_query_payload::<u32>()
+            .endpoint(|order: u32| async move { order });
+
+        let dispatch = |payload: &str| {
+            let query: PreCheckoutQuery = serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "from": { "id": 1, "is_bot": false, "first_name": "Test" },
+                "currency": "USD",
+                "total_amount": 100,
+                "invoice_payload": payload,
+                "order_info": {},
+            }))
+            .unwrap();
+            handler.dispatch(dptree::deps![query])
+        };
+
+        assert_eq!(dispatch("5").await, ControlFlow::Break(5));
+        assert!(matches!(dispatch("five").await, ControlFlow::Continue(_)));
+    }
+
+    #[tokio::test]
+    async fn named() {
+        let handler = dptree::entry()
+            .named("root")
+            .branch(dptree::filter(|message: Message| message.text() == Some("a")).named("a"))
+            .branch(dptree::entry().named("other").endpoint(
+                |branches: MatchedBranches| async move { branches.iter().collect::<Vec<_>>() },
+            ));
+
+        let message = Message::text_fixture(ChatId(1), UserId(1), "b");
+        let result = handler.dispatch(dptree::deps![message, MatchedBranches::default()]).await;
+        assert_eq!(result, ControlFlow::Break(vec!["root", "other"]));
+
+        // The dispatcher reads the matched branches from its own copy
+        let branches = MatchedBranches::recorded();
+        let message = Message::text_fixture(ChatId(1), UserId(1), "b");
+        handler.dispatch(dptree::deps![message, branches.clone()]).await;
+        assert_eq!(branches.latest().iter().collect::<Vec<_>>(), ["root", "other"]);
+        assert!(branches.is_empty());
+    }
+
+    #[tokio::test]
+    async fn filter_command_in_caption() {
+        use crate::types::{MediaKind, MediaPhoto, MessageKind};
+
+        let handler = dptree::entry()
+            .filter_command_in_caption::<RoleCommand>()
+            .endpoint(|cmd: RoleCommand| async move { cmd });
+
+        let photo = |caption: &str| {
+            let mut message = Message::text_fixture(ChatId(1), UserId(1), "");
+            if let MessageKind::Common(common) = &mut message.kind {
+                common.media_kind = MediaKind::Photo(MediaPhoto {
+                    photo: Vec::new(),
+                    caption: Some(caption.to_owned()),
+                    caption_entities: Vec::new(),
+                    has_media_spoiler: false,
+                    media_group_id: None,
+                });
+            }
+            message
+        };
+        let dispatch = |message: Message| handler.dispatch(dptree::deps![message, Me::fixture()]);
+        let roles = RoleCommand::Roles { user_id: UserId(5) };
+
+        assert_eq!(dispatch(photo("/roles 5")).await, ControlFlow::Break(roles.clone()));
+        assert_eq!(
+            dispatch(Message::text_fixture(ChatId(1), UserId(1), "/roles 5")).await,
+            ControlFlow::Break(roles)
+        );
+        assert!(matches!(dispatch(photo("roles 5")).await, ControlFlow::Continue(_)));
+
+        let plain = dptree::entry().filter_command::<RoleCommand>().endpoint(|| async {});
+        let res = plain.dispatch(dptree::deps![photo("/roles 5"), Me::fixture()]).await;
+        assert!(matches!(res, ControlFlow::Continue(_)));
+    }
+
+    #[tokio::test]
+    async fn filter_language() {
+        let handler = dptree::entry().filter_language(&["en", "pt-BR"]).endpoint(|| async {});
+
+        let dispatch = |code: Option<&str>| {
+            let mut query = CallbackQuery::fixture(UserId(1), "data");
+            query.from.language_code = code.map(str::to_owned);
+            let update = Update::fixture(UpdateKind::CallbackQuery(query));
+            handler.dispatch(dptree::deps![update])
+        };
+
+        assert!(matches!(dispatch(Some("en")).await, ControlFlow::Break(())));
+        assert!(matches!(dispatch(Some("en-GB")).await, ControlFlow::Break(())));
+        assert!(matches!(dispatch(Some("pt-br")).await, ControlFlow::Break(())));
+        assert!(matches!(dispatch(Some("pt-PT")).await, ControlFlow::Continue(_)));
+        assert!(matches!(dispatch(None).await, ControlFlow::Continue(_)));
+    }
+
+    #[tokio::test]
+    async fn filter_regex() {
+        let handler = dptree::entry()
+            .filter_regex::<(String, u32)>(r"^(\w+) (\d+)$")
+            .endpoint(|captures: (String, u32)| async move { captures });
+
+        let dispatch = |text: &str| {
+            handler.dispatch(dptree::deps![Message::text_fixture(ChatId(1), UserId(1), text)])
+        };
+
+        assert_eq!(dispatch("set 10").await, ControlFlow::Break(("set".to_owned(), 10)));
+        assert!(matches!(dispatch("set ten").await, ControlFlow::Continue(_)));
+        assert!(matches!(dispatch("set 99999999999").await, ControlFlow::Continue(_)));
+    }
+}
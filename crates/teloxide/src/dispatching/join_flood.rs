@@ -0,0 +1,229 @@
+//! Protection against mass-adding the bot to groups.
+//!
+//! A common attack on public bots is adding them to lots of spam groups at
+//! once. [`JoinFloodGuard`] counts how many times the bot was added to groups
+//! (as reported by [`my_chat_member`] updates) and, once there were more than
+//! the configured number of additions in a period, makes the bot leave each
+//! new group right away.
+//!
+//! ```no_run
+//! use std::{sync::Arc, time::Duration};
+//!
+//! use teloxide::{
+//!     dispatching::{join_flood::JoinFloodGuard, HandlerExt, UpdateHandler},
+//!     prelude::*,
+//! };
+//!
+//! # async fn run(handler: UpdateHandler<teloxide::RequestError>) {
+//! let bot = Bot::from_env();
+//! let guard = JoinFloodGuard::new(10).period(Duration::from_secs(60)).on_leave(|update| {
+//!     log::warn!("{} is adding the bot to too many groups", update.from.id);
+//! });
+//!
+//! let handler = dptree::entry().filter_join_flood::<Bot>().chain(handler);
+//!
+//! Dispatcher::builder(bot, handler)
+//!     .dependencies(dptree::deps![Arc::new(guard)])
+//!     .build()
+//!     .dispatch()
+//!     .await;
+//! # }
+//! ```
+//!
+//! [`my_chat_member`]: crate::types::UpdateKind::MyChatMember
+
+use std::{
+    collections::VecDeque,
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use dptree::{di::DependencyMap, Handler};
+
+use crate::{
+    dispatching::DpHandlerDescription,
+    requests::{Request, Requester},
+    types::{ChatMemberUpdated, Update, UpdateKind},
+};
+
+type LeaveCallback = Arc<dyn Fn(&ChatMemberUpdated) + Send + Sync>;
+
+/// Limits how often the bot can be added to groups.
+///
+/// See the [module-level documentation](self).
+pub struct JoinFloodGuard {
+    max_joins: usize,
+    period: Duration,
+    on_leave: Option<LeaveCallback>,
+    joins: Mutex<VecDeque<Instant>>,
+}
+
+impl JoinFloodGuard {
+    /// Creates a guard which allows the bot to be added to at most
+    /// `max_joins` groups per minute.
+    #[must_use]
+    pub fn new(max_joins: usize) -> Self {
+        Self { max_joins, period: Duration::from_secs(60), on_leave: None, joins: Mutex::default() }
+    }
+
+    /// Sets the period in which at most `max_joins` additions are allowed.
+    ///
+    /// The default is one minute.
+    #[must_use]
+    pub fn period(self, period: Duration) -> Self {
+        Self { period, ..self }
+    }
+
+    /// Sets a function which is called with the update every time the bot
+    /// leaves a group because of this guard.
+    #[must_use]
+    pub fn on_leave<F>(self, f: F) -> Self
+    where
+        F: Fn(&ChatMemberUpdated) + Send + Sync + 'static,
+    {
+        Self { on_leave: Some(Arc::new(f)), ..self }
+    }
+
+    /// Records that the bot was added to a group, returning `false` if it was
+    /// added to too many groups in the period.
+    ///
+    /// All additions are counted, including the ones which were not allowed,
+    /// so the guard stays active while the attack goes on.
+    pub fn record_join(&self) -> bool {
+        let now = Instant::now();
+        let mut joins = self.joins.lock().unwrap();
+
+        while joins.front().map_or(false, |&at| now.duration_since(at) >= self.period) {
+            joins.pop_front();
+        }
+        joins.push_back(now);
+
+        joins.len() <= self.max_joins
+    }
+}
+
+impl fmt::Debug for JoinFloodGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinFloodGuard")
+            .field("max_joins", &self.max_joins)
+            .field("period", &self.period)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns `true` if `update` reports that the bot was added to a group.
+fn is_added_to_group(update: &ChatMemberUpdated) -> bool {
+    !update.chat.is_private()
+        && !update.old_chat_member.is_present()
+        && update.new_chat_member.is_present()
+}
+
+/// Returns a handler that makes the bot `R` leave groups it's added to too
+/// often, according to a [`JoinFloodGuard`].
+///
+/// Updates about groups which the bot left are dropped, all the other updates
+/// are passed forwards.
+///
+/// A call to this function is the same as
+/// `dptree::entry().filter_join_flood::<R>()`.
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::Update`]
+///  - `R`
+///  - `Arc<JoinFloodGuard>`
+#[must_use]
+#[track_caller]
+pub fn filter_join_flood<R, Output>(
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    R: Requester + Clone + Send + Sync + 'static,
+    R::Err: fmt::Debug + Send,
+    Output: Send + Sync + 'static,
+{
+    dptree::filter_async(|update: Update, bot: R, guard: Arc<JoinFloodGuard>| async move {
+        let member = match &update.kind {
+            UpdateKind::MyChatMember(member) if is_added_to_group(member) => member,
+            _ => return true,
+        };
+
+        if guard.record_join() {
+            return true;
+        }
+
+        log::warn!(
+            "Leaving chat {}: the bot was added to more than {} groups in {:?}, last time by {}",
+            member.chat.id,
+            guard.max_joins,
+            guard.period,
+            member.from.id,
+        );
+        if let Err(err) = bot.leave_chat(member.chat.id).send().await {
+            log::error!("Couldn't leave chat {}: {err:?}", member.chat.id);
+        }
+        if let Some(on_leave) = &guard.on_leave {
+            on_leave(member);
+        }
+
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        ops::ControlFlow,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+    use crate::{
+        adaptors::MockBot,
+        payloads::LeaveChat,
+        types::{ChatId, ChatMemberKind, True, UserId},
+    };
+
+    #[tokio::test]
+    async fn leaves_after_limit() {
+        let bot = MockBot::new();
+        bot.respond::<LeaveChat>(True);
+
+        let left = Arc::new(AtomicUsize::new(0));
+        let guard = Arc::new(JoinFloodGuard::new(2).on_leave({
+            let left = Arc::clone(&left);
+            move |_| {
+                left.fetch_add(1, Ordering::Relaxed);
+            }
+        }));
+        let handler = filter_join_flood::<MockBot, ()>().endpoint(|| async {});
+        let dispatch = |update: Update| {
+            handler.dispatch(dptree::deps![update, bot.clone(), Arc::clone(&guard)])
+        };
+
+        let added = |chat_id| {
+            Update::fixture(UpdateKind::MyChatMember(ChatMemberUpdated::fixture(
+                ChatId(chat_id),
+                UserId(1),
+                ChatMemberKind::Left,
+                ChatMemberKind::Member,
+            )))
+        };
+
+        assert!(matches!(dispatch(added(-1)).await, ControlFlow::Break(())));
+        assert!(matches!(dispatch(added(-2)).await, ControlFlow::Break(())));
+        assert!(matches!(dispatch(added(-3)).await, ControlFlow::Continue(_)));
+        assert_eq!(bot.requests_of::<LeaveChat>().len(), 1);
+        assert_eq!(left.load(Ordering::Relaxed), 1);
+
+        let message = Update::message_fixture(ChatId(-1), UserId(2), "hi");
+        assert!(matches!(dispatch(message).await, ControlFlow::Break(())));
+    }
+
+    #[test]
+    fn window_expires() {
+        let guard = JoinFloodGuard::new(1).period(Duration::ZERO);
+        assert!(guard.record_join());
+        assert!(guard.record_join());
+    }
+}
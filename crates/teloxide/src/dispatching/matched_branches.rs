@@ -0,0 +1,87 @@
+use std::{
+    slice,
+    sync::{Arc, Mutex},
+};
+
+type Names = Arc<[&'static str]>;
+
+/// Names of the branches of a handler tree which an update has matched, from
+/// the outermost to the innermost one.
+///
+/// Branches are named with [`HandlerExt::named`]. [`Dispatcher`] passes an
+/// empty `MatchedBranches` for each update, so handlers can use it e.g. as
+/// labels of per-branch metrics.
+///
+/// [`HandlerExt::named`]: crate::dispatching::HandlerExt::named
+/// [`Dispatcher`]: crate::dispatching::Dispatcher
+#[derive(Clone, Debug, Default)]
+pub struct MatchedBranches {
+    names: Option<Names>,
+    /// The branches matched most recently by any copy of the original
+    /// `MatchedBranches`, see [`MatchedBranches::recorded`].
+    latest: Option<Arc<Mutex<Option<Names>>>>,
+}
+
+impl MatchedBranches {
+    /// Returns an iterator over the names of the matched branches, from the
+    /// outermost to the innermost one.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &'static str> + ExactSizeIterator + '_ {
+        self.as_slice().iter().copied()
+    }
+
+    /// Returns the name of the innermost matched branch.
+    #[must_use]
+    pub fn last(&self) -> Option<&'static str> {
+        self.as_slice().last().copied()
+    }
+
+    /// Returns `true` if no named branch has been matched.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    /// Returns an empty `MatchedBranches` which records the branches matched
+    /// by it and its descendants, so they can be retrieved with
+    /// [`MatchedBranches::latest`] even if handling is cancelled.
+    pub(crate) fn recorded() -> Self {
+        Self { names: None, latest: Some(Arc::default()) }
+    }
+
+    /// Returns the branches matched most recently by descendants of a
+    /// [recorded](MatchedBranches::recorded) `MatchedBranches`.
+    pub(crate) fn latest(&self) -> Self {
+        let names = self.latest.as_ref().and_then(|latest| latest.lock().unwrap().clone());
+        Self { names, latest: None }
+    }
+
+    pub(crate) fn with(&self, name: &'static str) -> Self {
+        let names: Names = self.as_slice().iter().copied().chain([name]).collect();
+        if let Some(latest) = &self.latest {
+            *latest.lock().unwrap() = Some(Arc::clone(&names));
+        }
+
+        Self { names: Some(names), latest: self.latest.clone() }
+    }
+
+    fn as_slice(&self) -> &[&'static str] {
+        self.names.as_deref().unwrap_or_default()
+    }
+}
+
+impl PartialEq for MatchedBranches {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl Eq for MatchedBranches {}
+
+impl<'a> IntoIterator for &'a MatchedBranches {
+    type Item = &'static str;
+    type IntoIter = std::iter::Copied<slice::Iter<'a, &'static str>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter().copied()
+    }
+}
@@ -0,0 +1,383 @@
+use std::{collections::HashMap, fmt, ops::ControlFlow, sync::Arc};
+
+use dptree::di::{DependencyMap, DependencySupplier};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dispatching::{
+        dialogue::{ErasedStorage, GetChatId, InMemStorage, Storage},
+        UpdateHandler,
+    },
+    types::{ChatId, Update},
+};
+
+/// A self-contained piece of bot functionality.
+///
+/// A plugin provides a handler branch which is composed into the handler tree
+/// by [`PluginManager`]. Each plugin has its own dependency namespace: values
+/// inserted in [`Plugin::setup`] are only visible to the plugin's own handler,
+/// so different plugins can use e.g. distinct dialogue storages of the same
+/// type without conflicts.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide::{
+///     dispatching::{Plugin, PluginManager, UpdateFilterExt, UpdateHandler},
+///     dptree::di::DependencyMap,
+///     prelude::*,
+/// };
+///
+/// struct Ping;
+///
+/// impl Plugin<teloxide::RequestError> for Ping {
+///     fn name(&self) -> &str {
+///         "ping"
+///     }
+///
+///     fn handler(&self) -> UpdateHandler<teloxide::RequestError> {
+///         Update::filter_message().filter(|msg: Message| msg.text() == Some("ping")).endpoint(
+///             |bot: Bot, msg: Message| async move {
+///                 bot.send_message(msg.chat.id, "pong").await?;
+///                 Ok(())
+///             },
+///         )
+///     }
+/// }
+///
+/// let plugins = PluginManager::new().register(Ping);
+/// let handler = plugins.handler();
+/// # let _ = handler;
+/// ```
+pub trait Plugin<Err>: Send + Sync + 'static {
+    /// A unique name of this plugin.
+    ///
+    /// The name is used as a key for enabling and disabling the plugin, so it
+    /// should not change between bot restarts.
+    fn name(&self) -> &str;
+
+    /// Returns the handler branch of this plugin.
+    fn handler(&self) -> UpdateHandler<Err>;
+
+    /// Populates the plugin's own dependency namespace.
+    ///
+    /// Called by [`PluginManager::handler`] before the plugin's handler is
+    /// constructed. By default, does nothing.
+    fn setup(&self, deps: &mut DependencyMap) {
+        let _ = deps;
+    }
+
+    /// Releases resources held by the plugin.
+    ///
+    /// Called by [`PluginManager::teardown`]. By default, does nothing.
+    fn teardown(&self) -> BoxFuture<'static, ()> {
+        Box::pin(async {})
+    }
+
+    /// Whether the plugin is enabled in chats which have no explicit setting.
+    ///
+    /// By default, `true`.
+    fn enabled_by_default(&self) -> bool {
+        true
+    }
+}
+
+/// Per-chat plugin settings, stored by [`PluginManager`].
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginSettings {
+    overrides: HashMap<String, bool>,
+}
+
+impl PluginSettings {
+    /// Returns whether the plugin named `name` is enabled, falling back to
+    /// `default` if there is no explicit setting.
+    #[must_use]
+    pub fn is_enabled(&self, name: &str, default: bool) -> bool {
+        self.overrides.get(name).copied().unwrap_or(default)
+    }
+
+    /// Explicitly enables or disables the plugin named `name`.
+    pub fn set_enabled(&mut self, name: &str, enabled: bool) {
+        self.overrides.insert(name.to_owned(), enabled);
+    }
+
+    /// Removes an explicit setting for the plugin named `name`.
+    pub fn reset(&mut self, name: &str) {
+        self.overrides.remove(name);
+    }
+}
+
+/// A registry of [`Plugin`]s that composes them into a single handler.
+///
+/// Plugins are tried in the order of registration. Per-chat enable/disable
+/// flags are kept in a dialogue [`Storage`] of [`PluginSettings`], so they
+/// survive bot restarts if a persistent storage is used (see
+/// [`PluginManager::storage`]).
+///
+/// `PluginManager` is cheap to clone. You can pass it to your handlers as a
+/// dependency to enable or disable plugins at runtime.
+pub struct PluginManager<Err> {
+    plugins: Vec<Arc<dyn Plugin<Err>>>,
+    storage: Arc<ErasedStorage<PluginSettings>>,
+}
+
+impl<Err> Clone for PluginManager<Err> {
+    fn clone(&self) -> Self {
+        Self { plugins: self.plugins.clone(), storage: Arc::clone(&self.storage) }
+    }
+}
+
+impl<Err> Default for PluginManager<Err>
+where
+    Err: Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Err> PluginManager<Err>
+where
+    Err: Send + Sync + 'static,
+{
+    /// Creates an empty plugin manager which keeps its settings in
+    /// [`InMemStorage`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self { plugins: Vec::new(), storage: InMemStorage::new().erase() }
+    }
+
+    /// Sets a storage used to persist [`PluginSettings`].
+    #[must_use]
+    pub fn storage(self, storage: Arc<ErasedStorage<PluginSettings>>) -> Self {
+        Self { storage, ..self }
+    }
+
+    /// Registers a new plugin.
+    ///
+    /// ## Panics
+    ///
+    /// If a plugin with the same name is already registered.
+    #[must_use]
+    pub fn register<P>(mut self, plugin: P) -> Self
+    where
+        P: Plugin<Err>,
+    {
+        assert!(
+            self.plugin(plugin.name()).is_none(),
+            "plugin `{}` is already registered",
+            plugin.name()
+        );
+
+        self.plugins.push(Arc::new(plugin));
+        self
+    }
+
+    /// Returns names of all registered plugins, in the order of registration.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.plugins.iter().map(|p| p.name())
+    }
+
+    /// Constructs a handler that tries registered plugins one by one.
+    ///
+    /// For each plugin, [`Plugin::setup`] is called and the plugin's handler
+    /// is run with its own dependencies added. Plugins disabled in the chat
+    /// of an incoming update are skipped.
+    ///
+    /// ## Dependency requirements
+    ///
+    ///  - [`crate::types::Update`]
+    #[must_use]
+    pub fn handler(&self) -> UpdateHandler<Err> {
+        self.plugins
+            .iter()
+            .fold(dptree::entry(), |handler, plugin| handler.branch(self.plugin_branch(plugin)))
+    }
+
+    /// Returns whether the plugin named `name` is enabled in `chat_id`.
+    ///
+    /// Returns `Ok(false)` if there is no such plugin.
+    pub async fn is_enabled(
+        &self,
+        chat_id: ChatId,
+        name: &str,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        let Some(plugin) = self.plugin(name) else { return Ok(false) };
+        let settings = Arc::clone(&self.storage).get_dialogue(chat_id).await?;
+
+        Ok(settings.unwrap_or_default().is_enabled(name, plugin.enabled_by_default()))
+    }
+
+    /// Enables the plugin named `name` in `chat_id`.
+    pub async fn enable(
+        &self,
+        chat_id: ChatId,
+        name: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.set_enabled(chat_id, name, true).await
+    }
+
+    /// Disables the plugin named `name` in `chat_id`.
+    pub async fn disable(
+        &self,
+        chat_id: ChatId,
+        name: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.set_enabled(chat_id, name, false).await
+    }
+
+    /// Calls [`Plugin::teardown`] of all registered plugins, in the reverse
+    /// order of registration.
+    pub async fn teardown(&self) {
+        for plugin in self.plugins.iter().rev() {
+            plugin.teardown().await;
+        }
+    }
+
+    async fn set_enabled(
+        &self,
+        chat_id: ChatId,
+        name: &str,
+        enabled: bool,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let storage = Arc::clone(&self.storage);
+        let mut settings = Arc::clone(&storage).get_dialogue(chat_id).await?.unwrap_or_default();
+        settings.set_enabled(name, enabled);
+        storage.update_dialogue(chat_id, settings).await
+    }
+
+    fn plugin(&self, name: &str) -> Option<&Arc<dyn Plugin<Err>>> {
+        self.plugins.iter().find(|p| p.name() == name)
+    }
+
+    fn plugin_branch(&self, plugin: &Arc<dyn Plugin<Err>>) -> UpdateHandler<Err> {
+        let mut plugin_deps = DependencyMap::new();
+        plugin.setup(&mut plugin_deps);
+
+        let plugin = Arc::clone(plugin);
+        let plugin_deps = Arc::new(plugin_deps);
+        let storage = Arc::clone(&self.storage);
+        let handler = plugin.handler();
+        let description = handler.description().clone();
+
+        dptree::from_fn_with_description(description, move |deps: DependencyMap, cont| {
+            let plugin = Arc::clone(&plugin);
+            let plugin_deps = Arc::clone(&plugin_deps);
+            let storage = Arc::clone(&storage);
+            let handler = handler.clone();
+
+            async move {
+                let update: Arc<Update> = deps.get();
+                if !enabled_for(&*plugin, &storage, &update).await {
+                    return cont(deps).await;
+                }
+
+                let mut scoped = deps.clone();
+                scoped.insert_container((*plugin_deps).clone());
+
+                match handler.dispatch(scoped).await {
+                    ControlFlow::Break(res) => ControlFlow::Break(res),
+                    ControlFlow::Continue(_) => cont(deps).await,
+                }
+            }
+        })
+    }
+}
+
+async fn enabled_for<Err>(
+    plugin: &dyn Plugin<Err>,
+    storage: &Arc<ErasedStorage<PluginSettings>>,
+    update: &Update,
+) -> bool
+where
+    Err: 'static,
+{
+    let default = plugin.enabled_by_default();
+    let Some(chat_id) = update.chat_id() else { return default };
+
+    match Arc::clone(storage).get_dialogue(chat_id).await {
+        Ok(settings) => settings.unwrap_or_default().is_enabled(plugin.name(), default),
+        Err(err) => {
+            log::error!("Failed to get settings of plugin `{}`: {:?}", plugin.name(), err);
+            default
+        }
+    }
+}
+
+impl<Err> fmt::Debug for PluginManager<Err>
+where
+    Err: 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PluginManager")
+            .field("plugins", &self.plugins.iter().map(|p| p.name()).collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+
+    type Log = Arc<std::sync::Mutex<Vec<&'static str>>>;
+
+    struct Echo(&'static str);
+
+    impl Plugin<Infallible> for Echo {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn handler(&self) -> UpdateHandler<Infallible> {
+            dptree::endpoint(|name: &'static str, log: Log| {
+                log.lock().unwrap().push(name);
+                async { Ok(()) }
+            })
+        }
+
+        fn setup(&self, deps: &mut DependencyMap) {
+            deps.insert(self.0);
+        }
+    }
+
+    fn update() -> Update {
+        serde_json::from_str(
+            r#"{
+                "update_id": 1,
+                "message": {
+                    "message_id": 1,
+                    "date": 1,
+                    "chat": { "id": 42, "type": "private", "first_name": "A" },
+                    "text": "hi"
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn disabled_plugins_are_skipped() {
+        let manager = PluginManager::new().register(Echo("first")).register(Echo("second"));
+        let handler = manager.handler();
+        let log = Log::default();
+
+        let res = handler.dispatch(dptree::deps![update(), Arc::clone(&log)]).await;
+        assert!(matches!(res, ControlFlow::Break(Ok(()))));
+
+        manager.disable(ChatId(42), "first").await.unwrap();
+        assert!(!manager.is_enabled(ChatId(42), "first").await.unwrap());
+        assert!(manager.is_enabled(ChatId(43), "first").await.unwrap());
+
+        let res = handler.dispatch(dptree::deps![update(), Arc::clone(&log)]).await;
+        assert!(matches!(res, ControlFlow::Break(Ok(()))));
+
+        manager.disable(ChatId(42), "second").await.unwrap();
+        let res = handler.dispatch(dptree::deps![update(), Arc::clone(&log)]).await;
+        assert!(matches!(res, ControlFlow::Continue(_)));
+
+        assert_eq!(*log.lock().unwrap(), ["first", "second"]);
+    }
+}
@@ -0,0 +1,478 @@
+//! Role-based access control.
+//!
+//! Roles are arbitrary strings (e.g. `"staff"` or `"moderator"`) granted to
+//! users. They are kept in a [`Roles`] store, checked with
+//! [`HandlerExt::require_role`] and can be managed at runtime with
+//! [`RoleCommand`]s:
+//!
+//! ```no_run
+//! use teloxide::{
+//!     dispatching::roles::{InMemRoles, RoleCommand},
+//!     prelude::*,
+//! };
+//!
+//! # async fn run() {
+//! let bot = Bot::from_env();
+//! let roles = InMemRoles::new();
+//!
+//! let handler = Update::filter_message()
+//!     .branch(
+//!         dptree::entry()
+//!             .filter_command::<RoleCommand>()
+//!             .require_role::<InMemRoles>("admin")
+//!             .endpoint(
+//!                 |bot: Bot,
+//!                  msg: Message,
+//!                  cmd: RoleCommand,
+//!                  roles: std::sync::Arc<InMemRoles>| async move {
+//!                     let reply = cmd.execute(roles).await.unwrap();
+//!                     bot.send_message(msg.chat.id, reply).await?;
+//!                     respond(())
+//!                 },
+//!             ),
+//!     )
+//!     .branch(dptree::entry().require_role::<InMemRoles>("staff").endpoint(
+//!         |bot: Bot, msg: Message| async move {
+//!             bot.send_message(msg.chat.id, "Hi, staff member!").await?;
+//!             respond(())
+//!         },
+//!     ));
+//!
+//! Dispatcher::builder(bot, handler).dependencies(dptree::deps![roles]).build().dispatch().await;
+//! # }
+//! ```
+//!
+//! [`HandlerExt::require_role`]: crate::dispatching::HandlerExt::require_role
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::Infallible,
+    fmt::Debug,
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use dptree::{di::DependencyMap, Handler, HandlerDescription};
+use futures::future::BoxFuture;
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+use crate::{
+    dispatching::DpHandlerDescription,
+    types::{BotCommand, Update, UserId},
+    utils::command::{
+        parse_command, BotCommands, CommandDescription, CommandDescriptions, ParseError,
+    },
+};
+
+/// A store of user roles.
+pub trait Roles {
+    type Error;
+
+    /// Returns all roles of `user_id`.
+    #[must_use = "Futures are lazy and do nothing unless polled with .await"]
+    fn roles(
+        self: Arc<Self>,
+        user_id: UserId,
+    ) -> BoxFuture<'static, Result<BTreeSet<String>, Self::Error>>;
+
+    /// Grants `role` to `user_id`.
+    ///
+    /// Returns `false` if the user already had the role.
+    #[must_use = "Futures are lazy and do nothing unless polled with .await"]
+    fn grant(
+        self: Arc<Self>,
+        user_id: UserId,
+        role: String,
+    ) -> BoxFuture<'static, Result<bool, Self::Error>>;
+
+    /// Revokes `role` from `user_id`.
+    ///
+    /// Returns `false` if the user didn't have the role.
+    #[must_use = "Futures are lazy and do nothing unless polled with .await"]
+    fn revoke(
+        self: Arc<Self>,
+        user_id: UserId,
+        role: String,
+    ) -> BoxFuture<'static, Result<bool, Self::Error>>;
+
+    /// Returns `true` if `user_id` has `role`.
+    #[must_use = "Futures are lazy and do nothing unless polled with .await"]
+    fn has_role(
+        self: Arc<Self>,
+        user_id: UserId,
+        role: String,
+    ) -> BoxFuture<'static, Result<bool, Self::Error>>
+    where
+        Self: Send + Sync + 'static,
+        Self::Error: Send,
+    {
+        Box::pin(async move { Ok(self.roles(user_id).await?.contains(&role)) })
+    }
+}
+
+type RoleMap = BTreeMap<UserId, BTreeSet<String>>;
+
+fn grant(map: &mut RoleMap, user_id: UserId, role: String) -> bool {
+    map.entry(user_id).or_default().insert(role)
+}
+
+fn revoke(map: &mut RoleMap, user_id: UserId, role: &str) -> bool {
+    let Some(roles) = map.get_mut(&user_id) else { return false };
+    let revoked = roles.remove(role);
+    if roles.is_empty() {
+        map.remove(&user_id);
+    }
+    revoked
+}
+
+/// A role store based on [`std::collections::BTreeMap`].
+///
+/// ## Note
+/// All roles will be lost after you restart your bot. If you need to keep
+/// them, use [`FileRoles`] or implement your own store.
+#[derive(Debug, Default)]
+pub struct InMemRoles {
+    map: Mutex<RoleMap>,
+}
+
+impl InMemRoles {
+    #[must_use]
+    pub fn new() -> Arc<Self> {
+        Arc::default()
+    }
+}
+
+impl Roles for InMemRoles {
+    type Error = Infallible;
+
+    fn roles(
+        self: Arc<Self>,
+        user_id: UserId,
+    ) -> BoxFuture<'static, Result<BTreeSet<String>, Self::Error>> {
+        Box::pin(
+            async move { Ok(self.map.lock().await.get(&user_id).cloned().unwrap_or_default()) },
+        )
+    }
+
+    fn grant(
+        self: Arc<Self>,
+        user_id: UserId,
+        role: String,
+    ) -> BoxFuture<'static, Result<bool, Self::Error>> {
+        Box::pin(async move { Ok(grant(&mut *self.map.lock().await, user_id, role)) })
+    }
+
+    fn revoke(
+        self: Arc<Self>,
+        user_id: UserId,
+        role: String,
+    ) -> BoxFuture<'static, Result<bool, Self::Error>> {
+        Box::pin(async move { Ok(revoke(&mut *self.map.lock().await, user_id, &role)) })
+    }
+}
+
+/// An error returned from [`FileRoles`].
+#[derive(Debug, Error)]
+pub enum FileRolesError {
+    /// Reading or writing the file has failed.
+    #[error("file error: {0}")]
+    Io(#[from] io::Error),
+
+    /// The file has an invalid format.
+    #[error("format error: {0}")]
+    Format(#[from] serde_json::Error),
+}
+
+/// A role store which keeps roles in a JSON file.
+///
+/// The whole file is rewritten on every change, so this store is meant for a
+/// moderate number of users.
+#[derive(Debug)]
+pub struct FileRoles {
+    path: PathBuf,
+    map: Mutex<RoleMap>,
+}
+
+impl FileRoles {
+    /// Opens a store at `path`, creating an empty one if the file doesn't
+    /// exist.
+    pub async fn open<P>(path: P) -> Result<Arc<Self>, FileRolesError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref().to_owned();
+        let map = match tokio::fs::read(&path).await {
+            Ok(contents) => serde_json::from_slice(&contents)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => RoleMap::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Arc::new(Self { path, map: Mutex::new(map) }))
+    }
+
+    async fn save(&self, map: &RoleMap) -> Result<(), FileRolesError> {
+        // Write to a temporary file first, so that the store isn't corrupted if
+        // the bot crashes in the middle of writing
+        let tmp = self.path.with_extension("tmp");
+        tokio::fs::write(&tmp, serde_json::to_vec_pretty(map)?).await?;
+        tokio::fs::rename(&tmp, &self.path).await?;
+        Ok(())
+    }
+}
+
+impl Roles for FileRoles {
+    type Error = FileRolesError;
+
+    fn roles(
+        self: Arc<Self>,
+        user_id: UserId,
+    ) -> BoxFuture<'static, Result<BTreeSet<String>, Self::Error>> {
+        Box::pin(
+            async move { Ok(self.map.lock().await.get(&user_id).cloned().unwrap_or_default()) },
+        )
+    }
+
+    fn grant(
+        self: Arc<Self>,
+        user_id: UserId,
+        role: String,
+    ) -> BoxFuture<'static, Result<bool, Self::Error>> {
+        Box::pin(async move {
+            let mut map = self.map.lock().await;
+            let granted = grant(&mut map, user_id, role);
+            if granted {
+                self.save(&map).await?;
+            }
+            Ok(granted)
+        })
+    }
+
+    fn revoke(
+        self: Arc<Self>,
+        user_id: UserId,
+        role: String,
+    ) -> BoxFuture<'static, Result<bool, Self::Error>> {
+        Box::pin(async move {
+            let mut map = self.map.lock().await;
+            let revoked = revoke(&mut map, user_id, &role);
+            if revoked {
+                self.save(&map).await?;
+            }
+            Ok(revoked)
+        })
+    }
+}
+
+/// Returns a handler that accepts updates from users with `role`.
+///
+/// A call to this function is the same as
+/// `dptree::entry().require_role::<S>(role)`.
+///
+/// See [`HandlerExt::require_role`].
+///
+/// ## Dependency requirements
+///
+///  - [`crate::types::Update`]
+///  - `Arc<S>`
+///
+/// [`HandlerExt::require_role`]: crate::dispatching::HandlerExt::require_role
+#[must_use]
+#[track_caller]
+pub fn require_role<S, Output>(
+    role: impl Into<String>,
+) -> Handler<'static, DependencyMap, Output, DpHandlerDescription>
+where
+    S: Roles + ?Sized + Send + Sync + 'static,
+    S::Error: Debug + Send,
+    Output: Send + Sync + 'static,
+{
+    let role: Arc<str> = role.into().into();
+
+    let description = DpHandlerDescription::filter_async().labelled("require_role");
+    dptree::filter_async_with_description(description, move |update: Update, roles: Arc<S>| {
+        let role = Arc::clone(&role);
+        async move {
+            let Some(user) = update.from() else { return false };
+
+            match roles.roles(user.id).await {
+                Ok(roles) => roles.contains(&*role),
+                Err(err) => {
+                    log::error!("Couldn't get roles of {}: {err:?}", user.id);
+                    false
+                }
+            }
+        }
+    })
+}
+
+/// Commands for managing roles at runtime.
+///
+/// Note that these commands don't check permissions by themselves, so make
+/// sure to guard them, e.g. with [`HandlerExt::require_role`].
+///
+/// [`HandlerExt::require_role`]: crate::dispatching::HandlerExt::require_role
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RoleCommand {
+    /// `/grant <user id> <role>`
+    Grant { user_id: UserId, role: String },
+
+    /// `/revoke <user id> <role>`
+    Revoke { user_id: UserId, role: String },
+
+    /// `/roles <user id>`
+    Roles { user_id: UserId },
+}
+
+impl RoleCommand {
+    /// Executes the command, returning a reply for the user.
+    pub async fn execute<S>(self, roles: Arc<S>) -> Result<String, S::Error>
+    where
+        S: Roles + ?Sized,
+    {
+        let reply = match self {
+            Self::Grant { user_id, role } => {
+                if roles.grant(user_id, role.clone()).await? {
+                    format!("Granted {role} to {user_id}")
+                } else {
+                    format!("{user_id} already has {role}")
+                }
+            }
+            Self::Revoke { user_id, role } => {
+                if roles.revoke(user_id, role.clone()).await? {
+                    format!("Revoked {role} from {user_id}")
+                } else {
+                    format!("{user_id} doesn't have {role}")
+                }
+            }
+            Self::Roles { user_id } => {
+                let roles = roles.roles(user_id).await?;
+                if roles.is_empty() {
+                    format!("{user_id} has no roles")
+                } else {
+                    let roles: Vec<_> = roles.into_iter().collect();
+                    format!("{user_id} has {}", roles.join(", "))
+                }
+            }
+        };
+
+        Ok(reply)
+    }
+}
+
+const DESCRIPTIONS: &[CommandDescription<'static>] = &[
+    CommandDescription {
+        prefix: "/",
+        command: "grant",
+        aliases: &[],
+        description: "grant a role to a user",
+    },
+    CommandDescription {
+        prefix: "/",
+        command: "revoke",
+        aliases: &[],
+        description: "revoke a role from a user",
+    },
+    CommandDescription {
+        prefix: "/",
+        command: "roles",
+        aliases: &[],
+        description: "list roles of a user",
+    },
+];
+
+impl BotCommands for RoleCommand {
+    fn parse(s: &str, bot_username: &str) -> Result<Self, ParseError> {
+        let (command, args) = parse_command(s, bot_username)
+            .ok_or_else(|| ParseError::UnknownCommand(s.to_owned()))?;
+
+        let expected = match command {
+            "grant" | "revoke" => 2,
+            "roles" => 1,
+            _ => return Err(ParseError::UnknownCommand(format!("/{command}"))),
+        };
+        match args.len() {
+            found if found < expected => {
+                return Err(ParseError::TooFewArguments { expected, found, message: s.to_owned() })
+            }
+            found if found > expected => {
+                return Err(ParseError::TooManyArguments { expected, found, message: s.to_owned() })
+            }
+            _ => {}
+        }
+
+        let user_id =
+            UserId(args[0].parse().map_err(|err| ParseError::IncorrectFormat(Box::new(err)))?);
+
+        Ok(match command {
+            "grant" => Self::Grant { user_id, role: args[1].to_owned() },
+            "revoke" => Self::Revoke { user_id, role: args[1].to_owned() },
+            _ => Self::Roles { user_id },
+        })
+    }
+
+    fn descriptions() -> CommandDescriptions<'static> {
+        CommandDescriptions::new(DESCRIPTIONS)
+    }
+
+    fn bot_commands() -> Vec<BotCommand> {
+        DESCRIPTIONS.iter().map(|d| BotCommand::new(d.command, d.description)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn in_mem_roles() {
+        let roles = InMemRoles::new();
+
+        assert!(roles.clone().grant(UserId(1), "staff".to_owned()).await.unwrap());
+        assert!(!roles.clone().grant(UserId(1), "staff".to_owned()).await.unwrap());
+        assert!(roles.clone().has_role(UserId(1), "staff".to_owned()).await.unwrap());
+        assert!(!roles.clone().has_role(UserId(2), "staff".to_owned()).await.unwrap());
+
+        assert!(roles.clone().revoke(UserId(1), "staff".to_owned()).await.unwrap());
+        assert!(!roles.clone().revoke(UserId(1), "staff".to_owned()).await.unwrap());
+        assert!(roles.roles(UserId(1)).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn file_roles() {
+        let path = std::env::temp_dir().join(format!("teloxide-roles-{}.json", std::process::id()));
+
+        let roles = FileRoles::open(&path).await.unwrap();
+        roles.clone().grant(UserId(1), "staff".to_owned()).await.unwrap();
+        roles.grant(UserId(1), "admin".to_owned()).await.unwrap();
+
+        let roles = FileRoles::open(&path).await.unwrap();
+        let expected: BTreeSet<_> = ["admin".to_owned(), "staff".to_owned()].into();
+        assert_eq!(roles.roles(UserId(1)).await.unwrap(), expected);
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn commands() {
+        let roles = InMemRoles::new();
+
+        let cmd = RoleCommand::parse("/grant@bot 1 staff", "bot").unwrap();
+        assert_eq!(cmd, RoleCommand::Grant { user_id: UserId(1), role: "staff".to_owned() });
+        assert_eq!(cmd.execute(roles.clone()).await.unwrap(), "Granted staff to 1");
+
+        let cmd = RoleCommand::parse("/roles 1", "bot").unwrap();
+        assert_eq!(cmd.execute(roles).await.unwrap(), "1 has staff");
+
+        assert!(matches!(
+            RoleCommand::parse("/grant 1", "bot"),
+            Err(ParseError::TooFewArguments { expected: 2, found: 1, .. })
+        ));
+        assert!(matches!(
+            RoleCommand::parse("/grant x staff", "bot"),
+            Err(ParseError::IncorrectFormat(_))
+        ));
+        assert!(matches!(RoleCommand::parse("/start", "bot"), Err(ParseError::UnknownCommand(_))));
+    }
+}
@@ -129,7 +129,7 @@
 #![allow(clippy::nonstandard_macro_braces)]
 
 #[cfg(feature = "ctrlc_handler")]
-pub use repls::{repl, repl_with_listener};
+pub use repls::{commands_repl, commands_repl_with_listener, repl, repl_with_listener};
 
 pub mod backoff;
 pub mod dispatching;
@@ -138,6 +138,8 @@ pub mod prelude;
 #[cfg(feature = "ctrlc_handler")]
 pub mod repls;
 pub mod stop;
+#[cfg(any(test, feature = "mock-bot"))]
+pub mod test;
 pub mod update_listeners;
 pub mod utils;
 
@@ -11,5 +11,5 @@
 mod commands_repl;
 mod repl;
 
-pub use commands_repl::CommandReplExt;
+pub use commands_repl::{commands_repl, commands_repl_with_listener, CommandReplExt};
 pub use repl::{repl, repl_with_listener};
@@ -147,3 +147,99 @@ where
         })
     }
 }
+
+/// A [REPL] for commands.
+///
+/// This is the same as [`CommandReplExt::repl`], but the command type is
+/// specified with a turbofish instead of being the receiver:
+///
+/// ```no_run
+/// use teloxide::{prelude::*, utils::command::BotCommands};
+///
+/// #[derive(BotCommands, Clone)]
+/// #[command(rename_rule = "lowercase")]
+/// enum Command {
+///     Help,
+/// }
+///
+/// # async fn run() {
+/// let bot = Bot::from_env();
+///
+/// teloxide::commands_repl::<Command, _, _, _>(
+///     bot,
+///     |bot: Bot, msg: Message, cmd: Command| async move {
+///         match cmd {
+///             Command::Help => {
+///                 bot.send_message(msg.chat.id, Command::descriptions().to_string()).await?
+///             }
+///         };
+///         Ok(())
+///     },
+/// )
+/// .await;
+/// # }
+/// ```
+///
+/// See [`CommandReplExt`] for more details.
+///
+/// [REPL]: https://en.wikipedia.org/wiki/Read-eval-print_loop
+#[cfg(feature = "ctrlc_handler")]
+pub async fn commands_repl<Cmd, R, H, Args>(bot: R, handler: H)
+where
+    Cmd: BotCommands + Send + Sync + 'static,
+    R: Requester + Clone + Send + Sync + 'static,
+    <R as Requester>::GetUpdates: Send,
+    <R as Requester>::GetWebhookInfo: Send,
+    <R as Requester>::GetMe: Send,
+    <R as Requester>::DeleteWebhook: Send,
+    H: Injectable<DependencyMap, ResponseResult<()>, Args> + Send + Sync + 'static,
+{
+    Cmd::repl(bot, handler).await
+}
+
+/// A [REPL] for commands, with a custom [`UpdateListener`].
+///
+/// This is the same as [`CommandReplExt::repl_with_listener`], but the command
+/// type is specified with a turbofish instead of being the receiver.
+///
+/// See [`CommandReplExt`] for more details.
+///
+/// [REPL]: https://en.wikipedia.org/wiki/Read-eval-print_loop
+#[cfg(feature = "ctrlc_handler")]
+pub async fn commands_repl_with_listener<'a, Cmd, R, H, L, Args>(bot: R, handler: H, listener: L)
+where
+    Cmd: BotCommands + Send + Sync + 'static,
+    H: Injectable<DependencyMap, ResponseResult<()>, Args> + Send + Sync + 'static,
+    L: UpdateListener + Send + 'a,
+    L::Err: Debug + Send + 'a,
+    R: Requester + Clone + Send + Sync + 'static,
+    <R as Requester>::GetMe: Send,
+{
+    Cmd::repl_with_listener(bot, handler, listener).await
+}
+
+#[test]
+fn commands_repl_is_send() {
+    #[derive(Clone)]
+    enum Command {}
+
+    impl BotCommands for Command {
+        fn parse(_: &str, _: &str) -> Result<Self, crate::utils::command::ParseError> {
+            unreachable!()
+        }
+
+        fn descriptions() -> crate::utils::command::CommandDescriptions<'static> {
+            unreachable!()
+        }
+
+        fn bot_commands() -> Vec<crate::types::BotCommand> {
+            unreachable!()
+        }
+    }
+
+    let bot = crate::Bot::new("");
+    let repl = commands_repl::<Command, _, _, _>(bot, || async { Ok(()) });
+    assert_send(&repl);
+
+    fn assert_send(_: &impl Send) {}
+}
@@ -0,0 +1,112 @@
+//! Utils for testing bots without access to Telegram.
+//!
+//! [`MockBot`] records requests instead of sending them and responds with
+//! scripted responses, and [`update_listener`] feeds synthetic updates into a
+//! [`Dispatcher`]. Together they allow unit-testing handlers:
+//!
+//! ```
+//! use teloxide::{
+//!     payloads::{GetMe, SendMessage},
+//!     prelude::*,
+//!     test::{self, MockBot},
+//!     types::Me,
+//! };
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let bot = MockBot::new();
+//! let me: Me = serde_json::from_str(
+//!     r#"{"id":1,"is_bot":true,"first_name":"Bot","username":"bot",
+//!         "can_join_groups":true,"can_read_all_group_messages":false,
+//!         "supports_inline_queries":false}"#,
+//! )
+//! .unwrap();
+//! bot.respond::<GetMe>(me);
+//!
+//! let update: Update = serde_json::from_str(
+//!     r#"{"update_id":1,"message":{"message_id":1,"date":0,"text":"hi",
+//!         "chat":{"id":42,"type":"private","first_name":"A"},
+//!         "from":{"id":42,"is_bot":false,"first_name":"A"}}}"#,
+//! )
+//! .unwrap();
+//!
+//! let handler = Update::filter_message().endpoint(|bot: MockBot, msg: Message| async move {
+//!     // The response is not scripted, so ignore the error
+//!     let _ = bot.send_message(msg.chat.id, "hello").await;
+//!     respond(())
+//! });
+//!
+//! test::dispatch(Dispatcher::builder(bot.clone(), handler).build(), [update]).await;
+//!
+//! assert_eq!(bot.requests_of::<SendMessage>()[0].text, "hello");
+//! # }
+//! ```
+//!
+//! With the `test-fixtures` feature enabled, values such as `Me` and `Update`
+//! can be built with fixture constructors (e.g. `Update::message_fixture`)
+//! instead of JSON, and [`mock_bot`] returns a bot with the response to
+//! `GetMe` already scripted.
+//!
+//! [`Dispatcher`]: crate::dispatching::Dispatcher
+
+use std::{convert::Infallible, fmt::Debug, hash::Hash};
+
+use futures::stream;
+
+use crate::{
+    dispatching::Dispatcher,
+    error_handlers::LoggingErrorHandler,
+    requests::Requester,
+    stop::mk_stop_token,
+    types::Update,
+    update_listeners::{StatefulListener, UpdateListener},
+};
+
+pub use teloxide_core::adaptors::{mock::MockRequest, MockBot};
+
+/// Returns an update listener which yields `updates` and then stops.
+pub fn update_listener<I>(updates: I) -> impl UpdateListener<Err = Infallible>
+where
+    I: IntoIterator<Item = Update>,
+{
+    let updates: Vec<_> = updates.into_iter().collect();
+
+    StatefulListener::new(
+        updates,
+        |updates: &mut Vec<Update>| stream::iter(std::mem::take(updates).into_iter().map(Ok)),
+        |_: &mut _| mk_stop_token().0,
+    )
+}
+
+/// Returns a [`MockBot`] which responds to [`GetMe`] with [`Me::fixture`], as
+/// needed to start a [`Dispatcher`].
+///
+/// [`GetMe`]: crate::payloads::GetMe
+/// [`Me::fixture`]: crate::types::Me::fixture
+#[cfg(any(test, feature = "test-fixtures"))]
+#[must_use]
+pub fn mock_bot() -> MockBot {
+    let bot = MockBot::new();
+    bot.respond::<crate::payloads::GetMe>(crate::types::Me::fixture());
+    bot
+}
+
+/// Dispatches `updates` and waits until all of them are handled.
+///
+/// Note that a dispatcher calls [`GetMe`] before dispatching, so a response to
+/// it must be scripted when using [`MockBot`].
+///
+/// [`GetMe`]: crate::payloads::GetMe
+pub async fn dispatch<R, Err, Key, I>(mut dispatcher: Dispatcher<R, Err, Key>, updates: I)
+where
+    R: Requester + Clone + Send + Sync + 'static,
+    R::Err: Debug,
+    Err: Send + Sync + 'static,
+    Key: Hash + Eq + Clone,
+    I: IntoIterator<Item = Update>,
+{
+    dispatcher
+        .try_dispatch_with_listener(update_listener(updates), LoggingErrorHandler::new())
+        .await
+        .expect("Couldn't prepare dispatching context")
+}
@@ -36,6 +36,9 @@ use crate::{
     types::{AllowedUpdate, Update},
 };
 
+pub mod multi_bot;
+pub mod recording;
+
 mod polling;
 mod stateful_listener;
 
@@ -0,0 +1,327 @@
+//! Receiving updates of several bots at once.
+//!
+//! [`MultiBotListener`] merges update listeners of several bots (with
+//! different tokens) into a single listener. When it is passed to
+//! [`Dispatcher::dispatch_with_multi_bot_listener`], each update is handled
+//! with the bot which has received it as a dependency, instead of the one the
+//! dispatcher was built with, so that all the bots share the same handler tree:
+//!
+//! ```no_run
+//! use teloxide::{
+//!     prelude::*,
+//!     update_listeners::{self, multi_bot::MultiBotListener},
+//! };
+//!
+//! # async fn run() {
+//! let first = Bot::new("FIRST_TOKEN");
+//! let second = Bot::new("SECOND_TOKEN");
+//!
+//! let listener = MultiBotListener::new()
+//!     .add(first.clone(), update_listeners::polling_default(first.clone()).await)
+//!     .add(second.clone(), update_listeners::polling_default(second).await);
+//!
+//! let handler = Update::filter_message().endpoint(|bot: Bot, msg: Message| async move {
+//!     // `bot` is the bot which has received `msg`
+//!     bot.send_message(msg.chat.id, "Hi!").await?;
+//!     respond(())
+//! });
+//!
+//! Dispatcher::builder(first, handler)
+//!     .build()
+//!     .dispatch_with_multi_bot_listener(listener, LoggingErrorHandler::new())
+//!     .await;
+//! # }
+//! ```
+//!
+//! The [`Me`] dependency is replaced in the same way: it is fetched for each
+//! bot before dispatching starts, so that e.g. [`HandlerExt::filter_command`]
+//! accepts commands addressed to any of the bots (`/start@second_bot`).
+//!
+//! [`Dispatcher::dispatch_with_multi_bot_listener`]: crate::dispatching::Dispatcher::dispatch_with_multi_bot_listener
+//! [`Me`]: crate::types::Me
+//! [`HandlerExt::filter_command`]: crate::dispatching::HandlerExt::filter_command
+
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use dptree::di::DependencyMap;
+use futures::{
+    stream::{BoxStream, SelectAll},
+    Future, Stream, StreamExt,
+};
+
+use crate::{
+    requests::{Request, Requester},
+    stop::{mk_stop_token, StopFlag, StopToken},
+    types::{AllowedUpdate, Me, Update},
+    update_listeners::UpdateListener,
+};
+
+/// An update listener which merges updates of several bots.
+///
+/// All the listeners must have the same error type.
+///
+/// See the [module-level documentation](self).
+#[must_use = "`MultiBotListener` does nothing unless passed to a dispatcher"]
+pub struct MultiBotListener<R, Err> {
+    listeners: Vec<Box<dyn TaggedListener<R, Err>>>,
+    token: StopToken,
+    flag: StopFlag,
+}
+
+impl<R, Err> MultiBotListener<R, Err>
+where
+    R: Clone + Send + Sync + 'static,
+    Err: 'static,
+{
+    /// Creates a listener without any bots.
+    pub fn new() -> Self {
+        let (token, flag) = mk_stop_token();
+        Self { listeners: Vec::new(), token, flag }
+    }
+
+    /// Adds `listener`, which receives updates of `bot`.
+    pub fn add<L>(mut self, bot: R, listener: L) -> Self
+    where
+        L: UpdateListener<Err = Err> + Send + 'static,
+    {
+        self.listeners.push(Box::new(Tagged { bot, me: None, listener }));
+        self
+    }
+}
+
+impl<R, Err> Default for MultiBotListener<R, Err>
+where
+    R: Clone + Send + Sync + 'static,
+    Err: 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R, Err> fmt::Debug for MultiBotListener<R, Err> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiBotListener").field("bots", &self.listeners.len()).finish()
+    }
+}
+
+impl<R, Err> MultiBotListener<R, Err>
+where
+    Err: Send + 'static,
+{
+    /// Fetches [`Me`] of every bot, to be added to the dependencies of their
+    /// updates.
+    pub(crate) async fn fetch_me(&mut self) -> Result<(), R::Err>
+    where
+        R: Requester + Clone,
+    {
+        let bots: Vec<R> = self.listeners.iter().map(|listener| listener.bot().clone()).collect();
+        for (listener, bot) in self.listeners.iter_mut().zip(bots) {
+            listener.set_me(bot.get_me().send().await?);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn stop_token(&mut self) -> StopToken {
+        self.token.clone()
+    }
+
+    pub(crate) fn hint_allowed_updates(&mut self, hint: &mut dyn Iterator<Item = AllowedUpdate>) {
+        let hint: Vec<_> = hint.collect();
+        for listener in &mut self.listeners {
+            listener.hint_allowed_updates(&mut hint.iter().copied());
+        }
+    }
+
+    /// Returns a stream of updates, each with the dependencies containing the
+    /// bot which has received it (and its [`Me`], if it has been fetched).
+    pub(crate) fn as_stream(&mut self) -> MultiBotStream<'_, Err> {
+        let tokens = self.listeners.iter_mut().map(|listener| listener.stop_token()).collect();
+        let streams =
+            futures::stream::select_all(self.listeners.iter_mut().map(|l| l.tagged_stream()));
+
+        MultiBotStream { streams, flag: Some(self.flag.clone()), tokens }
+    }
+}
+
+/// The update stream of a [`MultiBotListener`].
+///
+/// When the listener is stopped, all the merged listeners are stopped, and the
+/// stream ends after all of them end.
+#[pin_project::pin_project]
+pub(crate) struct MultiBotStream<'a, Err> {
+    #[pin]
+    streams: SelectAll<BoxStream<'a, Result<(Update, DependencyMap), Err>>>,
+    flag: Option<StopFlag>,
+    tokens: Vec<StopToken>,
+}
+
+impl<Err> Stream for MultiBotStream<'_, Err> {
+    type Item = Result<(Update, DependencyMap), Err>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if let Some(flag) = this.flag {
+            if Pin::new(flag).poll(cx).is_ready() {
+                this.tokens.iter().for_each(StopToken::stop);
+                *this.flag = None;
+            }
+        }
+
+        this.streams.poll_next(cx)
+    }
+}
+
+/// An object-safe version of [`UpdateListener`], which adds the bot to the
+/// dependencies of updates.
+trait TaggedListener<R, Err>: Send {
+    fn bot(&self) -> &R;
+
+    fn set_me(&mut self, me: Me);
+
+    fn stop_token(&mut self) -> StopToken;
+
+    fn hint_allowed_updates(&mut self, hint: &mut dyn Iterator<Item = AllowedUpdate>);
+
+    fn tagged_stream(&mut self) -> BoxStream<'_, Result<(Update, DependencyMap), Err>>;
+}
+
+struct Tagged<R, L> {
+    bot: R,
+    me: Option<Me>,
+    listener: L,
+}
+
+impl<R, L> TaggedListener<R, L::Err> for Tagged<R, L>
+where
+    R: Clone + Send + Sync + 'static,
+    L: UpdateListener + Send,
+{
+    fn bot(&self) -> &R {
+        &self.bot
+    }
+
+    fn set_me(&mut self, me: Me) {
+        self.me = Some(me);
+    }
+
+    fn stop_token(&mut self) -> StopToken {
+        self.listener.stop_token()
+    }
+
+    fn hint_allowed_updates(&mut self, hint: &mut dyn Iterator<Item = AllowedUpdate>) {
+        self.listener.hint_allowed_updates(hint)
+    }
+
+    fn tagged_stream(&mut self) -> BoxStream<'_, Result<(Update, DependencyMap), L::Err>> {
+        let (bot, me) = (&self.bot, &self.me);
+        let deps = move || {
+            let mut deps = dptree::deps![bot.clone()];
+            if let Some(me) = me {
+                deps.insert(me.clone());
+            }
+            deps
+        };
+
+        self.listener.as_stream().map(move |update| update.map(|update| (update, deps()))).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dptree::di::DependencySupplier;
+
+    use crate::{
+        dispatching::{roles::RoleCommand, Dispatcher, HandlerExt, UpdateFilterExt},
+        error_handlers::LoggingErrorHandler,
+        payloads::{GetMe, SendMessage},
+        test::{self, MockBot},
+        types::{ChatId, Message, UserId},
+    };
+
+    fn listener(texts: &[&str]) -> impl UpdateListener<Err = std::convert::Infallible> {
+        let updates: Vec<_> =
+            texts.iter().map(|text| Update::message_fixture(ChatId(1), UserId(1), *text)).collect();
+        test::update_listener(updates)
+    }
+
+    #[tokio::test]
+    async fn adds_bots_to_dependencies() {
+        let mut listener =
+            MultiBotListener::new().add(1, listener(&["a", "b"])).add(2, listener(&["c"]));
+
+        let mut bots: Vec<_> = listener
+            .as_stream()
+            .map(|res| {
+                let (_, deps) = res.unwrap();
+                *DependencySupplier::<i32>::get(&deps)
+            })
+            .collect()
+            .await;
+        bots.sort();
+
+        assert_eq!(bots, [1, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn injects_bots() {
+        let (primary, first, second) = (test::mock_bot(), test::mock_bot(), test::mock_bot());
+        for (bot, n) in [(&first, 2), (&second, 1)] {
+            for _ in 0..n {
+                bot.respond::<SendMessage>(Message::text_fixture(ChatId(1), UserId(1), "hi"));
+            }
+        }
+
+        let listener = MultiBotListener::new()
+            .add(first.clone(), listener(&["a", "b"]))
+            .add(second.clone(), listener(&["c"]));
+        let handler = Update::filter_message().endpoint(|bot: MockBot, msg: Message| async move {
+            bot.send_message(msg.chat.id, "hi").await.map(drop)
+        });
+
+        Dispatcher::builder(primary.clone(), handler)
+            .build()
+            .dispatch_with_multi_bot_listener(listener, LoggingErrorHandler::new())
+            .await;
+
+        assert_eq!(primary.request_names(), ["GetMe"]);
+        assert_eq!(first.requests_of::<SendMessage>().len(), 2);
+        assert_eq!(second.requests_of::<SendMessage>().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn injects_me() {
+        let (primary, first, second) = (test::mock_bot(), test::mock_bot(), MockBot::new());
+        let mut second_me = Me::fixture();
+        second_me.user.id = UserId(2);
+        second_me.user.username = Some("second_bot".to_owned());
+        second.respond::<GetMe>(second_me);
+        second.respond::<SendMessage>(Message::text_fixture(ChatId(1), UserId(1), "hi"));
+
+        // Each bot only accepts commands addressed to itself
+        let listener = MultiBotListener::new()
+            .add(first.clone(), listener(&["/roles@second_bot 5"]))
+            .add(second.clone(), listener(&["/roles@second_bot 5"]));
+        let handler = Update::filter_message().filter_command::<RoleCommand>().endpoint(
+            |bot: MockBot, msg: Message| async move {
+                bot.send_message(msg.chat.id, "hi").await.map(drop)
+            },
+        );
+
+        Dispatcher::builder(primary, handler)
+            .default_handler(|_| async {})
+            .build()
+            .dispatch_with_multi_bot_listener(listener, LoggingErrorHandler::new())
+            .await;
+
+        assert_eq!(first.request_names(), ["GetMe"]);
+        assert_eq!(second.request_names(), ["GetMe", "SendMessage"]);
+    }
+}
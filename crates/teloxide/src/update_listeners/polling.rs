@@ -82,6 +82,11 @@ where
     }
 
     /// Drops pending updates.
+    ///
+    /// Updates which were sent to the bot before the polling has started are
+    /// skipped (acknowledged without being yielded). This is done only once,
+    /// when the listener is first used as a stream, so updates aren't dropped
+    /// if the polling is restarted.
     pub fn drop_pending_updates(self) -> Self {
         Self { drop_pending_updates: true, ..self }
     }
@@ -118,6 +123,7 @@ where
             limit,
             allowed_updates,
             drop_pending_updates,
+            offset: 0,
             flag: Some(flag),
             token,
             stop_token_cloned: false,
@@ -187,6 +193,12 @@ where
 /// `timeout = 0, limit = 1` and appropriate `offset`, so future bot
 /// restarts won't see updates that were already seen.
 ///
+/// The `offset` is only advanced for updates that were yielded from the
+/// stream. If the stream is dropped (e.g. the in-flight request is cancelled
+/// by a shutdown) updates that were received, but not yielded, are not
+/// acknowledged, so they are received again by the next stream or the next
+/// bot start.
+///
 /// Consumers of a [`Polling`] update listener then need to repeatedly call
 /// [`futures::StreamExt::next`] to get the updates.
 ///
@@ -245,6 +257,8 @@ pub struct Polling<B: Requester> {
     limit: Option<u8>,
     allowed_updates: Option<Vec<AllowedUpdate>>,
     drop_pending_updates: bool,
+    /// Offset of the first update which was not yielded yet.
+    offset: i32,
     flag: Option<StopFlag>,
     token: StopToken,
     stop_token_cloned: bool,
@@ -290,15 +304,10 @@ pub struct PollingStream<'a, B: Requester> {
     /// Parent structure
     polling: &'a mut Polling<B>,
 
-    /// Whatever to drop pending updates or not.
-    drop_pending_updates: bool,
-
     /// Timeout parameter for normal `get_updates()` calls.
     timeout: Option<u32>,
     /// Allowed updates parameter for the first `get_updates()` call.
     allowed_updates: Option<Vec<AllowedUpdate>>,
-    /// Offset parameter  for normal `get_updates()` calls.
-    offset: i32,
 
     /// If this is set, return `None` from `poll_next` immediately.
     force_stop: bool,
@@ -348,7 +357,6 @@ impl<'a, B: Requester + Send + 'a> AsUpdateStream<'a> for Polling<B> {
     fn as_stream(&'a mut self) -> Self::Stream {
         let timeout = self.timeout.map(|t| t.as_secs().try_into().expect("timeout is too big"));
         let allowed_updates = self.allowed_updates.clone();
-        let drop_pending_updates = self.drop_pending_updates;
 
         let token_used_and_updated = self.reinit_stop_flag_if_needed();
 
@@ -366,10 +374,8 @@ impl<'a, B: Requester + Send + 'a> AsUpdateStream<'a> for Polling<B> {
         let flag = self.flag.take().unwrap();
         PollingStream {
             polling: self,
-            drop_pending_updates,
             timeout,
             allowed_updates,
-            offset: 0,
             force_stop: false,
             stopping: false,
             buffer: Vec::new().into_iter(),
@@ -394,7 +400,7 @@ impl<B: Requester> Stream for PollingStream<'_, B> {
 
         // If there are any buffered updates, return one
         if let Some(upd) = this.buffer.next() {
-            return Ready(Some(Ok(upd)));
+            return yield_update(this.polling, upd);
         }
 
         // Check if we should stop and if so — drop in flight request,
@@ -426,13 +432,14 @@ impl<B: Requester> Stream for PollingStream<'_, B> {
                     // Once we got the update hense the backoff reconnection strategy worked
                     *this.error_count = 0;
 
-                    if let Some(upd) = updates.last() {
-                        *this.offset = upd.id.as_offset();
-                    }
-
-                    match *this.drop_pending_updates {
-                        false => *this.buffer = updates.into_iter(),
-                        true => *this.drop_pending_updates = false,
+                    if this.polling.drop_pending_updates {
+                        // Skip the pending updates, this is done only once per `Polling`
+                        if let Some(upd) = updates.last() {
+                            this.polling.offset = upd.id.as_offset();
+                        }
+                        this.polling.drop_pending_updates = false;
+                    } else {
+                        *this.buffer = updates.into_iter();
                     }
                 }
                 Err(err) => {
@@ -454,9 +461,15 @@ impl<B: Requester> Stream for PollingStream<'_, B> {
             this.eepy.as_mut().set(None);
         }
 
-        let (offset, limit, timeout) = match (this.stopping, this.drop_pending_updates) {
+        // Return received updates before requesting new ones, the next `get_updates()`
+        // call must only acknowledge the updates that were already yielded
+        if let Some(upd) = this.buffer.next() {
+            return yield_update(this.polling, upd);
+        }
+
+        let (offset, limit, timeout) = match (*this.stopping, this.polling.drop_pending_updates) {
             // Normal `get_updates()` call
-            (false, false) => (*this.offset, this.polling.limit, *this.timeout),
+            (false, false) => (this.polling.offset, this.polling.limit, *this.timeout),
             // Graceful shutdown `get_updates()` call (shutdown takes priority over dropping pending
             // updates)
             //
@@ -464,7 +477,7 @@ impl<B: Requester> Stream for PollingStream<'_, B> {
             // set last seen update (offset) and return immediately
             (true, _) => {
                 log::trace!("graceful shutdown `get_updates` call");
-                (*this.offset, Some(1), Some(0))
+                (this.polling.offset, Some(1), Some(0))
             }
             // Drop pending updates
             (_, true) => (-1, Some(1), Some(0)),
@@ -490,6 +503,19 @@ impl<B: Requester> Stream for PollingStream<'_, B> {
     }
 }
 
+/// Marks `upd` as yielded, so that it's acknowledged by the next
+/// `get_updates()` call.
+///
+/// Updates are acknowledged only after they were yielded, so that they are not
+/// lost if the stream is dropped.
+fn yield_update<B: Requester>(
+    polling: &mut Polling<B>,
+    upd: Update,
+) -> Poll<Option<Result<Update, B::Err>>> {
+    polling.offset = upd.id.as_offset();
+    Ready(Some(Ok(upd)))
+}
+
 #[test]
 fn polling_is_send() {
     let bot = crate::Bot::new("TOKEN");
@@ -502,3 +528,43 @@ fn polling_is_send() {
 
     fn assert_send(_: &impl Send) {}
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use crate::{
+        adaptors::MockBot,
+        payloads::GetUpdates,
+        types::{ChatId, Update, UpdateId, UserId},
+        update_listeners::{AsUpdateStream, Polling},
+    };
+
+    fn update(id: u32) -> Update {
+        Update { id: UpdateId(id), ..Update::message_fixture(ChatId(1), UserId(1), "text") }
+    }
+
+    #[tokio::test]
+    async fn acknowledges_yielded_updates() {
+        let bot = MockBot::new();
+        // Pending update, which is dropped
+        bot.respond::<GetUpdates>(vec![update(5)]);
+        bot.respond::<GetUpdates>(vec![update(6), update(7)]);
+        bot.respond::<GetUpdates>(vec![update(7)]);
+
+        let mut polling = Polling::builder(bot.clone()).drop_pending_updates().build();
+
+        let mut stream = Box::pin(polling.as_stream());
+        assert_eq!(stream.next().await.unwrap().unwrap().id, UpdateId(6));
+        // The stream is dropped before update 7 is yielded
+        drop(stream);
+
+        let mut stream = Box::pin(polling.as_stream());
+        assert_eq!(stream.next().await.unwrap().unwrap().id, UpdateId(7));
+        drop(stream);
+
+        let offsets: Vec<_> =
+            bot.requests_of::<GetUpdates>().into_iter().map(|req| req.offset).collect();
+        assert_eq!(offsets, [Some(-1), Some(6), Some(7)]);
+    }
+}
@@ -0,0 +1,505 @@
+//! Recording and replaying updates.
+//!
+//! [`Recorder`] wraps an update listener and appends every received update to
+//! a [JSON Lines] file. [`Replay`] reads such a file back and feeds the updates
+//! into a [`Dispatcher`], at the original or accelerated speed. This makes it
+//! possible to reproduce production incidents locally:
+//!
+//! ```no_run
+//! use teloxide::{
+//!     prelude::*,
+//!     update_listeners::{polling_default, recording::Recorder},
+//! };
+//!
+//! # async fn run(handler: teloxide::dispatching::UpdateHandler<teloxide::RequestError>) -> std::io::Result<()> {
+//! let bot = Bot::from_env();
+//! let listener = Recorder::new(polling_default(bot.clone()).await, "updates.jsonl")?;
+//!
+//! Dispatcher::builder(bot, handler)
+//!     .build()
+//!     .dispatch_with_listener(listener, LoggingErrorHandler::new())
+//!     .await;
+//! # Ok(()) }
+//! ```
+//!
+//! And later, locally:
+//!
+//! ```no_run
+//! use teloxide::{prelude::*, update_listeners::recording::Replay};
+//!
+//! # async fn run(handler: teloxide::dispatching::UpdateHandler<teloxide::RequestError>) -> Result<(), Box<dyn std::error::Error>> {
+//! let bot = Bot::from_env();
+//! let listener = Replay::from_file("updates.jsonl")?.speed(10.0).into_listener();
+//!
+//! Dispatcher::builder(bot, handler)
+//!     .build()
+//!     .dispatch_with_listener(listener, LoggingErrorHandler::new())
+//!     .await;
+//! # Ok(()) }
+//! ```
+//!
+//! Requests sent by a bot and responses to them can be recorded as well, with a
+//! [`RequestRecorder`]:
+//!
+//! ```no_run
+//! use teloxide::{net::RequestLogging, prelude::*, update_listeners::recording::RequestRecorder};
+//!
+//! # async fn run() -> std::io::Result<()> {
+//! let recorder = RequestRecorder::new("requests.jsonl")?;
+//! let bot = Bot::from_env().set_request_logging(RequestLogging::new().sink(recorder));
+//! # Ok(()) }
+//! ```
+//!
+//! Files are written by a background task, so recorders must be created
+//! within a Tokio runtime.
+//!
+//! Note that recorded updates contain personal data of users, so recordings
+//! must be handled with care.
+//!
+//! [JSON Lines]: https://jsonlines.org
+//! [`Dispatcher`]: crate::dispatching::Dispatcher
+
+use std::{
+    convert::Infallible,
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader},
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures::{stream, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::{
+    io::AsyncWriteExt,
+    sync::{mpsc, oneshot},
+};
+
+use crate::{
+    net::LogSink,
+    stop::{mk_stop_token, StopFlag, StopToken},
+    types::{AllowedUpdate, Update},
+    update_listeners::{AsUpdateStream, StatefulListener, UpdateListener},
+};
+
+/// A recorded update.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Record {
+    /// When the update was received, in milliseconds since the Unix epoch.
+    pub received_at: u64,
+
+    /// The update itself.
+    pub update: Update,
+}
+
+/// A recorded request to Telegram or a response to it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RequestRecord {
+    /// When the request was sent or the response was received, in
+    /// milliseconds since the Unix epoch.
+    pub recorded_at: u64,
+
+    /// Whether this is a request or a response.
+    pub direction: Direction,
+
+    /// Name of the method, e.g. `sendMessage`.
+    pub method: String,
+
+    /// The redacted JSON body, `None` for multipart requests.
+    pub body: Option<serde_json::Value>,
+}
+
+/// A direction of a [`RequestRecord`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    /// A request to Telegram.
+    Request,
+
+    /// A response from Telegram.
+    Response,
+}
+
+/// An update listener which records updates received by another listener.
+///
+/// Updates are appended to a file, one [`Record`] per line. Errors of writing
+/// the file are logged and otherwise ignored.
+///
+/// See the [module-level documentation](self).
+#[derive(Debug)]
+pub struct Recorder<L> {
+    inner: L,
+    writer: Writer,
+}
+
+impl<L> Recorder<L> {
+    /// Wraps `inner`, appending updates to the file at `path`.
+    ///
+    /// The file is created if it doesn't exist.
+    ///
+    /// ## Panics
+    ///
+    /// If called outside of a Tokio runtime.
+    pub fn new<P>(inner: L, path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(Self { inner, writer: Writer::open(path.as_ref())? })
+    }
+
+    /// Waits until all the updates received so far are written to the file.
+    pub async fn flush(&self) -> io::Result<()> {
+        self.writer.flush().await
+    }
+}
+
+/// A [`LogSink`] which records requests of a bot and responses to them.
+///
+/// Requests and responses are appended to a file, one [`RequestRecord`] per
+/// line. Errors of writing the file are logged and otherwise ignored.
+///
+/// See the [module-level documentation](self).
+#[derive(Clone, Debug)]
+pub struct RequestRecorder {
+    writer: Writer,
+}
+
+impl RequestRecorder {
+    /// Creates a recorder appending requests and responses to the file at
+    /// `path`.
+    ///
+    /// The file is created if it doesn't exist.
+    ///
+    /// ## Panics
+    ///
+    /// If called outside of a Tokio runtime.
+    pub fn new<P>(path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        Ok(Self { writer: Writer::open(path.as_ref())? })
+    }
+
+    /// Waits until all the requests and responses recorded so far are written
+    /// to the file.
+    pub async fn flush(&self) -> io::Result<()> {
+        self.writer.flush().await
+    }
+
+    fn record(&self, direction: Direction, method: &str, body: Option<&str>) {
+        // Bodies which can't be parsed are already replaced by a placeholder
+        let body = body.map(|body| {
+            serde_json::from_str(body)
+                .unwrap_or_else(|_| serde_json::Value::String(body.to_owned()))
+        });
+        let record =
+            RequestRecord { recorded_at: now_millis(), direction, method: method.to_owned(), body };
+        self.writer.write(&record);
+    }
+}
+
+impl LogSink for RequestRecorder {
+    fn request(&self, method_name: &str, body: Option<&str>) {
+        self.record(Direction::Request, method_name, body);
+    }
+
+    fn response(&self, method_name: &str, body: &str) {
+        self.record(Direction::Response, method_name, Some(body));
+    }
+}
+
+impl<L> UpdateListener for Recorder<L>
+where
+    L: UpdateListener,
+{
+    type Err = L::Err;
+
+    fn stop_token(&mut self) -> StopToken {
+        self.inner.stop_token()
+    }
+
+    fn hint_allowed_updates(&mut self, hint: &mut dyn Iterator<Item = AllowedUpdate>) {
+        self.inner.hint_allowed_updates(hint)
+    }
+}
+
+impl<'a, L> AsUpdateStream<'a> for Recorder<L>
+where
+    L: AsUpdateStream<'a>,
+{
+    type StreamErr = L::StreamErr;
+    type Stream = RecordingStream<'a, L::Stream>;
+
+    fn as_stream(&'a mut self) -> Self::Stream {
+        RecordingStream { inner: self.inner.as_stream(), writer: &self.writer }
+    }
+}
+
+/// The update stream of a [`Recorder`].
+#[pin_project::pin_project]
+#[derive(Debug)]
+pub struct RecordingStream<'a, S> {
+    #[pin]
+    inner: S,
+    writer: &'a Writer,
+}
+
+impl<S, E> Stream for RecordingStream<'_, S>
+where
+    S: Stream<Item = Result<Update, E>>,
+{
+    type Item = Result<Update, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let item = futures::ready!(this.inner.poll_next(cx));
+
+        if let Some(Ok(update)) = &item {
+            this.writer.write(&Record { received_at: now_millis(), update: update.clone() });
+        }
+
+        Poll::Ready(item)
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+/// Appends records to a file in a background task, so that writing doesn't
+/// block the thread.
+#[derive(Clone, Debug)]
+struct Writer {
+    tx: mpsc::UnboundedSender<Command>,
+}
+
+#[derive(Debug)]
+enum Command {
+    Write(Vec<u8>),
+    Flush(oneshot::Sender<io::Result<()>>),
+}
+
+impl Writer {
+    fn open(path: &Path) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let mut file = tokio::fs::File::from_std(file);
+
+        // The task stops when all the senders are dropped
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Some(command) = rx.recv().await {
+                match command {
+                    Command::Write(line) => {
+                        if let Err(err) = file.write_all(&line).await {
+                            log::error!("Couldn't write a record: {err}");
+                        }
+                    }
+                    Command::Flush(done) => _ = done.send(file.flush().await),
+                }
+            }
+        });
+
+        Ok(Self { tx })
+    }
+
+    fn write<T>(&self, record: &T)
+    where
+        T: Serialize,
+    {
+        match serde_json::to_vec(record) {
+            Ok(mut line) => {
+                line.push(b'\n');
+                _ = self.tx.send(Command::Write(line));
+            }
+            Err(err) => log::error!("Couldn't serialize a record: {err}"),
+        }
+    }
+
+    async fn flush(&self) -> io::Result<()> {
+        let (tx, rx) = oneshot::channel();
+        _ = self.tx.send(Command::Flush(tx));
+        rx.await.unwrap_or(Ok(()))
+    }
+}
+
+/// An error returned from [`Replay::from_file`].
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    /// Reading the file has failed.
+    #[error("file error: {0}")]
+    Io(#[from] io::Error),
+
+    /// A line of the file isn't a valid [`Record`].
+    #[error("invalid record on line {line}: {source}")]
+    Format {
+        /// The number of the line, starting from 1.
+        line: usize,
+        source: serde_json::Error,
+    },
+}
+
+/// Replays recorded updates.
+///
+/// By default, updates are replayed with the same delays between them as when
+/// they were recorded.
+///
+/// See the [module-level documentation](self).
+#[derive(Clone, Debug)]
+#[must_use = "`Replay` does nothing unless turned into a listener"]
+pub struct Replay {
+    records: Vec<Record>,
+    speed: Option<f64>,
+}
+
+impl Replay {
+    /// Creates a replay of `records`.
+    pub fn new<I>(records: I) -> Self
+    where
+        I: IntoIterator<Item = Record>,
+    {
+        Self { records: records.into_iter().collect(), speed: Some(1.0) }
+    }
+
+    /// Reads records from a file written by a [`Recorder`].
+    ///
+    /// Empty lines are skipped.
+    pub fn from_file<P>(path: P) -> Result<Self, ReplayError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut records = Vec::new();
+        for (i, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let record = serde_json::from_str(&line)
+                .map_err(|source| ReplayError::Format { line: i + 1, source })?;
+            records.push(record);
+        }
+
+        Ok(Self::new(records))
+    }
+
+    /// Replays updates `speed` times faster than they were recorded.
+    ///
+    /// ## Panics
+    ///
+    /// If `speed` is not positive.
+    pub fn speed(self, speed: f64) -> Self {
+        assert!(speed > 0.0, "replay speed must be positive");
+        Self { speed: Some(speed), ..self }
+    }
+
+    /// Replays updates without delays between them.
+    pub fn instant(self) -> Self {
+        Self { speed: None, ..self }
+    }
+
+    /// Returns the recorded updates.
+    #[must_use]
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Turns this replay into an update listener, which stops after all the
+    /// updates are replayed.
+    pub fn into_listener(self) -> impl UpdateListener<Err = Infallible> {
+        let Self { records, speed } = self;
+
+        let mut previous = records.first().map_or(0, |record| record.received_at);
+        let updates: Vec<_> = records
+            .into_iter()
+            .map(|Record { received_at, update }| {
+                let elapsed = Duration::from_millis(received_at.saturating_sub(previous));
+                previous = received_at;
+                let delay = speed.map_or(Duration::ZERO, |speed| elapsed.div_f64(speed));
+                (delay, update)
+            })
+            .collect();
+
+        let (token, flag) = mk_stop_token();
+        let state = (updates, token, flag);
+
+        StatefulListener::new(
+            state,
+            |(updates, _, flag): &mut (Vec<(Duration, Update)>, StopToken, StopFlag)| {
+                stream::iter(std::mem::take(updates))
+                    .then(|(delay, update)| async move {
+                        if !delay.is_zero() {
+                            tokio::time::sleep(delay).await;
+                        }
+                        Ok::<_, Infallible>(update)
+                    })
+                    .take_until(flag.clone())
+            },
+            |(_, token, _): &mut (_, StopToken, _)| token.clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ChatId, UserId};
+
+    #[tokio::test]
+    async fn record_and_replay() {
+        let path =
+            std::env::temp_dir().join(format!("teloxide-recording-{}.jsonl", std::process::id()));
+        let updates: Vec<_> = ["a", "b"]
+            .into_iter()
+            .map(|text| Update::message_fixture(ChatId(1), UserId(1), text))
+            .collect();
+
+        let records =
+            updates.iter().map(|update| Record { received_at: 0, update: update.clone() });
+        let inner = Replay::new(records).instant().into_listener();
+
+        let mut recorder = Recorder::new(inner, &path).unwrap();
+        let recorded: Vec<_> = recorder.as_stream().map(Result::unwrap).collect().await;
+        assert_eq!(recorded, updates);
+        recorder.flush().await.unwrap();
+
+        let mut replay = Replay::from_file(&path).unwrap().instant().into_listener();
+        let replayed: Vec<_> = replay.as_stream().map(Result::unwrap).collect().await;
+        assert_eq!(replayed, updates);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn record_requests() {
+        let path = std::env::temp_dir()
+            .join(format!("teloxide-request-recording-{}.jsonl", std::process::id()));
+
+        let recorder = RequestRecorder::new(&path).unwrap();
+        recorder.request("sendMessage", Some(r#"{"chat_id":1}"#));
+        recorder.request("sendPhoto", None);
+        recorder.response("sendMessage", r#"{"ok":true}"#);
+        recorder.flush().await.unwrap();
+
+        let records: Vec<RequestRecord> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let summary: Vec<_> = records
+            .iter()
+            .map(|record| (record.direction, &*record.method, record.body.clone()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (Direction::Request, "sendMessage", Some(serde_json::json!({"chat_id": 1}))),
+                (Direction::Request, "sendPhoto", None),
+                (Direction::Response, "sendMessage", Some(serde_json::json!({"ok": true}))),
+            ]
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
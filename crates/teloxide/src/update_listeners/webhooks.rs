@@ -1,10 +1,23 @@
 //!
-use std::net::SocketAddr;
+use std::{
+    fmt,
+    net::{IpAddr, SocketAddr},
+    str::FromStr,
+    time::Duration,
+};
 
-use crate::{requests::Requester, types::InputFile};
+use ipnet::{IpNet, Ipv4Net};
+
+use crate::types::InputFile;
+#[cfg(any(feature = "webhooks-axum", feature = "webhooks-hyper"))]
+use crate::{payloads::SetWebhook, requests::Requester};
 
 /// Options related to setting up webhooks.
+///
+/// Construct it with [`Options::new`] and the setters, since new options may be
+/// added in the future.
 #[must_use]
+#[non_exhaustive]
 pub struct Options {
     /// Local address to listen to.
     pub address: SocketAddr,
@@ -51,6 +64,25 @@ pub struct Options {
     ///
     /// Default - `teloxide` will generate a random token.
     pub secret_token: Option<String>,
+
+    /// A previous secret token, which is still accepted for the given period
+    /// after the webhook is set up.
+    ///
+    /// This allows to change the secret token without rejecting updates sent
+    /// with the old one, e.g. when several instances of the bot are updated
+    /// one by one.
+    ///
+    /// Default - None.
+    pub old_secret_token: Option<(String, Duration)>,
+
+    /// IP ranges allowed to send updates. Requests from other addresses are
+    /// rejected with `403 Forbidden`.
+    ///
+    /// Note that if the webhook is behind a reverse proxy, the requests come
+    /// from the address of the proxy.
+    ///
+    /// Default - None, i.e. any address is allowed.
+    pub allowed_ips: Option<Vec<IpRange>>,
 }
 
 impl Options {
@@ -64,6 +96,8 @@ impl Options {
             max_connections: None,
             drop_pending_updates: false,
             secret_token: None,
+            old_secret_token: None,
+            allowed_ips: None,
         }
     }
 
@@ -103,6 +137,31 @@ impl Options {
         Self { secret_token: Some(token), ..self }
     }
 
+    /// A previous secret token, which is still accepted for `grace` period
+    /// after the webhook is set up.
+    ///
+    /// ## Panics
+    ///
+    /// If the token is invalid.
+    #[track_caller]
+    pub fn old_secret_token(self, token: String, grace: Duration) -> Self {
+        check_secret(token.as_bytes()).expect("Invalid secret token");
+
+        Self { old_secret_token: Some((token, grace)), ..self }
+    }
+
+    /// Only accept updates from the given IP ranges.
+    pub fn allowed_ips(self, ranges: impl IntoIterator<Item = IpRange>) -> Self {
+        Self { allowed_ips: Some(ranges.into_iter().collect()), ..self }
+    }
+
+    /// Only accept updates from the [IP ranges used by Telegram].
+    ///
+    /// [IP ranges used by Telegram]: https://core.telegram.org/bots/webhooks
+    pub fn telegram_ips_only(self) -> Self {
+        self.allowed_ips(telegram_ip_ranges())
+    }
+
     /// Returns `self.secret_token`, generating a new one if it's `None`.
     ///
     /// After a call to this function `self.secret_token` is always `Some(_)`.
@@ -115,38 +174,174 @@ impl Options {
     }
 }
 
+/// A range of IP addresses, e.g. `149.154.160.0/20`.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide::update_listeners::webhooks::IpRange;
+///
+/// let range: IpRange = "149.154.160.0/20".parse().unwrap();
+/// assert!(range.contains([149, 154, 167, 220].into()));
+/// assert!(!range.contains([1, 2, 3, 4].into()));
+/// assert_eq!(IpRange::new([149, 154, 160, 0].into(), 20), Some(range));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IpRange(IpNet);
+
+impl IpRange {
+    /// Returns the range of addresses whose first `prefix_len` bits are equal
+    /// to the ones of `addr`, or `None` if `prefix_len` is longer than the
+    /// address.
+    #[must_use]
+    pub fn new(addr: IpAddr, prefix_len: u8) -> Option<Self> {
+        IpNet::new(addr, prefix_len).ok().map(Self)
+    }
+
+    /// Returns `true` if `addr` is in the range.
+    #[must_use]
+    pub fn contains(&self, addr: IpAddr) -> bool {
+        self.0.contains(&addr)
+    }
+}
+
+impl FromStr for IpRange {
+    type Err = IpRangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Self).map_err(|_| IpRangeParseError(()))
+    }
+}
+
+impl fmt::Display for IpRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// An error returned when parsing an [`IpRange`] fails.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("invalid IP range syntax")]
+pub struct IpRangeParseError(());
+
+pub use self::sink::{update_sink, UpdateSink};
+
 #[cfg(feature = "webhooks-axum")]
 pub use self::axum::{axum, axum_no_setup, axum_to_router};
 
+#[cfg(feature = "webhooks-hyper")]
+pub use self::hyper::{hyper, hyper_no_setup, hyper_to_service, HyperService};
+
+#[cfg(feature = "webhooks-tls")]
+pub use self::tls::{hyper_tls, CertificateError, SelfSignedCertificate};
+
+mod sink;
+
 #[cfg(feature = "webhooks-axum")]
 mod axum;
 
-// TODO: add different implementation (for example: warp)
+#[cfg(feature = "webhooks-hyper")]
+mod hyper;
 
-/// Calls `set_webhook` with arguments from `options`.
+#[cfg(feature = "webhooks-tls")]
+mod tls;
+
+/// Returns the IP ranges Telegram sends webhook requests from.
+pub fn telegram_ip_ranges() -> [IpRange; 2] {
+    [
+        IpRange(IpNet::V4(Ipv4Net::new([149, 154, 160, 0].into(), 20).unwrap())),
+        IpRange(IpNet::V4(Ipv4Net::new([91, 108, 4, 0].into(), 22).unwrap())),
+    ]
+}
+
+/// Calls `set_webhook` with arguments from `options`, returning the payload
+/// which should be used to set the webhook again.
 ///
 /// Note: this takes out `certificate`.
-async fn setup_webhook<R>(bot: R, options: &mut Options) -> Result<(), R::Err>
+#[cfg(any(feature = "webhooks-axum", feature = "webhooks-hyper"))]
+async fn setup_webhook<R>(bot: R, options: &mut Options) -> Result<SetWebhook, R::Err>
 where
     R: Requester,
 {
-    use crate::requests::Request;
-    use teloxide_core::requests::HasPayload;
+    use crate::requests::{HasPayload, Request};
 
     let secret = options.get_or_gen_secret_token().to_owned();
     let &mut Options {
         ref url, ref mut certificate, max_connections, drop_pending_updates, ..
     } = options;
 
-    let mut req = bot.set_webhook(url.clone());
-    req.payload_mut().certificate = certificate.take();
-    req.payload_mut().max_connections = max_connections;
-    req.payload_mut().drop_pending_updates = Some(drop_pending_updates);
-    req.payload_mut().secret_token = Some(secret);
+    let mut payload = SetWebhook::new(url.clone());
+    payload.certificate = certificate.take();
+    payload.max_connections = max_connections;
+    payload.drop_pending_updates = Some(drop_pending_updates);
+    payload.secret_token = Some(secret);
 
+    let mut req = bot.set_webhook(url.clone());
+    *req.payload_mut() = payload.clone();
     req.send().await?;
 
-    Ok(())
+    // Pending updates should only be dropped once
+    payload.drop_pending_updates = None;
+    Ok(payload)
+}
+
+/// Returns a future which sets the webhook again (with `payload`) when allowed
+/// updates are hinted to the listener of `sink`, and calls `delete_webhook`
+/// after `stop_flag` is resolved.
+#[cfg(any(feature = "webhooks-axum", feature = "webhooks-hyper"))]
+fn manage_webhook<R>(
+    bot: R,
+    mut payload: SetWebhook,
+    sink: &UpdateSink,
+    stop_flag: impl std::future::Future<Output = ()> + Send,
+) -> impl std::future::Future<Output = ()> + Send
+where
+    R: Requester + Send,
+    <R as Requester>::DeleteWebhook: Send,
+{
+    use crate::requests::{HasPayload, Request};
+    use futures::future::{select, Either};
+
+    let mut hints = sink.hints();
+
+    async move {
+        let mut stop_flag = std::pin::pin!(stop_flag);
+
+        loop {
+            let changed = match select(stop_flag.as_mut(), std::pin::pin!(hints.changed())).await {
+                Either::Left(((), _)) => break,
+                Either::Right((changed, _)) => changed,
+            };
+
+            // The listener is dropped, so there will be no more hints
+            if changed.is_err() {
+                stop_flag.await;
+                break;
+            }
+
+            payload.allowed_updates.clone_from(&hints.borrow_and_update());
+            log::debug!("Setting the webhook with allowed updates: {:?}", payload.allowed_updates);
+
+            // The request is created in a block, so that it (and the `&bot` temporary)
+            // doesn't live across `.await` points.
+            let req = {
+                let mut req = bot.set_webhook(payload.url.clone());
+                *req.payload_mut() = payload.clone();
+                req.send()
+            };
+            if let Err(err) = req.await {
+                log::error!("Couldn't set allowed updates of the webhook: {}", err);
+            }
+        }
+
+        // This assignment is needed to not require `R: Sync` since without it `&bot`
+        // temporary lives across `.await` points.
+        let req = bot.delete_webhook().send();
+        let res = req.await;
+        if let Err(err) = res {
+            log::error!("Couldn't delete webhook: {}", err);
+        }
+    }
 }
 
 /// Generates a random string consisting of 32 characters (`a-z`, `A-Z`, `0-9`,
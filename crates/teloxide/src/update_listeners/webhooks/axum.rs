@@ -1,16 +1,13 @@
-use std::{convert::Infallible, future::Future, pin::Pin};
+use std::{convert::Infallible, future::Future, net::SocketAddr};
 
-use axum::{
-    extract::{FromRequestParts, State},
-    http::{request::Parts, status::StatusCode},
-};
-use tokio::sync::mpsc;
+use axum::http::StatusCode;
 
 use crate::{
     requests::Requester,
-    stop::StopFlag,
-    types::{Update, UpdateKind},
-    update_listeners::{webhooks::Options, UpdateListener},
+    update_listeners::{
+        webhooks::{Options, UpdateSink},
+        UpdateListener,
+    },
 };
 
 /// Webhook implementation based on the [mod@axum] framework.
@@ -53,7 +50,7 @@ where
 
     tokio::spawn(async move {
         axum::Server::bind(&address)
-            .serve(app.into_make_service())
+            .serve(app.into_make_service_with_connect_info::<SocketAddr>())
             .with_graceful_shutdown(stop_flag)
             .await
             .map_err(|err| {
@@ -86,11 +83,16 @@ where
 /// It may also be desired to use [`with_graceful_shutdown`] with the returned
 /// future in order to shutdown the server with the [`stop`] of the listener.
 ///
+/// If [`Options::allowed_ips`] are set, the router must be served with
+/// [`into_make_service_with_connect_info`] so that the addresses of the
+/// requests are known.
+///
 /// [`set_webhook`]: crate::payloads::SetWebhook
 /// [`delete_webhook`]: crate::payloads::DeleteWebhook
 /// [`stop`]: crate::stop::StopToken::stop
 /// [`options.address`]: Options::address
 /// [`with_graceful_shutdown`]: axum::Server::with_graceful_shutdown
+/// [`into_make_service_with_connect_info`]: axum::Router::into_make_service_with_connect_info
 ///
 /// ## Returns
 ///
@@ -118,24 +120,15 @@ where
     R: Requester + Send,
     <R as Requester>::DeleteWebhook: Send,
 {
-    use crate::{requests::Request, update_listeners::webhooks::setup_webhook};
-    use futures::FutureExt;
+    use crate::update_listeners::webhooks::{manage_webhook, setup_webhook, update_sink};
 
-    setup_webhook(&bot, &mut options).await?;
+    let payload = setup_webhook(&bot, &mut options).await?;
 
-    let (listener, stop_flag, router) = axum_no_setup(options);
+    let path = options.url.path().to_owned();
+    let (listener, stop_flag, sink) = update_sink(options);
+    let stop_flag = manage_webhook(bot, payload, &sink, stop_flag);
 
-    let stop_flag = stop_flag.then(move |()| async move {
-        // This assignment is needed to not require `R: Sync` since without it `&bot`
-        // temporary lives across `.await` points.
-        let req = bot.delete_webhook().send();
-        let res = req.await;
-        if let Err(err) = res {
-            log::error!("Couldn't delete webhook: {}", err);
-        }
-    });
-
-    Ok((listener, stop_flag, router))
+    Ok((listener, stop_flag, router(&path, sink)))
 }
 
 /// Webhook implementation based on the [mod@axum] framework that doesn't
@@ -155,146 +148,33 @@ where
 pub fn axum_no_setup(
     options: Options,
 ) -> (impl UpdateListener<Err = Infallible>, impl Future<Output = ()>, axum::Router) {
-    use crate::{
-        stop::{mk_stop_token, StopToken},
-        update_listeners::{webhooks::tuple_first_mut, StatefulListener},
-    };
-    use axum::{response::IntoResponse, routing::post};
-    use tokio_stream::wrappers::UnboundedReceiverStream;
-    use tower_http::trace::TraceLayer;
-
-    let (tx, rx): (UpdateSender, _) = mpsc::unbounded_channel();
-
-    async fn telegram_request(
-        State(WebhookState { secret, flag, mut tx }): State<WebhookState>,
-        secret_header: XTelegramBotApiSecretToken,
-        input: String,
-    ) -> impl IntoResponse {
-        // FIXME: use constant time comparison here
-        if secret_header.0.as_deref() != secret.as_deref().map(str::as_bytes) {
-            return StatusCode::UNAUTHORIZED;
-        }
-
-        let tx = match tx.get() {
-            None => return StatusCode::SERVICE_UNAVAILABLE,
-            // Do not process updates after `.stop()` is called even if the server is still
-            // running (useful for when you need to stop the bot but can't stop the server).
-            _ if flag.is_stopped() => {
-                tx.close();
-                return StatusCode::SERVICE_UNAVAILABLE;
-            }
-            Some(tx) => tx,
-        };
-
-        match serde_json::from_str::<Update>(&input) {
-            Ok(mut update) => {
-                // See HACK comment in
-                // `teloxide_core::net::request::process_response::{closure#0}`
-                if let UpdateKind::Error(value) = &mut update.kind {
-                    *value = serde_json::from_str(&input).unwrap_or_default();
-                }
-
-                tx.send(Ok(update)).expect("Cannot send an incoming update from the webhook")
-            }
-            Err(error) => {
-                log::error!(
-                    "Cannot parse an update.\nError: {:?}\nValue: {}\n\
-                     This is a bug in teloxide-core, please open an issue here: \
-                     https://github.com/teloxide/teloxide/issues.",
-                    error,
-                    input
-                );
-            }
-        };
-
-        StatusCode::OK
-    }
-
-    let (stop_token, stop_flag) = mk_stop_token();
-
-    let app = axum::Router::new()
-        .route(options.url.path(), post(telegram_request))
-        .layer(TraceLayer::new_for_http())
-        .with_state(WebhookState {
-            tx: ClosableSender::new(tx),
-            flag: stop_flag.clone(),
-            secret: options.secret_token,
-        });
-
-    let stream = UnboundedReceiverStream::new(rx);
-
-    // FIXME: this should support `hint_allowed_updates()`
-    let listener = StatefulListener::new(
-        (stream, stop_token),
-        tuple_first_mut,
-        |state: &mut (_, StopToken)| state.1.clone(),
-    );
-
-    (listener, stop_flag, app)
-}
-
-type UpdateSender = mpsc::UnboundedSender<Result<Update, std::convert::Infallible>>;
-type UpdateCSender = ClosableSender<Result<Update, std::convert::Infallible>>;
+    use crate::update_listeners::webhooks::update_sink;
 
-#[derive(Clone)]
-struct WebhookState {
-    tx: UpdateCSender,
-    flag: StopFlag,
-    secret: Option<String>,
-}
-
-/// A terrible workaround to drop axum extension
-struct ClosableSender<T> {
-    origin: std::sync::Arc<std::sync::RwLock<Option<mpsc::UnboundedSender<T>>>>,
-}
+    let path = options.url.path().to_owned();
+    let (listener, stop_flag, sink) = update_sink(options);
 
-impl<T> Clone for ClosableSender<T> {
-    fn clone(&self) -> Self {
-        Self { origin: self.origin.clone() }
-    }
+    (listener, stop_flag, router(&path, sink))
 }
 
-impl<T> ClosableSender<T> {
-    fn new(sender: mpsc::UnboundedSender<T>) -> Self {
-        Self { origin: std::sync::Arc::new(std::sync::RwLock::new(Some(sender))) }
-    }
-
-    fn get(&self) -> Option<mpsc::UnboundedSender<T>> {
-        self.origin.read().unwrap().clone()
-    }
+fn router(path: &str, sink: UpdateSink) -> axum::Router {
+    use axum::{
+        extract::{ConnectInfo, State},
+        http::HeaderMap,
+        routing::post,
+    };
+    use tower_http::trace::TraceLayer;
 
-    fn close(&mut self) {
-        self.origin.write().unwrap().take();
+    async fn telegram_request(
+        State(sink): State<UpdateSink>,
+        remote: Option<ConnectInfo<SocketAddr>>,
+        headers: HeaderMap,
+        input: axum::body::Bytes,
+    ) -> StatusCode {
+        sink.handle_from(remote.map(|ConnectInfo(addr)| addr.ip()), &headers, &input)
     }
-}
-
-struct XTelegramBotApiSecretToken(Option<Vec<u8>>);
-
-impl<S> FromRequestParts<S> for XTelegramBotApiSecretToken {
-    type Rejection = StatusCode;
 
-    fn from_request_parts<'l0, 'l1, 'at>(
-        req: &'l0 mut Parts,
-        _state: &'l1 S,
-    ) -> Pin<Box<dyn Future<Output = Result<Self, Self::Rejection>> + Send + 'at>>
-    where
-        'l0: 'at,
-        'l1: 'at,
-        Self: 'at,
-    {
-        use crate::update_listeners::webhooks::check_secret;
-
-        let res = req
-            .headers
-            .remove("x-telegram-bot-api-secret-token")
-            .map(|header| {
-                check_secret(header.as_bytes())
-                    .map(<_>::to_owned)
-                    .map_err(|_| StatusCode::BAD_REQUEST)
-            })
-            .transpose()
-            .map(Self);
-
-        Box::pin(async { res }) as _
-    }
+    axum::Router::new()
+        .route(path, post(telegram_request))
+        .layer(TraceLayer::new_for_http())
+        .with_state(sink)
 }
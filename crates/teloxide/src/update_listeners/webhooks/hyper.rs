@@ -0,0 +1,248 @@
+use std::{
+    convert::Infallible,
+    future::Future,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    task::{self, Poll},
+};
+
+use futures::future::BoxFuture;
+use hyper::{Body, Method, Request, Response, StatusCode};
+
+use crate::{
+    requests::Requester,
+    update_listeners::{
+        webhooks::{update_sink, Options, UpdateSink},
+        UpdateListener,
+    },
+};
+
+/// Webhook implementation based on [mod@hyper].
+///
+/// This function does all the work necessary for webhook to work, it:
+/// - Calls [`set_webhook`], so telegram starts sending updates our way
+/// - Spawns [mod@hyper] server listening for updates
+/// - When the update listener is [`stop`]ped, calls [`delete_webhook`]
+///
+/// [`set_webhook`]: crate::payloads::SetWebhook
+/// [`delete_webhook`]: crate::payloads::DeleteWebhook
+/// [`stop`]: crate::stop::StopToken::stop
+///
+/// ## Panics
+///
+/// If binding to the [address] fails.
+///
+/// [address]: Options::address
+///
+/// ## Fails
+///
+/// If `set_webhook()` fails.
+///
+/// ## See also
+///
+/// [`hyper_to_service`] and [`hyper_no_setup`] for lower-level versions of
+/// this function.
+pub async fn hyper<R>(
+    bot: R,
+    options: Options,
+) -> Result<impl UpdateListener<Err = Infallible>, R::Err>
+where
+    R: Requester + Send + 'static,
+    <R as Requester>::DeleteWebhook: Send,
+{
+    use hyper::{server::conn::AddrStream, service::make_service_fn};
+
+    let Options { address, .. } = options;
+
+    let (mut update_listener, stop_flag, service) = hyper_to_service(bot, options).await?;
+    let stop_token = update_listener.stop_token();
+
+    let make_service = make_service_fn(move |conn: &AddrStream| {
+        let service = service.clone().remote_addr(conn.remote_addr());
+        async move { Ok::<_, Infallible>(service) }
+    });
+
+    tokio::spawn(async move {
+        hyper::Server::bind(&address)
+            .serve(make_service)
+            .with_graceful_shutdown(stop_flag)
+            .await
+            .map_err(|err| {
+                stop_token.stop();
+                err
+            })
+            .expect("Hyper server error");
+    });
+
+    Ok(update_listener)
+}
+
+/// Webhook implementation based on [mod@hyper] that can reuse existing
+/// [mod@hyper] server.
+///
+/// This function does most of the work necessary for webhook to work, it:
+/// - Calls [`set_webhook`], so telegram starts sending updates our way
+/// - When the update listener is [`stop`]ped, calls [`delete_webhook`]
+///
+/// The only missing part is serving the returned [`HyperService`] with a
+/// [mod@hyper] server that is bound to [`options.address`].
+///
+/// It may also be desired to use [`with_graceful_shutdown`] with the returned
+/// future in order to shutdown the server with the [`stop`] of the listener.
+///
+/// If [`Options::allowed_ips`] are set, the address of every connection must be
+/// passed to the service with [`HyperService::remote_addr`].
+///
+/// [`set_webhook`]: crate::payloads::SetWebhook
+/// [`delete_webhook`]: crate::payloads::DeleteWebhook
+/// [`stop`]: crate::stop::StopToken::stop
+/// [`options.address`]: Options::address
+/// [`with_graceful_shutdown`]: hyper::Server::with_graceful_shutdown
+///
+/// ## Returns
+///
+/// A update listener, stop-future, service triplet on success.
+///
+/// The "stop-future" is resolved after [`stop`] is called on the stop token of
+/// the returned update listener.
+///
+/// ## Fails
+///
+/// If `set_webhook()` fails.
+///
+/// ## See also
+///
+/// [`fn@hyper`] for higher-level and [`hyper_no_setup`] for lower-level
+/// versions of this function.
+pub async fn hyper_to_service<R>(
+    bot: R,
+    mut options: Options,
+) -> Result<
+    (impl UpdateListener<Err = Infallible>, impl Future<Output = ()> + Send, HyperService),
+    R::Err,
+>
+where
+    R: Requester + Send,
+    <R as Requester>::DeleteWebhook: Send,
+{
+    use crate::update_listeners::webhooks::{manage_webhook, setup_webhook};
+
+    let payload = setup_webhook(&bot, &mut options).await?;
+
+    let (listener, stop_flag, service) = hyper_no_setup(options);
+    let stop_flag = manage_webhook(bot, payload, &service.sink, stop_flag);
+
+    Ok((listener, stop_flag, service))
+}
+
+/// Webhook implementation based on [mod@hyper] that doesn't perform any setup
+/// work.
+///
+/// ## Note about the stop-future
+///
+/// This function returns a future that is resolved when `.stop()` is called on
+/// a stop token of the update listener. Note that even if the future is not
+/// used, after `.stop()` is called, update listener will not produce new
+/// updates.
+///
+/// ## See also
+///
+/// [`fn@hyper`] and [`hyper_to_service`] for higher-level versions of this
+/// function.
+pub fn hyper_no_setup(
+    options: Options,
+) -> (impl UpdateListener<Err = Infallible>, impl Future<Output = ()>, HyperService) {
+    let path = Arc::from(options.url.path());
+    let (listener, stop_flag, sink) = update_sink(options);
+
+    (listener, stop_flag, HyperService { path, sink, remote: None })
+}
+
+/// A [mod@hyper] service which handles webhook requests, see
+/// [`hyper_no_setup`].
+///
+/// Requests to paths other than the path of [`Options::url`] are answered
+/// with `404 Not Found`.
+#[derive(Clone, Debug)]
+pub struct HyperService {
+    path: Arc<str>,
+    sink: UpdateSink,
+    remote: Option<IpAddr>,
+}
+
+impl HyperService {
+    /// Sets the address of the connection this service handles requests from.
+    pub fn remote_addr(self, addr: SocketAddr) -> Self {
+        Self { remote: Some(addr.ip()), ..self }
+    }
+}
+
+impl hyper::service::Service<Request<Body>> for HyperService {
+    type Response = Response<Body>;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut task::Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let this = self.clone();
+
+        Box::pin(async move {
+            let status = if req.uri().path() != &*this.path {
+                StatusCode::NOT_FOUND
+            } else if req.method() != Method::POST {
+                StatusCode::METHOD_NOT_ALLOWED
+            } else {
+                let (parts, body) = req.into_parts();
+                match hyper::body::to_bytes(body).await {
+                    Ok(body) => this.sink.handle_from(this.remote, &parts.headers, &body),
+                    Err(_) => StatusCode::BAD_REQUEST,
+                }
+            };
+
+            let mut response = Response::new(Body::empty());
+            *response.status_mut() = status;
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use hyper::service::Service;
+
+    use super::*;
+    use crate::update_listeners::AsUpdateStream;
+
+    #[tokio::test]
+    async fn handles_requests() {
+        let options = Options::new(
+            ([127, 0, 0, 1], 0).into(),
+            "https://example.com/webhook".parse().unwrap(),
+        )
+        .secret_token("SECRET".to_owned());
+        let (mut listener, _stop_flag, mut service) = hyper_no_setup(options);
+
+        let request = |path: &str, secret: &str| {
+            Request::post(path)
+                .header("x-telegram-bot-api-secret-token", secret)
+                .body(Body::from(r#"{"update_id":1,"poll_answer":{"poll_id":"1","user":{"id":1,"is_bot":false,"first_name":"A"},"option_ids":[]}}"#))
+                .unwrap()
+        };
+
+        let response = service.call(request("/other", "SECRET")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = service.call(request("/webhook", "WRONG")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = service.call(request("/webhook", "SECRET")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let update = Box::pin(listener.as_stream()).next().await.unwrap().unwrap();
+        assert_eq!(update.id.0, 1);
+    }
+}
@@ -0,0 +1,373 @@
+use std::{
+    convert::Infallible,
+    future::Future,
+    net::IpAddr,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use http::{HeaderMap, StatusCode};
+use tokio::sync::{mpsc, watch};
+
+use crate::{
+    stop::StopFlag,
+    types::{AllowedUpdate, Update},
+    update_listeners::{
+        webhooks::{IpRange, Options},
+        UpdateListener,
+    },
+};
+
+/// Framework-agnostic webhook implementation that doesn't perform any setup
+/// work.
+///
+/// Returns an update listener, a stop-future and an [`UpdateSink`], which
+/// should be called by an HTTP server with webhook requests sent by Telegram
+/// to [`options.url`]. This allows mounting the webhook into any HTTP server,
+/// the [`axum`] and [`hyper`] webhooks are implemented this way.
+///
+/// The stop-future is resolved when `.stop()` is called on a stop token of the
+/// update listener. Note that even if the future is not used, after `.stop()`
+/// is called, update listener will not produce new updates.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide::update_listeners::webhooks::{self, Options};
+///
+/// let options =
+///     Options::new(([127, 0, 0, 1], 8443).into(), "https://example.com".parse().unwrap())
+///         .secret_token("SECRET".to_owned());
+/// let (_listener, _stop_future, sink) = webhooks::update_sink(options);
+///
+/// // Call this in your HTTP server's handler
+/// let status = sink.handle(&http::HeaderMap::new(), b"{}");
+/// assert_eq!(status, http::StatusCode::UNAUTHORIZED);
+/// ```
+///
+/// [`options.url`]: Options::url
+/// [`axum`]: crate::update_listeners::webhooks::axum_no_setup
+/// [`hyper`]: crate::update_listeners::webhooks::hyper_no_setup
+pub fn update_sink(
+    options: Options,
+) -> (impl UpdateListener<Err = Infallible>, impl Future<Output = ()>, UpdateSink) {
+    use crate::{
+        stop::{mk_stop_token, StopToken},
+        update_listeners::{webhooks::tuple_first_mut, StatefulListener},
+    };
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    let (tx, rx) = mpsc::unbounded_channel();
+    let (stop_token, stop_flag) = mk_stop_token();
+    let (hints_tx, hints) = watch::channel(None);
+
+    let now = Instant::now();
+    let secrets = Secrets {
+        current: options.secret_token,
+        old: options.old_secret_token.map(|(token, grace)| (token, now + grace)),
+    };
+
+    let sink = UpdateSink {
+        tx: ClosableSender::new(tx),
+        flag: stop_flag.clone(),
+        secrets: Arc::new(RwLock::new(secrets)),
+        allowed_ips: options.allowed_ips.map(Arc::from),
+        hints,
+    };
+
+    let stream = UnboundedReceiverStream::new(rx);
+
+    let listener = StatefulListener::new_with_hints(
+        (stream, (stop_token, hints_tx)),
+        tuple_first_mut,
+        |state: &mut (_, (StopToken, _))| state.1 .0.clone(),
+        Some(|state: &mut (_, (_, HintsSender)), hint: &mut dyn Iterator<Item = AllowedUpdate>| {
+            state.1 .1.send_replace(Some(hint.collect()));
+        }),
+    );
+
+    (listener, stop_flag, sink)
+}
+
+/// Receiving end of a webhook, which passes updates to the update listener
+/// returned alongside it by [`update_sink`].
+#[derive(Clone)]
+pub struct UpdateSink {
+    tx: ClosableSender<Result<Update, Infallible>>,
+    flag: StopFlag,
+    secrets: Arc<RwLock<Secrets>>,
+    allowed_ips: Option<Arc<[IpRange]>>,
+    hints: watch::Receiver<Option<Vec<AllowedUpdate>>>,
+}
+
+type HintsSender = watch::Sender<Option<Vec<AllowedUpdate>>>;
+
+struct Secrets {
+    current: Option<String>,
+    // An old token and the time until which it's accepted
+    old: Option<(String, Instant)>,
+}
+
+impl UpdateSink {
+    /// Handles a webhook request, given its headers and body, returning the
+    /// status of the response.
+    ///
+    /// Requests without the secret token set in [`Options::secret_token`] are
+    /// rejected. Since the address the request came from is unknown, all
+    /// requests are rejected if [`Options::allowed_ips`] are set, use
+    /// [`handle_from`] instead in that case.
+    ///
+    /// [`handle_from`]: UpdateSink::handle_from
+    pub fn handle(&self, headers: &HeaderMap, body: &[u8]) -> StatusCode {
+        self.handle_from(None, headers, body)
+    }
+
+    /// Handles a webhook request which came from the `remote` address, given
+    /// its headers and body, returning the status of the response.
+    ///
+    /// Besides the checks done by [`handle`], requests from addresses which
+    /// are not in [`Options::allowed_ips`] are rejected. Note that if your
+    /// server is behind a reverse proxy, `remote` is the address of the proxy.
+    ///
+    /// [`handle`]: UpdateSink::handle
+    pub fn handle_from(
+        &self,
+        remote: Option<IpAddr>,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> StatusCode {
+        use crate::update_listeners::webhooks::check_secret;
+
+        if let Some(allowed_ips) = &self.allowed_ips {
+            let allowed = remote
+                .map(canonical_ip)
+                .map_or(false, |remote| allowed_ips.iter().any(|range| range.contains(remote)));
+
+            if !allowed {
+                log::warn!("Rejected a webhook request from a disallowed address: {remote:?}");
+                return StatusCode::FORBIDDEN;
+            }
+        }
+
+        let secret_header = match headers.get("x-telegram-bot-api-secret-token") {
+            None => None,
+            Some(header) => match check_secret(header.as_bytes()) {
+                Ok(secret) => Some(secret),
+                Err(_) => return StatusCode::BAD_REQUEST,
+            },
+        };
+
+        if !self.secrets.read().unwrap().accepts(secret_header) {
+            return StatusCode::UNAUTHORIZED;
+        }
+
+        let tx = match self.tx.get() {
+            None => return StatusCode::SERVICE_UNAVAILABLE,
+            // Do not process updates after `.stop()` is called even if the server is still
+            // running (useful for when you need to stop the bot but can't stop the server).
+            _ if self.flag.is_stopped() => {
+                self.tx.close();
+                return StatusCode::SERVICE_UNAVAILABLE;
+            }
+            Some(tx) => tx,
+        };
+
+        match Update::from_json_bytes(body) {
+            Ok(update) => {
+                tx.send(Ok(update)).expect("Cannot send an incoming update from the webhook")
+            }
+            Err(error) => {
+                log::error!(
+                    "Cannot parse an update.\nError: {:?}\nValue: {}\n\
+                     This is a bug in teloxide-core, please open an issue here: \
+                     https://github.com/teloxide/teloxide/issues.",
+                    error,
+                    String::from_utf8_lossy(body)
+                );
+            }
+        };
+
+        StatusCode::OK
+    }
+
+    /// Returns the kinds of updates the update listener was [hinted] to
+    /// receive, if any.
+    ///
+    /// These should be passed to [`set_webhook`] as `allowed_updates`.
+    ///
+    /// [hinted]: UpdateListener::hint_allowed_updates
+    /// [`set_webhook`]: crate::payloads::SetWebhook
+    #[must_use]
+    pub fn allowed_updates(&self) -> Option<Vec<AllowedUpdate>> {
+        self.hints.borrow().clone()
+    }
+
+    #[cfg(any(feature = "webhooks-axum", feature = "webhooks-hyper"))]
+    pub(super) fn hints(&self) -> watch::Receiver<Option<Vec<AllowedUpdate>>> {
+        self.hints.clone()
+    }
+
+    /// Replaces the secret token, accepting the old one during the `grace`
+    /// period.
+    ///
+    /// This allows to rotate the secret token without rejecting any updates:
+    /// call this function and then [`set_webhook`] with the new token.
+    ///
+    /// ## Panics
+    ///
+    /// If the token is invalid, see [`Options::secret_token`].
+    ///
+    /// [`set_webhook`]: crate::payloads::SetWebhook
+    #[track_caller]
+    pub fn rotate_secret_token(&self, token: String, grace: Duration) {
+        use crate::update_listeners::webhooks::check_secret;
+
+        check_secret(token.as_bytes()).expect("Invalid secret token");
+
+        let mut secrets = self.secrets.write().unwrap();
+        let old = secrets.current.replace(token);
+        secrets.old = old.map(|old| (old, Instant::now() + grace));
+    }
+}
+
+impl Secrets {
+    fn accepts(&self, token: Option<&[u8]>) -> bool {
+        // FIXME: use constant time comparison here
+        if token == self.current.as_deref().map(str::as_bytes) {
+            return true;
+        }
+
+        match (&self.old, token) {
+            (Some((old, until)), Some(token)) => old.as_bytes() == token && Instant::now() < *until,
+            _ => false,
+        }
+    }
+}
+
+/// Converts IPv4-mapped IPv6 addresses (e.g. of dual-stack sockets) to IPv4.
+fn canonical_ip(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(ip, IpAddr::V4),
+        IpAddr::V4(_) => ip,
+    }
+}
+
+impl std::fmt::Debug for UpdateSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UpdateSink").finish_non_exhaustive()
+    }
+}
+
+/// A sender which can be closed by any of its clones.
+struct ClosableSender<T> {
+    origin: Arc<RwLock<Option<mpsc::UnboundedSender<T>>>>,
+}
+
+impl<T> Clone for ClosableSender<T> {
+    fn clone(&self) -> Self {
+        Self { origin: self.origin.clone() }
+    }
+}
+
+impl<T> ClosableSender<T> {
+    fn new(sender: mpsc::UnboundedSender<T>) -> Self {
+        Self { origin: Arc::new(RwLock::new(Some(sender))) }
+    }
+
+    fn get(&self) -> Option<mpsc::UnboundedSender<T>> {
+        self.origin.read().unwrap().clone()
+    }
+
+    fn close(&self) {
+        self.origin.write().unwrap().take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use http::{HeaderMap, StatusCode};
+
+    use super::update_sink;
+    use crate::{
+        types::AllowedUpdate,
+        update_listeners::{webhooks::Options, UpdateListener},
+    };
+
+    const UPDATE: &[u8] = br#"{"update_id":1,"poll_answer":{"poll_id":"1","user":{"id":1,"is_bot":false,"first_name":"A"},"option_ids":[]}}"#;
+
+    fn options() -> Options {
+        Options::new(([127, 0, 0, 1], 0).into(), "https://example.com/webhook".parse().unwrap())
+            .secret_token("NEW".to_owned())
+    }
+
+    fn secret(token: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-telegram-bot-api-secret-token", token.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn allowed_ips() {
+        let (_listener, _stop_flag, sink) = update_sink(options().telegram_ips_only());
+
+        let telegram = [149, 154, 167, 220].into();
+        let mapped = "::ffff:91.108.4.1".parse().unwrap();
+        let other = [1, 2, 3, 4].into();
+
+        assert_eq!(sink.handle_from(Some(telegram), &secret("NEW"), UPDATE), StatusCode::OK);
+        assert_eq!(sink.handle_from(Some(mapped), &secret("NEW"), UPDATE), StatusCode::OK);
+        assert_eq!(sink.handle_from(Some(other), &secret("NEW"), UPDATE), StatusCode::FORBIDDEN);
+        assert_eq!(sink.handle(&secret("NEW"), UPDATE), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn old_secret_token() {
+        let options = options().old_secret_token("OLD".to_owned(), Duration::from_secs(60));
+        let (_listener, _stop_flag, sink) = update_sink(options);
+
+        assert_eq!(sink.handle(&secret("OLD"), UPDATE), StatusCode::OK);
+        assert_eq!(sink.handle(&secret("NEW"), UPDATE), StatusCode::OK);
+        assert_eq!(sink.handle(&secret("OTHER"), UPDATE), StatusCode::UNAUTHORIZED);
+
+        sink.rotate_secret_token("NEWER".to_owned(), Duration::ZERO);
+        assert_eq!(sink.handle(&secret("NEWER"), UPDATE), StatusCode::OK);
+        assert_eq!(sink.handle(&secret("NEW"), UPDATE), StatusCode::UNAUTHORIZED);
+        assert_eq!(sink.handle(&secret("OLD"), UPDATE), StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn records_hints() {
+        let (mut listener, _stop_flag, sink) = update_sink(options());
+        assert_eq!(sink.allowed_updates(), None);
+
+        listener.hint_allowed_updates(&mut [AllowedUpdate::Message].into_iter());
+        assert_eq!(sink.allowed_updates(), Some(vec![AllowedUpdate::Message]));
+    }
+
+    #[cfg(any(feature = "webhooks-axum", feature = "webhooks-hyper"))]
+    #[tokio::test]
+    async fn sets_allowed_updates() {
+        use crate::{
+            adaptors::MockBot, payloads::SetWebhook, update_listeners::webhooks::manage_webhook,
+        };
+
+        let bot = MockBot::new();
+        let (mut listener, stop_flag, sink) = update_sink(options());
+        let payload = SetWebhook::new("https://example.com/webhook".parse().unwrap());
+        let manage = tokio::spawn(manage_webhook(bot.clone(), payload, &sink, stop_flag));
+
+        listener.hint_allowed_updates(&mut [AllowedUpdate::Message].into_iter());
+        while bot.request_names().is_empty() {
+            tokio::task::yield_now().await;
+        }
+
+        listener.stop_token().stop();
+        manage.await.unwrap();
+
+        assert_eq!(bot.request_names(), ["SetWebhook", "DeleteWebhook"]);
+        let set = bot.requests_of::<SetWebhook>();
+        assert_eq!(set[0].allowed_updates, Some(vec![AllowedUpdate::Message]));
+    }
+}
@@ -0,0 +1,392 @@
+use std::{
+    convert::Infallible,
+    future::Future,
+    net::IpAddr,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use base64::Engine;
+use futures::future::{select, Either};
+use ring::{
+    rand::{SecureRandom, SystemRandom},
+    signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_ASN1_SIGNING},
+};
+use tokio::net::TcpListener;
+use tokio_rustls::{rustls, TlsAcceptor};
+
+use crate::{
+    requests::Requester,
+    types::InputFile,
+    update_listeners::{
+        webhooks::{HyperService, Options},
+        UpdateListener,
+    },
+};
+
+/// A self-signed certificate and its private key, for webhooks which can't
+/// get a certificate signed by a trusted authority (e.g. with a server without
+/// a domain name).
+///
+/// The certificate has an ECDSA P-256 key. The public certificate must be
+/// uploaded to Telegram, see [`Options::certificate`] and
+/// [`SelfSignedCertificate::input_file`]. [`hyper_tls`] does this
+/// automatically.
+pub struct SelfSignedCertificate {
+    certificate_der: Vec<u8>,
+    private_key_der: Vec<u8>,
+    certificate: Vec<u8>,
+    private_key: Vec<u8>,
+}
+
+/// An error returned when generating a [`SelfSignedCertificate`] fails.
+#[derive(Debug, thiserror::Error)]
+#[error("Couldn't generate a self-signed certificate: {0}")]
+pub struct CertificateError(&'static str);
+
+impl SelfSignedCertificate {
+    /// Generates a certificate for `host` (a domain name or an IP address),
+    /// which must match the host of the webhook [url], valid for a year.
+    ///
+    /// [url]: Options::url
+    pub fn generate(host: &str) -> Result<Self, CertificateError> {
+        const VALIDITY: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
+        let rng = SystemRandom::new();
+        let private_key_der = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng)
+            .map_err(|_| CertificateError("key generation failed"))?
+            .as_ref()
+            .to_vec();
+        let key = EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &private_key_der, &rng)
+            .map_err(|_| CertificateError("generated key was rejected"))?;
+
+        let mut serial = [0; 16];
+        rng.fill(&mut serial).map_err(|_| CertificateError("serial generation failed"))?;
+        // Positive and minimally encoded
+        serial[0] = serial[0] & 0x7F | 0x40;
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let name = der::seq(&[&der::set(&[&der::seq(&[
+            &der::oid(der::COMMON_NAME),
+            &der::tlv(der::UTF8_STRING, host.as_bytes()),
+        ])])]);
+        let alt_name = match host.parse::<IpAddr>() {
+            Ok(IpAddr::V4(ip)) => der::tlv(der::IP_ADDRESS, &ip.octets()),
+            Ok(IpAddr::V6(ip)) => der::tlv(der::IP_ADDRESS, &ip.octets()),
+            Err(_) => der::tlv(der::DNS_NAME, host.as_bytes()),
+        };
+
+        let tbs = der::seq(&[
+            &der::tlv(der::EXPLICIT_0, &der::tlv(der::INTEGER, &[2])),
+            &der::tlv(der::INTEGER, &serial),
+            &der::seq(&[&der::oid(der::ECDSA_WITH_SHA256)]),
+            &name,
+            &der::seq(&[&der::time(now), &der::time(now + VALIDITY)]),
+            &name,
+            &der::seq(&[
+                &der::seq(&[&der::oid(der::EC_PUBLIC_KEY), &der::oid(der::PRIME256V1)]),
+                &der::bit_string(key.public_key().as_ref()),
+            ]),
+            &der::tlv(
+                der::EXPLICIT_3,
+                &der::seq(&[&der::seq(&[
+                    &der::oid(der::SUBJECT_ALT_NAME),
+                    &der::tlv(der::OCTET_STRING, &der::seq(&[&alt_name])),
+                ])]),
+            ),
+        ]);
+        let signature = key.sign(&rng, &tbs).map_err(|_| CertificateError("signing failed"))?;
+        let certificate_der = der::seq(&[
+            &tbs,
+            &der::seq(&[&der::oid(der::ECDSA_WITH_SHA256)]),
+            &der::bit_string(signature.as_ref()),
+        ]);
+
+        Ok(Self {
+            certificate: pem("CERTIFICATE", &certificate_der),
+            private_key: pem("PRIVATE KEY", &private_key_der),
+            certificate_der,
+            private_key_der,
+        })
+    }
+
+    /// Returns the certificate in the PEM format.
+    #[must_use]
+    pub fn certificate_pem(&self) -> &[u8] {
+        &self.certificate
+    }
+
+    /// Returns the private key in the PEM (PKCS #8) format.
+    #[must_use]
+    pub fn private_key_pem(&self) -> &[u8] {
+        &self.private_key
+    }
+
+    /// Returns the certificate as a file to be uploaded to Telegram.
+    #[must_use]
+    pub fn input_file(&self) -> InputFile {
+        InputFile::memory(self.certificate.clone()).file_name("certificate.pem")
+    }
+
+    fn acceptor(&self) -> Result<TlsAcceptor, rustls::Error> {
+        let config = rustls::ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(
+                vec![rustls::Certificate(self.certificate_der.clone())],
+                rustls::PrivateKey(self.private_key_der.clone()),
+            )?;
+
+        Ok(Arc::new(config).into())
+    }
+}
+
+impl std::fmt::Debug for SelfSignedCertificate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SelfSignedCertificate").finish_non_exhaustive()
+    }
+}
+
+/// Webhook implementation based on [mod@hyper], which serves HTTPS with a
+/// [`SelfSignedCertificate`].
+///
+/// This function works just like [`fn@hyper`], but it also uploads the
+/// certificate to Telegram, unless [`Options::certificate`] is already set.
+///
+/// ## Panics
+///
+/// If binding to the [address] fails.
+///
+/// [address]: Options::address
+///
+/// ## Fails
+///
+/// If `set_webhook()` fails.
+///
+/// [`fn@hyper`]: fn@crate::update_listeners::webhooks::hyper
+pub async fn hyper_tls<R>(
+    bot: R,
+    mut options: Options,
+    certificate: &SelfSignedCertificate,
+) -> Result<impl UpdateListener<Err = Infallible>, R::Err>
+where
+    R: Requester + Send + 'static,
+    <R as Requester>::DeleteWebhook: Send,
+{
+    use crate::update_listeners::webhooks::hyper_to_service;
+
+    let acceptor = certificate.acceptor().expect("Invalid self-signed certificate");
+    let listener = TcpListener::bind(options.address).await.expect("Couldn't bind to the address");
+    options.certificate.get_or_insert_with(|| certificate.input_file());
+
+    let (update_listener, stop_flag, service) = hyper_to_service(bot, options).await?;
+    tokio::spawn(serve_tls(listener, acceptor, service, stop_flag));
+
+    Ok(update_listener)
+}
+
+/// Serves `service` over TLS until `stop_flag` is resolved.
+async fn serve_tls(
+    listener: TcpListener,
+    acceptor: TlsAcceptor,
+    service: HyperService,
+    stop_flag: impl Future<Output = ()>,
+) {
+    use hyper::server::conn::Http;
+
+    let mut stop_flag = std::pin::pin!(stop_flag);
+
+    loop {
+        let (stream, addr) = match select(&mut stop_flag, std::pin::pin!(listener.accept())).await {
+            Either::Left(((), _)) => break,
+            Either::Right((Ok(conn), _)) => conn,
+            Either::Right((Err(err), _)) => {
+                log::error!("Couldn't accept a webhook connection: {err}");
+                continue;
+            }
+        };
+
+        let acceptor = acceptor.clone();
+        let service = service.clone().remote_addr(addr);
+        tokio::spawn(async move {
+            let stream = match acceptor.accept(stream).await {
+                Ok(stream) => stream,
+                Err(err) => {
+                    log::debug!("TLS handshake with {addr} failed: {err}");
+                    return;
+                }
+            };
+
+            if let Err(err) = Http::new().serve_connection(stream, service).await {
+                log::debug!("Error while serving a webhook connection from {addr}: {err}");
+            }
+        });
+    }
+}
+
+/// Encodes `der` in the PEM format with the given `label`.
+fn pem(label: &str, der: &[u8]) -> Vec<u8> {
+    let base64 = base64::engine::general_purpose::STANDARD.encode(der);
+
+    let mut pem = format!("-----BEGIN {label}-----\n");
+    for line in base64.as_bytes().chunks(64) {
+        pem.push_str(std::str::from_utf8(line).unwrap());
+        pem.push('\n');
+    }
+    pem.push_str(&format!("-----END {label}-----\n"));
+    pem.into_bytes()
+}
+
+/// Just enough of DER to encode a certificate.
+mod der {
+    use std::time::Duration;
+
+    pub(super) const INTEGER: u8 = 0x02;
+    pub(super) const OCTET_STRING: u8 = 0x04;
+    pub(super) const UTF8_STRING: u8 = 0x0C;
+    pub(super) const EXPLICIT_0: u8 = 0xA0;
+    pub(super) const EXPLICIT_3: u8 = 0xA3;
+    pub(super) const DNS_NAME: u8 = 0x82;
+    pub(super) const IP_ADDRESS: u8 = 0x87;
+
+    const BIT_STRING: u8 = 0x03;
+    const OID: u8 = 0x06;
+    const UTC_TIME: u8 = 0x17;
+    const GENERALIZED_TIME: u8 = 0x18;
+    const SEQUENCE: u8 = 0x30;
+    const SET: u8 = 0x31;
+
+    /// 2.5.4.3
+    pub(super) const COMMON_NAME: &[u8] = &[0x55, 0x04, 0x03];
+    /// 2.5.29.17
+    pub(super) const SUBJECT_ALT_NAME: &[u8] = &[0x55, 0x1D, 0x11];
+    /// 1.2.840.10045.2.1
+    pub(super) const EC_PUBLIC_KEY: &[u8] = &[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x02, 0x01];
+    /// 1.2.840.10045.3.1.7
+    pub(super) const PRIME256V1: &[u8] = &[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x03, 0x01, 0x07];
+    /// 1.2.840.10045.4.3.2
+    pub(super) const ECDSA_WITH_SHA256: &[u8] = &[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x04, 0x03, 0x02];
+
+    pub(super) fn tlv(tag: u8, value: &[u8]) -> Vec<u8> {
+        let mut out = vec![tag];
+        match value.len() {
+            len @ 0..=0x7F => out.push(len as u8),
+            len => {
+                let bytes = len.to_be_bytes();
+                let skip = bytes.iter().take_while(|&&b| b == 0).count();
+                out.push(0x80 | (bytes.len() - skip) as u8);
+                out.extend_from_slice(&bytes[skip..]);
+            }
+        }
+        out.extend_from_slice(value);
+        out
+    }
+
+    pub(super) fn seq(items: &[&[u8]]) -> Vec<u8> {
+        tlv(SEQUENCE, &items.concat())
+    }
+
+    pub(super) fn set(items: &[&[u8]]) -> Vec<u8> {
+        tlv(SET, &items.concat())
+    }
+
+    pub(super) fn oid(encoded: &[u8]) -> Vec<u8> {
+        tlv(OID, encoded)
+    }
+
+    pub(super) fn bit_string(bytes: &[u8]) -> Vec<u8> {
+        // No unused bits
+        tlv(BIT_STRING, &[&[0], bytes].concat())
+    }
+
+    /// Encodes a time since the Unix epoch as `UTCTime` or, starting from 2050,
+    /// as `GeneralizedTime` (RFC 5280, section 4.1.2.5).
+    pub(super) fn time(since_epoch: Duration) -> Vec<u8> {
+        let secs = since_epoch.as_secs();
+        let (year, month, day) = civil_from_days((secs / 86400) as i64);
+        let (hour, min, sec) = (secs % 86400 / 3600, secs % 3600 / 60, secs % 60);
+
+        let time = format!("{month:02}{day:02}{hour:02}{min:02}{sec:02}Z");
+        if year < 2050 {
+            tlv(UTC_TIME, format!("{:02}{time}", year % 100).as_bytes())
+        } else {
+            tlv(GENERALIZED_TIME, format!("{year:04}{time}").as_bytes())
+        }
+    }
+
+    /// Converts days since the Unix epoch to a (year, month, day) date.
+    ///
+    /// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+    fn civil_from_days(days: i64) -> (i64, u32, u32) {
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z.rem_euclid(146097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        (year, month, day)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::time::Duration;
+
+        #[test]
+        fn time() {
+            assert_eq!(super::time(Duration::ZERO), b"\x17\x0D700101000000Z");
+            assert_eq!(super::time(Duration::from_secs(951_782_400)), b"\x17\x0D000229000000Z");
+            assert_eq!(
+                super::time(Duration::from_secs(2_524_608_000 + 3_723)),
+                b"\x18\x0F20500101010203Z"
+            );
+        }
+
+        #[test]
+        fn long_length() {
+            assert_eq!(super::tlv(0x04, &[0; 0x80])[..3], [0x04, 0x81, 0x80]);
+            assert_eq!(super::tlv(0x04, &[0; 0x100])[..4], [0x04, 0x82, 0x01, 0x00]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+    use crate::update_listeners::webhooks::hyper_no_setup;
+
+    #[tokio::test]
+    async fn serves_tls() {
+        let certificate = SelfSignedCertificate::generate("127.0.0.1").unwrap();
+        let acceptor = certificate.acceptor().unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let options = Options::new(addr, "https://127.0.0.1/webhook".parse().unwrap());
+        let (_update_listener, stop_flag, service) = hyper_no_setup(options);
+        tokio::spawn(serve_tls(listener, acceptor, service, stop_flag));
+
+        let mut roots = rustls::RootCertStore::empty();
+        roots.add(&rustls::Certificate(certificate.certificate_der.clone())).unwrap();
+        let config = rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        let connector = tokio_rustls::TlsConnector::from(Arc::new(config));
+
+        let stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let server_name = rustls::ServerName::IpAddress([127, 0, 0, 1].into());
+        let mut stream = connector.connect(server_name, stream).await.unwrap();
+
+        stream.write_all(b"GET /other HTTP/1.1\r\nhost: 127.0.0.1\r\n\r\n").await.unwrap();
+        let mut response = [0; 12];
+        stream.read_exact(&mut response).await.unwrap();
+        assert_eq!(&response, b"HTTP/1.1 404");
+    }
+}
@@ -1,8 +1,22 @@
 //! Some useful utilities.
 
+pub mod admin_cache;
+pub mod antispam;
+pub mod broadcast;
+pub mod chat_action;
+pub mod chat_cache;
 pub mod command;
+pub mod deep_link;
 pub mod html;
+pub mod i18n;
+pub mod inline_results;
+pub mod live_location;
+#[cfg(feature = "login-widget")]
+pub mod login_widget;
 pub mod markdown;
+pub mod media_cache;
+pub mod polls;
+pub mod sticker_set;
 pub(crate) mod shutdown_token;
 
 pub use teloxide_core::net::client_from_env;
@@ -0,0 +1,172 @@
+//! Utils for checking whether users are chat administrators.
+//!
+//! Moderation bots often need to check that a command was sent by an
+//! administrator. Calling [`GetChatAdministrators`] for every message is
+//! wasteful and quickly hits rate limits, so [`AdminCache`] remembers the list
+//! of administrators of each chat for some time.
+//!
+//! See also [`HandlerExt::filter_from_admin`].
+//!
+//! [`GetChatAdministrators`]: crate::payloads::GetChatAdministrators
+//! [`HandlerExt::filter_from_admin`]: crate::dispatching::HandlerExt::filter_from_admin
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    requests::Requester,
+    types::{ChatId, ChatMember, UserId},
+};
+
+/// A cache of chat administrators.
+///
+/// Lists of administrators are fetched on demand and are kept for the
+/// configured time to live (TTL). After it expires, the list is fetched again
+/// on the next request.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use teloxide::{prelude::*, utils::admin_cache::AdminCache};
+///
+/// # async fn run(bot: Bot, msg: Message) -> Result<(), teloxide::RequestError> {
+/// let cache = AdminCache::new(Duration::from_secs(60));
+///
+/// if let Some(user) = msg.from() {
+///     if cache.get(&bot, msg.chat.id, user.id).await?.is_some() {
+///         bot.send_message(msg.chat.id, "Hi, admin!").await?;
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct AdminCache {
+    ttl: Duration,
+    chats: Mutex<HashMap<ChatId, CachedAdmins>>,
+}
+
+#[derive(Debug)]
+struct CachedAdmins {
+    fetched_at: Instant,
+    admins: Arc<[ChatMember]>,
+}
+
+impl AdminCache {
+    /// Creates an empty cache which keeps lists of administrators for `ttl`.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self { ttl, chats: Mutex::default() }
+    }
+
+    /// Returns the administrators of `chat_id`.
+    ///
+    /// The list is fetched with [`GetChatAdministrators`] if it's not cached
+    /// or the cached list has expired.
+    ///
+    /// [`GetChatAdministrators`]: crate::payloads::GetChatAdministrators
+    pub async fn admins<R>(&self, bot: &R, chat_id: ChatId) -> Result<Arc<[ChatMember]>, R::Err>
+    where
+        R: Requester,
+    {
+        if let Some(cached) = self.chats.lock().unwrap().get(&chat_id) {
+            if cached.fetched_at.elapsed() < self.ttl {
+                return Ok(Arc::clone(&cached.admins));
+            }
+        }
+
+        let admins: Arc<[ChatMember]> = bot.get_chat_administrators(chat_id).await?.into();
+        self.chats.lock().unwrap().insert(
+            chat_id,
+            CachedAdmins { fetched_at: Instant::now(), admins: Arc::clone(&admins) },
+        );
+
+        Ok(admins)
+    }
+
+    /// Returns the [`ChatMember`] record of `user_id` in `chat_id`, if the
+    /// user is an administrator of the chat.
+    ///
+    /// Note that anonymous administrators send messages on behalf of the chat,
+    /// with the [anonymous] `GroupAnonymousBot` user as the sender, so `None`
+    /// is returned for them. Such messages can be recognized by
+    /// [`Message::sender_chat`] being the chat itself.
+    ///
+    /// See [`AdminCache::admins`].
+    ///
+    /// [anonymous]: crate::types::UserId::is_anonymous
+    /// [`Message::sender_chat`]: crate::types::Message::sender_chat
+    pub async fn get<R>(
+        &self,
+        bot: &R,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> Result<Option<ChatMember>, R::Err>
+    where
+        R: Requester,
+    {
+        let admins = self.admins(bot, chat_id).await?;
+        Ok(admins.iter().find(|member| member.user.id == user_id).cloned())
+    }
+
+    /// Forgets the cached administrators of `chat_id`.
+    ///
+    /// This is useful when a bot knows that administrators have changed, e.g.
+    /// when receiving a [`ChatMemberUpdated`].
+    ///
+    /// [`ChatMemberUpdated`]: crate::types::ChatMemberUpdated
+    pub fn invalidate(&self, chat_id: ChatId) {
+        self.chats.lock().unwrap().remove(&chat_id);
+    }
+}
+
+impl Default for AdminCache {
+    /// Creates an empty cache which keeps lists of administrators for 5
+    /// minutes.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5 * 60))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{adaptors::MockBot, payloads::GetChatAdministrators};
+
+    fn admin(user_id: UserId) -> ChatMember {
+        serde_json::from_value(serde_json::json!({
+            "user": {"id": user_id.0, "is_bot": false, "first_name": "Admin"},
+            "status": "administrator",
+            "can_be_edited": false,
+            "is_anonymous": false,
+            "can_manage_chat": true,
+            "can_delete_messages": true,
+            "can_manage_video_chats": false,
+            "can_restrict_members": true,
+            "can_promote_members": false,
+            "can_change_info": false,
+            "can_invite_users": true
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn caches_admins() {
+        let bot = MockBot::new();
+        bot.respond::<GetChatAdministrators>(vec![admin(UserId(1))]);
+        bot.respond::<GetChatAdministrators>(vec![admin(UserId(2))]);
+
+        let cache = AdminCache::default();
+        assert!(cache.get(&bot, ChatId(-1), UserId(1)).await.unwrap().is_some());
+        assert!(cache.get(&bot, ChatId(-1), UserId(2)).await.unwrap().is_none());
+        assert_eq!(bot.request_names().len(), 1);
+
+        cache.invalidate(ChatId(-1));
+        assert!(cache.get(&bot, ChatId(-1), UserId(2)).await.unwrap().is_some());
+        assert_eq!(bot.request_names().len(), 2);
+    }
+}
@@ -0,0 +1,421 @@
+//! Utils for detecting and punishing spam in groups.
+//!
+//! [`AntiSpam`] combines several [`Detector`]s, each of which looks at a
+//! message and returns a score from `0.0` (clean) to `1.0` (surely spam). The
+//! weighted sum of the scores is compared with thresholds at which spam
+//! messages are deleted and their senders are muted.
+//!
+//! The following detectors are provided:
+//!
+//!  - [`FloodDetector`] -- too many messages from a user,
+//!  - [`RepeatDetector`] -- the same text sent over and over,
+//!  - [`NewcomerLinkDetector`] -- links from users who have just started
+//!    writing in a chat,
+//!  - [`UnicodeTrickDetector`] -- invisible and text-direction characters,
+//!    often used to disguise links and names.
+//!
+//! See also [`HandlerExt::filter_spam`].
+//!
+//! ## Examples
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use teloxide::utils::antispam::{
+//!     AntiSpam, FloodDetector, RepeatDetector, UnicodeTrickDetector,
+//! };
+//!
+//! let antispam = AntiSpam::new()
+//!     .detector(FloodDetector::new(5, Duration::from_secs(10)), 1.0)
+//!     .detector(RepeatDetector::new(3, Duration::from_secs(60)), 1.0)
+//!     .detector(UnicodeTrickDetector, 0.5)
+//!     .delete_at(1.0)
+//!     .mute_at(1.5, Duration::from_secs(60 * 60));
+//! ```
+//!
+//! [`HandlerExt::filter_spam`]: crate::dispatching::HandlerExt::filter_spam
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    hash::Hash,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::{
+    payloads::RestrictChatMemberSetters,
+    requests::{Request, Requester},
+    types::{ChatId, ChatPermissions, Message, MessageEntityKind, UserId},
+};
+
+/// A heuristic which scores messages.
+pub trait Detector: Send + Sync {
+    /// Returns the spam score of `message`, from `0.0` (clean) to `1.0`
+    /// (surely spam).
+    ///
+    /// This method is called exactly once for each message, so detectors may
+    /// keep track of previous messages.
+    fn score(&self, message: &Message) -> f64;
+}
+
+/// A combination of [`Detector`]s with thresholds of actions.
+///
+/// See the [module-level documentation](self).
+#[must_use]
+pub struct AntiSpam {
+    detectors: Vec<(Box<dyn Detector>, f64)>,
+    delete_at: Option<f64>,
+    mute_at: Option<(f64, Duration)>,
+}
+
+/// What is done with a message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The message is not spam.
+    Clean,
+
+    /// The message is deleted.
+    Delete,
+
+    /// The message is deleted and its sender is muted for some time.
+    DeleteAndMute(Duration),
+}
+
+impl AntiSpam {
+    /// Creates an empty combination, which doesn't treat anything as spam.
+    pub fn new() -> Self {
+        Self { detectors: Vec::new(), delete_at: None, mute_at: None }
+    }
+
+    /// Adds `detector`, whose scores are multiplied by `weight`.
+    pub fn detector<D>(mut self, detector: D, weight: f64) -> Self
+    where
+        D: Detector + 'static,
+    {
+        self.detectors.push((Box::new(detector), weight));
+        self
+    }
+
+    /// Deletes messages with a score of at least `threshold`.
+    pub fn delete_at(self, threshold: f64) -> Self {
+        Self { delete_at: Some(threshold), ..self }
+    }
+
+    /// Deletes messages with a score of at least `threshold` and mutes their
+    /// senders for `duration`.
+    pub fn mute_at(self, threshold: f64, duration: Duration) -> Self {
+        Self { mute_at: Some((threshold, duration)), ..self }
+    }
+
+    /// Returns the weighted sum of scores of `message`.
+    #[must_use]
+    pub fn score(&self, message: &Message) -> f64 {
+        self.detectors.iter().map(|(detector, weight)| detector.score(message) * weight).sum()
+    }
+
+    /// Scores `message` and returns what should be done with it.
+    #[must_use]
+    pub fn verdict(&self, message: &Message) -> Verdict {
+        let score = self.score(message);
+        match (self.mute_at, self.delete_at) {
+            (Some((threshold, duration)), _) if score >= threshold => {
+                Verdict::DeleteAndMute(duration)
+            }
+            (_, Some(threshold)) if score >= threshold => Verdict::Delete,
+            _ => Verdict::Clean,
+        }
+    }
+
+    /// Scores `message` and deletes it or mutes its sender with the bot `R`,
+    /// according to the thresholds.
+    ///
+    /// Returns the verdict on success. Errors are returned only for messages
+    /// which are not [`Verdict::Clean`].
+    pub async fn enforce<R>(&self, bot: &R, message: &Message) -> Result<Verdict, R::Err>
+    where
+        R: Requester,
+    {
+        let verdict = self.verdict(message);
+        if verdict == Verdict::Clean {
+            return Ok(verdict);
+        }
+
+        bot.delete_message(message.chat.id, message.id).send().await?;
+        if let (Verdict::DeleteAndMute(duration), Some(user)) = (verdict, message.from()) {
+            // Counted from now, since the message may have been sent long ago (e.g.
+            // if the bot was offline)
+            bot.restrict_chat_member(message.chat.id, user.id, ChatPermissions::empty())
+                .until_date(SystemTime::now() + duration)
+                .send()
+                .await?;
+        }
+
+        Ok(verdict)
+    }
+}
+
+impl Default for AntiSpam {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for AntiSpam {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AntiSpam")
+            .field("detectors", &self.detectors.len())
+            .field("delete_at", &self.delete_at)
+            .field("mute_at", &self.mute_at)
+            .finish()
+    }
+}
+
+/// Times of recent events, per key.
+#[derive(Debug)]
+struct Window<K> {
+    period: Duration,
+    events: Mutex<HashMap<K, VecDeque<Instant>>>,
+}
+
+impl<K> Window<K>
+where
+    K: Eq + Hash,
+{
+    fn new(period: Duration) -> Self {
+        Self { period, events: Mutex::default() }
+    }
+
+    /// Records an event for `key`, returning the number of events in the
+    /// period, including this one.
+    fn record(&self, key: K) -> usize {
+        let now = Instant::now();
+        let mut events = self.events();
+
+        // Forget keys without recent events, so that the map doesn't grow
+        // indefinitely
+        events.retain(|_, times| {
+            while times.front().map_or(false, |&at| now.duration_since(at) >= self.period) {
+                times.pop_front();
+            }
+            !times.is_empty()
+        });
+
+        let times = events.entry(key).or_default();
+        times.push_back(now);
+        times.len()
+    }
+
+    fn events(&self) -> MutexGuard<'_, HashMap<K, VecDeque<Instant>>> {
+        self.events.lock().unwrap()
+    }
+}
+
+/// Returns the chat and the sender of `message`.
+fn key(message: &Message) -> Option<(ChatId, UserId)> {
+    Some((message.chat.id, message.from()?.id))
+}
+
+/// Detects users sending more than `max_messages` messages in `period`.
+#[derive(Debug)]
+pub struct FloodDetector {
+    max_messages: usize,
+    window: Window<(ChatId, UserId)>,
+}
+
+impl FloodDetector {
+    /// Creates a detector which allows `max_messages` per user in `period`.
+    #[must_use]
+    pub fn new(max_messages: usize, period: Duration) -> Self {
+        Self { max_messages, window: Window::new(period) }
+    }
+}
+
+impl Detector for FloodDetector {
+    fn score(&self, message: &Message) -> f64 {
+        match key(message) {
+            Some(key) if self.window.record(key) > self.max_messages => 1.0,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Detects users sending the same text more than `max_repeats` times in
+/// `period`.
+#[derive(Debug)]
+pub struct RepeatDetector {
+    max_repeats: usize,
+    window: Window<(ChatId, UserId, String)>,
+}
+
+impl RepeatDetector {
+    /// Creates a detector which allows `max_repeats` of the same text per user
+    /// in `period`.
+    #[must_use]
+    pub fn new(max_repeats: usize, period: Duration) -> Self {
+        Self { max_repeats, window: Window::new(period) }
+    }
+}
+
+impl Detector for RepeatDetector {
+    fn score(&self, message: &Message) -> f64 {
+        let (Some((chat_id, user_id)), Some(text)) =
+            (key(message), message.text().or_else(|| message.caption()))
+        else {
+            return 0.0;
+        };
+
+        let repeats = self.window.record((chat_id, user_id, text.trim().to_lowercase()));
+        if repeats > self.max_repeats {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Detects links from users whose first message in a chat was sent less than
+/// `grace` ago.
+///
+/// Telegram doesn't tell how old accounts are, so users are considered new
+/// since the first message the detector has seen from them.
+#[derive(Debug)]
+pub struct NewcomerLinkDetector {
+    grace: Duration,
+    first_seen: Mutex<HashMap<(ChatId, UserId), Instant>>,
+}
+
+impl NewcomerLinkDetector {
+    /// Creates a detector which doesn't allow links for `grace` after the
+    /// first message of a user.
+    #[must_use]
+    pub fn new(grace: Duration) -> Self {
+        Self { grace, first_seen: Mutex::default() }
+    }
+}
+
+impl Detector for NewcomerLinkDetector {
+    fn score(&self, message: &Message) -> f64 {
+        let Some(key) = key(message) else { return 0.0 };
+        let first_seen = *self.first_seen.lock().unwrap().entry(key).or_insert_with(Instant::now);
+        if first_seen.elapsed() >= self.grace {
+            return 0.0;
+        }
+
+        let entities = message.entities().or_else(|| message.caption_entities()).unwrap_or(&[]);
+        let has_links = entities.iter().any(|entity| {
+            matches!(entity.kind, MessageEntityKind::Url | MessageEntityKind::TextLink { .. })
+        });
+        if has_links {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Detects invisible and text-direction characters in texts and sender names.
+///
+/// Such characters are rarely typed by people, but are used by spammers to
+/// evade filters and to disguise links and names.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnicodeTrickDetector;
+
+impl UnicodeTrickDetector {
+    fn is_trick(c: char) -> bool {
+        matches!(
+            c,
+            // Zero-width characters
+            '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}'
+            // Directional marks, embeddings, overrides and isolates
+            | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+        )
+    }
+}
+
+impl Detector for UnicodeTrickDetector {
+    fn score(&self, message: &Message) -> f64 {
+        let text = message.text().or_else(|| message.caption()).unwrap_or_default();
+        let name = message.from().map(|user| user.full_name()).unwrap_or_default();
+        if text.chars().chain(name.chars()).any(Self::is_trick) {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adaptors::MockBot,
+        payloads::{DeleteMessage, RestrictChatMember},
+        types::{MediaKind, MessageEntity, MessageKind, True},
+    };
+
+    fn message(user_id: u64, text: &str) -> Message {
+        Message::text_fixture(ChatId(-1), UserId(user_id), text)
+    }
+
+    #[test]
+    fn flood_and_repeats() {
+        let flood = FloodDetector::new(2, Duration::from_secs(60));
+        let scores: Vec<_> = ["a", "b", "c"].map(|text| flood.score(&message(1, text))).into();
+        assert_eq!(scores, [0.0, 0.0, 1.0]);
+        assert_eq!(flood.score(&message(2, "a")), 0.0);
+
+        let repeats = RepeatDetector::new(1, Duration::from_secs(60));
+        assert_eq!(repeats.score(&message(1, "Buy now")), 0.0);
+        assert_eq!(repeats.score(&message(1, "buy now ")), 1.0);
+        assert_eq!(repeats.score(&message(1, "hi")), 0.0);
+    }
+
+    #[test]
+    fn newcomer_links() {
+        let mut with_link = message(1, "https://example.com");
+        if let MessageKind::Common(common) = &mut with_link.kind {
+            if let MediaKind::Text(text) = &mut common.media_kind {
+                text.entities.push(MessageEntity::new(MessageEntityKind::Url, 0, text.text.len()));
+            }
+        }
+
+        let detector = NewcomerLinkDetector::new(Duration::from_secs(60));
+        assert_eq!(detector.score(&message(1, "hi")), 0.0);
+        assert_eq!(detector.score(&with_link), 1.0);
+
+        let detector = NewcomerLinkDetector::new(Duration::ZERO);
+        assert_eq!(detector.score(&with_link), 0.0);
+    }
+
+    #[test]
+    fn unicode_tricks() {
+        assert_eq!(UnicodeTrickDetector.score(&message(1, "exe.\u{202E}fdp")), 1.0);
+        assert_eq!(UnicodeTrickDetector.score(&message(1, "Привет, مرحبا")), 0.0);
+    }
+
+    #[tokio::test]
+    async fn enforce() {
+        let bot = MockBot::new();
+        bot.respond::<DeleteMessage>(True);
+        bot.respond::<DeleteMessage>(True);
+        bot.respond::<RestrictChatMember>(True);
+
+        let antispam = AntiSpam::new()
+            .detector(FloodDetector::new(1, Duration::from_secs(60)), 1.0)
+            .detector(UnicodeTrickDetector, 1.0)
+            .delete_at(1.0)
+            .mute_at(2.0, Duration::from_secs(60));
+
+        assert_eq!(antispam.enforce(&bot, &message(1, "hi")).await.unwrap(), Verdict::Clean);
+        assert_eq!(antispam.enforce(&bot, &message(1, "hi")).await.unwrap(), Verdict::Delete);
+        assert_eq!(
+            antispam.enforce(&bot, &message(1, "\u{200B}hi")).await.unwrap(),
+            Verdict::DeleteAndMute(Duration::from_secs(60))
+        );
+        assert_eq!(bot.request_names(), ["DeleteMessage", "DeleteMessage", "RestrictChatMember"]);
+
+        let until_date = bot.requests_of::<RestrictChatMember>()[0].until_date.unwrap();
+        assert!(until_date > chrono::Utc::now() + Duration::from_secs(30));
+    }
+}
@@ -0,0 +1,444 @@
+//! Utils for sending a message to many chats.
+//!
+//! Sending a message to every user of a bot is a common task, which is easy to
+//! get wrong: requests must be sent concurrently, but not too fast, failures
+//! must be recorded, and a broadcast interrupted in the middle should not
+//! start over. [`Broadcaster`] takes care of this.
+//!
+//! Note that [`Broadcaster`] only limits the number of concurrent requests. To
+//! respect Telegram's rate limits, use a bot with the [`Throttle`] adaptor.
+//!
+//! For a handful of chats (e.g. to notify admins of a bot), see
+//! [`BroadcastExt::send_message_to_many`].
+//!
+//! [`Throttle`]: crate::adaptors::Throttle
+
+use std::{
+    future::{self, Future},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use futures::stream::{self, Stream, StreamExt};
+
+use crate::{
+    requests::{Request, Requester},
+    types::{ChatId, Message},
+    ApiError, RequestError,
+};
+
+/// A sender of the same message to many chats.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide::{prelude::*, utils::broadcast::Broadcaster};
+///
+/// # async fn run(bot: Bot) {
+/// let users = [ChatId(1), ChatId(2), ChatId(3)];
+///
+/// let report = Broadcaster::new()
+///     .concurrency(8)
+///     .run(users, |chat_id| bot.send_message(chat_id, "Hello!").send())
+///     .await;
+///
+/// for (chat_id, kind, error) in &report.failed {
+///     log::warn!("Failed to send a message to {chat_id} ({kind:?}): {error}");
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct Broadcaster {
+    concurrency: usize,
+    resume_from: usize,
+    max_retries: u32,
+}
+
+/// A result of sending a message to one chat.
+#[derive(Debug)]
+pub struct Delivery<T> {
+    /// Position of the chat in the list of chats.
+    ///
+    /// After this delivery, a broadcast can be resumed from `position + 1`.
+    pub position: usize,
+
+    /// The chat.
+    pub chat_id: ChatId,
+
+    /// Result of sending the message.
+    pub result: Result<T, RequestError>,
+}
+
+/// A category of a failed delivery.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FailureKind {
+    /// The user has blocked the bot, or the bot was kicked from the chat.
+    Blocked,
+
+    /// The chat doesn't exist.
+    ChatNotFound,
+
+    /// The user or the group is deactivated.
+    Deactivated,
+
+    /// Any other error.
+    Other,
+}
+
+/// A summary of a broadcast.
+#[derive(Debug)]
+pub struct BroadcastReport<T> {
+    /// Chats to which a message was sent, with results of sending.
+    pub delivered: Vec<(ChatId, T)>,
+
+    /// Chats to which a message was not sent, with errors.
+    pub failed: Vec<(ChatId, FailureKind, RequestError)>,
+
+    /// Position from which the broadcast can be resumed, i.e. the number of
+    /// processed chats.
+    pub checkpoint: usize,
+}
+
+impl Broadcaster {
+    /// Creates a broadcaster which sends up to 16 messages concurrently and
+    /// retries a request up to 3 times if Telegram asks to retry it later.
+    pub fn new() -> Self {
+        Self { concurrency: 16, resume_from: 0, max_retries: 3 }
+    }
+
+    /// Sets the maximal number of concurrent requests.
+    ///
+    /// ## Panics
+    ///
+    /// If `val` is zero.
+    pub fn concurrency(self, val: usize) -> Self {
+        assert!(val > 0, "concurrency must be non-zero");
+        Self { concurrency: val, ..self }
+    }
+
+    /// Skips first `checkpoint` chats, to resume an interrupted broadcast.
+    ///
+    /// A checkpoint can be taken either from [`BroadcastReport::checkpoint`]
+    /// or from [`Delivery::position`].
+    pub fn resume_from(self, checkpoint: usize) -> Self {
+        Self { resume_from: checkpoint, ..self }
+    }
+
+    /// Sets how many times a request is retried after a
+    /// [`RequestError::RetryAfter`] error.
+    pub fn max_retries(self, val: u32) -> Self {
+        Self { max_retries: val, ..self }
+    }
+
+    /// Sends a message to every chat of `chat_ids` via `send`, yielding
+    /// results in order of `chat_ids`.
+    ///
+    /// Persisting [`Delivery::position`] of yielded deliveries allows resuming
+    /// a broadcast, if the process is interrupted.
+    pub fn stream<I, F, Fut, T>(&self, chat_ids: I, send: F) -> impl Stream<Item = Delivery<T>>
+    where
+        I: IntoIterator<Item = ChatId>,
+        F: Fn(ChatId) -> Fut,
+        Fut: Future<Output = Result<T, RequestError>>,
+    {
+        let send = Arc::new(send);
+        let max_retries = self.max_retries;
+
+        stream::iter(chat_ids.into_iter().enumerate().skip(self.resume_from))
+            .map(move |(position, chat_id)| {
+                let send = Arc::clone(&send);
+                async move {
+                    let mut retries = 0;
+                    let result = loop {
+                        match send(chat_id).await {
+                            Err(RequestError::RetryAfter(delay)) if retries < max_retries => {
+                                retries += 1;
+                                tokio::time::sleep(delay.duration()).await;
+                            }
+                            result => break result,
+                        }
+                    };
+
+                    Delivery { position, chat_id, result }
+                }
+            })
+            .buffered(self.concurrency)
+    }
+
+    /// Sends a message to every chat of `chat_ids` via `send` and collects the
+    /// results.
+    ///
+    /// See also [`Broadcaster::stream`].
+    pub async fn run<I, F, Fut, T>(&self, chat_ids: I, send: F) -> BroadcastReport<T>
+    where
+        I: IntoIterator<Item = ChatId>,
+        F: Fn(ChatId) -> Fut,
+        Fut: Future<Output = Result<T, RequestError>>,
+    {
+        let mut report =
+            BroadcastReport { delivered: vec![], failed: vec![], checkpoint: self.resume_from };
+
+        let deliveries = self.stream(chat_ids, send);
+        futures::pin_mut!(deliveries);
+
+        while let Some(Delivery { position, chat_id, result }) = deliveries.next().await {
+            match result {
+                Ok(output) => report.delivered.push((chat_id, output)),
+                Err(err) => report.failed.push((chat_id, FailureKind::of(&err), err)),
+            }
+            report.checkpoint = position + 1;
+        }
+
+        report
+    }
+}
+
+impl Default for Broadcaster {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FailureKind {
+    /// Categorizes an error of sending a message.
+    #[must_use]
+    pub fn of(error: &RequestError) -> Self {
+        use ApiError::*;
+
+        match error {
+            RequestError::Api(
+                BotBlocked | BotKicked | BotKickedFromSupergroup | CantInitiateConversation,
+            ) => Self::Blocked,
+            RequestError::Api(ChatNotFound | UserNotFound) => Self::ChatNotFound,
+            RequestError::Api(UserDeactivated | GroupDeactivated) => Self::Deactivated,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl<T> BroadcastReport<T> {
+    /// Returns chats to which a message was not sent because of an error of
+    /// `kind`.
+    pub fn failed_with(&self, kind: FailureKind) -> impl Iterator<Item = ChatId> + '_ {
+        self.failed.iter().filter(move |(_, k, _)| *k == kind).map(|(chat_id, _, _)| *chat_id)
+    }
+}
+
+/// Extension methods for sending messages to many chats.
+pub trait BroadcastExt: Requester {
+    /// Creates a builder which sends a text message to every chat of
+    /// `chat_ids`.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use teloxide::{prelude::*, utils::broadcast::BroadcastExt};
+    ///
+    /// # async fn run(bot: Bot) {
+    /// let admins = [ChatId(1), ChatId(2)];
+    ///
+    /// let results = bot
+    ///     .send_message_to_many(admins, "The bot has been restarted")
+    ///     .concurrency(2)
+    ///     .abort_if(|err| matches!(err, teloxide::RequestError::Network(_)))
+    ///     .send()
+    ///     .await;
+    ///
+    /// for (chat_id, result) in results {
+    ///     if let Err(err) = result {
+    ///         log::warn!("Failed to notify {chat_id}: {err}");
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    fn send_message_to_many<I, T>(&self, chat_ids: I, text: T) -> SendMessageToMany<'_, Self>
+    where
+        I: IntoIterator<Item = ChatId>,
+        T: Into<String>,
+    {
+        SendMessageToMany {
+            bot: self,
+            chat_ids: chat_ids.into_iter().collect(),
+            text: text.into(),
+            concurrency: 4,
+            abort_if: None,
+        }
+    }
+}
+
+impl<R> BroadcastExt for R where R: Requester {}
+
+/// A builder returned by [`BroadcastExt::send_message_to_many`].
+#[must_use = "`SendMessageToMany` does nothing unless `send` is called"]
+pub struct SendMessageToMany<'a, R>
+where
+    R: Requester + ?Sized,
+{
+    bot: &'a R,
+    chat_ids: Vec<ChatId>,
+    text: String,
+    concurrency: usize,
+    abort_if: Option<AbortPredicate<'a, R::Err>>,
+}
+
+type AbortPredicate<'a, E> = Box<dyn Fn(&E) -> bool + Send + Sync + 'a>;
+
+impl<'a, R> SendMessageToMany<'a, R>
+where
+    R: Requester + ?Sized,
+{
+    /// Sets the maximal number of concurrent requests, 4 by default.
+    ///
+    /// ## Panics
+    ///
+    /// If `val` is zero.
+    pub fn concurrency(self, val: usize) -> Self {
+        assert!(val > 0, "concurrency must be non-zero");
+        Self { concurrency: val, ..self }
+    }
+
+    /// Stops sending messages after an error for which `predicate` returns
+    /// `true`.
+    ///
+    /// Requests which are already in flight are still awaited and their
+    /// results are included. Chats to which a message was not sent because of
+    /// this are not included in the results.
+    pub fn abort_if<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&R::Err) -> bool + Send + Sync + 'a,
+    {
+        Self { abort_if: Some(Box::new(predicate)), ..self }
+    }
+
+    /// Sends the messages, returning results in order of chats.
+    pub async fn send(self) -> Vec<(ChatId, Result<Message, R::Err>)> {
+        let Self { bot, chat_ids, text, concurrency, abort_if } = self;
+        let aborted = AtomicBool::new(false);
+
+        let results = stream::iter(chat_ids)
+            .take_while(|_| future::ready(!aborted.load(Ordering::Relaxed)))
+            .map(|chat_id| {
+                let request = bot.send_message(chat_id, text.clone());
+                async move { (chat_id, request.send().await) }
+            })
+            .buffered(concurrency);
+        futures::pin_mut!(results);
+
+        // After an abort no new requests are started, but the ones in flight are
+        // drained, so that their results are not lost
+        let mut out = Vec::new();
+        while let Some((chat_id, result)) = results.next().await {
+            if let (Err(err), Some(abort_if)) = (&result, &abort_if) {
+                if abort_if(err) {
+                    aborted.store(true, Ordering::Relaxed);
+                }
+            }
+
+            out.push((chat_id, result));
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::{
+        adaptors::MockBot,
+        payloads::SendMessage,
+        types::{Seconds, UserId},
+    };
+
+    fn chats(n: i64) -> Vec<ChatId> {
+        (0..n).map(ChatId).collect()
+    }
+
+    async fn send(chat_id: ChatId) -> Result<i64, RequestError> {
+        match chat_id.0 {
+            1 => Err(RequestError::Api(ApiError::BotBlocked)),
+            2 => Err(RequestError::Api(ApiError::UserDeactivated)),
+            3 => Err(RequestError::Api(ApiError::ChatNotFound)),
+            id => Ok(id),
+        }
+    }
+
+    #[tokio::test]
+    async fn report() {
+        let report = Broadcaster::new().concurrency(2).run(chats(6), send).await;
+
+        assert_eq!(report.delivered, [(ChatId(0), 0), (ChatId(4), 4), (ChatId(5), 5)]);
+        assert_eq!(report.failed_with(FailureKind::Blocked).collect::<Vec<_>>(), [ChatId(1)]);
+        assert_eq!(report.failed_with(FailureKind::Deactivated).collect::<Vec<_>>(), [ChatId(2)]);
+        assert_eq!(report.failed_with(FailureKind::ChatNotFound).collect::<Vec<_>>(), [ChatId(3)]);
+        assert_eq!(report.checkpoint, 6);
+    }
+
+    #[tokio::test]
+    async fn resume() {
+        let report = Broadcaster::new().resume_from(4).run(chats(6), send).await;
+
+        assert_eq!(report.delivered, [(ChatId(4), 4), (ChatId(5), 5)]);
+        assert_eq!(report.checkpoint, 6);
+    }
+
+    #[tokio::test]
+    async fn retry() {
+        let attempts = Mutex::new(0);
+        let send = |chat_id: ChatId| {
+            let attempt = {
+                let mut attempts = attempts.lock().unwrap();
+                *attempts += 1;
+                *attempts
+            };
+            async move {
+                match attempt {
+                    1 => Err(RequestError::RetryAfter(Seconds::from_seconds(0))),
+                    _ => Ok(chat_id),
+                }
+            }
+        };
+
+        let report = Broadcaster::new().run(chats(1), send).await;
+        assert_eq!(report.delivered, [(ChatId(0), ChatId(0))]);
+        assert_eq!(*attempts.lock().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn send_message_to_many() {
+        let bot = MockBot::new();
+        for id in 0..3 {
+            bot.respond::<SendMessage>(Message::text_fixture(ChatId(id), UserId(1), "text"));
+        }
+
+        let results = bot.send_message_to_many(chats(3), "text").send().await;
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|(_, r)| r.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn send_message_to_many_abort() {
+        let bot = MockBot::new();
+        bot.respond_err::<SendMessage>(RequestError::Api(ApiError::BotBlocked));
+        bot.respond::<SendMessage>(Message::text_fixture(ChatId(1), UserId(1), "text"));
+
+        let results = bot
+            .send_message_to_many(chats(4), "text")
+            .concurrency(2)
+            .abort_if(|err| matches!(err, RequestError::Api(ApiError::BotBlocked)))
+            .send()
+            .await;
+
+        // The request to the second chat was in flight, when the first one failed
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0], (ChatId(0), Err(RequestError::Api(ApiError::BotBlocked)))));
+        assert!(matches!(results[1], (ChatId(1), Ok(_))));
+        assert_eq!(bot.request_names().len(), 2);
+    }
+}
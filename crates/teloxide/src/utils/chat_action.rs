@@ -0,0 +1,106 @@
+//! Utils for showing [chat actions] while a bot is busy.
+//!
+//! A chat action (e.g. "typing…") is shown for 5 seconds, or until the bot
+//! sends a message. To indicate a longer operation, the action must be sent
+//! repeatedly, which is what [`with_chat_action`] and [`typing_while`] do.
+//!
+//! [chat actions]: https://core.telegram.org/bots/api#sendchataction
+
+use std::{future::Future, time::Duration};
+
+use futures::future::{self, Either};
+
+use crate::{
+    requests::Requester,
+    types::{ChatAction, Recipient},
+};
+
+/// Interval between repeated chat actions.
+///
+/// It is a bit shorter than the time a chat action is shown for, so that the
+/// action is not interrupted.
+pub const CHAT_ACTION_INTERVAL: Duration = Duration::from_secs(4);
+
+/// Shows `action` in `chat_id` while `fut` is pending, and returns its output.
+///
+/// The action is sent right away and then every [`CHAT_ACTION_INTERVAL`].
+/// Errors of sending the action are logged and otherwise ignored.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide::{prelude::*, types::ChatAction, utils::chat_action::with_chat_action};
+///
+/// # async fn run(bot: Bot, chat_id: ChatId) {
+/// let photo = with_chat_action(&bot, chat_id, ChatAction::UploadPhoto, async {
+///     // Render a photo...
+/// })
+/// .await;
+/// # }
+/// ```
+pub async fn with_chat_action<R, C, F>(bot: R, chat_id: C, action: ChatAction, fut: F) -> F::Output
+where
+    R: Requester,
+    C: Into<Recipient>,
+    F: Future,
+{
+    let chat_id = chat_id.into();
+    let actions = async {
+        loop {
+            if let Err(err) = bot.send_chat_action(chat_id.clone(), action).await {
+                log::warn!("Failed to send a chat action: {err}");
+            }
+            tokio::time::sleep(CHAT_ACTION_INTERVAL).await;
+        }
+    };
+
+    futures::pin_mut!(fut, actions);
+    match future::select(fut, actions).await {
+        Either::Left((output, _)) => output,
+        Either::Right(_) => unreachable!("chat actions are sent forever"),
+    }
+}
+
+/// Shows "typing…" in `chat_id` while `fut` is pending, and returns its
+/// output.
+///
+/// A call to this function is the same as
+/// `with_chat_action(bot, chat_id, ChatAction::Typing, fut)`, see
+/// [`with_chat_action`].
+pub async fn typing_while<R, C, F>(bot: R, chat_id: C, fut: F) -> F::Output
+where
+    R: Requester,
+    C: Into<Recipient>,
+    F: Future,
+{
+    with_chat_action(bot, chat_id, ChatAction::Typing, fut).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{payloads::SendChatAction, test::MockBot, types::ChatId};
+
+    #[tokio::test(start_paused = true)]
+    async fn repeats_actions() {
+        // Responses aren't scripted, so sending actions fails, which is ignored
+        let bot = MockBot::new();
+        let sent = || bot.requests_of::<SendChatAction>().len();
+
+        let output = typing_while(&bot, ChatId(1), async {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            let on_start = sent();
+            tokio::time::sleep(CHAT_ACTION_INTERVAL).await;
+            (on_start, sent())
+        })
+        .await;
+        assert_eq!(output, (1, 2));
+
+        // No actions are sent after the future completes
+        tokio::time::sleep(CHAT_ACTION_INTERVAL * 3).await;
+        let actions = bot.requests_of::<SendChatAction>();
+        assert_eq!(actions.len(), 2);
+        assert!(actions.iter().all(|action| action.chat_id == Recipient::Id(ChatId(1))
+            && action.action == ChatAction::Typing));
+    }
+}
@@ -0,0 +1,221 @@
+//! Utils for caching chats and chat members.
+//!
+//! Bots often call [`GetChat`] or [`GetChatMember`] to check permissions of a
+//! chat or a user before doing something. [`ChatCache`] remembers the results
+//! for some time, so that hot paths don't need a request to Telegram every
+//! time.
+//!
+//! Cached members are replaced with the fresh ones from [`ChatMemberUpdated`]
+//! updates passed to [`ChatCache::observe`].
+//!
+//! See also [`AdminCache`].
+//!
+//! [`GetChat`]: crate::payloads::GetChat
+//! [`GetChatMember`]: crate::payloads::GetChatMember
+//! [`ChatMemberUpdated`]: crate::types::ChatMemberUpdated
+//! [`AdminCache`]: crate::utils::admin_cache::AdminCache
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    requests::Requester,
+    types::{Chat, ChatId, ChatMember, Update, UpdateKind, UserId},
+};
+
+/// A cache of chats and chat members.
+///
+/// Values are fetched on demand and are kept for the configured time to live
+/// (TTL). After it expires, a value is fetched again on the next request.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::{sync::Arc, time::Duration};
+///
+/// use teloxide::{prelude::*, utils::chat_cache::ChatCache};
+///
+/// # async fn run(bot: Bot) {
+/// let cache = Arc::new(ChatCache::new(Duration::from_secs(60)));
+///
+/// let handler = dptree::entry()
+///     // Keep cached members up to date
+///     .inspect(|update: Update, cache: Arc<ChatCache>| cache.observe(&update))
+///     .branch(Update::filter_message().endpoint(
+///         |bot: Bot, msg: Message, cache: Arc<ChatCache>| async move {
+///             let Some(user) = msg.from() else { return Ok(()) };
+///             let member = cache.cached_chat_member(&bot, msg.chat.id, user.id).await?;
+///             if member.is_privileged() {
+///                 bot.send_message(msg.chat.id, "Hi, admin!").await?;
+///             }
+///             respond(())
+///         },
+///     ));
+///
+/// Dispatcher::builder(bot, handler).dependencies(dptree::deps![cache]).build().dispatch().await;
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ChatCache {
+    ttl: Duration,
+    chats: Mutex<HashMap<ChatId, Cached<Chat>>>,
+    members: Mutex<HashMap<(ChatId, UserId), Cached<ChatMember>>>,
+}
+
+#[derive(Debug)]
+struct Cached<T> {
+    fetched_at: Instant,
+    value: T,
+}
+
+impl ChatCache {
+    /// Creates an empty cache which keeps values for `ttl`.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self { ttl, chats: Mutex::default(), members: Mutex::default() }
+    }
+
+    /// Returns the chat `chat_id`.
+    ///
+    /// The chat is fetched with [`GetChat`] if it's not cached or the cached
+    /// chat has expired.
+    ///
+    /// [`GetChat`]: crate::payloads::GetChat
+    pub async fn cached_chat<R>(&self, bot: &R, chat_id: ChatId) -> Result<Chat, R::Err>
+    where
+        R: Requester,
+    {
+        if let Some(chat) = self.get(&self.chats, &chat_id) {
+            return Ok(chat);
+        }
+
+        let chat = bot.get_chat(chat_id).await?;
+        insert(&self.chats, chat_id, chat.clone());
+        Ok(chat)
+    }
+
+    /// Returns the member `user_id` of `chat_id`.
+    ///
+    /// The member is fetched with [`GetChatMember`] if it's not cached or the
+    /// cached member has expired.
+    ///
+    /// [`GetChatMember`]: crate::payloads::GetChatMember
+    pub async fn cached_chat_member<R>(
+        &self,
+        bot: &R,
+        chat_id: ChatId,
+        user_id: UserId,
+    ) -> Result<ChatMember, R::Err>
+    where
+        R: Requester,
+    {
+        if let Some(member) = self.get(&self.members, &(chat_id, user_id)) {
+            return Ok(member);
+        }
+
+        let member = bot.get_chat_member(chat_id, user_id).await?;
+        insert(&self.members, (chat_id, user_id), member.clone());
+        Ok(member)
+    }
+
+    /// Updates the cache with the information from `update`.
+    ///
+    /// Members from [`ChatMemberUpdated`] updates replace the cached ones.
+    ///
+    /// [`ChatMemberUpdated`]: crate::types::ChatMemberUpdated
+    pub fn observe(&self, update: &Update) {
+        if let UpdateKind::ChatMember(updated) | UpdateKind::MyChatMember(updated) = &update.kind {
+            let member = &updated.new_chat_member;
+            insert(&self.members, (updated.chat.id, member.user.id), member.clone());
+        }
+    }
+
+    /// Forgets the cached chat `chat_id`.
+    pub fn invalidate_chat(&self, chat_id: ChatId) {
+        self.chats.lock().unwrap().remove(&chat_id);
+    }
+
+    /// Forgets the cached member `user_id` of `chat_id`.
+    pub fn invalidate_chat_member(&self, chat_id: ChatId, user_id: UserId) {
+        self.members.lock().unwrap().remove(&(chat_id, user_id));
+    }
+
+    fn get<K, T>(&self, map: &Mutex<HashMap<K, Cached<T>>>, key: &K) -> Option<T>
+    where
+        K: Eq + Hash,
+        T: Clone,
+    {
+        let map = map.lock().unwrap();
+        map.get(key)
+            .filter(|cached| cached.fetched_at.elapsed() < self.ttl)
+            .map(|c| c.value.clone())
+    }
+}
+
+fn insert<K, T>(map: &Mutex<HashMap<K, Cached<T>>>, key: K, value: T)
+where
+    K: Eq + Hash,
+{
+    map.lock().unwrap().insert(key, Cached { fetched_at: Instant::now(), value });
+}
+
+impl Default for ChatCache {
+    /// Creates an empty cache which keeps values for 5 minutes.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5 * 60))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adaptors::MockBot,
+        payloads::{GetChat, GetChatMember},
+        types::{ChatMemberKind, ChatMemberUpdated, User},
+    };
+
+    #[tokio::test]
+    async fn caches_chats() {
+        let bot = MockBot::new();
+        bot.respond::<GetChat>(Chat::fixture(ChatId(-1)));
+        bot.respond::<GetChat>(Chat::fixture(ChatId(-1)));
+
+        let cache = ChatCache::default();
+        assert_eq!(cache.cached_chat(&bot, ChatId(-1)).await.unwrap().id, ChatId(-1));
+        assert_eq!(cache.cached_chat(&bot, ChatId(-1)).await.unwrap().id, ChatId(-1));
+        assert_eq!(bot.request_names().len(), 1);
+
+        cache.invalidate_chat(ChatId(-1));
+        cache.cached_chat(&bot, ChatId(-1)).await.unwrap();
+        assert_eq!(bot.request_names().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn updates_members() {
+        let (chat_id, user_id) = (ChatId(-1), UserId(2));
+        let bot = MockBot::new();
+        bot.respond::<GetChatMember>(ChatMember {
+            user: User::fixture(user_id),
+            kind: ChatMemberKind::Member,
+        });
+
+        let cache = ChatCache::default();
+        let member = cache.cached_chat_member(&bot, chat_id, user_id).await.unwrap();
+        assert_eq!(member.kind, ChatMemberKind::Member);
+
+        cache.observe(&Update::fixture(UpdateKind::ChatMember(ChatMemberUpdated::fixture(
+            chat_id,
+            user_id,
+            ChatMemberKind::Member,
+            ChatMemberKind::Left,
+        ))));
+        let member = cache.cached_chat_member(&bot, chat_id, user_id).await.unwrap();
+        assert_eq!(member.kind, ChatMemberKind::Left);
+        assert_eq!(bot.request_names(), ["GetChatMember"]);
+    }
+}
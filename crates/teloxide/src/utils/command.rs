@@ -381,7 +381,7 @@ impl<'a> CommandDescriptions<'a> {
     /// [`username`]: self::CommandDescriptions::username
     #[must_use]
     pub fn username_from_me(self, me: &'a Me) -> CommandDescriptions<'a> {
-        self.username(me.user.username.as_deref().expect("Bots must have usernames"))
+        self.username(me.username())
     }
 }
 
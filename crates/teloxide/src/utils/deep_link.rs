@@ -0,0 +1,164 @@
+//! Utils for working with [deep links].
+//!
+//! Deep links allow users to start a bot with an additional parameter (a
+//! _payload_), e.g. `https://t.me/examplebot?start=ref_42`. When a user follows
+//! such a link, the bot receives a `/start ref_42` message.
+//!
+//! A payload can be up to 64 characters long and may only contain
+//! `A-Z`, `a-z`, `0-9`, `_` and `-`. To pass arbitrary data, encode it via
+//! [`encode_payload`], which uses the URL-safe base64 alphabet.
+//!
+//! [deep links]: https://core.telegram.org/bots/features#deep-linking
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use thiserror::Error;
+use url::Url;
+
+/// Maximal length of a deep link payload.
+pub const MAX_PAYLOAD_LEN: usize = 64;
+
+/// An error returned when a deep link payload is invalid.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum DeepLinkError {
+    /// A payload is longer than [`MAX_PAYLOAD_LEN`].
+    #[error("A deep link payload must be at most 64 characters long, got {0}")]
+    TooLong(usize),
+
+    /// A payload contains a character which is not allowed in deep links.
+    #[error("A deep link payload contains an invalid character: {0:?}")]
+    InvalidCharacter(char),
+
+    /// A payload is not valid URL-safe base64.
+    #[error("A deep link payload is not valid base64: {0}")]
+    Base64(#[from] base64::DecodeError),
+}
+
+/// Checks that `payload` can be used in a deep link.
+pub fn validate_payload(payload: &str) -> Result<(), DeepLinkError> {
+    if payload.len() > MAX_PAYLOAD_LEN {
+        return Err(DeepLinkError::TooLong(payload.len()));
+    }
+
+    match payload.chars().find(|&c| !(c.is_ascii_alphanumeric() || c == '_' || c == '-')) {
+        Some(c) => Err(DeepLinkError::InvalidCharacter(c)),
+        None => Ok(()),
+    }
+}
+
+/// Encodes arbitrary bytes as a deep link payload, using the URL-safe base64
+/// alphabet without padding.
+///
+/// Note that a payload can hold at most 48 bytes of data.
+pub fn encode_payload<D>(data: D) -> Result<String, DeepLinkError>
+where
+    D: AsRef<[u8]>,
+{
+    let payload = URL_SAFE_NO_PAD.encode(data);
+    validate_payload(&payload)?;
+    Ok(payload)
+}
+
+/// Decodes a payload encoded via [`encode_payload`].
+pub fn decode_payload(payload: &str) -> Result<Vec<u8>, DeepLinkError> {
+    validate_payload(payload)?;
+    Ok(URL_SAFE_NO_PAD.decode(payload)?)
+}
+
+/// Creates a link which starts a private chat with a bot, passing `payload`.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide::utils::deep_link;
+///
+/// let link = deep_link::start_link("examplebot", "ref_42").unwrap();
+/// assert_eq!(link.as_str(), "https://t.me/examplebot?start=ref_42");
+/// ```
+pub fn start_link(bot_username: &str, payload: &str) -> Result<Url, DeepLinkError> {
+    link(bot_username, "start", payload)
+}
+
+/// Creates a link which prompts a user to add a bot to a group, passing
+/// `payload`.
+pub fn startgroup_link(bot_username: &str, payload: &str) -> Result<Url, DeepLinkError> {
+    link(bot_username, "startgroup", payload)
+}
+
+/// Extracts a payload from the text of a `/start` command.
+///
+/// If the command is addressed to a specific bot (`/start@examplebot`),
+/// `bot_username` must match its name. Returns `None` if `text` is not a
+/// `/start` command or it has no payload.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide::utils::deep_link::parse_start_payload;
+///
+/// assert_eq!(parse_start_payload("/start ref_42", "examplebot"), Some("ref_42"));
+/// assert_eq!(parse_start_payload("/start@examplebot ref_42", "examplebot"), Some("ref_42"));
+/// assert_eq!(parse_start_payload("/start@otherbot ref_42", "examplebot"), None);
+/// assert_eq!(parse_start_payload("/start", "examplebot"), None);
+/// ```
+#[must_use]
+pub fn parse_start_payload<'a>(text: &'a str, bot_username: &str) -> Option<&'a str> {
+    let (command, payload) = text.split_once(' ')?;
+
+    let mention = command.strip_prefix("/start")?;
+    if !mention.is_empty() && !mention.strip_prefix('@')?.eq_ignore_ascii_case(bot_username) {
+        return None;
+    }
+
+    let payload = payload.trim();
+    (!payload.is_empty()).then_some(payload)
+}
+
+fn link(bot_username: &str, param: &str, payload: &str) -> Result<Url, DeepLinkError> {
+    validate_payload(payload)?;
+
+    let mut url = Url::parse("https://t.me/").expect("the base URL is valid");
+    url.set_path(bot_username);
+    url.query_pairs_mut().append_pair(param, payload);
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn links() {
+        assert_eq!(
+            startgroup_link("examplebot", "abc").unwrap().as_str(),
+            "https://t.me/examplebot?startgroup=abc"
+        );
+        assert_eq!(
+            start_link("examplebot", "a b").unwrap_err(),
+            DeepLinkError::InvalidCharacter(' ')
+        );
+        assert_eq!(
+            start_link("examplebot", &"a".repeat(65)).unwrap_err(),
+            DeepLinkError::TooLong(65)
+        );
+    }
+
+    #[test]
+    fn payload_roundtrip() {
+        let data = b"\xff\xfe user 42 \x00";
+        let payload = encode_payload(data).unwrap();
+        assert!(validate_payload(&payload).is_ok());
+        assert_eq!(decode_payload(&payload).unwrap(), data);
+
+        assert_eq!(encode_payload([0; 49]).unwrap_err(), DeepLinkError::TooLong(66));
+        assert!(matches!(decode_payload("a"), Err(DeepLinkError::Base64(_))));
+    }
+
+    #[test]
+    fn start_payload() {
+        assert_eq!(parse_start_payload("/start  abc ", "bot"), Some("abc"));
+        assert_eq!(parse_start_payload("/START@Bot abc", "bot"), None);
+        assert_eq!(parse_start_payload("/start@BOT abc", "bot"), Some("abc"));
+        assert_eq!(parse_start_payload("/started abc", "bot"), None);
+        assert_eq!(parse_start_payload("/help abc", "bot"), None);
+    }
+}
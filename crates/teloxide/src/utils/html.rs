@@ -44,6 +44,36 @@ pub fn strike(s: &str) -> String {
     format!("<s>{s}</s>")
 }
 
+/// Applies the spoiler style to the string.
+///
+/// Passed string will not be automatically escaped because it can contain
+/// nested markup.
+#[must_use = "This function returns a new string, rather than mutating the argument, so calling it \
+              without using its output does nothing useful"]
+pub fn spoiler(s: &str) -> String {
+    format!("<tg-spoiler>{s}</tg-spoiler>")
+}
+
+/// Formats the block quotation.
+///
+/// Passed string will not be automatically escaped because it can contain
+/// nested markup.
+#[must_use = "This function returns a new string, rather than mutating the argument, so calling it \
+              without using its output does nothing useful"]
+pub fn blockquote(s: &str) -> String {
+    format!("<blockquote>{s}</blockquote>")
+}
+
+/// Formats the block quotation that is collapsed by default.
+///
+/// Passed string will not be automatically escaped because it can contain
+/// nested markup.
+#[must_use = "This function returns a new string, rather than mutating the argument, so calling it \
+              without using its output does nothing useful"]
+pub fn expandable_blockquote(s: &str) -> String {
+    format!("<blockquote expandable>{s}</blockquote>")
+}
+
 /// Builds an inline link with an anchor.
 ///
 /// Escapes the passed URL and the link text.
@@ -60,6 +90,15 @@ pub fn user_mention(user_id: UserId, text: &str) -> String {
     link(format!("tg://user?id={user_id}").as_str(), text)
 }
 
+/// Builds a custom emoji with `alt` used as a fallback.
+///
+/// `alt` must be a valid emoji, it is escaped.
+#[must_use = "This function returns a new string, rather than mutating the argument, so calling it \
+              without using its output does nothing useful"]
+pub fn custom_emoji(custom_emoji_id: &str, alt: &str) -> String {
+    format!("<tg-emoji emoji-id=\"{}\">{}</tg-emoji>", escape(custom_emoji_id), escape(alt))
+}
+
 /// Formats the code block.
 ///
 /// Escapes HTML characters inside the block.
@@ -145,6 +184,29 @@ mod tests {
         assert_eq!(strike("<b>(`foobar`)</b>"), "<s><b>(`foobar`)</b></s>");
     }
 
+    #[test]
+    fn test_spoiler() {
+        assert_eq!(spoiler(" foobar "), "<tg-spoiler> foobar </tg-spoiler>");
+        assert_eq!(spoiler(" <b>foobar</b> "), "<tg-spoiler> <b>foobar</b> </tg-spoiler>");
+    }
+
+    #[test]
+    fn test_blockquote() {
+        assert_eq!(blockquote(" <b>foobar</b> "), "<blockquote> <b>foobar</b> </blockquote>");
+        assert_eq!(
+            expandable_blockquote("foo\nbar"),
+            "<blockquote expandable>foo\nbar</blockquote>"
+        );
+    }
+
+    #[test]
+    fn test_custom_emoji() {
+        assert_eq!(
+            custom_emoji("5368324170671202286", "👍"),
+            "<tg-emoji emoji-id=\"5368324170671202286\">👍</tg-emoji>"
+        );
+    }
+
     #[test]
     fn test_link() {
         assert_eq!(
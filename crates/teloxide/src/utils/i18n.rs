@@ -0,0 +1,182 @@
+//! Utils for localizing bots.
+//!
+//! [`Translations`] is a simple map-based lookup of localized strings by key.
+//! The locale of a user is taken from [`User::language_code`], unless the user
+//! has chosen another one, which is stored in a [dialogue storage] (see
+//! [`set_user_locale`] and [`user_locale`]).
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use teloxide::{
+//!     dispatching::dialogue::InMemStorage,
+//!     prelude::*,
+//!     utils::i18n::{self, Translations},
+//! };
+//!
+//! # async fn run(bot: Bot, msg: Message) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let translations = Translations::new("en")
+//!     .add("en", "greeting", "Hello!")
+//!     .add("pt", "greeting", "Olá!")
+//!     .add("pt-BR", "greeting", "Oi!");
+//! let locales = InMemStorage::<String>::new();
+//!
+//! let user = msg.from().unwrap();
+//! let locale = i18n::user_locale(locales, user).await?;
+//! bot.send_message(msg.chat.id, translations.get(locale.as_deref(), "greeting")).await?;
+//! # Ok(()) }
+//! ```
+//!
+//! See also [`HandlerExt::filter_language`].
+//!
+//! [`User::language_code`]: crate::types::User::language_code
+//! [dialogue storage]: crate::dispatching::dialogue::Storage
+//! [`HandlerExt::filter_language`]: crate::dispatching::HandlerExt::filter_language
+
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+    dispatching::dialogue::Storage,
+    types::{ChatId, User, UserId},
+};
+
+/// Localized strings.
+///
+/// Strings are looked up in the following locales, in order:
+///
+///  1. The requested locale, e.g. `pt-BR`;
+///  2. Its language, e.g. `pt`;
+///  3. The default locale.
+///
+/// If a string is not found in any of them, the key itself is returned.
+///
+/// See the [module-level documentation](self).
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct Translations {
+    default_locale: String,
+    strings: HashMap<String, HashMap<String, String>>,
+}
+
+impl Translations {
+    /// Creates empty translations with the `default_locale` fallback.
+    pub fn new<L>(default_locale: L) -> Self
+    where
+        L: Into<String>,
+    {
+        Self { default_locale: default_locale.into(), strings: HashMap::new() }
+    }
+
+    /// Adds a string `value` for `key` in `locale`.
+    pub fn add<L, K, V>(mut self, locale: L, key: K, value: V) -> Self
+    where
+        L: Into<String>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.strings.entry(locale.into()).or_default().insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds strings in `locale`.
+    pub fn add_all<L, I, K, V>(mut self, locale: L, strings: I) -> Self
+    where
+        L: Into<String>,
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let locale = self.strings.entry(locale.into()).or_default();
+        locale.extend(strings.into_iter().map(|(key, value)| (key.into(), value.into())));
+        self
+    }
+
+    /// Returns the default locale.
+    #[must_use]
+    pub fn default_locale(&self) -> &str {
+        &self.default_locale
+    }
+
+    /// Returns the string for `key` in `locale`, or in the default locale if
+    /// `locale` is `None`.
+    ///
+    /// See [`Translations`] for the fallback rules.
+    #[must_use]
+    pub fn get<'a>(&'a self, locale: Option<&str>, key: &'a str) -> &'a str {
+        let locale = locale.unwrap_or(&self.default_locale);
+        let language = language(locale);
+
+        [locale, language, &self.default_locale]
+            .into_iter()
+            .find_map(|locale| self.strings.get(locale)?.get(key))
+            .map_or(key, String::as_str)
+    }
+}
+
+/// Returns the language of `locale`, e.g. `pt` for `pt-BR`.
+pub(crate) fn language(locale: &str) -> &str {
+    locale.split(['-', '_']).next().unwrap_or(locale)
+}
+
+/// Returns the locale of `user`: the one set with [`set_user_locale`] or, if
+/// there is none, the [language code] of the user.
+///
+/// Locales are stored in `storage` as dialogues of private chats with users.
+///
+/// [language code]: crate::types::User::language_code
+pub async fn user_locale<S>(storage: Arc<S>, user: &User) -> Result<Option<String>, S::Error>
+where
+    S: Storage<String> + ?Sized,
+{
+    let locale = storage.get_dialogue(ChatId::from(user.id)).await?;
+    Ok(locale.or_else(|| user.language_code.clone()))
+}
+
+/// Sets the locale of `user_id`, overriding their [language code].
+///
+/// See [`user_locale`].
+///
+/// [language code]: crate::types::User::language_code
+pub async fn set_user_locale<S, L>(
+    storage: Arc<S>,
+    user_id: UserId,
+    locale: L,
+) -> Result<(), S::Error>
+where
+    S: Storage<String> + ?Sized,
+    L: Into<String>,
+{
+    storage.update_dialogue(ChatId::from(user_id), locale.into()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dispatching::dialogue::InMemStorage;
+
+    #[test]
+    fn fallbacks() {
+        let translations = Translations::new("en")
+            .add_all("en", [("hi", "Hello"), ("bye", "Bye")])
+            .add("pt", "hi", "Olá")
+            .add("pt-BR", "hi", "Oi");
+
+        assert_eq!(translations.get(Some("pt-BR"), "hi"), "Oi");
+        assert_eq!(translations.get(Some("pt-PT"), "hi"), "Olá");
+        assert_eq!(translations.get(Some("pt_PT"), "bye"), "Bye");
+        assert_eq!(translations.get(None, "hi"), "Hello");
+        assert_eq!(translations.get(Some("de"), "unknown"), "unknown");
+    }
+
+    #[tokio::test]
+    async fn overrides() {
+        let storage = InMemStorage::<String>::new();
+        let mut user = User::fixture(UserId(1));
+        user.language_code = Some("de".to_owned());
+
+        assert_eq!(user_locale(Arc::clone(&storage), &user).await.unwrap().as_deref(), Some("de"));
+
+        set_user_locale(Arc::clone(&storage), user.id, "fr").await.unwrap();
+        assert_eq!(user_locale(storage, &user).await.unwrap().as_deref(), Some("fr"));
+    }
+}
@@ -0,0 +1,200 @@
+//! Utils for tracking [chosen inline results].
+//!
+//! A [`ChosenInlineResult`] only contains the identifier of a result and the
+//! text of the query. To find out what was actually sent, a bot must remember
+//! its answers. [`InlineResultTracker`] does exactly this: you attach data to
+//! every result when answering an [`InlineQuery`], and get it back when a user
+//! chooses one of them. If the sent message has an inline keyboard, the data
+//! is also remembered by its `inline_message_id`, so that the message can be
+//! edited later.
+//!
+//! Note that Telegram sends chosen inline results only if [inline feedback] is
+//! enabled for the bot.
+//!
+//! [chosen inline results]: https://core.telegram.org/bots/api#choseninlineresult
+//! [inline feedback]: https://core.telegram.org/bots/inline#collecting-feedback
+
+use std::{collections::HashMap, sync::Mutex};
+
+use crate::types::{ChosenInlineResult, InlineQuery, UserId};
+
+/// Correlates chosen inline results with the queries they were sent for.
+///
+/// Only results of the latest query of each user are kept, so the memory usage
+/// is bounded by the number of users. Data of sent messages is kept until it
+/// is [forgotten].
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide::{types::ChosenInlineResult, utils::inline_results::InlineResultTracker};
+///
+/// # fn f(query: teloxide::types::InlineQuery, chosen: ChosenInlineResult) {
+/// let tracker = InlineResultTracker::new();
+///
+/// // When answering an inline query:
+/// tracker.track(&query, "result-1", "data of the first result");
+///
+/// // When receiving a chosen inline result:
+/// if let Some(data) = tracker.choose(&chosen) {
+///     println!("The user has sent {data}");
+/// }
+/// # }
+/// ```
+///
+/// [forgotten]: InlineResultTracker::forget
+#[derive(Debug)]
+pub struct InlineResultTracker<T> {
+    pending: Mutex<HashMap<UserId, PendingResults<T>>>,
+    sent: Mutex<HashMap<String, T>>,
+}
+
+#[derive(Debug)]
+struct PendingResults<T> {
+    query: String,
+    results: HashMap<String, T>,
+}
+
+impl<T> InlineResultTracker<T> {
+    /// Creates a tracker without tracked results.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { pending: Mutex::new(HashMap::new()), sent: Mutex::new(HashMap::new()) }
+    }
+
+    /// Attaches `data` to a result with `result_id`, sent as an answer to
+    /// `query`.
+    ///
+    /// Results of previous queries of the same user with a different text are
+    /// discarded. Results of the same query text (e.g. different pages of it)
+    /// are kept together.
+    pub fn track<R>(&self, query: &InlineQuery, result_id: R, data: T)
+    where
+        R: Into<String>,
+    {
+        let mut pending = self.pending.lock().unwrap();
+        let entry = pending.entry(query.from.id).or_insert_with(|| PendingResults {
+            query: query.query.clone(),
+            results: HashMap::new(),
+        });
+
+        if entry.query != query.query {
+            entry.query.clone_from(&query.query);
+            entry.results.clear();
+        }
+
+        entry.results.insert(result_id.into(), data);
+    }
+
+    /// Returns data of a chosen result, or `None` if it wasn't tracked.
+    ///
+    /// All other results of the same query are discarded. If the sent message
+    /// has an `inline_message_id`, the data is remembered and can be retrieved
+    /// later via [`InlineResultTracker::get`].
+    pub fn choose(&self, chosen: &ChosenInlineResult) -> Option<T>
+    where
+        T: Clone,
+    {
+        let data = {
+            let mut pending = self.pending.lock().unwrap();
+            match pending.get(&chosen.from.id) {
+                Some(entry) if entry.query == chosen.query => {}
+                _ => return None,
+            }
+
+            pending.remove(&chosen.from.id)?.results.remove(&chosen.result_id)?
+        };
+
+        if let Some(inline_message_id) = &chosen.inline_message_id {
+            self.sent.lock().unwrap().insert(inline_message_id.clone(), data.clone());
+        }
+
+        Some(data)
+    }
+
+    /// Returns data of a sent message with `inline_message_id`.
+    #[must_use]
+    pub fn get(&self, inline_message_id: &str) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.sent.lock().unwrap().get(inline_message_id).cloned()
+    }
+
+    /// Forgets and returns data of a sent message with `inline_message_id`.
+    pub fn forget(&self, inline_message_id: &str) -> Option<T> {
+        self.sent.lock().unwrap().remove(inline_message_id)
+    }
+}
+
+impl<T> Default for InlineResultTracker<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::User;
+
+    fn user(id: u64) -> User {
+        User {
+            id: UserId(id),
+            is_bot: false,
+            first_name: "A".to_owned(),
+            last_name: None,
+            username: None,
+            language_code: None,
+            is_premium: false,
+            added_to_attachment_menu: false,
+        }
+    }
+
+    fn query(user_id: u64, text: &str) -> InlineQuery {
+        InlineQuery {
+            id: "id".to_owned(),
+            from: user(user_id),
+            location: None,
+            query: text.to_owned(),
+            offset: String::new(),
+            chat_type: None,
+        }
+    }
+
+    fn chosen(user_id: u64, text: &str, result_id: &str) -> ChosenInlineResult {
+        ChosenInlineResult {
+            result_id: result_id.to_owned(),
+            from: user(user_id),
+            location: None,
+            inline_message_id: Some(format!("msg-{result_id}")),
+            query: text.to_owned(),
+        }
+    }
+
+    #[test]
+    fn choose() {
+        let tracker = InlineResultTracker::new();
+        tracker.track(&query(1, "cat"), "a", 1);
+        tracker.track(&query(1, "cat"), "b", 2);
+        tracker.track(&query(2, "dog"), "a", 3);
+
+        assert_eq!(tracker.choose(&chosen(1, "cat", "b")), Some(2));
+        assert_eq!(tracker.choose(&chosen(1, "cat", "a")), None);
+        assert_eq!(tracker.choose(&chosen(2, "dog", "a")), Some(3));
+
+        assert_eq!(tracker.get("msg-b"), Some(2));
+        assert_eq!(tracker.forget("msg-b"), Some(2));
+        assert_eq!(tracker.get("msg-b"), None);
+    }
+
+    #[test]
+    fn new_query_discards_results() {
+        let tracker = InlineResultTracker::new();
+        tracker.track(&query(1, "cat"), "a", 1);
+        tracker.track(&query(1, "cats"), "b", 2);
+
+        assert_eq!(tracker.choose(&chosen(1, "cat", "a")), None);
+        assert_eq!(tracker.choose(&chosen(1, "cats", "b")), Some(2));
+    }
+}
@@ -0,0 +1,263 @@
+//! Utils for sharing [live locations].
+//!
+//! [live locations]: https://telegram.org/blog/live-locations
+
+use std::time::Duration;
+
+use futures::{
+    future,
+    stream::{self, Stream, StreamExt},
+};
+use tokio::time::{interval_at, Instant};
+use tokio_stream::wrappers::IntervalStream;
+
+use crate::{
+    payloads::SendLocationSetters,
+    requests::Requester,
+    types::{MessageId, Recipient},
+};
+
+/// A live location session.
+///
+/// A session sends a live location message and keeps it up to date with
+/// coordinates from a stream. To respect rate limits, the message is edited at
+/// most once per heartbeat interval, with the latest coordinates received in
+/// the meantime. When the stream ends, the latest coordinates are sent and the
+/// live location is stopped.
+///
+/// Telegram doesn't allow editing a live location after its live period
+/// expires, so the session finishes at this point as well.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use teloxide::{prelude::*, utils::live_location::Session};
+///
+/// # async fn run(bot: Bot, chat_id: ChatId) -> Result<(), teloxide::RequestError> {
+/// let coordinates = futures::stream::iter([(51.5, -0.12), (51.51, -0.11)]);
+///
+/// Session::new(chat_id)
+///     .live_period(Duration::from_secs(15 * 60))
+///     .heartbeat(Duration::from_secs(10))
+///     .run(&bot, coordinates)
+///     .await?;
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "`Session` does nothing unless `run` is called"]
+pub struct Session {
+    chat_id: Recipient,
+    live_period: Duration,
+    heartbeat: Duration,
+}
+
+enum Event {
+    Location((f64, f64)),
+    Heartbeat,
+    End,
+}
+
+impl Session {
+    /// Minimal live period allowed by Telegram.
+    pub const MIN_LIVE_PERIOD: Duration = Duration::from_secs(60);
+
+    /// Maximal live period allowed by Telegram.
+    pub const MAX_LIVE_PERIOD: Duration = Duration::from_secs(86400);
+
+    /// Creates a session which shares a live location in `chat_id`.
+    ///
+    /// By default, the live period is 15 minutes and the heartbeat interval is
+    /// 5 seconds.
+    pub fn new<C>(chat_id: C) -> Self
+    where
+        C: Into<Recipient>,
+    {
+        Self {
+            chat_id: chat_id.into(),
+            live_period: Duration::from_secs(15 * 60),
+            heartbeat: Duration::from_secs(5),
+        }
+    }
+
+    /// Sets the period during which the location can be updated.
+    ///
+    /// The period is clamped to [`Session::MIN_LIVE_PERIOD`] and
+    /// [`Session::MAX_LIVE_PERIOD`].
+    pub fn live_period(self, val: Duration) -> Self {
+        Self { live_period: val.clamp(Self::MIN_LIVE_PERIOD, Self::MAX_LIVE_PERIOD), ..self }
+    }
+
+    /// Sets the minimal interval between edits of the location.
+    ///
+    /// ## Panics
+    ///
+    /// If `val` is zero.
+    pub fn heartbeat(self, val: Duration) -> Self {
+        assert!(!val.is_zero(), "heartbeat interval must be non-zero");
+        Self { heartbeat: val, ..self }
+    }
+
+    /// Runs the session, sharing `coordinates` (latitude and longitude).
+    ///
+    /// The live location message is sent when the first coordinates are
+    /// received. If `coordinates` ends without yielding anything, nothing is
+    /// sent.
+    pub async fn run<R, S>(self, bot: R, coordinates: S) -> Result<(), R::Err>
+    where
+        R: Requester,
+        S: Stream<Item = (f64, f64)>,
+    {
+        let Self { chat_id, live_period, heartbeat } = self;
+        futures::pin_mut!(coordinates);
+
+        let Some((latitude, longitude)) = coordinates.next().await else {
+            return Ok(());
+        };
+
+        let deadline = Instant::now() + live_period;
+        let message_id = bot
+            .send_location(chat_id.clone(), latitude, longitude)
+            .live_period(live_period.as_secs() as u32)
+            .await?
+            .id;
+
+        let edit = |(latitude, longitude): (f64, f64), message_id: MessageId| {
+            bot.edit_message_live_location(chat_id.clone(), message_id, latitude, longitude)
+        };
+
+        let ticks = IntervalStream::new(interval_at(Instant::now() + heartbeat, heartbeat))
+            .map(|_| Event::Heartbeat);
+        let locations =
+            coordinates.map(Event::Location).chain(stream::once(future::ready(Event::End)));
+        let mut events = stream::select(locations, ticks);
+
+        let mut pending = None;
+        while let Some(event) = events.next().await {
+            if Instant::now() >= deadline {
+                return Ok(());
+            }
+
+            match event {
+                Event::Location(location) => pending = Some(location),
+                Event::Heartbeat => {
+                    if let Some(location) = pending.take() {
+                        edit(location, message_id).await?;
+                    }
+                }
+                Event::End => break,
+            }
+        }
+
+        if let Some(location) = pending {
+            edit(location, message_id).await?;
+        }
+        bot.stop_message_live_location(chat_id, message_id).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        payloads::{EditMessageLiveLocationTarget, SendLocation, StopMessageLiveLocationTarget},
+        test::MockBot,
+        types::{ChatId, EditedMessage, Message, TargetMessage, UserId},
+    };
+
+    fn mock_bot() -> (MockBot, Message) {
+        let bot = MockBot::new();
+        let message = Message::text_fixture(ChatId(1), UserId(1), "");
+        bot.respond::<SendLocation>(message.clone());
+        for _ in 0..3 {
+            let edited = EditedMessage::Common(Box::new(message.clone()));
+            bot.respond::<EditMessageLiveLocationTarget>(edited.clone());
+            bot.respond::<StopMessageLiveLocationTarget>(edited);
+        }
+        (bot, message)
+    }
+
+    /// Yields `locations` after the corresponding delays.
+    fn delayed(locations: Vec<(u64, (f64, f64))>) -> impl Stream<Item = (f64, f64)> {
+        stream::iter(locations).then(|(secs, location)| async move {
+            tokio::time::sleep(Duration::from_secs(secs)).await;
+            location
+        })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn clamps_live_period() {
+        for (live_period, expected) in [(1, 60), (15 * 60, 15 * 60), (7 * 86400, 86400)] {
+            let (bot, _) = mock_bot();
+            Session::new(ChatId(1))
+                .live_period(Duration::from_secs(live_period))
+                .run(&bot, stream::iter([(1.0, 2.0)]))
+                .await
+                .unwrap();
+
+            assert_eq!(bot.requests_of::<SendLocation>()[0].live_period, Some(expected));
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn edits_on_heartbeat_and_stops() {
+        let (bot, message) = mock_bot();
+        let coordinates =
+            delayed(vec![(0, (1.0, 1.0)), (1, (2.0, 2.0)), (1, (3.0, 3.0)), (5, (4.0, 4.0))]);
+
+        Session::new(ChatId(1))
+            .heartbeat(Duration::from_secs(5))
+            .run(&bot, coordinates)
+            .await
+            .unwrap();
+
+        // Only the latest coordinates are sent on a heartbeat, and the pending
+        // ones are sent when the stream ends
+        let target = TargetMessage::from((ChatId(1), message.id));
+        let edits: Vec<_> = bot
+            .requests_of::<EditMessageLiveLocationTarget>()
+            .into_iter()
+            .map(|edit| {
+                assert_eq!(edit.target, target);
+                (edit.latitude, edit.longitude)
+            })
+            .collect();
+        assert_eq!(edits, [(3.0, 3.0), (4.0, 4.0)]);
+        assert_eq!(bot.requests_of::<StopMessageLiveLocationTarget>()[0].target, target);
+        assert_eq!(
+            bot.request_names(),
+            [
+                "SendLocation",
+                "EditMessageLiveLocationTarget",
+                "EditMessageLiveLocationTarget",
+                "StopMessageLiveLocationTarget"
+            ]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn finishes_when_live_period_expires() {
+        let (bot, _) = mock_bot();
+        let coordinates = delayed(vec![(0, (1.0, 1.0)), (1, (2.0, 2.0))]).chain(stream::pending());
+
+        Session::new(ChatId(1))
+            .live_period(Duration::from_secs(60))
+            .heartbeat(Duration::from_secs(5))
+            .run(&bot, coordinates)
+            .await
+            .unwrap();
+
+        // The location can't be edited after the live period, so it isn't stopped
+        assert_eq!(bot.request_names(), ["SendLocation", "EditMessageLiveLocationTarget"]);
+    }
+
+    #[tokio::test]
+    async fn sends_nothing_without_coordinates() {
+        let (bot, _) = mock_bot();
+        Session::new(ChatId(1)).run(&bot, stream::empty()).await.unwrap();
+        assert!(bot.request_names().is_empty());
+    }
+}
@@ -0,0 +1,183 @@
+//! Utils for verifying data received from the [Telegram Login Widget].
+//!
+//! When a user is authorized via the Login Widget or a [`LoginUrl`] button,
+//! Telegram redirects them to your website with the user's data in the query
+//! string. The data is signed with a bot token, so the website can check that
+//! it actually came from Telegram using [`verify`].
+//!
+//! [Telegram Login Widget]: https://core.telegram.org/widgets/login
+//! [`LoginUrl`]: crate::types::LoginUrl
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use url::Url;
+
+use crate::types::UserId;
+
+/// Data of a user authorized via the Telegram Login Widget.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LoginData {
+    /// Unique identifier of the user.
+    pub id: UserId,
+
+    /// First name of the user.
+    pub first_name: String,
+
+    /// Last name of the user.
+    pub last_name: Option<String>,
+
+    /// Username of the user.
+    pub username: Option<String>,
+
+    /// URL of the user's profile photo.
+    pub photo_url: Option<Url>,
+
+    /// The time when the user was authorized.
+    pub auth_date: DateTime<Utc>,
+}
+
+/// An error returned by [`verify`].
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum LoginWidgetError {
+    /// The `hash` parameter is missing.
+    #[error("The `hash` parameter is missing")]
+    MissingHash,
+
+    /// The data is not signed with the given bot token, or it was tampered
+    /// with.
+    #[error("The hash doesn't match the data")]
+    InvalidHash,
+
+    /// A required parameter is missing.
+    #[error("The `{0}` parameter is missing")]
+    MissingField(&'static str),
+
+    /// A parameter has an invalid value.
+    #[error("The `{0}` parameter has an invalid value")]
+    InvalidField(&'static str),
+}
+
+/// Verifies data received from the Telegram Login Widget.
+///
+/// `query_params` are all parameters of the callback query string (including
+/// `hash`). The data check string is built from every parameter except `hash`,
+/// and its HMAC-SHA-256 signature with the SHA-256 of `bot_token` as a key is
+/// compared with `hash`, as described in the [official docs].
+///
+/// Note that this function doesn't check `auth_date`. You may want to reject
+/// outdated data, to prevent replay attacks.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide::utils::login_widget;
+/// use url::Url;
+///
+/// let url = Url::parse(
+///     "https://example.com/login?id=42&first_name=John&username=john&auth_date=1700000000\
+///      &hash=b81455cfc581264682577e7f578fa046e04fedb7cec5732045ca59c77374f370",
+/// )
+/// .unwrap();
+///
+/// let data = login_widget::verify(url.query_pairs(), "123:ABC").unwrap();
+/// assert_eq!(data.username.as_deref(), Some("john"));
+/// ```
+///
+/// [official docs]: https://core.telegram.org/widgets/login#checking-authorization
+pub fn verify<I, K, V>(query_params: I, bot_token: &str) -> Result<LoginData, LoginWidgetError>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let mut params: BTreeMap<String, String> = query_params
+        .into_iter()
+        .map(|(k, v)| (k.as_ref().to_owned(), v.as_ref().to_owned()))
+        .collect();
+
+    let hash = params.remove("hash").ok_or(LoginWidgetError::MissingHash)?;
+    let hash = hex::decode(hash).map_err(|_| LoginWidgetError::InvalidHash)?;
+
+    let data_check_string =
+        params.iter().map(|(k, v)| format!("{k}={v}")).collect::<Vec<_>>().join("\n");
+
+    let secret_key = Sha256::digest(bot_token.as_bytes());
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(&secret_key).expect("HMAC can take a key of any size");
+    mac.update(data_check_string.as_bytes());
+    mac.verify_slice(&hash).map_err(|_| LoginWidgetError::InvalidHash)?;
+
+    parse(params)
+}
+
+fn parse(mut params: BTreeMap<String, String>) -> Result<LoginData, LoginWidgetError> {
+    let mut required = |name| params.remove(name).ok_or(LoginWidgetError::MissingField(name));
+
+    let id = required("id")?.parse().map_err(|_| LoginWidgetError::InvalidField("id"))?;
+    let first_name = required("first_name")?;
+    let auth_date = required("auth_date")?
+        .parse()
+        .ok()
+        .and_then(|secs| DateTime::from_timestamp(secs, 0))
+        .ok_or(LoginWidgetError::InvalidField("auth_date"))?;
+    let photo_url = params
+        .remove("photo_url")
+        .map(|url| url.parse().map_err(|_| LoginWidgetError::InvalidField("photo_url")))
+        .transpose()?;
+
+    Ok(LoginData {
+        id: UserId(id),
+        first_name,
+        last_name: params.remove("last_name"),
+        username: params.remove("username"),
+        photo_url,
+        auth_date,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: &str = "123:ABC";
+    const HASH: &str = "b81455cfc581264682577e7f578fa046e04fedb7cec5732045ca59c77374f370";
+
+    fn params(hash: &str) -> Vec<(&str, &str)> {
+        vec![
+            ("username", "john"),
+            ("hash", hash),
+            ("id", "42"),
+            ("auth_date", "1700000000"),
+            ("first_name", "John"),
+        ]
+    }
+
+    #[test]
+    fn valid() {
+        let data = verify(params(HASH), TOKEN).unwrap();
+
+        assert_eq!(data.id, UserId(42));
+        assert_eq!(data.first_name, "John");
+        assert_eq!(data.last_name, None);
+        assert_eq!(data.username.as_deref(), Some("john"));
+        assert_eq!(data.auth_date.timestamp(), 1_700_000_000);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(verify(params(HASH), "123:ABD"), Err(LoginWidgetError::InvalidHash));
+        assert_eq!(verify(params("zz"), TOKEN), Err(LoginWidgetError::InvalidHash));
+        assert_eq!(
+            verify(params(HASH).into_iter().filter(|(k, _)| *k != "hash"), TOKEN),
+            Err(LoginWidgetError::MissingHash)
+        );
+
+        let mut tampered = params(HASH);
+        tampered[0].1 = "durov";
+        assert_eq!(verify(tampered, TOKEN), Err(LoginWidgetError::InvalidHash));
+    }
+}
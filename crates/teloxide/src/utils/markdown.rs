@@ -59,6 +59,36 @@ pub fn strike(s: &str) -> String {
     format!("~{s}~")
 }
 
+/// Applies the spoiler style to the string.
+///
+/// Passed string will not be automatically escaped because it can contain
+/// nested markup.
+#[must_use = "This function returns a new string, rather than mutating the argument, so calling it \
+              without using its output does nothing useful"]
+pub fn spoiler(s: &str) -> String {
+    format!("||{s}||")
+}
+
+/// Formats the block quotation.
+///
+/// Passed string will not be automatically escaped because it can contain
+/// nested markup.
+#[must_use = "This function returns a new string, rather than mutating the argument, so calling it \
+              without using its output does nothing useful"]
+pub fn blockquote(s: &str) -> String {
+    s.split('\n').map(|line| format!(">{line}")).collect::<Vec<_>>().join("\n")
+}
+
+/// Formats the block quotation that is collapsed by default.
+///
+/// Passed string will not be automatically escaped because it can contain
+/// nested markup.
+#[must_use = "This function returns a new string, rather than mutating the argument, so calling it \
+              without using its output does nothing useful"]
+pub fn expandable_blockquote(s: &str) -> String {
+    format!("**{}||", blockquote(s))
+}
+
 /// Builds an inline link with an anchor.
 ///
 /// Escapes `)` and ``` characters inside the link url.
@@ -75,6 +105,15 @@ pub fn user_mention(user_id: UserId, text: &str) -> String {
     link(format!("tg://user?id={user_id}").as_str(), text)
 }
 
+/// Builds a custom emoji with `alt` used as a fallback.
+///
+/// `alt` must be a valid emoji.
+#[must_use = "This function returns a new string, rather than mutating the argument, so calling it \
+              without using its output does nothing useful"]
+pub fn custom_emoji(custom_emoji_id: &str, alt: &str) -> String {
+    format!("![{alt}](tg://emoji?id={})", escape_link_url(custom_emoji_id))
+}
+
 /// Formats the code block.
 ///
 /// Escapes ``` and `\` characters inside the block.
@@ -186,6 +225,26 @@ mod tests {
         assert_eq!(strike("*(foobar)*"), "~*(foobar)*~");
     }
 
+    #[test]
+    fn test_spoiler() {
+        assert_eq!(spoiler(" foobar "), "|| foobar ||");
+        assert_eq!(spoiler("*foobar*"), "||*foobar*||");
+    }
+
+    #[test]
+    fn test_blockquote() {
+        assert_eq!(blockquote("foo\n*bar*"), ">foo\n>*bar*");
+        assert_eq!(expandable_blockquote("foo\nbar"), "**>foo\n>bar||");
+    }
+
+    #[test]
+    fn test_custom_emoji() {
+        assert_eq!(
+            custom_emoji("5368324170671202286", "👍"),
+            "![👍](tg://emoji?id=5368324170671202286)"
+        );
+    }
+
     #[test]
     fn test_italic_with_underline() {
         assert_eq!(underline(italic("foobar").as_str()), r"___foobar_\r__");
@@ -0,0 +1,309 @@
+//! Utils for caching downloaded media files.
+//!
+//! Bots which process media (e.g. convert stickers or resize images) often
+//! receive the same file many times. [`MediaCache`] keeps downloaded files in a
+//! directory, keyed by their [`FileUniqueId`], so that each file is downloaded
+//! only once.
+
+use std::{
+    collections::HashMap,
+    io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+use thiserror::Error;
+use tokio::io::AsyncWriteExt;
+
+use crate::{
+    net::Download,
+    requests::Requester,
+    types::{FileId, FileUniqueId},
+    DownloadError,
+};
+
+/// The extension of files which are being downloaded.
+const PARTIAL_EXTENSION: &str = "part";
+
+/// A disk-backed cache of downloaded files.
+///
+/// Files are stored in a directory, named by their [`FileUniqueId`]. When the
+/// total size of the files exceeds the configured maximum, the least recently
+/// used files are removed. Files in the directory are picked up again when a
+/// cache is [opened], e.g. after a restart of the bot.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide::{prelude::*, utils::media_cache::MediaCache};
+///
+/// # async fn run(bot: Bot, msg: Message) -> Result<(), Box<dyn std::error::Error>> {
+/// // Keep up to 100 MiB of files
+/// let cache = MediaCache::open("media", 100 * 1024 * 1024).await?;
+///
+/// if let Some(sticker) = msg.sticker() {
+///     let path = cache.get_or_download(&bot, sticker.file.id.clone()).await?;
+///     let bytes = tokio::fs::read(path).await?;
+///     // ...
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [opened]: MediaCache::open
+#[derive(Debug)]
+pub struct MediaCache {
+    dir: PathBuf,
+    max_size: u64,
+    files: Mutex<CachedFiles>,
+    downloads: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct CachedFiles {
+    files: HashMap<FileUniqueId, CachedFile>,
+    size: u64,
+    clock: u64,
+}
+
+#[derive(Debug)]
+struct CachedFile {
+    size: u64,
+    last_used: u64,
+}
+
+/// An error returned by [`MediaCache::get_or_download`].
+#[derive(Debug, Error)]
+pub enum MediaCacheError<E> {
+    /// Getting the file has failed.
+    #[error("Couldn't get the file: {0}")]
+    Request(#[source] E),
+
+    /// Downloading the file has failed.
+    #[error("Couldn't download the file: {0}")]
+    Download(#[from] DownloadError),
+
+    /// Storing the file in the cache has failed.
+    #[error("Couldn't store the file: {0}")]
+    Io(#[from] io::Error),
+}
+
+impl MediaCache {
+    /// Opens a cache in `dir`, which keeps at most `max_size` bytes of files.
+    ///
+    /// The directory is created if it doesn't exist. Files which are already
+    /// in it are kept, unfinished downloads are removed.
+    pub async fn open(dir: impl Into<PathBuf>, max_size: u64) -> io::Result<Self> {
+        let dir = dir.into();
+        tokio::fs::create_dir_all(&dir).await?;
+
+        let mut found = Vec::new();
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().map_or(false, |ext| ext == PARTIAL_EXTENSION) {
+                tokio::fs::remove_file(&path).await?;
+                continue;
+            }
+
+            let metadata = entry.metadata().await?;
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else { continue };
+            if metadata.is_file() {
+                found.push((FileUniqueId::from(name), metadata.len(), metadata.modified().ok()));
+            }
+        }
+
+        // Files which were modified earlier were used earlier
+        found.sort_by_key(|(_, _, modified)| *modified);
+        let mut files = CachedFiles::default();
+        for (unique_id, size, _) in found {
+            files.insert(unique_id, size);
+        }
+
+        Ok(Self { dir, max_size, files: Mutex::new(files), downloads: AtomicU64::new(0) })
+    }
+
+    /// Returns the path of a cached file with `unique_id`, if any.
+    ///
+    /// Note that the file can be removed from the cache (and the disk) after
+    /// other files are downloaded.
+    #[must_use]
+    pub fn get(&self, unique_id: &FileUniqueId) -> Option<PathBuf> {
+        self.files.lock().unwrap().touch(unique_id).then(|| self.path(unique_id))
+    }
+
+    /// Returns the path of a file with `file_id`, downloading it if it isn't
+    /// cached.
+    ///
+    /// The file is first requested with [`GetFile`], to learn its
+    /// [`FileUniqueId`]. If its unique id is already known, [`MediaCache::get`]
+    /// can be used to avoid the request.
+    ///
+    /// Note that the file can be removed from the cache (and the disk) after
+    /// other files are downloaded.
+    ///
+    /// [`GetFile`]: crate::payloads::GetFile
+    pub async fn get_or_download<R>(
+        &self,
+        bot: &R,
+        file_id: impl Into<FileId>,
+    ) -> Result<PathBuf, MediaCacheError<<R as Requester>::Err>>
+    where
+        R: Requester + for<'dst> Download<Err<'dst> = DownloadError>,
+    {
+        let file = bot.get_file(file_id).await.map_err(MediaCacheError::Request)?;
+        let unique_id = file.meta.unique_id;
+        if let Some(path) = self.get(&unique_id) {
+            return Ok(path);
+        }
+
+        // Concurrent downloads of the same file must not write to the same file
+        let download = self.downloads.fetch_add(1, Ordering::Relaxed);
+        let partial = self.dir.join(format!("{unique_id}.{download}.{PARTIAL_EXTENSION}"));
+        let path = self.path(&unique_id);
+
+        let downloaded = async {
+            let mut destination = tokio::fs::File::create(&partial).await?;
+            bot.download_file(&file.path, &mut destination).await?;
+            destination.flush().await?;
+            drop(destination);
+            tokio::fs::rename(&partial, &path).await?;
+            Ok::<_, MediaCacheError<<R as Requester>::Err>>(())
+        };
+        if let Err(err) = downloaded.await {
+            let _ = tokio::fs::remove_file(&partial).await;
+            return Err(err);
+        }
+
+        let size = tokio::fs::metadata(&path).await?.len();
+        let evicted = {
+            let mut files = self.files.lock().unwrap();
+            files.insert(unique_id.clone(), size);
+            files.evict(self.max_size, &unique_id)
+        };
+        for unique_id in evicted {
+            remove(&self.path(&unique_id)).await;
+        }
+
+        Ok(path)
+    }
+
+    /// Returns the total size of the cached files, in bytes.
+    #[must_use]
+    pub fn size(&self) -> u64 {
+        self.files.lock().unwrap().size
+    }
+
+    fn path(&self, unique_id: &FileUniqueId) -> PathBuf {
+        self.dir.join(unique_id.as_str())
+    }
+}
+
+impl CachedFiles {
+    fn insert(&mut self, unique_id: FileUniqueId, size: u64) {
+        self.clock += 1;
+        let file = CachedFile { size, last_used: self.clock };
+        if let Some(old) = self.files.insert(unique_id, file) {
+            self.size -= old.size;
+        }
+        self.size += size;
+    }
+
+    /// Marks a file as used, returns `false` if it isn't cached.
+    fn touch(&mut self, unique_id: &FileUniqueId) -> bool {
+        let Some(file) = self.files.get_mut(unique_id) else { return false };
+
+        self.clock += 1;
+        file.last_used = self.clock;
+        true
+    }
+
+    /// Removes the least recently used files, except for `keep`, until the
+    /// total size is at most `max_size`.
+    fn evict(&mut self, max_size: u64, keep: &FileUniqueId) -> Vec<FileUniqueId> {
+        let mut evicted = Vec::new();
+        while self.size > max_size {
+            let lru = self
+                .files
+                .iter()
+                .filter(|(unique_id, _)| *unique_id != keep)
+                .min_by_key(|(_, file)| file.last_used)
+                .map(|(unique_id, _)| unique_id.clone());
+            let Some(unique_id) = lru else { break };
+
+            let file = self.files.remove(&unique_id).expect("the file was just found");
+            self.size -= file.size;
+            evicted.push(unique_id);
+        }
+        evicted
+    }
+}
+
+async fn remove(path: &Path) {
+    match tokio::fs::remove_file(path).await {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => log::error!("Couldn't remove a cached file {}: {err}", path.display()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adaptors::MockBot,
+        payloads::GetFile,
+        types::{File, FileMeta},
+    };
+
+    fn file(bot: &MockBot, dir: &Path, unique_id: &str, contents: &str) {
+        let path = dir.join(format!("{unique_id}.src"));
+        std::fs::write(&path, contents).unwrap();
+
+        let meta = FileMeta {
+            id: FileId::from(unique_id),
+            unique_id: FileUniqueId::from(unique_id),
+            size: contents.len() as u64,
+        };
+        bot.respond::<GetFile>(File { meta, path: path.to_str().unwrap().to_owned() });
+    }
+
+    #[tokio::test]
+    async fn get_or_download() {
+        let dir = std::env::temp_dir().join(format!("teloxide-media-cache-{}", std::process::id()));
+        let sources = dir.join("sources");
+        std::fs::create_dir_all(&sources).unwrap();
+        let bot = MockBot::new();
+
+        let cache = MediaCache::open(dir.join("cache"), 8).await.unwrap();
+        file(&bot, &sources, "a", "aaaa");
+        file(&bot, &sources, "a", "aaaa");
+        file(&bot, &sources, "b", "bbbb");
+        file(&bot, &sources, "c", "cccc");
+
+        let a = cache.get_or_download(&bot, "a").await.unwrap();
+        assert_eq!(std::fs::read_to_string(&a).unwrap(), "aaaa");
+        // Cached, so the source isn't read again
+        std::fs::remove_file(sources.join("a.src")).unwrap();
+        assert_eq!(cache.get_or_download(&bot, "a").await.unwrap(), a);
+
+        cache.get_or_download(&bot, "b").await.unwrap();
+        assert_eq!(cache.size(), 8);
+
+        // `a` was used last, so `b` is evicted
+        assert!(cache.get(&FileUniqueId::from("a")).is_some());
+        cache.get_or_download(&bot, "c").await.unwrap();
+        assert_eq!(cache.size(), 8);
+        assert!(cache.get(&FileUniqueId::from("b")).is_none());
+        assert!(!dir.join("cache/b").exists());
+        drop(cache);
+
+        let cache = MediaCache::open(dir.join("cache"), 8).await.unwrap();
+        assert_eq!(cache.size(), 8);
+        assert_eq!(cache.get(&FileUniqueId::from("a")), Some(a));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
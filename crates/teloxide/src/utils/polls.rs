@@ -0,0 +1,139 @@
+//! Utils for tracking answers to polls.
+//!
+//! A [`PollAnswer`] only contains the identifier of a poll, but not the chat
+//! or the message it was sent in. [`PollTracker`] remembers the messages of
+//! polls sent by the bot, so that answers can be correlated back to them.
+//!
+//! Note that Telegram sends poll answers only for non-anonymous polls sent by
+//! the bot.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use crate::types::{ChatId, Message, MessageId, PollAnswer};
+
+/// The message of a poll sent by the bot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PollMessage {
+    /// The chat the poll was sent to.
+    pub chat_id: ChatId,
+
+    /// The message with the poll.
+    pub message_id: MessageId,
+}
+
+/// Correlates poll answers with the messages of the polls.
+///
+/// Polls are kept until they are [forgotten], e.g. after they are closed.
+///
+/// ## Examples
+///
+/// ```
+/// use teloxide::{prelude::*, utils::polls::PollTracker};
+///
+/// # async fn f(bot: Bot, answer: teloxide::types::PollAnswer) -> ResponseResult<()> {
+/// let tracker = PollTracker::new();
+///
+/// // When sending a poll:
+/// let message = bot
+///     .send_poll(ChatId(1), "Tea or coffee?", ["Tea".to_owned(), "Coffee".to_owned()])
+///     .is_anonymous(false)
+///     .await?;
+/// tracker.track(&message);
+///
+/// // When receiving a poll answer:
+/// if let Some(poll) = tracker.get(&answer) {
+///     bot.send_message(poll.chat_id, format!("{} has voted", answer.user.first_name))
+///         .reply_to_message_id(poll.message_id)
+///         .await?;
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [forgotten]: PollTracker::forget
+#[derive(Debug, Default)]
+pub struct PollTracker {
+    polls: Mutex<HashMap<String, PollMessage>>,
+}
+
+impl PollTracker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remembers the poll of `message`.
+    ///
+    /// Returns `false` if the message doesn't contain a poll.
+    pub fn track(&self, message: &Message) -> bool {
+        let Some(poll) = message.poll() else { return false };
+
+        let poll_message = PollMessage { chat_id: message.chat.id, message_id: message.id };
+        self.polls.lock().unwrap().insert(poll.id.clone(), poll_message);
+        true
+    }
+
+    /// Returns the message of the poll `answer` is for, or `None` if it wasn't
+    /// tracked.
+    #[must_use]
+    pub fn get(&self, answer: &PollAnswer) -> Option<PollMessage> {
+        self.polls.lock().unwrap().get(&answer.poll_id).copied()
+    }
+
+    /// Forgets and returns the message of a poll with `poll_id`.
+    pub fn forget(&self, poll_id: &str) -> Option<PollMessage> {
+        self.polls.lock().unwrap().remove(poll_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{MediaKind, MediaPoll, MessageKind, Poll, PollType, User, UserId};
+
+    fn poll_message(chat_id: i64, poll_id: &str) -> Message {
+        let poll = Poll {
+            id: poll_id.to_owned(),
+            question: "?".to_owned(),
+            options: Vec::new(),
+            is_closed: false,
+            total_voter_count: 0,
+            is_anonymous: false,
+            poll_type: PollType::Regular,
+            allows_multiple_answers: false,
+            correct_option_id: None,
+            explanation: None,
+            explanation_entities: None,
+            open_period: None,
+            close_date: None,
+        };
+
+        let mut message = Message::text_fixture(ChatId(chat_id), UserId(1), "");
+        if let MessageKind::Common(common) = &mut message.kind {
+            common.media_kind = MediaKind::Poll(MediaPoll { poll });
+        }
+        message
+    }
+
+    fn answer(poll_id: &str) -> PollAnswer {
+        PollAnswer {
+            poll_id: poll_id.to_owned(),
+            voter_chat: None,
+            user: User::fixture(UserId(2)),
+            option_ids: vec![0],
+        }
+    }
+
+    #[test]
+    fn track() {
+        let tracker = PollTracker::new();
+        assert!(tracker.track(&poll_message(1, "a")));
+        assert!(!tracker.track(&Message::text_fixture(ChatId(1), UserId(1), "text")));
+
+        let expected = PollMessage { chat_id: ChatId(1), message_id: MessageId(1) };
+        assert_eq!(tracker.get(&answer("a")), Some(expected));
+        assert_eq!(tracker.get(&answer("b")), None);
+
+        assert_eq!(tracker.forget("a"), Some(expected));
+        assert_eq!(tracker.get(&answer("a")), None);
+    }
+}
@@ -0,0 +1,457 @@
+//! Utils for creating sticker sets.
+//!
+//! Creating a sticker set takes several requests: each PNG sticker is uploaded
+//! with [`UploadStickerFile`], the set is created with the first sticker via
+//! [`CreateNewStickerSet`], and the rest of the stickers are added one by one
+//! with [`AddStickerToSet`]. [`StickerSetBuilder`] makes these requests,
+//! checking the stickers before the first one, so that a set isn't left
+//! half-created because of an invalid file.
+//!
+//! [`UploadStickerFile`]: crate::payloads::UploadStickerFile
+//! [`CreateNewStickerSet`]: crate::payloads::CreateNewStickerSet
+//! [`AddStickerToSet`]: crate::payloads::AddStickerToSet
+
+use bytes::Bytes;
+use thiserror::Error;
+
+use crate::{
+    payloads::{AddStickerToSetSetters, CreateNewStickerSetSetters},
+    requests::{Request, Requester},
+    types::{InputFile, InputSticker, MaskPosition, StickerFormat, StickerType, UserId},
+};
+
+/// The maximal size of a PNG sticker.
+const MAX_PNG_SIZE: usize = 512 * 1024;
+
+/// The maximal size of a TGS sticker.
+const MAX_TGS_SIZE: usize = 64 * 1024;
+
+/// The maximal size of a WEBM sticker.
+const MAX_WEBM_SIZE: usize = 256 * 1024;
+
+/// The size of the longest side of a PNG sticker.
+const PNG_SIDE: u32 = 512;
+
+/// A builder of a new sticker set.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use teloxide::{
+///     prelude::*,
+///     utils::sticker_set::{NewSticker, StickerSetBuilder},
+/// };
+///
+/// # async fn run(bot: Bot, user_id: UserId) -> Result<(), Box<dyn std::error::Error>> {
+/// let report = StickerSetBuilder::new(user_id, "animals_by_my_bot", "Animals")
+///     .sticker(NewSticker::png(tokio::fs::read("cat.png").await?, "🐱"))
+///     .sticker(NewSticker::png(tokio::fs::read("dog.png").await?, "🐶"))
+///     .create_with_progress(&bot, |progress| {
+///         log::info!("Sticker {}/{} is done", progress.index + 1, progress.total);
+///     })
+///     .await?;
+///
+/// for (index, error) in &report.failed {
+///     log::warn!("Couldn't add sticker #{index}: {error}");
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct StickerSetBuilder {
+    user_id: UserId,
+    name: String,
+    title: String,
+    sticker_type: Option<StickerType>,
+    stickers: Vec<NewSticker>,
+}
+
+/// A sticker to be added to a set.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct NewSticker {
+    format: StickerFormat,
+    data: Bytes,
+    emojis: String,
+    mask_position: Option<MaskPosition>,
+}
+
+/// A sticker which can't be added to a set.
+#[derive(Clone, Debug, PartialEq, Error)]
+pub enum InvalidSticker {
+    /// The file is larger than allowed for its format.
+    #[error("The sticker is {size} bytes, but at most {max} bytes are allowed")]
+    TooLarge { size: usize, max: usize },
+
+    /// The file is not of the declared format.
+    #[error("The file is not a {0:?} sticker")]
+    WrongFormat(StickerFormat),
+
+    /// A PNG sticker doesn't fit 512x512, or none of its sides is 512px.
+    #[error("The sticker is {width}x{height}, but it must fit 512x512 with a side of 512px")]
+    WrongDimensions { width: u32, height: u32 },
+
+    /// The sticker has no emojis.
+    #[error("The sticker has no emojis")]
+    NoEmojis,
+}
+
+/// An error returned by [`StickerSetBuilder::create`].
+#[derive(Debug, Error)]
+pub enum StickerSetError<E> {
+    /// The set has no stickers.
+    #[error("A sticker set must have at least one sticker")]
+    NoStickers,
+
+    /// Some stickers are invalid, with their positions. No requests were
+    /// made.
+    #[error("{} stickers are invalid", .0.len())]
+    InvalidStickers(Vec<(usize, InvalidSticker)>),
+
+    /// Uploading the first sticker or creating the set has failed.
+    #[error("Couldn't create the sticker set: {0}")]
+    Create(#[source] E),
+}
+
+/// Progress of creating a sticker set, see
+/// [`StickerSetBuilder::create_with_progress`].
+#[derive(Debug)]
+pub struct StickerProgress<'a, E> {
+    /// The position of the sticker.
+    pub index: usize,
+
+    /// The number of stickers in the set.
+    pub total: usize,
+
+    /// The result of adding the sticker.
+    pub result: Result<(), &'a E>,
+}
+
+/// A summary of creating a sticker set.
+#[derive(Debug)]
+pub struct StickerSetReport<E> {
+    /// Stickers which were not added to the set, with their positions and
+    /// errors.
+    pub failed: Vec<(usize, E)>,
+}
+
+impl StickerSetBuilder {
+    /// Creates a builder of a set `name` with `title`, owned by `user_id`.
+    ///
+    /// The name must end with `_by_<bot username>`.
+    pub fn new(user_id: UserId, name: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            user_id,
+            name: name.into(),
+            title: title.into(),
+            sticker_type: None,
+            stickers: vec![],
+        }
+    }
+
+    /// Sets the type of stickers in the set.
+    ///
+    /// By default, a regular sticker set is created.
+    pub fn sticker_type(self, val: StickerType) -> Self {
+        Self { sticker_type: Some(val), ..self }
+    }
+
+    /// Adds a sticker to the set.
+    pub fn sticker(mut self, sticker: NewSticker) -> Self {
+        self.stickers.push(sticker);
+        self
+    }
+
+    /// Adds stickers to the set.
+    pub fn stickers(mut self, stickers: impl IntoIterator<Item = NewSticker>) -> Self {
+        self.stickers.extend(stickers);
+        self
+    }
+
+    /// Checks all the stickers, returning the invalid ones with their
+    /// positions.
+    #[must_use]
+    pub fn validate(&self) -> Vec<(usize, InvalidSticker)> {
+        self.stickers
+            .iter()
+            .enumerate()
+            .filter_map(|(index, sticker)| Some((index, sticker.validate().err()?)))
+            .collect()
+    }
+
+    /// Creates the set and adds all the stickers to it.
+    ///
+    /// See [`StickerSetBuilder::create_with_progress`].
+    pub async fn create<R>(
+        self,
+        bot: &R,
+    ) -> Result<StickerSetReport<R::Err>, StickerSetError<R::Err>>
+    where
+        R: Requester,
+    {
+        self.create_with_progress(bot, |_| {}).await
+    }
+
+    /// Creates the set and adds all the stickers to it, calling `progress`
+    /// after each sticker.
+    ///
+    /// The stickers are [validated] first, and no requests are made if some
+    /// of them are invalid. If uploading the first sticker or creating the set
+    /// fails, an error is returned. Errors of the rest of the stickers are
+    /// collected in the returned report, so that they can be added later.
+    ///
+    /// [validated]: StickerSetBuilder::validate
+    pub async fn create_with_progress<R, F>(
+        self,
+        bot: &R,
+        mut progress: F,
+    ) -> Result<StickerSetReport<R::Err>, StickerSetError<R::Err>>
+    where
+        R: Requester,
+        F: FnMut(StickerProgress<'_, R::Err>),
+    {
+        let invalid = self.validate();
+        if !invalid.is_empty() {
+            return Err(StickerSetError::InvalidStickers(invalid));
+        }
+
+        let Self { user_id, name, title, sticker_type, stickers } = self;
+        let total = stickers.len();
+        let mut stickers = stickers.into_iter().enumerate();
+        let Some((_, first)) = stickers.next() else { return Err(StickerSetError::NoStickers) };
+
+        let mask_position = first.mask_position;
+        let emojis = first.emojis.clone();
+        let input = first.upload(bot, user_id).await.map_err(StickerSetError::Create)?;
+        let mut request = bot.create_new_sticker_set(user_id, &name, title, input, emojis);
+        if let Some(sticker_type) = sticker_type {
+            request = request.sticker_type(sticker_type);
+        }
+        if let Some(mask_position) = mask_position {
+            request = request.mask_position(mask_position);
+        }
+        request.send().await.map_err(StickerSetError::Create)?;
+        progress(StickerProgress { index: 0, total, result: Ok(()) });
+
+        let mut report = StickerSetReport { failed: vec![] };
+        for (index, sticker) in stickers {
+            let result = sticker.add(bot, user_id, &name).await;
+            progress(StickerProgress { index, total, result: result.as_ref().map(drop) });
+            if let Err(err) = result {
+                report.failed.push((index, err));
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+impl NewSticker {
+    /// Creates a PNG sticker.
+    ///
+    /// It must be up to 512 kilobytes in size, must fit 512x512, and either
+    /// its width or its height must be exactly 512px.
+    pub fn png(data: impl Into<Bytes>, emojis: impl Into<String>) -> Self {
+        Self::new(StickerFormat::Raster, data.into(), emojis.into())
+    }
+
+    /// Creates an animated TGS sticker.
+    ///
+    /// It must be up to 64 kilobytes in size. See
+    /// <https://core.telegram.org/stickers#animated-sticker-requirements> for
+    /// other requirements.
+    pub fn tgs(data: impl Into<Bytes>, emojis: impl Into<String>) -> Self {
+        Self::new(StickerFormat::Animated, data.into(), emojis.into())
+    }
+
+    /// Creates a video WEBM sticker.
+    ///
+    /// It must be up to 256 kilobytes in size. See
+    /// <https://core.telegram.org/stickers#video-sticker-requirements> for
+    /// other requirements.
+    pub fn webm(data: impl Into<Bytes>, emojis: impl Into<String>) -> Self {
+        Self::new(StickerFormat::Video, data.into(), emojis.into())
+    }
+
+    fn new(format: StickerFormat, data: Bytes, emojis: String) -> Self {
+        Self { format, data, emojis, mask_position: None }
+    }
+
+    /// Sets the position of a mask sticker.
+    pub fn mask_position(self, val: MaskPosition) -> Self {
+        Self { mask_position: Some(val), ..self }
+    }
+
+    /// Checks the size and the format of the sticker, and the dimensions of a
+    /// PNG sticker.
+    pub fn validate(&self) -> Result<(), InvalidSticker> {
+        const PNG_MAGIC: &[u8] = b"\x89PNG\r\n\x1a\n";
+        const GZIP_MAGIC: &[u8] = b"\x1f\x8b";
+        const EBML_MAGIC: &[u8] = b"\x1a\x45\xdf\xa3";
+
+        if self.emojis.is_empty() {
+            return Err(InvalidSticker::NoEmojis);
+        }
+
+        let (magic, max) = match self.format {
+            StickerFormat::Raster => (PNG_MAGIC, MAX_PNG_SIZE),
+            StickerFormat::Animated => (GZIP_MAGIC, MAX_TGS_SIZE),
+            StickerFormat::Video => (EBML_MAGIC, MAX_WEBM_SIZE),
+        };
+        if !self.data.starts_with(magic) {
+            return Err(InvalidSticker::WrongFormat(self.format.clone()));
+        }
+        if self.data.len() > max {
+            return Err(InvalidSticker::TooLarge { size: self.data.len(), max });
+        }
+
+        if self.format == StickerFormat::Raster {
+            let (width, height) = png_dimensions(&self.data)
+                .ok_or(InvalidSticker::WrongFormat(StickerFormat::Raster))?;
+            if width > PNG_SIDE || height > PNG_SIDE || width.max(height) != PNG_SIDE {
+                return Err(InvalidSticker::WrongDimensions { width, height });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Uploads a PNG sticker, so that it isn't uploaded again if adding it
+    /// fails.
+    async fn upload<R>(self, bot: &R, user_id: UserId) -> Result<InputSticker, R::Err>
+    where
+        R: Requester,
+    {
+        let file = InputFile::memory(self.data);
+        Ok(match self.format {
+            StickerFormat::Raster => {
+                let meta = bot.upload_sticker_file(user_id, file).send().await?;
+                InputSticker::Png(InputFile::file_id(meta.id))
+            }
+            StickerFormat::Animated => InputSticker::Tgs(file),
+            StickerFormat::Video => InputSticker::Webm(file),
+        })
+    }
+
+    async fn add<R>(self, bot: &R, user_id: UserId, name: &str) -> Result<(), R::Err>
+    where
+        R: Requester,
+    {
+        let mask_position = self.mask_position;
+        let emojis = self.emojis.clone();
+        let input = self.upload(bot, user_id).await?;
+
+        let mut request = bot.add_sticker_to_set(user_id, name, input, emojis);
+        if let Some(mask_position) = mask_position {
+            request = request.mask_position(mask_position);
+        }
+        request.send().await?;
+        Ok(())
+    }
+}
+
+/// Returns the width and the height of a PNG image, from its `IHDR` chunk.
+fn png_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let ihdr = data.get(12..24)?;
+    if &ihdr[..4] != b"IHDR" {
+        return None;
+    }
+
+    let width = u32::from_be_bytes(ihdr[4..8].try_into().ok()?);
+    let height = u32::from_be_bytes(ihdr[8..12].try_into().ok()?);
+    Some((width, height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        adaptors::MockBot,
+        payloads::{AddStickerToSet, CreateNewStickerSet, UploadStickerFile},
+        types::{FileId, FileMeta, FileUniqueId, True},
+        ApiError, RequestError,
+    };
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut data = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        data.extend(width.to_be_bytes());
+        data.extend(height.to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(NewSticker::png(png(512, 300), "🐱").validate(), Ok(()));
+        assert_eq!(
+            NewSticker::png(png(300, 300), "🐱").validate(),
+            Err(InvalidSticker::WrongDimensions { width: 300, height: 300 })
+        );
+        assert_eq!(
+            NewSticker::png(png(1024, 512), "🐱").validate(),
+            Err(InvalidSticker::WrongDimensions { width: 1024, height: 512 })
+        );
+        assert_eq!(NewSticker::png(png(512, 512), "").validate(), Err(InvalidSticker::NoEmojis));
+        assert_eq!(
+            NewSticker::tgs(png(512, 512), "🐱").validate(),
+            Err(InvalidSticker::WrongFormat(StickerFormat::Animated))
+        );
+
+        let mut tgs = b"\x1f\x8b".to_vec();
+        tgs.resize(MAX_TGS_SIZE + 1, 0);
+        assert_eq!(
+            NewSticker::tgs(tgs, "🐱").validate(),
+            Err(InvalidSticker::TooLarge { size: MAX_TGS_SIZE + 1, max: MAX_TGS_SIZE })
+        );
+    }
+
+    #[tokio::test]
+    async fn create() {
+        let bot = MockBot::new();
+        for id in ["a", "b"] {
+            let meta =
+                FileMeta { id: FileId::from(id), unique_id: FileUniqueId::from(id), size: 0 };
+            bot.respond::<UploadStickerFile>(meta);
+        }
+        bot.respond::<CreateNewStickerSet>(True);
+        bot.respond_err::<AddStickerToSet>(RequestError::Api(ApiError::StickerSetNameOccupied));
+        bot.respond::<AddStickerToSet>(True);
+
+        let mut progress = vec![];
+        let report = StickerSetBuilder::new(UserId(1), "set_by_bot", "Set")
+            .sticker(NewSticker::png(png(512, 512), "🐱"))
+            .sticker(NewSticker::png(png(512, 512), "🐶"))
+            .sticker(NewSticker::tgs(b"\x1f\x8b".to_vec(), "🐭"))
+            .create_with_progress(&bot, |p| progress.push((p.index, p.total, p.result.is_ok())))
+            .await
+            .unwrap();
+
+        assert_eq!(progress, [(0, 3, true), (1, 3, false), (2, 3, true)]);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, 1);
+
+        let created = &bot.requests_of::<CreateNewStickerSet>()[0];
+        assert_eq!(created.name, "set_by_bot");
+        assert!(matches!(&created.sticker, InputSticker::Png(_)));
+        let added = bot.requests_of::<AddStickerToSet>();
+        assert_eq!(added.iter().map(|p| p.emojis.as_str()).collect::<Vec<_>>(), ["🐶", "🐭"]);
+        assert!(matches!(&added[1].sticker, InputSticker::Tgs(_)));
+    }
+
+    #[tokio::test]
+    async fn create_invalid() {
+        let bot = MockBot::new();
+        let result = StickerSetBuilder::new(UserId(1), "set_by_bot", "Set")
+            .sticker(NewSticker::png(png(512, 512), "🐱"))
+            .sticker(NewSticker::png(png(100, 100), "🐶"))
+            .create(&bot)
+            .await;
+
+        assert!(
+            matches!(result, Err(StickerSetError::InvalidStickers(invalid)) if invalid.len() == 1)
+        );
+        assert!(bot.request_names().is_empty());
+
+        let result = StickerSetBuilder::new(UserId(1), "set_by_bot", "Set").create(&bot).await;
+        assert!(matches!(result, Err(StickerSetError::NoStickers)));
+    }
+}
@@ -81,6 +81,15 @@ where
 
     test_dialogues!(storage, None, None, None);
 
+    Arc::clone(&storage).update_dialogue(ChatId(1), "ABC".to_owned()).await.unwrap();
+    Arc::clone(&storage).update_dialogue(ChatId(11), "DEF".to_owned()).await.unwrap();
+    Arc::clone(&storage).migrate_chat(ChatId(1), ChatId(11)).await.unwrap();
+    Arc::clone(&storage).migrate_chat(ChatId(256), ChatId(1)).await.unwrap();
+
+    test_dialogues!(storage, None, Some("ABC".to_owned()), None);
+
+    Arc::clone(&storage).remove_dialogue(ChatId(11)).await.unwrap();
+
     // Check that a try to remove a non-existing dialogue results in an error.
     assert!(matches!(
         Arc::clone(&storage).remove_dialogue(ChatId(1)).await.unwrap_err(),